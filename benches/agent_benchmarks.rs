@@ -109,6 +109,8 @@ impl Tool for NoopTool {
             success: true,
             output: String::new(),
             error: None,
+            metadata: None,
+            content_type: None,
         })
     }
 }
@@ -138,7 +140,7 @@ fn make_observer() -> Arc<dyn Observer> {
 // ─────────────────────────────────────────────────────────────────────────────
 
 fn bench_xml_parsing(c: &mut Criterion) {
-    let dispatcher = XmlToolDispatcher;
+    let dispatcher = XmlToolDispatcher::default();
 
     let single_tool = ChatResponse {
         text: Some(
@@ -1,8 +1,8 @@
 /*
  * ZeroClaw Arduino Uno Firmware
  *
- * Listens for JSON commands on Serial (115200 baud), executes gpio_read/gpio_write,
- * responds with JSON. Compatible with ZeroClaw SerialPeripheral protocol.
+ * Listens for JSON commands on Serial (115200 baud), executes gpio_read/gpio_write
+ * and i2c_scan, responds with JSON. Compatible with ZeroClaw SerialPeripheral protocol.
  *
  * Protocol (newline-delimited JSON):
  *   Request:  {"id":"1","cmd":"gpio_write","args":{"pin":13,"value":1}}
@@ -16,6 +16,8 @@
  * 4. Upload
  */
 
+#include <Wire.h>
+
 #define BAUDRATE 115200
 #define MAX_LINE 256
 
@@ -75,6 +77,24 @@ void handleLine(const char* line) {
     return;
   }
 
+  // Probe every 7-bit address; result is a JSON array of responders (decimal).
+  if (hasCmd(line, "i2c_scan")) {
+    Serial.print("{\"id\":\"");
+    Serial.print(idBuf);
+    Serial.print("\",\"ok\":true,\"result\":\"[");
+    bool first = true;
+    for (uint8_t addr = 0x08; addr < 0x78; addr++) {
+      Wire.beginTransmission(addr);
+      if (Wire.endTransmission() == 0) {
+        if (!first) Serial.print(",");
+        Serial.print(addr);
+        first = false;
+      }
+    }
+    Serial.println("]\"}");
+    return;
+  }
+
   if (hasCmd(line, "gpio_read")) {
     int pin = parseArg("pin", line);
     if (pin < 0 || pin > 13) {
@@ -122,6 +142,7 @@ void handleLine(const char* line) {
 
 void setup() {
   Serial.begin(BAUDRATE);
+  Wire.begin();
   lineLen = 0;
 }
 
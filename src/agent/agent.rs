@@ -1,6 +1,7 @@
 use crate::agent::dispatcher::{
     NativeToolDispatcher, ParsedToolCall, ToolDispatcher, ToolExecutionResult, XmlToolDispatcher,
 };
+use crate::agent::loop_::{run_tool_call, ToolCallContext};
 use crate::agent::memory_loader::{DefaultMemoryLoader, MemoryLoader};
 use crate::agent::prompt::{PromptContext, SystemPromptBuilder};
 use crate::config::Config;
@@ -8,14 +9,19 @@ use crate::memory::{self, Memory, MemoryCategory};
 use crate::observability::{self, Observer, ObserverEvent};
 use crate::providers::{self, ChatMessage, ChatRequest, ConversationMessage, Provider};
 use crate::runtime;
+use crate::security::AuditLogger;
 use crate::security::SecurityPolicy;
 use crate::tools::{self, Tool, ToolSpec};
 use crate::util::truncate_with_ellipsis;
 use anyhow::Result;
+use std::collections::HashMap;
 use std::io::Write as IoWrite;
 use std::sync::Arc;
 use std::time::Instant;
 
+/// Channel recorded in audit entries for tool calls made through [`Agent`].
+const AUDIT_CHANNEL: &str = "agent";
+
 pub struct Agent {
     provider: Box<dyn Provider>,
     tools: Vec<Box<dyn Tool>>,
@@ -28,6 +34,7 @@ pub struct Agent {
     config: crate::config::AgentConfig,
     model_name: String,
     temperature: f64,
+    model_routes: Vec<crate::config::ModelRouteConfig>,
     workspace_dir: std::path::PathBuf,
     identity_config: crate::config::IdentityConfig,
     skills: Vec<crate::skills::Skill>,
@@ -35,6 +42,8 @@ pub struct Agent {
     history: Vec<ConversationMessage>,
     classification_config: crate::config::QueryClassificationConfig,
     available_hints: Vec<String>,
+    audit_log: Option<Arc<AuditLogger>>,
+    secret_refs: HashMap<String, String>,
 }
 
 pub struct AgentBuilder {
@@ -48,12 +57,15 @@ pub struct AgentBuilder {
     config: Option<crate::config::AgentConfig>,
     model_name: Option<String>,
     temperature: Option<f64>,
+    model_routes: Option<Vec<crate::config::ModelRouteConfig>>,
     workspace_dir: Option<std::path::PathBuf>,
     identity_config: Option<crate::config::IdentityConfig>,
     skills: Option<Vec<crate::skills::Skill>>,
     auto_save: Option<bool>,
     classification_config: Option<crate::config::QueryClassificationConfig>,
     available_hints: Option<Vec<String>>,
+    audit_log: Option<Arc<AuditLogger>>,
+    secret_refs: Option<HashMap<String, String>>,
 }
 
 impl AgentBuilder {
@@ -69,12 +81,15 @@ impl AgentBuilder {
             config: None,
             model_name: None,
             temperature: None,
+            model_routes: None,
             workspace_dir: None,
             identity_config: None,
             skills: None,
             auto_save: None,
             classification_config: None,
             available_hints: None,
+            audit_log: None,
+            secret_refs: None,
         }
     }
 
@@ -128,6 +143,13 @@ impl AgentBuilder {
         self
     }
 
+    /// `[[model_routes]]`; a matching route's `temperature` replaces the
+    /// default for that model.
+    pub fn model_routes(mut self, model_routes: Vec<crate::config::ModelRouteConfig>) -> Self {
+        self.model_routes = Some(model_routes);
+        self
+    }
+
     pub fn workspace_dir(mut self, workspace_dir: std::path::PathBuf) -> Self {
         self.workspace_dir = Some(workspace_dir);
         self
@@ -161,6 +183,18 @@ impl AgentBuilder {
         self
     }
 
+    /// Write every tool execution to this `[security.audit]` log.
+    pub fn audit_log(mut self, audit_log: Option<Arc<AuditLogger>>) -> Self {
+        self.audit_log = audit_log;
+        self
+    }
+
+    /// Decrypted `[secrets.refs]` that tool-call placeholders resolve against.
+    pub fn secret_refs(mut self, secret_refs: HashMap<String, String>) -> Self {
+        self.secret_refs = Some(secret_refs);
+        self
+    }
+
     pub fn build(self) -> Result<Agent> {
         let tools = self
             .tools
@@ -193,6 +227,7 @@ impl AgentBuilder {
                 .model_name
                 .unwrap_or_else(|| "anthropic/claude-sonnet-4-20250514".into()),
             temperature: self.temperature.unwrap_or(0.7),
+            model_routes: self.model_routes.unwrap_or_default(),
             workspace_dir: self
                 .workspace_dir
                 .unwrap_or_else(|| std::path::PathBuf::from(".")),
@@ -202,6 +237,8 @@ impl AgentBuilder {
             history: Vec::new(),
             classification_config: self.classification_config.unwrap_or_default(),
             available_hints: self.available_hints.unwrap_or_default(),
+            audit_log: self.audit_log,
+            secret_refs: self.secret_refs.unwrap_or_default(),
         })
     }
 }
@@ -248,7 +285,7 @@ impl Agent {
             None
         };
 
-        let tools = tools::all_tools_with_runtime(
+        let mut tools = tools::all_tools_with_runtime(
             Arc::new(config.clone()),
             &security,
             runtime,
@@ -262,6 +299,7 @@ impl Agent {
             config.api_key.as_deref(),
             config,
         );
+        tools::register_self_info(&mut tools, config);
 
         let provider_name = config.default_provider.as_deref().unwrap_or("openrouter");
 
@@ -271,26 +309,40 @@ impl Agent {
             .unwrap_or("anthropic/claude-sonnet-4-20250514")
             .to_string();
 
-        let provider: Box<dyn Provider> = providers::create_routed_provider(
+        let provider: Box<dyn Provider> = providers::create_routed_provider_with_options(
             provider_name,
             config.api_key.as_deref(),
             config.api_url.as_deref(),
             &config.reliability,
             &config.model_routes,
             &model_name,
+            &providers::ProviderRuntimeOptions {
+                azure_openai: config.azure_openai.clone(),
+                ..providers::ProviderRuntimeOptions::default()
+            },
         )?;
 
         let dispatcher_choice = config.agent.tool_dispatcher.as_str();
         let tool_dispatcher: Box<dyn ToolDispatcher> = match dispatcher_choice {
             "native" => Box::new(NativeToolDispatcher),
-            "xml" => Box::new(XmlToolDispatcher),
+            "xml" => Box::new(XmlToolDispatcher::new(
+                config.agent.tool_call_formats.clone(),
+            )),
             _ if provider.supports_native_tools() => Box::new(NativeToolDispatcher),
-            _ => Box::new(XmlToolDispatcher),
+            _ => Box::new(XmlToolDispatcher::new(
+                config.agent.tool_call_formats.clone(),
+            )),
         };
 
         let available_hints: Vec<String> =
             config.model_routes.iter().map(|r| r.hint.clone()).collect();
 
+        // Ephemeral runs neither read nor write persistent preferences.
+        let mut prompt_builder = SystemPromptBuilder::with_defaults();
+        if config.agent.ephemeral {
+            prompt_builder = prompt_builder.without_section("preferences");
+        }
+
         Agent::builder()
             .provider(provider)
             .tools(tools)
@@ -298,19 +350,25 @@ impl Agent {
             .observer(observer)
             .tool_dispatcher(tool_dispatcher)
             .memory_loader(Box::new(DefaultMemoryLoader::new(
-                5,
+                config.memory.recall_limit,
                 config.memory.min_relevance_score,
             )))
-            .prompt_builder(SystemPromptBuilder::with_defaults())
+            .prompt_builder(prompt_builder)
             .config(config.agent.clone())
             .model_name(model_name)
             .temperature(config.default_temperature)
+            .model_routes(config.model_routes.clone())
             .workspace_dir(config.workspace_dir.clone())
             .classification_config(config.query_classification.clone())
             .available_hints(available_hints)
             .identity_config(config.identity.clone())
             .skills(crate::skills::load_skills(&config.workspace_dir))
             .auto_save(config.memory.auto_save)
+            .audit_log(AuditLogger::for_run(
+                &config.security.audit,
+                &config.config_path,
+            )?)
+            .secret_refs(config.secrets.refs.clone())
             .build()
     }
 
@@ -333,8 +391,15 @@ impl Agent {
         }
 
         if other_messages.len() > max {
-            let drop_count = other_messages.len() - max;
-            other_messages.drain(0..drop_count);
+            let mut drop_count = other_messages.len() - max;
+            other_messages.retain(|msg| {
+                if drop_count == 0 || matches!(msg, ConversationMessage::Chat(chat) if chat.pinned)
+                {
+                    return true;
+                }
+                drop_count -= 1;
+                false
+            });
         }
 
         self.history = system_messages;
@@ -355,38 +420,24 @@ impl Agent {
     }
 
     async fn execute_tool_call(&self, call: &ParsedToolCall) -> ToolExecutionResult {
-        let start = Instant::now();
-
-        let result = if let Some(tool) = self.tools.iter().find(|t| t.name() == call.name) {
-            match tool.execute(call.arguments.clone()).await {
-                Ok(r) => {
-                    self.observer.record_event(&ObserverEvent::ToolCall {
-                        tool: call.name.clone(),
-                        duration: start.elapsed(),
-                        success: r.success,
-                    });
-                    if r.success {
-                        r.output
-                    } else {
-                        format!("Error: {}", r.error.unwrap_or(r.output))
-                    }
-                }
-                Err(e) => {
-                    self.observer.record_event(&ObserverEvent::ToolCall {
-                        tool: call.name.clone(),
-                        duration: start.elapsed(),
-                        success: false,
-                    });
-                    format!("Error executing {}: {e}", call.name)
-                }
-            }
-        } else {
-            format!("Unknown tool: {}", call.name)
-        };
+        let run = run_tool_call(
+            &call.name,
+            &call.arguments,
+            &ToolCallContext {
+                tools: &self.tools,
+                observer: self.observer.as_ref(),
+                audit_log: self.audit_log.as_deref(),
+                channel_name: AUDIT_CHANNEL,
+                arg_validation: self.config.tool_arg_validation,
+                secret_refs: &self.secret_refs,
+                silent: true,
+            },
+        )
+        .await;
 
         ToolExecutionResult {
             name: call.name.clone(),
-            output: result,
+            output: run.output,
             success: true,
             tool_call_id: call.tool_call_id.clone(),
         }
@@ -418,6 +469,16 @@ impl Agent {
         self.model_name.clone()
     }
 
+    /// Temperature for `model` (possibly a `hint:*` route): the matching
+    /// route's `temperature`, otherwise the agent default.
+    fn temperature_for(&self, model: &str) -> f64 {
+        self.model_routes
+            .iter()
+            .filter(|route| route.matches_model(model))
+            .find_map(|route| route.temperature)
+            .unwrap_or(self.temperature)
+    }
+
     pub async fn turn(&mut self, user_message: &str) -> Result<String> {
         if self.history.is_empty() {
             let system_prompt = self.build_system_prompt()?;
@@ -428,10 +489,13 @@ impl Agent {
         }
 
         if self.auto_save {
-            let _ = self
-                .memory
-                .store("user_msg", user_message, MemoryCategory::Conversation, None)
-                .await;
+            let _ = memory::store_autosave(
+                self.memory.as_ref(),
+                &memory::autosave_memory_key("user_msg"),
+                user_message,
+                MemoryCategory::Conversation,
+            )
+            .await;
         }
 
         let context = self
@@ -450,6 +514,9 @@ impl Agent {
             .push(ConversationMessage::Chat(ChatMessage::user(enriched)));
 
         let effective_model = self.classify_model(user_message);
+        let temperature = self.temperature_for(&effective_model);
+
+        let params = self.config.generation_params();
 
         for _ in 0..self.config.max_tool_iterations {
             let messages = self.tool_dispatcher.to_provider_messages(&self.history);
@@ -463,9 +530,10 @@ impl Agent {
                         } else {
                             None
                         },
+                        params: Some(&params),
                     },
                     &effective_model,
-                    self.temperature,
+                    temperature,
                 )
                 .await
             {
@@ -489,10 +557,13 @@ impl Agent {
 
                 if self.auto_save {
                     let summary = truncate_with_ellipsis(&final_text, 100);
-                    let _ = self
-                        .memory
-                        .store("assistant_resp", &summary, MemoryCategory::Daily, None)
-                        .await;
+                    let _ = memory::store_autosave(
+                        self.memory.as_ref(),
+                        &memory::autosave_memory_key("assistant_resp"),
+                        &summary,
+                        MemoryCategory::Daily,
+                    )
+                    .await;
                 }
 
                 return Ok(final_text);
@@ -669,6 +740,8 @@ mod tests {
                 success: true,
                 output: "tool-out".into(),
                 error: None,
+                metadata: None,
+                content_type: None,
             })
         }
     }
@@ -696,7 +769,7 @@ mod tests {
             .tools(vec![Box::new(MockTool)])
             .memory(mem)
             .observer(observer)
-            .tool_dispatcher(Box::new(XmlToolDispatcher))
+            .tool_dispatcher(Box::new(XmlToolDispatcher::default()))
             .workspace_dir(std::path::PathBuf::from("/tmp"))
             .build()
             .unwrap();
@@ -1,3 +1,4 @@
+use crate::config::ToolCallFormat;
 use crate::providers::{ChatMessage, ChatResponse, ConversationMessage, ToolResultMessage};
 use crate::tools::{Tool, ToolSpec};
 use serde_json::Value;
@@ -26,59 +27,16 @@ pub trait ToolDispatcher: Send + Sync {
     fn should_send_tool_specs(&self) -> bool;
 }
 
+/// Reads tool calls from the response text, trying each of
+/// `agent.tool_call_formats` in order (empty means the default order).
 #[derive(Default)]
-pub struct XmlToolDispatcher;
+pub struct XmlToolDispatcher {
+    formats: Vec<ToolCallFormat>,
+}
 
 impl XmlToolDispatcher {
-    fn parse_xml_tool_calls(response: &str) -> (String, Vec<ParsedToolCall>) {
-        let mut text_parts = Vec::new();
-        let mut calls = Vec::new();
-        let mut remaining = response;
-
-        while let Some(start) = remaining.find("<tool_call>") {
-            let before = &remaining[..start];
-            if !before.trim().is_empty() {
-                text_parts.push(before.trim().to_string());
-            }
-
-            if let Some(end) = remaining[start..].find("</tool_call>") {
-                let inner = &remaining[start + 11..start + end];
-                match serde_json::from_str::<Value>(inner.trim()) {
-                    Ok(parsed) => {
-                        let name = parsed
-                            .get("name")
-                            .and_then(Value::as_str)
-                            .unwrap_or("")
-                            .to_string();
-                        if name.is_empty() {
-                            remaining = &remaining[start + end + 12..];
-                            continue;
-                        }
-                        let arguments = parsed
-                            .get("arguments")
-                            .cloned()
-                            .unwrap_or_else(|| Value::Object(serde_json::Map::new()));
-                        calls.push(ParsedToolCall {
-                            name,
-                            arguments,
-                            tool_call_id: None,
-                        });
-                    }
-                    Err(e) => {
-                        tracing::warn!("Malformed <tool_call> JSON: {e}");
-                    }
-                }
-                remaining = &remaining[start + end + 12..];
-            } else {
-                break;
-            }
-        }
-
-        if !remaining.trim().is_empty() {
-            text_parts.push(remaining.trim().to_string());
-        }
-
-        (text_parts.join("\n"), calls)
+    pub fn new(formats: Vec<ToolCallFormat>) -> Self {
+        Self { formats }
     }
 
     pub fn tool_specs(tools: &[Box<dyn Tool>]) -> Vec<ToolSpec> {
@@ -88,8 +46,17 @@ impl XmlToolDispatcher {
 
 impl ToolDispatcher for XmlToolDispatcher {
     fn parse_response(&self, response: &ChatResponse) -> (String, Vec<ParsedToolCall>) {
-        let text = response.text_or_empty();
-        Self::parse_xml_tool_calls(text)
+        let (text, calls) =
+            crate::agent::loop_::parse_tool_calls(response.text_or_empty(), &self.formats);
+        let calls = calls
+            .into_iter()
+            .map(|call| ParsedToolCall {
+                name: call.name,
+                arguments: call.arguments,
+                tool_call_id: None,
+            })
+            .collect();
+        (text, calls)
     }
 
     fn format_results(&self, results: &[ToolExecutionResult]) -> ConversationMessage {
@@ -115,14 +82,25 @@ impl ToolDispatcher for XmlToolDispatcher {
         );
         instructions.push_str("### Available Tools\n\n");
 
-        for tool in tools {
-            let _ = writeln!(
-                instructions,
-                "- **{}**: {}\n  Parameters: `{}`",
-                tool.name(),
-                tool.description(),
-                tool.parameters_schema()
-            );
+        let groups = crate::tools::group_by_category(tools);
+        let show_headings = groups.len() > 1;
+        for (category, group) in groups {
+            if show_headings {
+                let _ = writeln!(
+                    instructions,
+                    "\n#### {}\n",
+                    crate::tools::category_heading(category)
+                );
+            }
+            for tool in group {
+                let _ = writeln!(
+                    instructions,
+                    "- **{}**: {}\n  Parameters: `{}`",
+                    tool.name(),
+                    tool.description(),
+                    tool.parameters_schema()
+                );
+            }
         }
 
         instructions
@@ -244,12 +222,28 @@ mod tests {
             ),
             tool_calls: vec![],
         };
-        let dispatcher = XmlToolDispatcher;
+        let dispatcher = XmlToolDispatcher::default();
         let (_, calls) = dispatcher.parse_response(&response);
         assert_eq!(calls.len(), 1);
         assert_eq!(calls[0].name, "shell");
     }
 
+    #[test]
+    fn xml_dispatcher_honors_configured_formats() {
+        let response = ChatResponse {
+            text: Some(
+                "<function_call>{\"name\":\"shell\",\"arguments\":{}}</function_call>".into(),
+            ),
+            tool_calls: vec![],
+        };
+        let (_, calls) = XmlToolDispatcher::default().parse_response(&response);
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].name, "shell");
+
+        let glm_only = XmlToolDispatcher::new(vec![ToolCallFormat::Glm]);
+        assert!(glm_only.parse_response(&response).1.is_empty());
+    }
+
     #[test]
     fn native_dispatcher_roundtrip() {
         let response = ChatResponse {
@@ -282,7 +276,7 @@ mod tests {
 
     #[test]
     fn xml_format_results_contains_tool_result_tags() {
-        let dispatcher = XmlToolDispatcher;
+        let dispatcher = XmlToolDispatcher::default();
         let msg = dispatcher.format_results(&[ToolExecutionResult {
             name: "shell".into(),
             output: "ok".into(),
@@ -0,0 +1,198 @@
+//! Workspace file listing injected into the system prompt when
+//! `agent.include_file_tree` is set, so the model can orient itself without
+//! spending tool calls on `ls`/`find`.
+
+use crate::config::Config;
+use crate::security::SecurityPolicy;
+use std::fmt::Write;
+use std::path::Path;
+
+/// Character budget for the listing (smaller under `compact_context`).
+const FILE_TREE_MAX_CHARS: usize = 8000;
+const COMPACT_FILE_TREE_MAX_CHARS: usize = 2000;
+
+/// One `.gitignore`-style pattern. Negations (`!`) are not supported.
+struct IgnorePattern {
+    pattern: glob::Pattern,
+    dir_only: bool,
+    /// Matched against the workspace-relative path instead of the name.
+    anchored: bool,
+}
+
+impl IgnorePattern {
+    fn parse(line: &str) -> Option<Self> {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') || line.starts_with('!') {
+            return None;
+        }
+        let dir_only = line.ends_with('/');
+        let line = line.trim_end_matches('/');
+        let anchored = line.contains('/');
+        let pattern = glob::Pattern::new(line.trim_start_matches('/')).ok()?;
+        Some(Self {
+            pattern,
+            dir_only,
+            anchored,
+        })
+    }
+
+    fn matches(&self, rel_path: &str, name: &str, is_dir: bool) -> bool {
+        if self.dir_only && !is_dir {
+            return false;
+        }
+        if self.anchored {
+            self.pattern.matches(rel_path)
+        } else {
+            self.pattern.matches(name)
+        }
+    }
+}
+
+struct TreeWalk<'a> {
+    security: &'a SecurityPolicy,
+    ignore: &'a [IgnorePattern],
+    max_depth: usize,
+    budget: usize,
+    listing: String,
+    omitted: usize,
+}
+
+impl TreeWalk<'_> {
+    fn walk(&mut self, dir: &Path, rel_dir: &str, depth: usize) {
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            return;
+        };
+        let mut entries: Vec<_> = entries.filter_map(Result::ok).collect();
+        entries.sort_by_key(std::fs::DirEntry::file_name);
+
+        for entry in entries {
+            let name = entry.file_name().to_string_lossy().into_owned();
+            if name == ".git" {
+                continue;
+            }
+            let rel_path = if rel_dir.is_empty() {
+                name.clone()
+            } else {
+                format!("{rel_dir}/{name}")
+            };
+            // Symlinks are listed but never followed, so the walk can't
+            // leave the workspace.
+            let Ok(file_type) = entry.file_type() else {
+                continue;
+            };
+            let is_dir = file_type.is_dir();
+            if !self.security.is_path_allowed(&rel_path)
+                || self
+                    .ignore
+                    .iter()
+                    .any(|p| p.matches(&rel_path, &name, is_dir))
+            {
+                continue;
+            }
+
+            let indent = "  ".repeat(depth - 1);
+            let line = if is_dir {
+                format!("{indent}{name}/\n")
+            } else {
+                format!("{indent}{name}\n")
+            };
+            if self.listing.len() + line.len() > self.budget {
+                self.omitted += 1;
+                continue;
+            }
+            self.listing.push_str(&line);
+
+            if is_dir && depth < self.max_depth {
+                self.walk(&entry.path(), &rel_path, depth + 1);
+            }
+        }
+    }
+}
+
+/// Depth-limited listing of the workspace as a system prompt section, or an
+/// empty string when `agent.include_file_tree` is off.
+///
+/// Skips `.git`, entries matched by the workspace's root `.gitignore` or
+/// `agent.file_tree_ignore`, and paths the security policy forbids.
+pub fn build_workspace_file_tree(config: &Config) -> String {
+    if !config.agent.include_file_tree || config.agent.file_tree_depth == 0 {
+        return String::new();
+    }
+
+    let workspace_dir = &config.workspace_dir;
+    let security = SecurityPolicy::from_config(&config.autonomy, workspace_dir);
+    let gitignore = std::fs::read_to_string(workspace_dir.join(".gitignore")).unwrap_or_default();
+    let ignore: Vec<IgnorePattern> = gitignore
+        .lines()
+        .chain(config.agent.file_tree_ignore.iter().map(String::as_str))
+        .filter_map(IgnorePattern::parse)
+        .collect();
+
+    let mut walk = TreeWalk {
+        security: &security,
+        ignore: &ignore,
+        max_depth: config.agent.file_tree_depth,
+        budget: if config.agent.compact_context {
+            COMPACT_FILE_TREE_MAX_CHARS
+        } else {
+            FILE_TREE_MAX_CHARS
+        },
+        listing: String::new(),
+        omitted: 0,
+    };
+    walk.walk(workspace_dir, "", 1);
+    if walk.listing.is_empty() {
+        return String::new();
+    }
+
+    let mut section = format!(
+        "## Workspace Files\n\nWorkspace listing, {} level(s) deep (directories end with `/`):\n\n```\n{}```\n",
+        walk.max_depth, walk.listing
+    );
+    if walk.omitted > 0 {
+        let _ = writeln!(section, "[... {} more entries not shown]", walk.omitted);
+    }
+    section.push('\n');
+    section
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn file_tree_respects_depth_gitignore_and_extra_patterns() {
+        let tmp = tempfile::tempdir().unwrap();
+        let ws = tmp.path();
+        std::fs::create_dir_all(ws.join("src/agent/deep")).unwrap();
+        std::fs::create_dir_all(ws.join("target/debug")).unwrap();
+        std::fs::create_dir_all(ws.join(".git")).unwrap();
+        std::fs::write(ws.join("src/main.rs"), "").unwrap();
+        std::fs::write(ws.join("src/agent/loop.rs"), "").unwrap();
+        std::fs::write(ws.join("notes.log"), "").unwrap();
+        std::fs::write(ws.join("README.md"), "").unwrap();
+        std::fs::write(ws.join(".gitignore"), "target/\n# comment\n*.log\n").unwrap();
+
+        let mut config = Config {
+            workspace_dir: ws.to_path_buf(),
+            ..Config::default()
+        };
+        assert!(build_workspace_file_tree(&config).is_empty());
+
+        config.agent.include_file_tree = true;
+        config.agent.file_tree_depth = 2;
+        let tree = build_workspace_file_tree(&config);
+        assert!(tree.starts_with("## Workspace Files"));
+        assert!(tree.contains("\nREADME.md\n"));
+        assert!(tree.contains("\nsrc/\n  agent/\n  main.rs\n"));
+        assert!(!tree.contains("loop.rs"), "depth 2 stops below src/agent");
+        assert!(!tree.contains("target"));
+        assert!(!tree.contains("notes.log"));
+        assert!(!tree.contains(".git/"));
+
+        config.agent.file_tree_ignore = vec!["src/agent".into()];
+        let tree = build_workspace_file_tree(&config);
+        assert!(tree.contains("main.rs"));
+        assert!(!tree.contains("agent/"));
+    }
+}
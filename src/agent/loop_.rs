@@ -1,19 +1,26 @@
-use crate::approval::{ApprovalManager, ApprovalRequest, ApprovalResponse};
-use crate::config::Config;
-use crate::memory::{self, Memory, MemoryCategory};
+use crate::agent::file_tree::build_workspace_file_tree;
+use crate::approval::{
+    describe_hardware_action, ApprovalManager, ApprovalRequest, ApprovalResponse, HARDWARE_DECLINED,
+};
+use crate::config::{Config, ToolArgValidation, ToolCallFormat, ToolResultRole};
+use crate::memory::{self, autosave_memory_key, Memory, MemoryCategory};
 use crate::observability::{self, Observer, ObserverEvent};
-use crate::providers::{self, ChatMessage, ChatRequest, Provider, ToolCall};
+use crate::providers::budget::{CostBudget, CostBudgetExceeded, CostBudgetProvider};
+use crate::providers::{self, ChatMessage, ChatRequest, GenerationParams, Provider, ToolCall};
 use crate::runtime;
+use crate::security::audit::{AuditLogger, ToolExecutionLog};
+use crate::security::moderation::ModerationGate;
+use crate::security::secret_refs::{resolve_tool_secret_refs, ResolvedArgs};
 use crate::security::SecurityPolicy;
 use crate::tools::{self, Tool};
 use crate::util::truncate_with_ellipsis;
 use anyhow::Result;
 use regex::{Regex, RegexSet};
+use std::collections::HashMap;
 use std::fmt::Write;
 use std::io::Write as _;
 use std::sync::{Arc, LazyLock};
-use std::time::Instant;
-use uuid::Uuid;
+use std::time::{Duration, Instant};
 
 /// Minimum characters per chunk when relaying LLM text to a streaming draft.
 const STREAM_CHUNK_MIN_CHARS: usize = 80;
@@ -22,6 +29,36 @@ const STREAM_CHUNK_MIN_CHARS: usize = 80;
 /// Used as a safe fallback when `max_tool_iterations` is unset or configured as zero.
 const DEFAULT_MAX_TOOL_ITERATIONS: usize = 10;
 
+/// Consecutive iterations of the same failing tool call(s) before the loop
+/// stops and asks the model to summarize (matches `agent.no_progress_window`).
+pub(crate) const DEFAULT_NO_PROGRESS_WINDOW: usize = 3;
+
+/// First delay before re-sending a model call that lost its connection;
+/// doubles per attempt up to [`TURN_RETRY_MAX_BACKOFF_MS`].
+const TURN_RETRY_BASE_BACKOFF_MS: u64 = 1_000;
+const TURN_RETRY_MAX_BACKOFF_MS: u64 = 10_000;
+
+/// Sent to the model when the no-progress window is reached.
+const NO_PROGRESS_PROMPT: &str = "[System] The same tool call has failed repeatedly with no progress. Stop calling tools. Summarize what you tried, what failed, and what the user could do next.";
+
+/// Sent once per turn when the model describes a tool call in prose instead
+/// of making it (see [`looks_like_narrated_tool_use`]).
+const TOOL_PROTOCOL_REMINDER: &str = "[System] You described using a tool but did not call it, so nothing was executed. If you need the tool, call it now using the tool-call format from your instructions. Otherwise, give your final answer.";
+
+/// Phrases announcing an action the model is about to take.
+const TOOL_INTENT_PHRASES: [&str; 10] = [
+    "i will run",
+    "i'll run",
+    "i will use",
+    "i'll use",
+    "i will call",
+    "i'll call",
+    "let me run",
+    "let me use",
+    "let me call",
+    "i'm going to",
+];
+
 static SENSITIVE_KEY_PATTERNS: LazyLock<RegexSet> = LazyLock::new(|| {
     RegexSet::new([
         r"(?i)token",
@@ -90,6 +127,13 @@ const COMPACTION_MAX_SOURCE_CHARS: usize = 12_000;
 /// Max characters retained in stored compaction summary.
 const COMPACTION_MAX_SUMMARY_CHARS: usize = 2_000;
 
+/// Tool outputs at or below this size are left alone by tool-result compaction.
+const TOOL_RESULT_SUMMARY_CHARS: usize = 120;
+
+static TOOL_RESULT_BLOCK_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"(?s)<tool_result name="([^"]*)">\n(.*?)\n</tool_result>"#).unwrap()
+});
+
 /// Convert a tool registry to OpenAI function-calling format for native tool support.
 fn tools_to_openai_format(tools_registry: &[Box<dyn Tool>]) -> Vec<serde_json::Value> {
     tools_registry
@@ -107,12 +151,9 @@ fn tools_to_openai_format(tools_registry: &[Box<dyn Tool>]) -> Vec<serde_json::V
         .collect()
 }
 
-fn autosave_memory_key(prefix: &str) -> String {
-    format!("{prefix}_{}", Uuid::new_v4())
-}
-
 /// Trim conversation history to prevent unbounded growth.
-/// Preserves the system prompt (first message if role=system) and the most recent messages.
+/// Preserves the system prompt (first message if role=system), pinned messages,
+/// and the most recent messages.
 fn trim_history(history: &mut Vec<ChatMessage>, max_history: usize) {
     // Nothing to trim if within limit
     let has_system = history.first().map_or(false, |m| m.role == "system");
@@ -127,8 +168,93 @@ fn trim_history(history: &mut Vec<ChatMessage>, max_history: usize) {
     }
 
     let start = if has_system { 1 } else { 0 };
-    let to_remove = non_system_count - max_history;
-    history.drain(start..start + to_remove);
+    let mut to_remove = non_system_count - max_history;
+    let mut index = 0;
+    history.retain(|msg| {
+        let keep = index < start || msg.pinned || to_remove == 0;
+        index += 1;
+        if !keep {
+            to_remove -= 1;
+        }
+        keep
+    });
+}
+
+/// One-line stand-in for a tool output: its size plus the first non-empty line.
+fn summarize_tool_output(output: &str) -> Option<String> {
+    let output = output.trim();
+    if output.chars().count() <= TOOL_RESULT_SUMMARY_CHARS || output.starts_with("[compacted:") {
+        return None;
+    }
+    let first_line = output
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .unwrap_or_default();
+    Some(format!(
+        "[compacted: {} lines, {} chars] {}",
+        output.lines().count(),
+        output.chars().count(),
+        truncate_with_ellipsis(first_line, 80)
+    ))
+}
+
+/// Replace bulky tool outputs older than the last `keep_turns` user turns
+/// with one-line summaries, keeping the messages themselves so the
+/// conversation still shows which tools ran. Returns how many outputs were
+/// compacted. `keep_turns == 0` disables this.
+fn compact_old_tool_results(history: &mut [ChatMessage], keep_turns: usize) -> usize {
+    if keep_turns == 0 {
+        return 0;
+    }
+    let turn_starts: Vec<usize> = history
+        .iter()
+        .enumerate()
+        .filter(|(_, msg)| msg.role == "user" && !msg.content.starts_with("[Tool results]"))
+        .map(|(index, _)| index)
+        .collect();
+    let Some(&cutoff) = turn_starts
+        .len()
+        .checked_sub(keep_turns)
+        .and_then(|i| turn_starts.get(i))
+    else {
+        return 0;
+    };
+
+    let mut compacted = 0;
+    for msg in history[..cutoff].iter_mut().filter(|msg| !msg.pinned) {
+        if msg.content.starts_with("[Tool results]") {
+            let content =
+                TOOL_RESULT_BLOCK_REGEX.replace_all(&msg.content, |caps: &regex::Captures| {
+                    match summarize_tool_output(&caps[2]) {
+                        Some(summary) => {
+                            compacted += 1;
+                            format!(
+                                "<tool_result name=\"{}\">\n{summary}\n</tool_result>",
+                                &caps[1]
+                            )
+                        }
+                        None => caps[0].to_string(),
+                    }
+                });
+            msg.content = content.into_owned();
+        } else if msg.role == "tool" {
+            let Ok(mut value) = serde_json::from_str::<serde_json::Value>(&msg.content) else {
+                continue;
+            };
+            let Some(summary) = value
+                .get("content")
+                .and_then(serde_json::Value::as_str)
+                .and_then(summarize_tool_output)
+            else {
+                continue;
+            };
+            value["content"] = serde_json::Value::String(summary);
+            msg.content = value.to_string();
+            compacted += 1;
+        }
+    }
+    compacted
 }
 
 fn build_compaction_transcript(messages: &[ChatMessage]) -> String {
@@ -152,7 +278,15 @@ fn apply_compaction_summary(
     summary: &str,
 ) {
     let summary_msg = ChatMessage::assistant(format!("[Compaction summary]\n{}", summary.trim()));
-    history.splice(start..compact_end, std::iter::once(summary_msg));
+    let pinned: Vec<ChatMessage> = history[start..compact_end]
+        .iter()
+        .filter(|m| m.pinned)
+        .cloned()
+        .collect();
+    history.splice(
+        start..compact_end,
+        pinned.into_iter().chain(std::iter::once(summary_msg)),
+    );
 }
 
 async fn auto_compact_history(
@@ -180,7 +314,14 @@ async fn auto_compact_history(
     }
 
     let compact_end = start + compact_count;
-    let to_compact: Vec<ChatMessage> = history[start..compact_end].to_vec();
+    let to_compact: Vec<ChatMessage> = history[start..compact_end]
+        .iter()
+        .filter(|m| !m.pinned)
+        .cloned()
+        .collect();
+    if to_compact.is_empty() {
+        return Ok(false);
+    }
     let transcript = build_compaction_transcript(&to_compact);
 
     let summarizer_system = "You are a conversation compaction engine. Summarize older chat history into concise context for future turns. Preserve: user preferences, commitments, decisions, unresolved tasks, key facts. Omit: filler, repeated chit-chat, verbose tool logs. Output plain text bullet points only.";
@@ -205,13 +346,19 @@ async fn auto_compact_history(
 }
 
 /// Build context preamble by searching memory for relevant entries.
-/// Entries with a hybrid score below `min_relevance_score` are dropped to
-/// prevent unrelated memories from bleeding into the conversation.
-async fn build_context(mem: &dyn Memory, user_msg: &str, min_relevance_score: f64) -> String {
+/// At most `recall_limit` entries are recalled; those with a hybrid score
+/// below `min_relevance_score` are dropped to prevent unrelated memories
+/// from bleeding into the conversation.
+async fn build_context(
+    mem: &dyn Memory,
+    user_msg: &str,
+    recall_limit: usize,
+    min_relevance_score: f64,
+) -> String {
     let mut context = String::new();
 
     // Pull relevant memories for this message
-    if let Ok(entries) = mem.recall(user_msg, 5, None).await {
+    if let Ok(entries) = memory::recall_for_context(mem, user_msg, recall_limit).await {
         let relevant: Vec<_> = entries
             .iter()
             .filter(|e| match e.score {
@@ -277,6 +424,155 @@ fn find_tool<'a>(tools: &'a [Box<dyn Tool>], name: &str) -> Option<&'a dyn Tool>
     tools.iter().find(|t| t.name() == name).map(|t| t.as_ref())
 }
 
+/// Apply `agent.tool_arg_validation` to a call. Returns the error to report
+/// instead of running the tool, or `None` when it may run.
+pub(crate) fn check_tool_arguments(
+    tool: &dyn Tool,
+    arguments: &serde_json::Value,
+    mode: ToolArgValidation,
+) -> Option<String> {
+    if mode == ToolArgValidation::Off {
+        return None;
+    }
+    let problem = tool.validate_args(arguments).err()?;
+    if mode == ToolArgValidation::Warn {
+        tracing::warn!(
+            tool = tool.name(),
+            "Invalid tool arguments (running anyway): {problem}"
+        );
+        return None;
+    }
+    Some(format!(
+        "Error: invalid arguments for {}: {problem}",
+        tool.name()
+    ))
+}
+
+/// Run one tool call. Outside silent mode, tools with incremental output
+/// (e.g. `shell`) print each line as it arrives, scrubbed like the final result.
+async fn execute_tool(
+    tool: &dyn Tool,
+    resolved: &ResolvedArgs,
+    silent: bool,
+) -> Result<tools::ToolResult> {
+    if silent {
+        return tool.execute(resolved.arguments.clone()).await;
+    }
+    let on_output = |line: &str| {
+        println!("  │ {}", scrub_credentials(&resolved.redact(line)));
+    };
+    tool.execute_streaming(resolved.arguments.clone(), &on_output)
+        .await
+}
+
+/// What a tool call needs besides the call itself.
+pub(crate) struct ToolCallContext<'a> {
+    pub tools: &'a [Box<dyn Tool>],
+    pub observer: &'a dyn Observer,
+    pub audit_log: Option<&'a AuditLogger>,
+    /// Channel recorded in audit entries.
+    pub channel_name: &'a str,
+    pub arg_validation: ToolArgValidation,
+    /// Decrypted `[secrets.refs]` for `{{secret:name}}` placeholders.
+    pub secret_refs: &'a HashMap<String, String>,
+    /// Run without streaming tool output to stdout.
+    pub silent: bool,
+}
+
+/// Result text handed back to the model, and whether the tool succeeded.
+pub(crate) struct ToolCallRun {
+    pub output: String,
+    pub success: bool,
+}
+
+/// Run one tool call end to end: audit start, argument validation, secret
+/// ref resolution, execution, scrubbing and audit end. The audit trail is
+/// written synchronously around execution; if the start entry can't be
+/// written, the tool doesn't run.
+pub(crate) async fn run_tool_call(
+    name: &str,
+    arguments: &serde_json::Value,
+    ctx: &ToolCallContext<'_>,
+) -> ToolCallRun {
+    if let Some(audit_log) = ctx.audit_log {
+        if let Err(e) = audit_log.log_tool_start(ctx.channel_name, name, arguments) {
+            tracing::error!(tool = %name, "Audit log write failed: {e}");
+            return ToolCallRun {
+                output: format!("Error: audit log unavailable, tool not executed: {e}"),
+                success: false,
+            };
+        }
+    }
+
+    ctx.observer.record_event(&ObserverEvent::ToolCallStart {
+        tool: name.to_string(),
+    });
+    let start = Instant::now();
+    let record_finished = |success: bool| {
+        ctx.observer.record_event(&ObserverEvent::ToolCall {
+            tool: name.to_string(),
+            duration: start.elapsed(),
+            success,
+        });
+    };
+    let mut success = false;
+    let mut metadata = None;
+    let mut content_type = None;
+    let output = if let Some(tool) = find_tool(ctx.tools, name) {
+        if let Some(invalid) = check_tool_arguments(tool, arguments, ctx.arg_validation) {
+            record_finished(false);
+            invalid
+        } else {
+            // `{{secret:name}}` placeholders are filled in only here, only
+            // in fields that accept them, and scrubbed from whatever
+            // comes back.
+            match resolve_tool_secret_refs(name, arguments, ctx.secret_refs) {
+                Ok(resolved) => match execute_tool(tool, &resolved, ctx.silent).await {
+                    Ok(r) => {
+                        record_finished(r.success);
+                        success = r.success;
+                        metadata = r.metadata;
+                        content_type = r.content_type;
+                        if r.success {
+                            scrub_credentials(&resolved.redact(&r.output))
+                        } else {
+                            resolved.redact(&format!("Error: {}", r.error.unwrap_or(r.output)))
+                        }
+                    }
+                    Err(e) => {
+                        record_finished(false);
+                        resolved.redact(&format!("Error executing {name}: {e}"))
+                    }
+                },
+                Err(e) => {
+                    record_finished(false);
+                    format!("Error: {e}")
+                }
+            }
+        }
+    } else {
+        format!("Unknown tool: {name}")
+    };
+
+    if let Some(audit_log) = ctx.audit_log {
+        let entry = ToolExecutionLog {
+            channel: ctx.channel_name,
+            tool: name,
+            arguments,
+            success,
+            duration_ms: u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX),
+            result: &output,
+            metadata: metadata.as_ref(),
+            content_type: content_type.as_deref(),
+        };
+        if let Err(e) = audit_log.log_tool_event(entry) {
+            tracing::error!(tool = %name, "Audit log write failed: {e}");
+        }
+    }
+
+    ToolCallRun { output, success }
+}
+
 fn parse_arguments_value(raw: Option<&serde_json::Value>) -> serde_json::Value {
     match raw {
         Some(serde_json::Value::String(s)) => serde_json::from_str::<serde_json::Value>(s)
@@ -346,7 +642,13 @@ fn parse_tool_calls_from_json_value(value: &serde_json::Value) -> Vec<ParsedTool
     calls
 }
 
-const TOOL_CALL_OPEN_TAGS: [&str; 4] = ["<tool_call>", "<toolcall>", "<tool-call>", "<invoke>"];
+const TOOL_CALL_OPEN_TAGS: [&str; 5] = [
+    "<tool_call>",
+    "<toolcall>",
+    "<tool-call>",
+    "<function_call>",
+    "<invoke>",
+];
 
 fn find_first_tag<'a>(haystack: &str, tags: &'a [&'a str]) -> Option<(usize, &'a str)> {
     tags.iter()
@@ -359,6 +661,7 @@ fn matching_tool_call_close_tag(open_tag: &str) -> Option<&'static str> {
         "<tool_call>" => Some("</tool_call>"),
         "<toolcall>" => Some("</toolcall>"),
         "<tool-call>" => Some("</tool-call>"),
+        "<function_call>" => Some("</function_call>"),
         "<invoke>" => Some("</invoke>"),
         _ => None,
     }
@@ -579,40 +882,87 @@ fn parse_glm_style_tool_calls(text: &str) -> Vec<(String, serde_json::Value, Opt
     calls
 }
 
-/// Parse tool calls from an LLM response that uses XML-style function calling.
+/// Parser for one text tool-call syntax. Returns the prose with the calls
+/// removed, plus the calls found (empty when the syntax isn't present).
+type ToolCallParser = fn(&str) -> (String, Vec<ParsedToolCall>);
+
+fn tool_call_parser(format: ToolCallFormat) -> ToolCallParser {
+    match format {
+        ToolCallFormat::Xml => parse_xml_tool_calls,
+        ToolCallFormat::OpenaiJson => parse_openai_json_tool_calls,
+        ToolCallFormat::MarkdownFence => parse_markdown_fence_tool_calls,
+        ToolCallFormat::Glm => parse_glm_tool_calls,
+        ToolCallFormat::JsonFence => parse_json_fence_tool_calls,
+    }
+}
+
+/// Parse tool calls from an LLM response that calls tools in text rather
+/// than through the provider's native API.
 ///
-/// Expected format (common with system-prompt-guided tool use):
+/// The parsers for `agent.tool_call_formats` are tried in order and the
+/// first one that finds any calls wins. By default that is XML-style tags,
+/// then an OpenAI-style `tool_calls` JSON body, then ` ```tool_call `
+/// fences, then GLM line calls. An empty `formats` means that default order.
+pub(crate) fn parse_tool_calls(
+    response: &str,
+    formats: &[ToolCallFormat],
+) -> (String, Vec<ParsedToolCall>) {
+    let default_order;
+    let formats = if formats.is_empty() {
+        default_order = ToolCallFormat::default_order();
+        &default_order
+    } else {
+        formats
+    };
+    for format in formats {
+        let (text, calls) = tool_call_parser(*format)(response);
+        if !calls.is_empty() {
+            return (text, calls);
+        }
+    }
+
+    // SECURITY: We do NOT fall back to extracting arbitrary JSON from the response
+    // here. That would enable prompt injection attacks where malicious content
+    // (e.g., in emails, files, or web pages) could include JSON that mimics a
+    // tool call. Tool calls MUST be explicitly wrapped in one of the formats
+    // above. This ensures only the LLM's intentional tool calls are executed.
+
+    // No calls: still drop the bodies of malformed tool-call tags.
+    (parse_xml_tool_calls(response).0, Vec::new())
+}
+
+/// OpenAI-style JSON response with a `tool_calls` array. This handles
+/// providers like Minimax that return tool calls in native JSON format
+/// inside the text.
+fn parse_openai_json_tool_calls(response: &str) -> (String, Vec<ParsedToolCall>) {
+    let Ok(json_value) = serde_json::from_str::<serde_json::Value>(response.trim()) else {
+        return (String::new(), Vec::new());
+    };
+    let calls = parse_tool_calls_from_json_value(&json_value);
+    // If we found tool_calls, extract any content field as text
+    let text = json_value
+        .get("content")
+        .and_then(|v| v.as_str())
+        .map(str::trim)
+        .unwrap_or_default()
+        .to_string();
+    (text, calls)
+}
+
+/// XML-style function calling:
 /// ```text
 /// <tool_call>
 /// {"name": "shell", "arguments": {"command": "ls"}}
 /// </tool_call>
 /// ```
 ///
-/// Also accepts common tag variants (`<toolcall>`, `<tool-call>`) for model
-/// compatibility.
-///
-/// Also supports JSON with `tool_calls` array from OpenAI-format responses.
-fn parse_tool_calls(response: &str) -> (String, Vec<ParsedToolCall>) {
+/// Also accepts common tag variants (`<toolcall>`, `<tool-call>`,
+/// `<function_call>`, `<invoke>`) for model compatibility.
+fn parse_xml_tool_calls(response: &str) -> (String, Vec<ParsedToolCall>) {
     let mut text_parts = Vec::new();
     let mut calls = Vec::new();
     let mut remaining = response;
 
-    // First, try to parse as OpenAI-style JSON response with tool_calls array
-    // This handles providers like Minimax that return tool_calls in native JSON format
-    if let Ok(json_value) = serde_json::from_str::<serde_json::Value>(response.trim()) {
-        calls = parse_tool_calls_from_json_value(&json_value);
-        if !calls.is_empty() {
-            // If we found tool_calls, extract any content field as text
-            if let Some(content) = json_value.get("content").and_then(|v| v.as_str()) {
-                if !content.trim().is_empty() {
-                    text_parts.push(content.trim().to_string());
-                }
-            }
-            return (text_parts.join("\n"), calls);
-        }
-    }
-
-    // Fall back to XML-style tool-call tag parsing.
     while let Some((start, open_tag)) = find_first_tag(remaining, &TOOL_CALL_OPEN_TAGS) {
         // Everything before the tag is text
         let before = &remaining[..start];
@@ -670,81 +1020,100 @@ fn parse_tool_calls(response: &str) -> (String, Vec<ParsedToolCall>) {
         }
     }
 
-    // If XML tags found nothing, try markdown code blocks with tool_call language.
-    // Models behind OpenRouter sometimes output ```tool_call ... ``` or hybrid
-    // ```tool_call ... </tool_call> instead of structured API calls or XML tags.
-    if calls.is_empty() {
-        static MD_TOOL_CALL_RE: LazyLock<Regex> = LazyLock::new(|| {
-            Regex::new(
-                r"(?s)```(?:tool[_-]?call|invoke)\s*\n(.*?)(?:```|</tool[_-]?call>|</toolcall>|</invoke>)",
-            )
-            .unwrap()
-        });
-        let mut md_text_parts: Vec<String> = Vec::new();
-        let mut last_end = 0;
-
-        for cap in MD_TOOL_CALL_RE.captures_iter(response) {
-            let full_match = cap.get(0).unwrap();
-            let before = &response[last_end..full_match.start()];
-            if !before.trim().is_empty() {
-                md_text_parts.push(before.trim().to_string());
-            }
-            let inner = &cap[1];
-            let json_values = extract_json_values(inner);
-            for value in json_values {
-                let parsed_calls = parse_tool_calls_from_json_value(&value);
-                calls.extend(parsed_calls);
-            }
-            last_end = full_match.end();
-        }
+    // Remaining text after last tool call
+    if !remaining.trim().is_empty() {
+        text_parts.push(remaining.trim().to_string());
+    }
 
-        if !calls.is_empty() {
-            let after = &response[last_end..];
-            if !after.trim().is_empty() {
-                md_text_parts.push(after.trim().to_string());
-            }
-            text_parts = md_text_parts;
-            remaining = "";
+    (text_parts.join("\n"), calls)
+}
+
+/// Pull the calls out of every block `pattern` matches (capture 1 is the
+/// block body), keeping the prose around the blocks.
+fn parse_fenced_tool_calls(
+    response: &str,
+    pattern: &Regex,
+    accept: fn(&serde_json::Value) -> bool,
+) -> (String, Vec<ParsedToolCall>) {
+    let mut text_parts: Vec<String> = Vec::new();
+    let mut calls = Vec::new();
+    let mut last_end = 0;
+
+    for cap in pattern.captures_iter(response) {
+        let full_match = cap.get(0).unwrap();
+        let block_calls: Vec<ParsedToolCall> = extract_json_values(&cap[1])
+            .iter()
+            .filter(|value| accept(value))
+            .flat_map(parse_tool_calls_from_json_value)
+            .collect();
+        if block_calls.is_empty() {
+            continue;
+        }
+        let before = &response[last_end..full_match.start()];
+        if !before.trim().is_empty() {
+            text_parts.push(before.trim().to_string());
         }
+        calls.extend(block_calls);
+        last_end = full_match.end();
     }
 
-    // GLM-style tool calls (browser_open/url>https://..., shell/command>ls, etc.)
-    if calls.is_empty() {
-        let glm_calls = parse_glm_style_tool_calls(remaining);
-        if !glm_calls.is_empty() {
-            let mut cleaned_text = remaining.to_string();
-            for (name, args, raw) in &glm_calls {
-                calls.push(ParsedToolCall {
-                    name: name.clone(),
-                    arguments: args.clone(),
-                });
-                if let Some(r) = raw {
-                    cleaned_text = cleaned_text.replace(r, "");
-                }
+    let after = &response[last_end..];
+    if !after.trim().is_empty() {
+        text_parts.push(after.trim().to_string());
+    }
+    (text_parts.join("\n"), calls)
+}
+
+/// Markdown code blocks with a tool_call language. Models behind OpenRouter
+/// sometimes output ```tool_call ... ``` or hybrid ```tool_call ... </tool_call>
+/// instead of structured API calls or XML tags.
+fn parse_markdown_fence_tool_calls(response: &str) -> (String, Vec<ParsedToolCall>) {
+    static MD_TOOL_CALL_RE: LazyLock<Regex> = LazyLock::new(|| {
+        Regex::new(
+            r"(?s)```(?:tool[_-]?call|invoke)\s*\n(.*?)(?:```|</tool[_-]?call>|</toolcall>|</invoke>)",
+        )
+        .unwrap()
+    });
+    parse_fenced_tool_calls(response, &MD_TOOL_CALL_RE, |_| true)
+}
+
+/// Plain ```json fences holding a tool-call object (`name` + `arguments`),
+/// an OpenAI-style `function`/`tool_calls` object, or an array of those.
+/// Off by default: a quoted JSON example is much easier to mistake for a
+/// call than an explicit tag.
+fn parse_json_fence_tool_calls(response: &str) -> (String, Vec<ParsedToolCall>) {
+    static JSON_FENCE_RE: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"(?s)```(?:json)?[ \t]*\n(.*?)```").unwrap());
+
+    fn looks_like_tool_call(value: &serde_json::Value) -> bool {
+        match value {
+            serde_json::Value::Array(items) => {
+                !items.is_empty() && items.iter().all(looks_like_tool_call)
             }
-            if !cleaned_text.trim().is_empty() {
-                text_parts.push(cleaned_text.trim().to_string());
+            serde_json::Value::Object(map) => {
+                map.contains_key("tool_calls")
+                    || map.contains_key("function")
+                    || (map.contains_key("name") && map.contains_key("arguments"))
             }
-            remaining = "";
+            _ => false,
         }
     }
 
-    // SECURITY: We do NOT fall back to extracting arbitrary JSON from the response
-    // here. That would enable prompt injection attacks where malicious content
-    // (e.g., in emails, files, or web pages) could include JSON that mimics a
-    // tool call. Tool calls MUST be explicitly wrapped in either:
-    // 1. OpenAI-style JSON with a "tool_calls" array
-    // 2. ZeroClaw tool-call tags (<tool_call>, <toolcall>, <tool-call>)
-    // 3. Markdown code blocks with tool_call/toolcall/tool-call language
-    // 4. Explicit GLM line-based call formats (e.g. `shell/command>...`)
-    // This ensures only the LLM's intentional tool calls are executed.
+    parse_fenced_tool_calls(response, &JSON_FENCE_RE, looks_like_tool_call)
+}
 
-    // Remaining text after last tool call
-    if !remaining.trim().is_empty() {
-        text_parts.push(remaining.trim().to_string());
+/// GLM-style line calls (`browser_open/url>https://...`, `shell/command>ls`).
+fn parse_glm_tool_calls(response: &str) -> (String, Vec<ParsedToolCall>) {
+    let glm_calls = parse_glm_style_tool_calls(response);
+    let mut cleaned_text = response.to_string();
+    let mut calls = Vec::new();
+    for (name, arguments, raw) in glm_calls {
+        calls.push(ParsedToolCall { name, arguments });
+        if let Some(r) = raw {
+            cleaned_text = cleaned_text.replace(&r, "");
+        }
     }
-
-    (text_parts.join("\n"), calls)
+    (cleaned_text.trim().to_string(), calls)
 }
 
 fn parse_structured_tool_calls(tool_calls: &[ToolCall]) -> Vec<ParsedToolCall> {
@@ -808,9 +1177,43 @@ fn build_assistant_history_with_tool_calls(text: &str, tool_calls: &[ToolCall])
 }
 
 #[derive(Debug)]
-struct ParsedToolCall {
-    name: String,
-    arguments: serde_json::Value,
+pub(crate) struct ParsedToolCall {
+    pub(crate) name: String,
+    pub(crate) arguments: serde_json::Value,
+}
+
+fn turn_retry_backoff(attempt: u32) -> Duration {
+    let factor = 1u64 << attempt.saturating_sub(1).min(16);
+    Duration::from_millis(
+        TURN_RETRY_BASE_BACKOFF_MS
+            .saturating_mul(factor)
+            .min(TURN_RETRY_MAX_BACKOFF_MS),
+    )
+}
+
+/// Whether a tool-free response looks like the model narrated a tool call
+/// instead of making one: it announces an action ("I'll run", "let me use", ...)
+/// and names a registered tool, either in backticks or as a multi-word
+/// identifier like `file_read`. Deliberately conservative: a bare mention of a
+/// one-word tool name such as "shell" in prose doesn't count.
+fn looks_like_narrated_tool_use(text: &str, tool_names: &[&str]) -> bool {
+    let lower = text.to_ascii_lowercase();
+    if !TOOL_INTENT_PHRASES.iter().any(|p| lower.contains(p)) {
+        return false;
+    }
+    let is_ident = |c: char| c.is_ascii_alphanumeric() || c == '_';
+    tool_names.iter().any(|name| {
+        let name = name.to_ascii_lowercase();
+        if lower.contains(&format!("`{name}`")) {
+            return true;
+        }
+        name.contains('_')
+            && lower.match_indices(&name).any(|(start, _)| {
+                let before = lower[..start].chars().next_back();
+                let after = lower[start + name.len()..].chars().next();
+                !before.is_some_and(is_ident) && !after.is_some_and(is_ident)
+            })
+    })
 }
 
 /// Execute a single turn of the agent loop: send messages, parse tool calls,
@@ -828,7 +1231,7 @@ pub(crate) async fn agent_turn(
     silent: bool,
     max_tool_iterations: usize,
 ) -> Result<String> {
-    run_tool_call_loop(
+    let outcome = agent_turn_outcome(
         provider,
         history,
         tools_registry,
@@ -837,18 +1240,22 @@ pub(crate) async fn agent_turn(
         model,
         temperature,
         silent,
-        None,
-        "channel",
         max_tool_iterations,
-        None,
     )
-    .await
+    .await?;
+    if outcome.truncated {
+        anyhow::bail!(
+            "Agent exceeded maximum tool iterations ({})",
+            outcome.max_iterations
+        );
+    }
+    Ok(outcome.text)
 }
 
-/// Execute a single turn of the agent loop: send messages, parse tool calls,
-/// execute tools, and loop until the LLM produces a final text response.
+/// Like [`agent_turn`], but reaching the iteration cap returns the partial
+/// answer with `truncated = true` instead of an error.
 #[allow(clippy::too_many_arguments)]
-pub(crate) async fn run_tool_call_loop(
+pub(crate) async fn agent_turn_outcome(
     provider: &dyn Provider,
     history: &mut Vec<ChatMessage>,
     tools_registry: &[Box<dyn Tool>],
@@ -857,11 +1264,144 @@ pub(crate) async fn run_tool_call_loop(
     model: &str,
     temperature: f64,
     silent: bool,
-    approval: Option<&ApprovalManager>,
-    channel_name: &str,
     max_tool_iterations: usize,
-    on_delta: Option<tokio::sync::mpsc::Sender<String>>,
+) -> Result<TurnOutcome> {
+    run_tool_call_loop_outcome(
+        provider,
+        history,
+        tools_registry,
+        observer,
+        LoopOptions {
+            provider_name,
+            model,
+            temperature,
+            params: None,
+            silent,
+            approval: None,
+            channel_name: "channel",
+            max_tool_iterations,
+            no_progress_window: DEFAULT_NO_PROGRESS_WINDOW,
+            turn_retries: 0,
+            tool_result_role: ToolResultRole::User,
+            arg_validation: ToolArgValidation::default(),
+            tool_call_formats: &[],
+            on_delta: None,
+            audit_log: None,
+            secret_refs: &HashMap::new(),
+        },
+    )
+    .await
+}
+
+/// Result of one agent turn.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct TurnOutcome {
+    /// Final answer, or the last text the model produced when `truncated`.
+    pub text: String,
+    /// The loop hit `max_tool_iterations` before a final answer. History is
+    /// left intact, so sending "continue" resumes where it stopped.
+    pub truncated: bool,
+    /// Iteration cap that was in effect for this turn.
+    pub max_iterations: usize,
+}
+
+/// Per-turn settings for [`run_tool_call_loop`] and
+/// [`run_tool_call_loop_outcome`].
+pub(crate) struct LoopOptions<'a> {
+    pub provider_name: &'a str,
+    pub model: &'a str,
+    pub temperature: f64,
+    pub params: Option<&'a GenerationParams>,
+    /// Suppress stdout (channels, JSON output).
+    pub silent: bool,
+    pub approval: Option<&'a ApprovalManager>,
+    /// Channel recorded in audit entries and approval prompts.
+    pub channel_name: &'a str,
+    /// `0` means [`DEFAULT_MAX_TOOL_ITERATIONS`].
+    pub max_tool_iterations: usize,
+    pub no_progress_window: usize,
+    pub turn_retries: u32,
+    pub tool_result_role: ToolResultRole,
+    /// `agent.tool_arg_validation`.
+    pub arg_validation: ToolArgValidation,
+    /// `agent.tool_call_formats`; empty means the default order.
+    pub tool_call_formats: &'a [ToolCallFormat],
+    /// Receives streamed text deltas when set.
+    pub on_delta: Option<tokio::sync::mpsc::Sender<String>>,
+    /// `[security.audit]` sink for tool executions; `None` when disabled.
+    pub audit_log: Option<Arc<AuditLogger>>,
+    /// Decrypted `[secrets.refs]` for `{{secret:name}}` placeholders.
+    pub secret_refs: &'a HashMap<String, String>,
+}
+
+/// Execute a single turn of the agent loop: send messages, parse tool calls,
+/// execute tools, and loop until the LLM produces a final text response.
+///
+/// Hitting the iteration cap is an error here; use
+/// [`run_tool_call_loop_outcome`] to get the partial answer instead.
+pub(crate) async fn run_tool_call_loop(
+    provider: &dyn Provider,
+    history: &mut Vec<ChatMessage>,
+    tools_registry: &[Box<dyn Tool>],
+    observer: &dyn Observer,
+    options: LoopOptions<'_>,
 ) -> Result<String> {
+    let outcome =
+        run_tool_call_loop_outcome(provider, history, tools_registry, observer, options).await?;
+
+    if outcome.truncated {
+        anyhow::bail!(
+            "Agent exceeded maximum tool iterations ({})",
+            outcome.max_iterations
+        );
+    }
+    Ok(outcome.text)
+}
+
+/// Render a turn for the CLI, flagging truncation so the user knows they
+/// can reply "continue" to resume.
+pub(crate) fn render_turn_outcome(outcome: &TurnOutcome) -> String {
+    if !outcome.truncated {
+        return outcome.text.clone();
+    }
+    let note = format!(
+        "⚠️ Stopped after {} tool iterations without a final answer. Reply \"continue\" to resume.",
+        outcome.max_iterations
+    );
+    if outcome.text.trim().is_empty() {
+        note
+    } else {
+        format!("{}\n\n{note}", outcome.text)
+    }
+}
+
+/// Like [`run_tool_call_loop`], but reaching the iteration cap returns the
+/// best partial answer with `truncated = true` instead of an error.
+pub(crate) async fn run_tool_call_loop_outcome(
+    provider: &dyn Provider,
+    history: &mut Vec<ChatMessage>,
+    tools_registry: &[Box<dyn Tool>],
+    observer: &dyn Observer,
+    options: LoopOptions<'_>,
+) -> Result<TurnOutcome> {
+    let LoopOptions {
+        provider_name,
+        model,
+        temperature,
+        params,
+        silent,
+        approval,
+        channel_name,
+        max_tool_iterations,
+        no_progress_window,
+        turn_retries,
+        tool_result_role,
+        arg_validation,
+        tool_call_formats,
+        on_delta,
+        audit_log,
+        secret_refs,
+    } = options;
     let max_iterations = if max_tool_iterations == 0 {
         DEFAULT_MAX_TOOL_ITERATIONS
     } else {
@@ -871,8 +1411,17 @@ pub(crate) async fn run_tool_call_loop(
     let tool_specs: Vec<crate::tools::ToolSpec> =
         tools_registry.iter().map(|tool| tool.spec()).collect();
     let use_native_tools = provider.supports_native_tools() && !tool_specs.is_empty();
-
-    for _iteration in 0..max_iterations {
+    // Latest text the model wrote alongside tool calls; returned if the cap is hit.
+    let mut partial_text = String::new();
+    // Last batch of tool calls in which every call failed, and how many
+    // iterations in a row it has been repeated.
+    let mut failing_batch: Vec<(String, serde_json::Value)> = Vec::new();
+    let mut failing_repeats = 0usize;
+    let tool_names: Vec<&str> = tool_specs.iter().map(|spec| spec.name.as_str()).collect();
+    // The protocol reminder is sent at most once per turn.
+    let mut reminded_tool_protocol = false;
+
+    for iteration in 0..max_iterations {
         observer.record_event(&ObserverEvent::LlmRequest {
             provider: provider_name.to_string(),
             model: model.to_string(),
@@ -889,18 +1438,43 @@ pub(crate) async fn run_tool_call_loop(
             None
         };
 
-        let (response_text, parsed_text, tool_calls, assistant_history_content, native_tool_calls) =
-            match provider
+        // Only this model call is re-sent on a dropped connection; tool calls
+        // already executed this turn are kept in history and never re-run.
+        let mut transport_retries = 0u32;
+        let chat_result = loop {
+            let result = provider
                 .chat(
                     ChatRequest {
                         messages: history,
                         tools: request_tools,
+                        params,
                     },
                     model,
                     temperature,
                 )
-                .await
-            {
+                .await;
+            match result {
+                Err(e)
+                    if transport_retries < turn_retries
+                        && crate::providers::reliable::is_transport_error(&e) =>
+                {
+                    transport_retries += 1;
+                    let backoff = turn_retry_backoff(transport_retries);
+                    tracing::warn!(
+                        provider = provider_name,
+                        attempt = transport_retries,
+                        backoff_ms = u64::try_from(backoff.as_millis()).unwrap_or(u64::MAX),
+                        "Provider connection dropped mid-turn; retrying model call: {}",
+                        crate::providers::sanitize_api_error(&e.to_string())
+                    );
+                    tokio::time::sleep(backoff).await;
+                }
+                other => break other,
+            }
+        };
+
+        let (response_text, parsed_text, tool_calls, assistant_history_content, native_tool_calls) =
+            match chat_result {
                 Ok(resp) => {
                     observer.record_event(&ObserverEvent::LlmResponse {
                         provider: provider_name.to_string(),
@@ -915,7 +1489,8 @@ pub(crate) async fn run_tool_call_loop(
                     let mut parsed_text = String::new();
 
                     if calls.is_empty() {
-                        let (fallback_text, fallback_calls) = parse_tool_calls(&response_text);
+                        let (fallback_text, fallback_calls) =
+                            parse_tool_calls(&response_text, tool_call_formats);
                         if !fallback_text.is_empty() {
                             parsed_text = fallback_text;
                         }
@@ -951,12 +1526,32 @@ pub(crate) async fn run_tool_call_loop(
                 }
             };
 
+        let has_prose = !parsed_text.trim().is_empty();
         let display_text = if parsed_text.is_empty() {
             response_text.clone()
         } else {
             parsed_text
         };
 
+        // Only re-prompt when another iteration is left to answer it; on the
+        // last one the narration is the best answer we have.
+        if tool_calls.is_empty()
+            && !reminded_tool_protocol
+            && iteration + 1 < max_iterations
+            && looks_like_narrated_tool_use(&response_text, &tool_names)
+        {
+            reminded_tool_protocol = true;
+            partial_text.clone_from(&display_text);
+            tracing::info!(
+                provider = provider_name,
+                model,
+                "Model narrated a tool call without making it; re-prompting once"
+            );
+            history.push(ChatMessage::assistant(response_text));
+            history.push(ChatMessage::user(TOOL_PROTOCOL_REMINDER));
+            continue;
+        }
+
         if tool_calls.is_empty() {
             // No tool calls — this is the final response.
             // If a streaming sender is provided, relay the text in small chunks
@@ -978,7 +1573,15 @@ pub(crate) async fn run_tool_call_loop(
                 }
             }
             history.push(ChatMessage::assistant(response_text.clone()));
-            return Ok(display_text);
+            return Ok(TurnOutcome {
+                text: display_text,
+                truncated: false,
+                max_iterations,
+            });
+        }
+
+        if has_prose {
+            partial_text.clone_from(&display_text);
         }
 
         // Print any text the LLM produced alongside tool calls (unless silent)
@@ -992,17 +1595,39 @@ pub(crate) async fn run_tool_call_loop(
         // can emit one `role: tool` message per tool call with the correct ID.
         let mut tool_results = String::new();
         let mut individual_results: Vec<String> = Vec::new();
+        // Models occasionally repeat the exact same call within one response;
+        // execute each unique (name, arguments) pair once and reuse its result.
+        let mut executed: Vec<(&ParsedToolCall, String)> = Vec::new();
+        let mut any_call_succeeded = false;
         for call in &tool_calls {
+            if let Some((_, cached)) = executed
+                .iter()
+                .find(|(prev, _)| prev.name == call.name && prev.arguments == call.arguments)
+            {
+                let result = cached.clone();
+                individual_results.push(result.clone());
+                let _ = writeln!(
+                    tool_results,
+                    "<tool_result name=\"{}\">\n{}\n</tool_result>",
+                    call.name, result
+                );
+                continue;
+            }
+
             // ── Approval hook ────────────────────────────────
             if let Some(mgr) = approval {
-                if mgr.needs_approval(&call.name) {
+                // Hardware writes get their own per-call prompt on the CLI.
+                let hardware = channel_name == "cli" && mgr.needs_hardware_confirmation(&call.name);
+                if hardware || mgr.needs_approval(&call.name) {
                     let request = ApprovalRequest {
                         tool_name: call.name.clone(),
                         arguments: call.arguments.clone(),
                     };
 
                     // Only prompt interactively on CLI; auto-approve on other channels.
-                    let decision = if channel_name == "cli" {
+                    let decision = if hardware {
+                        mgr.confirm_hardware_cli(&request)
+                    } else if channel_name == "cli" {
                         mgr.prompt_cli(&request)
                     } else {
                         ApprovalResponse::Yes
@@ -1011,7 +1636,15 @@ pub(crate) async fn run_tool_call_loop(
                     mgr.record_decision(&call.name, &call.arguments, decision, channel_name);
 
                     if decision == ApprovalResponse::No {
-                        let denied = "Denied by user.".to_string();
+                        let denied = if hardware {
+                            format!(
+                                "{HARDWARE_DECLINED} {} was not performed.",
+                                describe_hardware_action(&call.name, &call.arguments)
+                            )
+                        } else {
+                            "Denied by user.".to_string()
+                        };
+                        executed.push((call, denied.clone()));
                         individual_results.push(denied.clone());
                         let _ = writeln!(
                             tool_results,
@@ -1023,37 +1656,24 @@ pub(crate) async fn run_tool_call_loop(
                 }
             }
 
-            observer.record_event(&ObserverEvent::ToolCallStart {
-                tool: call.name.clone(),
-            });
-            let start = Instant::now();
-            let result = if let Some(tool) = find_tool(tools_registry, &call.name) {
-                match tool.execute(call.arguments.clone()).await {
-                    Ok(r) => {
-                        observer.record_event(&ObserverEvent::ToolCall {
-                            tool: call.name.clone(),
-                            duration: start.elapsed(),
-                            success: r.success,
-                        });
-                        if r.success {
-                            scrub_credentials(&r.output)
-                        } else {
-                            format!("Error: {}", r.error.unwrap_or_else(|| r.output))
-                        }
-                    }
-                    Err(e) => {
-                        observer.record_event(&ObserverEvent::ToolCall {
-                            tool: call.name.clone(),
-                            duration: start.elapsed(),
-                            success: false,
-                        });
-                        format!("Error executing {}: {e}", call.name)
-                    }
-                }
-            } else {
-                format!("Unknown tool: {}", call.name)
-            };
+            let run = run_tool_call(
+                &call.name,
+                &call.arguments,
+                &ToolCallContext {
+                    tools: tools_registry,
+                    observer,
+                    audit_log: audit_log.as_deref(),
+                    channel_name,
+                    arg_validation,
+                    secret_refs,
+                    silent,
+                },
+            )
+            .await;
+            any_call_succeeded |= run.success;
+            let result = run.output;
 
+            executed.push((call, result.clone()));
             individual_results.push(result.clone());
             let _ = writeln!(
                 tool_results,
@@ -1068,19 +1688,189 @@ pub(crate) async fn run_tool_call_loop(
         // Prompt mode: use XML-based text format as before.
         history.push(ChatMessage::assistant(assistant_history_content));
         if native_tool_calls.is_empty() {
-            history.push(ChatMessage::user(format!("[Tool results]\n{tool_results}")));
-        } else {
-            for (native_call, result) in native_tool_calls.iter().zip(individual_results.iter()) {
-                let tool_msg = serde_json::json!({
+            match tool_result_role {
+                ToolResultRole::User => {
+                    history.push(ChatMessage::user(format!("[Tool results]\n{tool_results}")));
+                }
+                ToolResultRole::Assistant => {
+                    history.push(ChatMessage::assistant(format!(
+                        "[Tool results]\n{tool_results}"
+                    )));
+                }
+                ToolResultRole::Tool => {
+                    for (call, result) in tool_calls.iter().zip(individual_results.iter()) {
+                        let tool_msg = serde_json::json!({
+                            "tool_name": call.name,
+                            "content": result,
+                        });
+                        history.push(ChatMessage::tool(tool_msg.to_string()));
+                    }
+                }
+            }
+        } else {
+            for (native_call, result) in native_tool_calls.iter().zip(individual_results.iter()) {
+                let tool_msg = serde_json::json!({
                     "tool_call_id": native_call.id,
                     "content": result,
                 });
                 history.push(ChatMessage::tool(tool_msg.to_string()));
             }
         }
+
+        let batch: Vec<(String, serde_json::Value)> = tool_calls
+            .iter()
+            .map(|call| (call.name.clone(), call.arguments.clone()))
+            .collect();
+        if any_call_succeeded {
+            failing_repeats = 0;
+        } else if batch == failing_batch {
+            failing_repeats += 1;
+        } else {
+            failing_batch = batch;
+            failing_repeats = 1;
+        }
+
+        if no_progress_window > 0 && failing_repeats >= no_progress_window {
+            tracing::warn!(
+                repeats = failing_repeats,
+                "Tool loop made no progress; asking the model to summarize"
+            );
+            let text = summarize_after_no_progress(
+                provider,
+                history,
+                model,
+                temperature,
+                params,
+                tool_call_formats,
+                failing_repeats,
+            )
+            .await?;
+            return Ok(TurnOutcome {
+                text,
+                truncated: false,
+                max_iterations,
+            });
+        }
+    }
+
+    Ok(TurnOutcome {
+        text: partial_text,
+        truncated: true,
+        max_iterations,
+    })
+}
+
+/// Ask the model, with tools withheld, to wrap up after a run of identical
+/// failing tool calls. Any tool calls it still emits are dropped.
+async fn summarize_after_no_progress(
+    provider: &dyn Provider,
+    history: &mut Vec<ChatMessage>,
+    model: &str,
+    temperature: f64,
+    params: Option<&GenerationParams>,
+    tool_call_formats: &[ToolCallFormat],
+    repeats: usize,
+) -> Result<String> {
+    history.push(ChatMessage::user(NO_PROGRESS_PROMPT));
+    let response = provider
+        .chat(
+            ChatRequest {
+                messages: history,
+                tools: None,
+                params,
+            },
+            model,
+            temperature,
+        )
+        .await?;
+    let raw = response.text_or_empty().to_string();
+    history.push(ChatMessage::assistant(raw.clone()));
+
+    let (parsed_text, calls) = parse_tool_calls(&raw, tool_call_formats);
+    let text = if calls.is_empty() { raw } else { parsed_text };
+    if text.trim().is_empty() {
+        Ok(format!(
+            "Stopped after the same tool call failed {repeats} times in a row."
+        ))
+    } else {
+        Ok(text)
+    }
+}
+
+/// Response-cache key for a whole agent turn: model, temperature, system
+/// prompt, and the whitespace-normalized user message (which already carries
+/// the recalled memory context, so new memories change the key).
+fn turn_cache_key(model: &str, temperature: f64, system_prompt: &str, enriched: &str) -> String {
+    let normalized = enriched.split_whitespace().collect::<Vec<_>>().join(" ");
+    memory::ResponseCache::cache_key(
+        &format!("{model}@{temperature}"),
+        Some(system_prompt),
+        &normalized,
+    )
+}
+
+/// Return a cached answer for this turn (opt-in via
+/// `memory.response_cache_enabled`), emitting [`ObserverEvent::CacheHit`].
+fn lookup_cached_turn(
+    cache: Option<&memory::ResponseCache>,
+    key: &str,
+    observer: &dyn Observer,
+    model: &str,
+) -> Option<String> {
+    let cached = cache?.get(key).ok().flatten()?;
+    observer.record_event(&ObserverEvent::CacheHit {
+        cache: "response".to_string(),
+        model: model.to_string(),
+    });
+    Some(cached)
+}
+
+/// Cache a turn's answer unless it used tools: tool calls can have side
+/// effects, and replaying the answer would silently skip them.
+/// `turn_messages` is everything appended to history during the turn.
+fn store_cached_turn(
+    cache: Option<&memory::ResponseCache>,
+    key: &str,
+    model: &str,
+    response: &str,
+    turn_messages: &[ChatMessage],
+) {
+    let Some(cache) = cache else { return };
+    let used_tools = turn_messages.len() > 1;
+    if used_tools || response.trim().is_empty() {
+        return;
     }
+    if let Err(e) = cache.put(key, model, response, 0) {
+        tracing::debug!("Response cache write failed: {e}");
+    }
+}
 
-    anyhow::bail!("Agent exceeded maximum tool iterations ({max_iterations})")
+/// Latest prose the model wrote in `turn_messages`, with tool-call markup
+/// removed. Used to salvage output from a turn that was cancelled.
+fn partial_answer_from_history(
+    turn_messages: &[ChatMessage],
+    tool_call_formats: &[ToolCallFormat],
+) -> String {
+    turn_messages
+        .iter()
+        .rev()
+        .filter(|msg| msg.role == "assistant" && !msg.content.starts_with("[Tool results]"))
+        .map(|msg| {
+            // Native tool-call turns are stored as `{"content": ..., "tool_calls": [...]}`.
+            if let Ok(value) = serde_json::from_str::<serde_json::Value>(&msg.content) {
+                if value.get("tool_calls").is_some() {
+                    return value
+                        .get("content")
+                        .and_then(serde_json::Value::as_str)
+                        .unwrap_or_default()
+                        .to_string();
+                }
+            }
+            parse_tool_calls(&msg.content, tool_call_formats).0
+        })
+        .map(|text| text.trim().to_string())
+        .find(|text| !text.is_empty())
+        .unwrap_or_default()
 }
 
 /// Build the tool instruction block for the system prompt so the LLM knows
@@ -1100,48 +1890,441 @@ pub(crate) fn build_tool_instructions(tools_registry: &[Box<dyn Tool>]) -> Strin
         .push_str("Continue reasoning with the results until you can give a final answer.\n\n");
     instructions.push_str("### Available Tools\n\n");
 
-    for tool in tools_registry {
-        let _ = writeln!(
-            instructions,
-            "**{}**: {}\nParameters: `{}`\n",
-            tool.name(),
-            tool.description(),
-            tool.parameters_schema()
-        );
+    let groups = tools::group_by_category(tools_registry);
+    let show_headings = groups.len() > 1;
+    for (category, group) in groups {
+        if show_headings {
+            let _ = writeln!(instructions, "#### {}\n", tools::category_heading(category));
+        }
+        for tool in group {
+            let _ = writeln!(
+                instructions,
+                "**{}**: {}\nParameters: `{}`\n",
+                tool.name(),
+                tool.description(),
+                tool.parameters_schema()
+            );
+        }
     }
 
     instructions
 }
 
-#[allow(clippy::too_many_lines)]
+/// How a single-message `zeroclaw agent -m` run prints its answer.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum OutputFormat {
+    /// The answer as plain text.
+    #[default]
+    Text,
+    /// One JSON object with the answer, tool calls and timing, for scripts.
+    Json,
+}
+
+/// Forwards to the configured observer while remembering each tool call's
+/// name and outcome, so `--output json` can report them.
+struct ToolCallRecorder {
+    inner: Arc<dyn Observer>,
+    calls: parking_lot::Mutex<Vec<(String, bool)>>,
+}
+
+impl ToolCallRecorder {
+    fn new(inner: Arc<dyn Observer>) -> Self {
+        Self {
+            inner,
+            calls: parking_lot::Mutex::new(Vec::new()),
+        }
+    }
+
+    fn calls(&self) -> Vec<(String, bool)> {
+        self.calls.lock().clone()
+    }
+}
+
+/// `agent.max_tool_calls_per_session` bookkeeping for interactive mode.
+/// Shared by every [`BudgetedTool`], so the limit holds per call rather than
+/// only at turn start. `/clear` resets it.
+struct SessionToolBudget {
+    limit: usize,
+    used: std::sync::atomic::AtomicUsize,
+}
+
+impl SessionToolBudget {
+    fn new(limit: usize) -> Self {
+        Self {
+            limit,
+            used: std::sync::atomic::AtomicUsize::new(0),
+        }
+    }
+
+    fn exhausted(&self) -> bool {
+        self.limit > 0 && self.used.load(std::sync::atomic::Ordering::SeqCst) >= self.limit
+    }
+
+    /// Count one call; `false` once the limit is reached.
+    fn try_take(&self) -> bool {
+        use std::sync::atomic::Ordering;
+        self.limit == 0
+            || self
+                .used
+                .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |used| {
+                    (used < self.limit).then_some(used + 1)
+                })
+                .is_ok()
+    }
+
+    fn reset(&self) {
+        self.used.store(0, std::sync::atomic::Ordering::SeqCst);
+    }
+}
+
+/// Appended to the system prompt once the session tool budget is used up,
+/// since the prompt still lists every tool.
+const TOOLS_PAUSED_NOTE: &str = "\n\n## Tools paused\n\nThe session's tool-call budget is used up. No tools are available until the user runs /clear; answer directly from what you already know.\n";
+
+/// Refuses to run its tool once the shared [`SessionToolBudget`] is spent,
+/// so a turn that starts under the limit can't overshoot it.
+struct BudgetedTool {
+    inner: Box<dyn Tool>,
+    budget: Arc<SessionToolBudget>,
+}
+
+impl BudgetedTool {
+    fn wrap(inner: Box<dyn Tool>, budget: Arc<SessionToolBudget>) -> Box<dyn Tool> {
+        Box::new(Self { inner, budget })
+    }
+
+    fn refusal(&self) -> tools::ToolResult {
+        tools::ToolResult::err(format!(
+            "Session tool-call budget ({}) reached; {} was not run. Tools are paused until the user runs /clear, so answer with what you have.",
+            self.budget.limit,
+            self.inner.name()
+        ))
+    }
+}
+
+#[async_trait::async_trait]
+impl Tool for BudgetedTool {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn description(&self) -> &str {
+        self.inner.description()
+    }
+
+    fn category(&self) -> &str {
+        self.inner.category()
+    }
+
+    fn parameters_schema(&self) -> serde_json::Value {
+        self.inner.parameters_schema()
+    }
+
+    fn validate_args(&self, args: &serde_json::Value) -> Result<(), String> {
+        self.inner.validate_args(args)
+    }
+
+    async fn execute(&self, args: serde_json::Value) -> Result<tools::ToolResult> {
+        if !self.budget.try_take() {
+            return Ok(self.refusal());
+        }
+        self.inner.execute(args).await
+    }
+
+    async fn execute_streaming(
+        &self,
+        args: serde_json::Value,
+        on_output: &tools::ToolOutputCallback<'_>,
+    ) -> Result<tools::ToolResult> {
+        if !self.budget.try_take() {
+            return Ok(self.refusal());
+        }
+        self.inner.execute_streaming(args, on_output).await
+    }
+}
+
+impl Observer for ToolCallRecorder {
+    fn record_event(&self, event: &ObserverEvent) {
+        if let ObserverEvent::ToolCall { tool, success, .. } = event {
+            self.calls.lock().push((tool.clone(), *success));
+        }
+        self.inner.record_event(event);
+    }
+
+    fn record_metric(&self, metric: &observability::traits::ObserverMetric) {
+        self.inner.record_metric(metric);
+    }
+
+    fn flush(&self) {
+        self.inner.flush();
+    }
+
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+/// The `--output json` result object. Providers don't report token usage to
+/// the loop yet, so `tokens` is null until they do.
+fn render_json_output(response: &str, tool_calls: &[(String, bool)], duration: Duration) -> String {
+    let tool_calls: Vec<serde_json::Value> = tool_calls
+        .iter()
+        .map(|(name, success)| serde_json::json!({ "name": name, "success": success }))
+        .collect();
+    serde_json::json!({
+        "response": response,
+        "tool_calls": tool_calls,
+        "duration_ms": u64::try_from(duration.as_millis()).unwrap_or(u64::MAX),
+        "tokens": serde_json::Value::Null,
+    })
+    .to_string()
+}
+
+/// Subsystems brought up together by [`warmup_all`].
+struct WarmSubsystems {
+    mem: Arc<dyn Memory>,
+    peripheral_tools: Vec<Box<dyn Tool>>,
+    hardware_rag: Option<crate::rag::HardwareRag>,
+}
+
+/// Open memory, probe peripherals and index datasheets while the provider
+/// warms up its connection. None of them depends on another, so cold start
+/// costs the slowest rather than the sum. Failures stay per-subsystem: memory
+/// and peripheral errors fail the run as before, while a datasheet index or
+/// provider warmup failure is only logged.
+async fn warmup_all(config: &Config, provider: Option<&dyn Provider>) -> Result<WarmSubsystems> {
+    let memory = async {
+        if config.agent.ephemeral {
+            return Ok::<Arc<dyn Memory>, anyhow::Error>(Arc::new(memory::NoneMemory::new()));
+        }
+        let memory_config = config.memory.clone();
+        let storage_config = config.storage.provider.config.clone();
+        let workspace_dir = config.workspace_dir.clone();
+        let api_key = config.api_key.clone();
+        let mem = tokio::task::spawn_blocking(move || {
+            memory::create_memory_with_storage(
+                &memory_config,
+                Some(&storage_config),
+                &workspace_dir,
+                api_key.as_deref(),
+            )
+        })
+        .await??;
+        Ok(Arc::from(mem))
+    };
+
+    let hardware_rag = async {
+        let dir = config
+            .peripherals
+            .datasheet_dir
+            .as_deref()
+            .map(str::trim)
+            .filter(|d| !d.is_empty())?
+            .to_string();
+        let workspace_dir = config.workspace_dir.clone();
+        let strategy = config.peripherals.datasheet_chunk_strategy;
+        let loaded = tokio::task::spawn_blocking(move || {
+            crate::rag::HardwareRag::load_with_strategy(&workspace_dir, &dir, strategy)
+        })
+        .await;
+        let rag = match loaded {
+            Ok(Ok(rag)) => rag,
+            Ok(Err(e)) => {
+                tracing::warn!("Hardware RAG load failed: {e}");
+                return None;
+            }
+            Err(e) => {
+                tracing::warn!("Hardware RAG load panicked: {e}");
+                return None;
+            }
+        };
+        Some(rag.with_recency_weight(config.peripherals.datasheet_recency_weight))
+            .filter(|r| !r.is_empty())
+    };
+
+    let provider_warmup = async {
+        if let Some(provider) = provider {
+            if let Err(e) = provider.warmup().await {
+                tracing::warn!("Provider warmup failed: {e}");
+            }
+        }
+    };
+
+    let (mem, peripheral_tools, hardware_rag, ()) = tokio::join!(
+        memory,
+        crate::peripherals::create_peripheral_tools(&config.peripherals),
+        hardware_rag,
+        provider_warmup,
+    );
+    Ok(WarmSubsystems {
+        mem: mem?,
+        peripheral_tools: peripheral_tools?,
+        hardware_rag,
+    })
+}
+
+/// Durations of the init steps in `run`, for `--profile-startup`.
+struct StartupTimer {
+    print: bool,
+    last: Instant,
+    steps: Vec<(&'static str, Duration)>,
+}
+
+impl StartupTimer {
+    fn new(print: bool) -> Self {
+        Self {
+            print,
+            last: Instant::now(),
+            steps: Vec::new(),
+        }
+    }
+
+    /// Record the time since the previous step as `step`.
+    fn step(&mut self, step: &'static str) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last);
+        self.last = now;
+        tracing::debug!(step, elapsed_ms = elapsed.as_millis(), "Startup step");
+        self.steps.push((step, elapsed));
+    }
+
+    /// Print the breakdown to stderr when `--profile-startup` was given.
+    fn finish(&self) {
+        if self.print {
+            eprint!("{}", render_startup_breakdown(&self.steps));
+        }
+    }
+}
+
+fn render_startup_breakdown(steps: &[(&str, Duration)]) -> String {
+    let width = steps.iter().map(|(name, _)| name.len()).max().unwrap_or(0);
+    let total: Duration = steps.iter().map(|(_, d)| *d).sum();
+    let mut out = String::from("Startup timing:\n");
+    for (name, elapsed) in steps {
+        let _ = writeln!(out, "  {name:<width$}  {:>6} ms", elapsed.as_millis());
+    }
+    let _ = writeln!(out, "  {:<width$}  {:>6} ms", "total", total.as_millis());
+    out
+}
+
+/// How an agent session ended.
+enum SessionEnd {
+    Finished(String),
+    /// `/profile <name>` in interactive mode: start over with that profile.
+    SwitchProfile(String),
+}
+
+/// `zeroclaw agent --replay`: a recorded provider request log to feed back
+/// through the mock provider.
+#[derive(Debug, Clone)]
+pub struct ReplayOptions {
+    /// `reliability.log_requests` transcript to replay.
+    pub log: std::path::PathBuf,
+    /// Run the recorded tool calls for real. Off by default, since a log
+    /// from someone else would otherwise run its shell and file writes here.
+    pub exec_tools: bool,
+}
+
+/// How [`run`] reports its work.
+#[derive(Debug, Clone, Default)]
+pub struct RunOptions {
+    pub output: OutputFormat,
+    /// Print the fully assembled system prompt and return before the
+    /// provider is created or called.
+    pub dump_prompt: bool,
+    /// Print a per-step breakdown of init time to stderr (it is always
+    /// logged at debug level).
+    pub profile_startup: bool,
+    /// Replay a request log offline instead of calling a real provider.
+    pub replay: Option<ReplayOptions>,
+}
+
+/// Run the agent: one message when `message` is set, otherwise interactive.
+///
+/// `temperature` overrides the active profile's temperature, which in turn
+/// overrides the model's `[[model_routes]]` temperature and then
+/// `default_temperature`.
 pub async fn run(
-    config: Config,
+    mut config: Config,
     message: Option<String>,
     provider_override: Option<String>,
     model_override: Option<String>,
-    temperature: f64,
+    temperature: Option<f64>,
     peripheral_overrides: Vec<String>,
+    options: RunOptions,
 ) -> Result<String> {
+    loop {
+        let session = run_session(
+            config.clone(),
+            message.clone(),
+            provider_override.clone(),
+            model_override.clone(),
+            temperature,
+            peripheral_overrides.clone(),
+            options.clone(),
+        )
+        .await?;
+        match session {
+            SessionEnd::Finished(text) => return Ok(text),
+            SessionEnd::SwitchProfile(name) => config.agent.profile = Some(name),
+        }
+    }
+}
+
+#[allow(clippy::too_many_lines)]
+async fn run_session(
+    mut config: Config,
+    message: Option<String>,
+    provider_override: Option<String>,
+    model_override: Option<String>,
+    temperature: Option<f64>,
+    peripheral_overrides: Vec<String>,
+    options: RunOptions,
+) -> Result<SessionEnd> {
+    let RunOptions {
+        output,
+        dump_prompt,
+        profile_startup,
+        replay,
+    } = options;
+    let mut startup = StartupTimer::new(profile_startup);
+
+    // Wall-clock bound for single-message runs (cron/CI), counted from here.
+    let run_deadline = (config.agent.run_timeout_secs > 0)
+        .then(|| tokio::time::Instant::now() + Duration::from_secs(config.agent.run_timeout_secs));
+
+    // ── Agent profile ─────────────────────────────────────────────
+    let profile = config.agent.active_profile()?.cloned();
+    if let Some(file) = profile.as_ref().and_then(|p| p.system_prompt_file.clone()) {
+        config.agent.system_prompt_file = Some(file);
+    }
+    let profile_temperature = profile.as_ref().and_then(|p| p.temperature);
+    let profile_tools = profile.and_then(|p| p.tools);
+
     // ── Wire up agnostic subsystems ──────────────────────────────
     let base_observer = observability::create_observer(&config.observability);
-    let observer: Arc<dyn Observer> = Arc::from(base_observer);
+    let tool_recorder = Arc::new(ToolCallRecorder::new(Arc::from(base_observer)));
+    let observer: Arc<dyn Observer> = tool_recorder.clone();
     let runtime: Arc<dyn runtime::RuntimeAdapter> =
         Arc::from(runtime::create_runtime(&config.runtime)?);
     let security = Arc::new(SecurityPolicy::from_config(
         &config.autonomy,
         &config.workspace_dir,
     ));
+    let audit_log = AuditLogger::for_run(&config.security.audit, &config.config_path)?;
+    startup.step("observer + runtime");
+
+    // Ephemeral runs neither recall nor persist anything, including the
+    // on-disk response cache.
+    if config.agent.ephemeral {
+        config.memory.auto_save = false;
+        config.memory.response_cache_enabled = false;
+    }
 
-    // ── Memory (the brain) ────────────────────────────────────────
-    let mem: Arc<dyn Memory> = Arc::from(memory::create_memory_with_storage(
-        &config.memory,
-        Some(&config.storage.provider.config),
-        &config.workspace_dir,
-        config.api_key.as_deref(),
-    )?);
-    tracing::info!(backend = mem.name(), "Memory initialized");
-
-    // ── Peripherals (merge peripheral tools into registry) ─
     if !peripheral_overrides.is_empty() {
         tracing::info!(
             peripherals = ?peripheral_overrides,
@@ -1149,6 +2332,71 @@ pub async fn run(
         );
     }
 
+    // ── Resolve provider ─────────────────────────────────────────
+    let provider_name = provider_override
+        .as_deref()
+        .or(config.default_provider.as_deref())
+        .unwrap_or("openrouter");
+
+    let model_name = model_override
+        .as_deref()
+        .or(config.default_model.as_deref())
+        .unwrap_or("anthropic/claude-sonnet-4");
+    let temperature = temperature
+        .or(profile_temperature)
+        .unwrap_or_else(|| config.default_temperature_for(model_name));
+
+    // --dump-prompt never talks to the model, so it needs no provider.
+    let cost_budget = config
+        .agent
+        .max_cost_usd
+        .filter(|_| !dump_prompt)
+        .map(|limit| CostBudget::new(limit, provider_name, model_name, &config.cost.prices))
+        .transpose()?
+        .map(Arc::new);
+    let provider: Option<Box<dyn Provider>> = if dump_prompt {
+        None
+    } else {
+        // A replay reads its responses from the log and never goes through
+        // model routes or request logging.
+        let provider: Box<dyn Provider> = if let Some(replay) = &replay {
+            Box::new(providers::mock::MockProvider::from_request_log(
+                &replay.log,
+            )?)
+        } else {
+            providers::create_routed_provider_with_options(
+                provider_name,
+                config.api_key.as_deref(),
+                config.api_url.as_deref(),
+                &config.reliability,
+                &config.model_routes,
+                model_name,
+                &providers::ProviderRuntimeOptions {
+                    observer: Some(observer.clone()),
+                    azure_openai: config.azure_openai.clone(),
+                    ..providers::ProviderRuntimeOptions::default()
+                },
+            )?
+        };
+        Some(match &cost_budget {
+            Some(budget) => Box::new(CostBudgetProvider::new(provider, budget.clone())),
+            None => provider,
+        })
+    };
+    startup.step("provider creation");
+
+    // ── Memory, peripherals, hardware RAG, provider warmup ───────
+    let WarmSubsystems {
+        mem,
+        peripheral_tools,
+        hardware_rag,
+    } = warmup_all(&config, provider.as_deref()).await?;
+    tracing::info!(backend = mem.name(), "Memory initialized");
+    if let Some(ref rag) = hardware_rag {
+        tracing::info!(chunks = rag.len(), "Hardware RAG loaded");
+    }
+    startup.step("concurrent warmup");
+
     // ── Tools (including memory tools and peripherals) ────────────
     let (composio_key, composio_entity_id) = if config.composio.enabled {
         (
@@ -1172,50 +2420,25 @@ pub async fn run(
         config.api_key.as_deref(),
         &config,
     );
-
-    let peripheral_tools: Vec<Box<dyn Tool>> =
-        crate::peripherals::create_peripheral_tools(&config.peripherals).await?;
     if !peripheral_tools.is_empty() {
         tracing::info!(count = peripheral_tools.len(), "Peripheral tools added");
         tools_registry.extend(peripheral_tools);
     }
-
-    // ── Resolve provider ─────────────────────────────────────────
-    let provider_name = provider_override
-        .as_deref()
-        .or(config.default_provider.as_deref())
-        .unwrap_or("openrouter");
-
-    let model_name = model_override
-        .as_deref()
-        .or(config.default_model.as_deref())
-        .unwrap_or("anthropic/claude-sonnet-4");
-
-    let provider: Box<dyn Provider> = providers::create_routed_provider(
-        provider_name,
-        config.api_key.as_deref(),
-        config.api_url.as_deref(),
-        &config.reliability,
-        &config.model_routes,
-        model_name,
-    )?;
-
-    observer.record_event(&ObserverEvent::AgentStart {
-        provider: provider_name.to_string(),
-        model: model_name.to_string(),
-    });
-
-    // ── Hardware RAG (datasheet retrieval when peripherals + datasheet_dir) ──
-    let hardware_rag: Option<crate::rag::HardwareRag> = config
-        .peripherals
-        .datasheet_dir
+    if replay.as_ref().is_some_and(|replay| !replay.exec_tools) {
+        tools_registry = tools_registry
+            .into_iter()
+            .map(providers::mock::ReplayedTool::wrap)
+            .collect();
+    }
+    startup.step("tool registry");
+    if let Some(enabled) = &profile_tools {
+        tools_registry.retain(|tool| enabled.iter().any(|name| name == tool.name()));
+    }
+    if profile_tools
         .as_ref()
-        .filter(|d| !d.trim().is_empty())
-        .map(|dir| crate::rag::HardwareRag::load(&config.workspace_dir, dir.trim()))
-        .and_then(Result::ok)
-        .filter(|r: &crate::rag::HardwareRag| !r.is_empty());
-    if let Some(ref rag) = hardware_rag {
-        tracing::info!(chunks = rag.len(), "Hardware RAG loaded");
+        .is_none_or(|enabled| enabled.iter().any(|name| name == "self_info"))
+    {
+        tools::register_self_info(&mut tools_registry, &config);
     }
 
     let board_names: Vec<String> = config
@@ -1252,7 +2475,14 @@ pub async fn run(
             "memory_forget",
             "Delete a memory entry. Use when: memory is incorrect/stale or explicitly requested for removal. Don't use when: impact is uncertain.",
         ),
+        (
+            "set_preference",
+            "Set or remove a durable user preference that is always shown in your instructions. Use when: the user states a standing preference (language, units, tone, timezone). Don't use when: it only applies to the current task.",
+        ),
     ];
+    if config.agent.ephemeral {
+        tool_descs.retain(|(name, _)| *name != "set_preference");
+    }
     tool_descs.push((
         "cron_add",
         "Create a cron job. Supports schedule kinds: cron, at, every; and job types: shell or agent.",
@@ -1279,22 +2509,45 @@ pub async fn run(
         "image_info",
         "Read image file metadata (format, dimensions, size) and optionally base64-encode it. Use when: inspecting images, preparing visual data for analysis.",
     ));
+    if cfg!(feature = "ocr") {
+        tool_descs.push((
+            "image_ocr",
+            "Extract text from an image file with OCR. Use when: reading a screenshot or scanned text with a text-only model.",
+        ));
+    }
+    if cfg!(feature = "clipboard") && config.autonomy.allow_clipboard {
+        tool_descs.push((
+            "clipboard",
+            "Read (action='get') or write (action='set') plain text on the system clipboard. Use when: the user refers to something they copied, or wants a result to paste.",
+        ));
+    }
     if config.browser.enabled {
         tool_descs.push((
             "browser_open",
             "Open approved HTTPS URLs in Brave Browser (allowlist-only, no scraping)",
         ));
     }
+    if config.browser.fetch_enabled {
+        tool_descs.push(("browser_fetch", "Fetch an approved HTTPS page and return its readable text (allowlist-only). Use when: researching docs or articles on allowed domains."));
+    }
     if config.composio.enabled {
         tool_descs.push((
             "composio",
             "Execute actions on 1000+ apps via Composio (Gmail, Notion, GitHub, Slack, etc.). Use action='list' to discover, 'execute' to run (optionally with connected_account_id), 'connect' to OAuth.",
         ));
     }
+    tool_descs.push((
+        "wait",
+        "Wait a number of seconds (capped by agent.max_wait_secs). Use when: timing hardware actions or polling; prefer over shell sleep.",
+    ));
     tool_descs.push((
         "schedule",
         "Manage scheduled tasks (create/list/get/cancel/pause/resume). Supports recurring cron and one-shot delays.",
     ));
+    tool_descs.push((
+        "self_info",
+        "Show your own provider, model, enabled tools, boards and build features (no secrets). Use when: asked what you can do or which model you are.",
+    ));
     if !config.agents.is_empty() {
         tool_descs.push((
             "delegate",
@@ -1331,43 +2584,79 @@ pub async fn run(
             "Query connected hardware for reported GPIO pins and LED pin. Use when: user asks what pins are available.",
         ));
     }
+    if let Some(enabled) = &profile_tools {
+        tool_descs.retain(|(name, _)| enabled.iter().any(|n| n == name));
+    }
     let bootstrap_max_chars = if config.agent.compact_context {
         Some(6000)
     } else {
         None
     };
-    let mut system_prompt = crate::channels::build_system_prompt(
-        &config.workspace_dir,
-        model_name,
-        &tool_descs,
-        &skills,
-        Some(&config.identity),
-        bootstrap_max_chars,
+    let mut prompt_base = crate::channels::load_system_prompt_file(&config)?.unwrap_or_else(|| {
+        crate::channels::build_system_prompt(
+            &config.workspace_dir,
+            model_name,
+            &tool_descs,
+            &skills,
+            Some(&config.identity),
+            bootstrap_max_chars,
+        )
+    });
+    prompt_base.push_str(&crate::channels::load_context_files(&config));
+    prompt_base.push_str(&crate::channels::load_user_preferences(&config));
+
+    // The file tree sits between the base prompt and the structured tool-use
+    // instructions so `/tree` can swap it out.
+    let tool_instructions = build_tool_instructions(&tools_registry);
+    let mut system_prompt = format!(
+        "{prompt_base}{}{tool_instructions}",
+        build_workspace_file_tree(&config)
     );
+    startup.step("system prompt build");
 
-    // Append structured tool-use instructions with schemas
-    system_prompt.push_str(&build_tool_instructions(&tools_registry));
+    if dump_prompt {
+        startup.finish();
+        println!("{system_prompt}");
+        return Ok(SessionEnd::Finished(system_prompt));
+    }
+
+    let Some(provider) = provider else {
+        unreachable!("the provider is only skipped for --dump-prompt");
+    };
+    startup.finish();
+
+    observer.record_event(&ObserverEvent::AgentStart {
+        provider: provider_name.to_string(),
+        model: model_name.to_string(),
+    });
 
     // ── Approval manager (supervised mode) ───────────────────────
     let approval_manager = ApprovalManager::from_config(&config.autonomy);
+    let generation = config.agent.generation_params();
 
     // ── Execute ──────────────────────────────────────────────────
     let start = Instant::now();
 
     let mut final_output = String::new();
+    let mut switch_profile: Option<String> = None;
 
     if let Some(msg) = message {
         // Auto-save user message to memory
         if config.memory.auto_save {
             let user_key = autosave_memory_key("user_msg");
-            let _ = mem
-                .store(&user_key, &msg, MemoryCategory::Conversation, None)
-                .await;
+            let _ =
+                memory::store_autosave(mem.as_ref(), &user_key, &msg, MemoryCategory::Conversation)
+                    .await;
         }
 
         // Inject memory + hardware RAG context into user message
-        let mem_context =
-            build_context(mem.as_ref(), &msg, config.memory.min_relevance_score).await;
+        let mem_context = build_context(
+            mem.as_ref(),
+            &msg,
+            config.memory.recall_limit,
+            config.memory.min_relevance_score,
+        )
+        .await;
         let rag_limit = if config.agent.compact_context { 2 } else { 5 };
         let hw_context = hardware_rag
             .as_ref()
@@ -1385,40 +2674,158 @@ pub async fn run(
             ChatMessage::user(&enriched),
         ];
 
-        let response = run_tool_call_loop(
-            provider.as_ref(),
-            &mut history,
-            &tools_registry,
+        let response_cache = memory::create_response_cache(&config.memory, &config.workspace_dir);
+        let cache_key = turn_cache_key(model_name, temperature, &system_prompt, &enriched);
+        let cached = lookup_cached_turn(
+            response_cache.as_ref(),
+            &cache_key,
             observer.as_ref(),
-            provider_name,
             model_name,
-            temperature,
-            false,
-            Some(&approval_manager),
-            "cli",
-            config.agent.max_tool_iterations,
-            None,
-        )
-        .await?;
+        );
+
+        let response = if let Some(cached) = cached {
+            cached
+        } else {
+            let turn = run_tool_call_loop_outcome(
+                provider.as_ref(),
+                &mut history,
+                &tools_registry,
+                observer.as_ref(),
+                LoopOptions {
+                    provider_name,
+                    model: model_name,
+                    temperature,
+                    params: Some(&generation),
+                    // JSON output must be the only thing on stdout.
+                    silent: output == OutputFormat::Json,
+                    approval: Some(&approval_manager),
+                    channel_name: "cli",
+                    max_tool_iterations: config.agent.max_tool_iterations,
+                    no_progress_window: config.agent.no_progress_window,
+                    turn_retries: config.reliability.turn_retries,
+                    tool_result_role: config.agent.tool_result_role_for(provider_name),
+                    arg_validation: config.agent.tool_arg_validation,
+                    tool_call_formats: &config.agent.tool_call_formats,
+                    on_delta: None,
+                    audit_log: audit_log.clone(),
+                    secret_refs: &config.secrets.refs,
+                },
+            );
+            let outcome = match run_deadline {
+                Some(deadline) => tokio::time::timeout_at(deadline, turn).await.ok(),
+                None => Some(turn.await),
+            };
+            let outcome = match outcome.transpose() {
+                Err(e) => {
+                    let Some(exceeded) = e.downcast_ref::<CostBudgetExceeded>() else {
+                        return Err(e);
+                    };
+                    observer.record_event(&ObserverEvent::CostBudgetExceeded {
+                        limit_usd: exceeded.limit_usd,
+                        spent_usd: exceeded.spent_usd,
+                    });
+                    let partial =
+                        partial_answer_from_history(&history[2..], &config.agent.tool_call_formats);
+                    if output == OutputFormat::Json {
+                        println!(
+                            "{}",
+                            render_json_output(&partial, &tool_recorder.calls(), start.elapsed())
+                        );
+                    } else if !partial.is_empty() {
+                        println!("{partial}");
+                    }
+                    observer.record_event(&ObserverEvent::AgentEnd {
+                        provider: provider_name.to_string(),
+                        model: model_name.to_string(),
+                        duration: start.elapsed(),
+                        tokens_used: None,
+                        cost_usd: Some(exceeded.spent_usd),
+                    });
+                    return Err(e);
+                }
+                Ok(outcome) => outcome,
+            };
+            let Some(outcome) = outcome else {
+                // Dropping the turn cancels any in-flight provider or tool call;
+                // whatever the model said before that is still in `history`.
+                let partial =
+                    partial_answer_from_history(&history[2..], &config.agent.tool_call_formats);
+                if output == OutputFormat::Json {
+                    println!(
+                        "{}",
+                        render_json_output(&partial, &tool_recorder.calls(), start.elapsed())
+                    );
+                } else if !partial.is_empty() {
+                    println!("{partial}");
+                }
+                observer.record_event(&ObserverEvent::AgentEnd {
+                    provider: provider_name.to_string(),
+                    model: model_name.to_string(),
+                    duration: start.elapsed(),
+                    tokens_used: None,
+                    cost_usd: None,
+                });
+                anyhow::bail!(
+                    "Agent run timed out after {}s",
+                    config.agent.run_timeout_secs
+                );
+            };
+            let response = render_turn_outcome(&outcome);
+            if !outcome.truncated {
+                store_cached_turn(
+                    response_cache.as_ref(),
+                    &cache_key,
+                    model_name,
+                    &response,
+                    &history[2..],
+                );
+            }
+            response
+        };
         final_output = response.clone();
-        println!("{response}");
+        match output {
+            OutputFormat::Text => println!("{response}"),
+            OutputFormat::Json => println!(
+                "{}",
+                render_json_output(&response, &tool_recorder.calls(), start.elapsed())
+            ),
+        }
         observer.record_event(&ObserverEvent::TurnComplete);
 
         // Auto-save assistant response to daily log
         if config.memory.auto_save {
             let summary = truncate_with_ellipsis(&response, 100);
             let response_key = autosave_memory_key("assistant_resp");
-            let _ = mem
-                .store(&response_key, &summary, MemoryCategory::Daily, None)
-                .await;
+            let _ = memory::store_autosave(
+                mem.as_ref(),
+                &response_key,
+                &summary,
+                MemoryCategory::Daily,
+            )
+            .await;
         }
     } else {
         println!("🦀 ZeroClaw Interactive Mode");
+        if let Some(name) = config.agent.profile.as_deref() {
+            println!("Profile: {name}");
+        }
         println!("Type /help for commands.\n");
         let cli = crate::channels::CliChannel::new();
 
         // Persistent conversation history across turns
         let mut history = vec![ChatMessage::system(&system_prompt)];
+        let tool_budget = Arc::new(SessionToolBudget::new(
+            config.agent.max_tool_calls_per_session,
+        ));
+        let mut tool_budget_warned = false;
+        let tools_registry: Vec<Box<dyn Tool>> = if config.agent.max_tool_calls_per_session > 0 {
+            tools_registry
+                .into_iter()
+                .map(|tool| BudgetedTool::wrap(tool, tool_budget.clone()))
+                .collect()
+        } else {
+            tools_registry
+        };
 
         loop {
             print!("> ");
@@ -1444,9 +2851,65 @@ pub async fn run(
                     println!("Available commands:");
                     println!("  /help        Show this help message");
                     println!("  /clear /new  Clear conversation history");
+                    println!("  /pin         Keep your last message through history trimming");
+                    println!("  /unpin       Release all pinned messages");
+                    println!("  /profile     List profiles, or /profile <name> to switch");
+                    println!("  /tree        Refresh the workspace file tree in the system prompt");
                     println!("  /quit /exit  Exit interactive mode\n");
                     continue;
                 }
+                cmd if cmd == "/profile" || cmd.starts_with("/profile ") => {
+                    let requested = cmd["/profile".len()..].trim();
+                    if requested.is_empty() {
+                        print_profiles(&config.agent);
+                    } else if config.agent.profiles.contains_key(requested) {
+                        println!("Switching to profile '{requested}' (new conversation).\n");
+                        switch_profile = Some(requested.to_string());
+                        break;
+                    } else {
+                        println!("Unknown profile '{requested}'.");
+                        print_profiles(&config.agent);
+                    }
+                    continue;
+                }
+                "/tree" => {
+                    if !config.agent.include_file_tree {
+                        println!("Set agent.include_file_tree = true to use /tree.\n");
+                        continue;
+                    }
+                    system_prompt = format!(
+                        "{prompt_base}{}{tool_instructions}",
+                        build_workspace_file_tree(&config)
+                    );
+                    if let Some(first) = history.first_mut().filter(|m| m.role == "system") {
+                        first.content.clone_from(&system_prompt);
+                    }
+                    println!("Workspace file tree refreshed.\n");
+                    continue;
+                }
+                "/pin" => {
+                    match history
+                        .iter_mut()
+                        .rev()
+                        .find(|m| m.role == "user" && !m.content.starts_with("[Tool results]"))
+                    {
+                        Some(msg) => {
+                            msg.pinned = true;
+                            println!("Pinned your last message.\n");
+                        }
+                        None => println!("Nothing to pin yet.\n"),
+                    }
+                    continue;
+                }
+                "/unpin" => {
+                    let mut released = 0;
+                    for msg in history.iter_mut().filter(|m| m.pinned) {
+                        msg.pinned = false;
+                        released += 1;
+                    }
+                    println!("Released {released} pinned message(s).\n");
+                    continue;
+                }
                 "/clear" | "/new" => {
                     println!(
                         "This will clear the current conversation and delete all session memory."
@@ -1466,6 +2929,8 @@ pub async fn run(
 
                     history.clear();
                     history.push(ChatMessage::system(&system_prompt));
+                    tool_budget.reset();
+                    tool_budget_warned = false;
                     // Clear conversation and daily memory
                     let mut cleared = 0;
                     for category in [MemoryCategory::Conversation, MemoryCategory::Daily] {
@@ -1489,14 +2954,23 @@ pub async fn run(
             // Auto-save conversation turns
             if config.memory.auto_save {
                 let user_key = autosave_memory_key("user_msg");
-                let _ = mem
-                    .store(&user_key, &user_input, MemoryCategory::Conversation, None)
-                    .await;
+                let _ = memory::store_autosave(
+                    mem.as_ref(),
+                    &user_key,
+                    &user_input,
+                    MemoryCategory::Conversation,
+                )
+                .await;
             }
 
             // Inject memory + hardware RAG context into user message
-            let mem_context =
-                build_context(mem.as_ref(), &user_input, config.memory.min_relevance_score).await;
+            let mem_context = build_context(
+                mem.as_ref(),
+                &user_input,
+                config.memory.recall_limit,
+                config.memory.min_relevance_score,
+            )
+            .await;
             let rag_limit = if config.agent.compact_context { 2 } else { 5 };
             let hw_context = hardware_rag
                 .as_ref()
@@ -1510,25 +2984,70 @@ pub async fn run(
             };
 
             history.push(ChatMessage::user(&enriched));
+            let turn_start = history.len();
+
+            let budget_exhausted = tool_budget.exhausted();
+            if budget_exhausted && !tool_budget_warned {
+                tool_budget_warned = true;
+                history[0] = ChatMessage::system(format!("{system_prompt}{TOOLS_PAUSED_NOTE}"));
+                observer.record_event(&ObserverEvent::ToolBudgetExhausted {
+                    limit: config.agent.max_tool_calls_per_session,
+                });
+                println!(
+                    "⚠️  Session tool-call budget ({}) reached; continuing without tools. Use /clear to reset.\n",
+                    config.agent.max_tool_calls_per_session
+                );
+            }
+            let turn_tools: &[Box<dyn Tool>] = if budget_exhausted {
+                &[]
+            } else {
+                &tools_registry
+            };
 
-            let response = match run_tool_call_loop(
+            let response = match run_tool_call_loop_outcome(
                 provider.as_ref(),
                 &mut history,
-                &tools_registry,
+                turn_tools,
                 observer.as_ref(),
-                provider_name,
-                model_name,
-                temperature,
-                false,
-                Some(&approval_manager),
-                "cli",
-                config.agent.max_tool_iterations,
-                None,
+                LoopOptions {
+                    provider_name,
+                    model: model_name,
+                    temperature,
+                    params: Some(&generation),
+                    silent: false,
+                    approval: Some(&approval_manager),
+                    channel_name: "cli",
+                    max_tool_iterations: config.agent.max_tool_iterations,
+                    no_progress_window: config.agent.no_progress_window,
+                    turn_retries: config.reliability.turn_retries,
+                    tool_result_role: config.agent.tool_result_role_for(provider_name),
+                    arg_validation: config.agent.tool_arg_validation,
+                    tool_call_formats: &config.agent.tool_call_formats,
+                    on_delta: None,
+                    audit_log: audit_log.clone(),
+                    secret_refs: &config.secrets.refs,
+                },
             )
             .await
             {
-                Ok(resp) => resp,
+                Ok(outcome) => render_turn_outcome(&outcome),
                 Err(e) => {
+                    if let Some(exceeded) = e.downcast_ref::<CostBudgetExceeded>() {
+                        observer.record_event(&ObserverEvent::CostBudgetExceeded {
+                            limit_usd: exceeded.limit_usd,
+                            spent_usd: exceeded.spent_usd,
+                        });
+                        let partial = partial_answer_from_history(
+                            &history[turn_start..],
+                            &config.agent.tool_call_formats,
+                        );
+                        if !partial.is_empty() {
+                            println!("\n{partial}\n");
+                            final_output = partial;
+                        }
+                        println!("💸 {exceeded}. Ending the session.\n");
+                        break;
+                    }
                     eprintln!("\nError: {e}\n");
                     continue;
                 }
@@ -1544,6 +3063,8 @@ pub async fn run(
             }
             observer.record_event(&ObserverEvent::TurnComplete);
 
+            compact_old_tool_results(&mut history, config.agent.compact_tool_results_after);
+
             // Auto-compaction before hard trimming to preserve long-context signal.
             if let Ok(compacted) = auto_compact_history(
                 &mut history,
@@ -1564,9 +3085,13 @@ pub async fn run(
             if config.memory.auto_save {
                 let summary = truncate_with_ellipsis(&response, 100);
                 let response_key = autosave_memory_key("assistant_resp");
-                let _ = mem
-                    .store(&response_key, &summary, MemoryCategory::Daily, None)
-                    .await;
+                let _ = memory::store_autosave(
+                    mem.as_ref(),
+                    &response_key,
+                    &summary,
+                    MemoryCategory::Daily,
+                )
+                .await;
             }
         }
     }
@@ -1577,15 +3102,58 @@ pub async fn run(
         model: model_name.to_string(),
         duration,
         tokens_used: None,
-        cost_usd: None,
+        cost_usd: cost_budget.as_ref().map(|budget| budget.spent_usd()),
     });
 
-    Ok(final_output)
+    Ok(match switch_profile {
+        Some(name) => SessionEnd::SwitchProfile(name),
+        None => SessionEnd::Finished(final_output),
+    })
 }
 
-/// Process a single message through the full agent (with tools, peripherals, memory).
-/// Used by channels (Telegram, Discord, etc.) to enable hardware and tool use.
-pub async fn process_message(config: Config, message: &str) -> Result<String> {
+fn print_profiles(agent: &crate::config::AgentConfig) {
+    if agent.profiles.is_empty() {
+        println!("No profiles defined. Add [agent.profiles.<name>] to config.toml.\n");
+        return;
+    }
+    let mut names: Vec<&String> = agent.profiles.keys().collect();
+    names.sort();
+    println!("Profiles:");
+    for name in names {
+        let marker = if agent.profile.as_deref() == Some(name.as_str()) {
+            "*"
+        } else {
+            " "
+        };
+        println!("  {marker} {name}");
+    }
+    println!();
+}
+
+/// Process a single message through the full agent (with tools, peripherals, memory).
+/// Used by channels (Telegram, Discord, etc.) to enable hardware and tool use.
+/// Hitting the iteration cap returns the partial answer with a note saying so.
+pub async fn process_message(config: Config, message: &str) -> Result<String> {
+    process_message_outcome(config, message)
+        .await
+        .map(|outcome| render_turn_outcome(&outcome))
+}
+
+/// Like [`process_message`], but reports whether the turn was cut off at the
+/// iteration cap instead of folding that into the text.
+pub(crate) async fn process_message_outcome(config: Config, message: &str) -> Result<TurnOutcome> {
+    let finished = |text: String| TurnOutcome {
+        text,
+        truncated: false,
+        max_iterations: config.agent.max_tool_iterations,
+    };
+    let moderation = ModerationGate::from_config(&config.moderation)?;
+    if let Some(gate) = &moderation {
+        if let Some(blocked) = gate.screen_input(message).await {
+            return Ok(finished(blocked));
+        }
+    }
+
     let observer: Arc<dyn Observer> =
         Arc::from(observability::create_observer(&config.observability));
     let runtime: Arc<dyn runtime::RuntimeAdapter> =
@@ -1594,6 +3162,7 @@ pub async fn process_message(config: Config, message: &str) -> Result<String> {
         &config.autonomy,
         &config.workspace_dir,
     ));
+    let audit_log = AuditLogger::for_run(&config.security.audit, &config.config_path)?;
     let mem: Arc<dyn Memory> = Arc::from(memory::create_memory_with_storage(
         &config.memory,
         Some(&config.storage.provider.config),
@@ -1626,19 +3195,26 @@ pub async fn process_message(config: Config, message: &str) -> Result<String> {
     let peripheral_tools: Vec<Box<dyn Tool>> =
         crate::peripherals::create_peripheral_tools(&config.peripherals).await?;
     tools_registry.extend(peripheral_tools);
+    tools::register_self_info(&mut tools_registry, &config);
 
     let provider_name = config.default_provider.as_deref().unwrap_or("openrouter");
     let model_name = config
         .default_model
         .clone()
         .unwrap_or_else(|| "anthropic/claude-sonnet-4-20250514".into());
-    let provider: Box<dyn Provider> = providers::create_routed_provider(
+    let temperature = config.default_temperature_for(&model_name);
+    let provider: Box<dyn Provider> = providers::create_routed_provider_with_options(
         provider_name,
         config.api_key.as_deref(),
         config.api_url.as_deref(),
         &config.reliability,
         &config.model_routes,
         &model_name,
+        &providers::ProviderRuntimeOptions {
+            observer: Some(observer.clone()),
+            azure_openai: config.azure_openai.clone(),
+            ..providers::ProviderRuntimeOptions::default()
+        },
     )?;
 
     let hardware_rag: Option<crate::rag::HardwareRag> = config
@@ -1646,8 +3222,15 @@ pub async fn process_message(config: Config, message: &str) -> Result<String> {
         .datasheet_dir
         .as_ref()
         .filter(|d| !d.trim().is_empty())
-        .map(|dir| crate::rag::HardwareRag::load(&config.workspace_dir, dir.trim()))
+        .map(|dir| {
+            crate::rag::HardwareRag::load_with_strategy(
+                &config.workspace_dir,
+                dir.trim(),
+                config.peripherals.datasheet_chunk_strategy,
+            )
+        })
         .and_then(Result::ok)
+        .map(|r| r.with_recency_weight(config.peripherals.datasheet_recency_weight))
         .filter(|r: &crate::rag::HardwareRag| !r.is_empty());
     let board_names: Vec<String> = config
         .peripherals
@@ -1664,9 +3247,13 @@ pub async fn process_message(config: Config, message: &str) -> Result<String> {
         ("memory_store", "Save to memory."),
         ("memory_recall", "Search memory."),
         ("memory_forget", "Delete a memory entry."),
+        ("set_preference", "Set or remove a user preference."),
         ("screenshot", "Capture a screenshot."),
         ("image_info", "Read image metadata."),
     ];
+    if config.agent.ephemeral {
+        tool_descs.retain(|(name, _)| *name != "set_preference");
+    }
     if config.browser.enabled {
         tool_descs.push(("browser_open", "Open approved URLs in browser."));
     }
@@ -1705,17 +3292,29 @@ pub async fn process_message(config: Config, message: &str) -> Result<String> {
     } else {
         None
     };
-    let mut system_prompt = crate::channels::build_system_prompt(
-        &config.workspace_dir,
-        &model_name,
-        &tool_descs,
-        &skills,
-        Some(&config.identity),
-        bootstrap_max_chars,
-    );
+    let mut system_prompt =
+        crate::channels::load_system_prompt_file(&config)?.unwrap_or_else(|| {
+            crate::channels::build_system_prompt(
+                &config.workspace_dir,
+                &model_name,
+                &tool_descs,
+                &skills,
+                Some(&config.identity),
+                bootstrap_max_chars,
+            )
+        });
+    system_prompt.push_str(&crate::channels::load_context_files(&config));
+    system_prompt.push_str(&crate::channels::load_user_preferences(&config));
+    system_prompt.push_str(&build_workspace_file_tree(&config));
     system_prompt.push_str(&build_tool_instructions(&tools_registry));
 
-    let mem_context = build_context(mem.as_ref(), message, config.memory.min_relevance_score).await;
+    let mem_context = build_context(
+        mem.as_ref(),
+        message,
+        config.memory.recall_limit,
+        config.memory.min_relevance_score,
+    )
+    .await;
     let rag_limit = if config.agent.compact_context { 2 } else { 5 };
     let hw_context = hardware_rag
         .as_ref()
@@ -1733,18 +3332,58 @@ pub async fn process_message(config: Config, message: &str) -> Result<String> {
         ChatMessage::user(&enriched),
     ];
 
-    agent_turn(
+    let response_cache = memory::create_response_cache(&config.memory, &config.workspace_dir);
+    let cache_key = turn_cache_key(&model_name, temperature, &system_prompt, &enriched);
+    if let Some(cached) = lookup_cached_turn(
+        response_cache.as_ref(),
+        &cache_key,
+        observer.as_ref(),
+        &model_name,
+    ) {
+        return Ok(finished(cached));
+    }
+
+    let generation = config.agent.generation_params();
+    let outcome = run_tool_call_loop_outcome(
         provider.as_ref(),
         &mut history,
         &tools_registry,
         observer.as_ref(),
-        provider_name,
-        &model_name,
-        config.default_temperature,
-        true,
-        config.agent.max_tool_iterations,
+        LoopOptions {
+            provider_name,
+            model: &model_name,
+            temperature,
+            params: Some(&generation),
+            silent: true,
+            approval: None,
+            channel_name: "channel",
+            max_tool_iterations: config.agent.max_tool_iterations,
+            no_progress_window: config.agent.no_progress_window,
+            turn_retries: config.reliability.turn_retries,
+            tool_result_role: config.agent.tool_result_role_for(provider_name),
+            arg_validation: config.agent.tool_arg_validation,
+            tool_call_formats: &config.agent.tool_call_formats,
+            on_delta: None,
+            audit_log,
+            secret_refs: &config.secrets.refs,
+        },
     )
-    .await
+    .await?;
+    if let Some(gate) = &moderation {
+        if let Some(blocked) = gate.screen_output(&outcome.text).await {
+            return Ok(finished(blocked));
+        }
+    }
+    if !outcome.truncated {
+        store_cached_turn(
+            response_cache.as_ref(),
+            &cache_key,
+            &model_name,
+            &outcome.text,
+            &history[2..],
+        );
+    }
+    Ok(outcome)
 }
 
 #[cfg(test)]
@@ -1779,7 +3418,7 @@ mod tests {
 {"name": "shell", "arguments": {"command": "ls -la"}}
 </tool_call>"#;
 
-        let (text, calls) = parse_tool_calls(response);
+        let (text, calls) = parse_tool_calls(response, &[]);
         assert_eq!(text, "Let me check that.");
         assert_eq!(calls.len(), 1);
         assert_eq!(calls[0].name, "shell");
@@ -1798,7 +3437,7 @@ mod tests {
 {"name": "file_read", "arguments": {"path": "b.txt"}}
 </tool_call>"#;
 
-        let (_, calls) = parse_tool_calls(response);
+        let (_, calls) = parse_tool_calls(response, &[]);
         assert_eq!(calls.len(), 2);
         assert_eq!(calls[0].name, "file_read");
         assert_eq!(calls[1].name, "file_read");
@@ -1807,7 +3446,7 @@ mod tests {
     #[test]
     fn parse_tool_calls_returns_text_only_when_no_calls() {
         let response = "Just a normal response with no tools.";
-        let (text, calls) = parse_tool_calls(response);
+        let (text, calls) = parse_tool_calls(response, &[]);
         assert_eq!(text, "Just a normal response with no tools.");
         assert!(calls.is_empty());
     }
@@ -1819,7 +3458,7 @@ not valid json
 </tool_call>
 Some text after."#;
 
-        let (text, calls) = parse_tool_calls(response);
+        let (text, calls) = parse_tool_calls(response, &[]);
         assert!(calls.is_empty());
         assert!(text.contains("Some text after."));
     }
@@ -1832,7 +3471,7 @@ Some text after."#;
 </tool_call>
 After text."#;
 
-        let (text, calls) = parse_tool_calls(response);
+        let (text, calls) = parse_tool_calls(response, &[]);
         assert!(text.contains("Before text."));
         assert!(text.contains("After text."));
         assert_eq!(calls.len(), 1);
@@ -1843,7 +3482,7 @@ After text."#;
         // OpenAI-style response with tool_calls array
         let response = r#"{"content": "Let me check that for you.", "tool_calls": [{"type": "function", "function": {"name": "shell", "arguments": "{\"command\": \"ls -la\"}"}}]}"#;
 
-        let (text, calls) = parse_tool_calls(response);
+        let (text, calls) = parse_tool_calls(response, &[]);
         assert_eq!(text, "Let me check that for you.");
         assert_eq!(calls.len(), 1);
         assert_eq!(calls[0].name, "shell");
@@ -1857,7 +3496,7 @@ After text."#;
     fn parse_tool_calls_handles_openai_format_multiple_calls() {
         let response = r#"{"tool_calls": [{"type": "function", "function": {"name": "file_read", "arguments": "{\"path\": \"a.txt\"}"}}, {"type": "function", "function": {"name": "file_read", "arguments": "{\"path\": \"b.txt\"}"}}]}"#;
 
-        let (_, calls) = parse_tool_calls(response);
+        let (_, calls) = parse_tool_calls(response, &[]);
         assert_eq!(calls.len(), 2);
         assert_eq!(calls[0].name, "file_read");
         assert_eq!(calls[1].name, "file_read");
@@ -1868,7 +3507,7 @@ After text."#;
         // Some providers don't include content field with tool_calls
         let response = r#"{"tool_calls": [{"type": "function", "function": {"name": "memory_recall", "arguments": "{}"}}]}"#;
 
-        let (text, calls) = parse_tool_calls(response);
+        let (text, calls) = parse_tool_calls(response, &[]);
         assert!(text.is_empty()); // No content field
         assert_eq!(calls.len(), 1);
         assert_eq!(calls[0].name, "memory_recall");
@@ -1882,7 +3521,7 @@ After text."#;
 ```
 </tool_call>"#;
 
-        let (text, calls) = parse_tool_calls(response);
+        let (text, calls) = parse_tool_calls(response, &[]);
         assert!(text.is_empty());
         assert_eq!(calls.len(), 1);
         assert_eq!(calls[0].name, "file_write");
@@ -1899,7 +3538,7 @@ I will now call the tool with this payload:
 {"name": "shell", "arguments": {"command": "pwd"}}
 </tool_call>"#;
 
-        let (text, calls) = parse_tool_calls(response);
+        let (text, calls) = parse_tool_calls(response, &[]);
         assert!(text.is_empty());
         assert_eq!(calls.len(), 1);
         assert_eq!(calls[0].name, "shell");
@@ -1917,7 +3556,7 @@ I will now call the tool with this payload:
 ```
 Done."#;
 
-        let (text, calls) = parse_tool_calls(response);
+        let (text, calls) = parse_tool_calls(response, &[]);
         assert_eq!(calls.len(), 1);
         assert_eq!(calls[0].name, "shell");
         assert_eq!(
@@ -1937,7 +3576,7 @@ Done."#;
 </tool_call>
 Tail"#;
 
-        let (text, calls) = parse_tool_calls(response);
+        let (text, calls) = parse_tool_calls(response, &[]);
         assert_eq!(calls.len(), 1);
         assert_eq!(calls[0].name, "shell");
         assert_eq!(
@@ -1957,7 +3596,7 @@ Tail"#;
 ```
 Done."#;
 
-        let (text, calls) = parse_tool_calls(response);
+        let (text, calls) = parse_tool_calls(response, &[]);
         assert_eq!(calls.len(), 1);
         assert_eq!(calls[0].name, "shell");
         assert_eq!(
@@ -1974,7 +3613,7 @@ Done."#;
 {"name": "shell", "arguments": {"command": "date"}}
 </toolcall>"#;
 
-        let (text, calls) = parse_tool_calls(response);
+        let (text, calls) = parse_tool_calls(response, &[]);
         assert!(text.is_empty());
         assert_eq!(calls.len(), 1);
         assert_eq!(calls[0].name, "shell");
@@ -1990,7 +3629,7 @@ Done."#;
 {"name": "shell", "arguments": {"command": "whoami"}}
 </tool-call>"#;
 
-        let (text, calls) = parse_tool_calls(response);
+        let (text, calls) = parse_tool_calls(response, &[]);
         assert!(text.is_empty());
         assert_eq!(calls.len(), 1);
         assert_eq!(calls[0].name, "shell");
@@ -2006,7 +3645,7 @@ Done."#;
 {"name": "shell", "arguments": {"command": "uptime"}}
 </invoke>"#;
 
-        let (text, calls) = parse_tool_calls(response);
+        let (text, calls) = parse_tool_calls(response, &[]);
         assert!(text.is_empty());
         assert_eq!(calls.len(), 1);
         assert_eq!(calls[0].name, "shell");
@@ -2022,7 +3661,7 @@ Done."#;
 <tool_call>
 {"name": "shell", "arguments": {"command": "uptime -p"}}"#;
 
-        let (text, calls) = parse_tool_calls(response);
+        let (text, calls) = parse_tool_calls(response, &[]);
         assert!(text.contains("I will call the tool now."));
         assert_eq!(calls.len(), 1);
         assert_eq!(calls[0].name, "shell");
@@ -2038,7 +3677,7 @@ Done."#;
 {"name": "shell", "arguments": {"command": "uptime"}}
 </arg_value>"#;
 
-        let (text, calls) = parse_tool_calls(response);
+        let (text, calls) = parse_tool_calls(response, &[]);
         assert!(text.is_empty());
         assert_eq!(calls.len(), 1);
         assert_eq!(calls[0].name, "shell");
@@ -2054,7 +3693,7 @@ Done."#;
 {"name": "shell", "arguments": {"command": "date"}}
 </tool_call>"#;
 
-        let (text, calls) = parse_tool_calls(response);
+        let (text, calls) = parse_tool_calls(response, &[]);
         assert!(text.is_empty());
         assert_eq!(calls.len(), 1);
         assert_eq!(calls[0].name, "shell");
@@ -2068,7 +3707,7 @@ Done."#;
         let response = r#"Sure, creating the file now.
 {"name": "file_write", "arguments": {"path": "hello.py", "content": "print('hello')"}}"#;
 
-        let (text, calls) = parse_tool_calls(response);
+        let (text, calls) = parse_tool_calls(response, &[]);
         assert!(text.contains("Sure, creating the file now."));
         assert_eq!(
             calls.len(),
@@ -2144,6 +3783,140 @@ Done."#;
         );
     }
 
+    #[test]
+    fn trim_history_and_compaction_keep_pinned_messages() {
+        let mut task = ChatMessage::user("task: migrate the billing service");
+        task.pinned = true;
+        let mut history = vec![ChatMessage::system("sys"), task];
+        for i in 0..10 {
+            history.push(ChatMessage::user(format!("msg {i}")));
+        }
+
+        trim_history(&mut history, 4);
+        let contents: Vec<&str> = history.iter().map(|m| m.content.as_str()).collect();
+        assert_eq!(
+            contents,
+            [
+                "sys",
+                "task: migrate the billing service",
+                "msg 7",
+                "msg 8",
+                "msg 9"
+            ]
+        );
+
+        apply_compaction_summary(&mut history, 1, 3, "- summary");
+        assert!(history[1].pinned);
+        assert!(history[2].content.contains("Compaction summary"));
+        assert_eq!(history.len(), 5);
+    }
+
+    #[test]
+    fn compact_old_tool_results_leaves_recent_turns_untouched() {
+        let listing = (0..42)
+            .map(|i| format!("file_{i}.rs"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let tool_turn = |ask: &str| {
+            vec![
+                ChatMessage::user(ask),
+                ChatMessage::assistant("<tool_call>{\"name\":\"shell\"}</tool_call>"),
+                ChatMessage::user(format!(
+                    "[Tool results]\n<tool_result name=\"shell\">\n{listing}\n</tool_result>\n<tool_result name=\"file_read\">\nok\n</tool_result>\n"
+                )),
+                ChatMessage::tool(
+                    serde_json::json!({"tool_call_id": "c1", "content": listing}).to_string(),
+                ),
+                ChatMessage::assistant("done"),
+            ]
+        };
+        let mut history = vec![ChatMessage::system("sys")];
+        history.extend(tool_turn("list the repo"));
+        history.extend(tool_turn("list it again"));
+        let recent = history[6..].to_vec();
+
+        assert_eq!(compact_old_tool_results(&mut history, 0), 0);
+        assert_eq!(compact_old_tool_results(&mut history, 1), 2);
+
+        assert!(history[3]
+            .content
+            .contains("<tool_result name=\"shell\">\n[compacted: 42 lines"));
+        assert!(history[3]
+            .content
+            .contains("<tool_result name=\"file_read\">\nok\n</tool_result>"));
+        let tool_msg: serde_json::Value = serde_json::from_str(&history[4].content).unwrap();
+        assert_eq!(tool_msg["tool_call_id"], "c1");
+        assert!(tool_msg["content"]
+            .as_str()
+            .unwrap()
+            .starts_with("[compacted: 42 lines"));
+        let after: Vec<&str> = history[6..].iter().map(|m| m.content.as_str()).collect();
+        let before: Vec<&str> = recent.iter().map(|m| m.content.as_str()).collect();
+        assert_eq!(after, before);
+
+        // Already-compacted outputs are not summarized again.
+        assert_eq!(compact_old_tool_results(&mut history, 1), 0);
+    }
+
+    #[test]
+    fn session_tool_budget_counts_from_last_reset() {
+        let budget = SessionToolBudget::new(2);
+        assert!(budget.try_take());
+        assert!(!budget.exhausted());
+        assert!(budget.try_take());
+        assert!(budget.exhausted());
+        assert!(!budget.try_take());
+        budget.reset();
+        assert!(!budget.exhausted());
+        assert!(budget.try_take());
+
+        let unlimited = SessionToolBudget::new(0);
+        assert!((0..10_000).all(|_| unlimited.try_take()));
+        assert!(!unlimited.exhausted());
+    }
+
+    #[tokio::test]
+    async fn budgeted_tools_stop_mid_turn_once_the_budget_is_spent() {
+        let calls = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let budget = Arc::new(SessionToolBudget::new(2));
+        let tools: Vec<Box<dyn Tool>> = vec![BudgetedTool::wrap(
+            Box::new(CountingReadTool {
+                calls: calls.clone(),
+            }),
+            budget.clone(),
+        )];
+        let provider = ScriptedTextProvider::new(vec![
+            r#"<tool_call>
+{"name": "file_read", "arguments": {"path": "a.txt"}}
+</tool_call>"#,
+            r#"<tool_call>
+{"name": "file_read", "arguments": {"path": "b.txt"}}
+</tool_call>"#,
+            r#"<tool_call>
+{"name": "file_read", "arguments": {"path": "c.txt"}}
+</tool_call>"#,
+        ]);
+        let mut history = vec![ChatMessage::system("sys"), ChatMessage::user("read")];
+
+        let _ = agent_turn_outcome(
+            &provider,
+            &mut history,
+            &tools,
+            &crate::observability::NoopObserver,
+            "test",
+            "test-model",
+            0.0,
+            true,
+            5,
+        )
+        .await;
+        assert_eq!(calls.load(std::sync::atomic::Ordering::SeqCst), 2);
+        assert!(budget.exhausted());
+        assert!(history
+            .iter()
+            .any(|m| m.content.contains("Session tool-call budget (2) reached")));
+    }
+
     #[test]
     fn trim_history_noop_when_within_limit() {
         let mut history = vec![
@@ -2227,7 +4000,7 @@ Done."#;
 
 </tool_result>
 Done."#;
-        let (text, calls) = parse_tool_calls(response);
+        let (text, calls) = parse_tool_calls(response, &[]);
         assert!(text.contains("Done."));
         assert!(calls.is_empty());
     }
@@ -2244,7 +4017,7 @@ Done."#;
     fn parse_tool_calls_handles_empty_tool_calls_array() {
         // Recovery: Empty tool_calls array returns original response (no tool parsing)
         let response = r#"{"content": "Hello", "tool_calls": []}"#;
-        let (text, calls) = parse_tool_calls(response);
+        let (text, calls) = parse_tool_calls(response, &[]);
         // When tool_calls is empty, the entire JSON is returned as text
         assert!(text.contains("Hello"));
         assert!(calls.is_empty());
@@ -2488,13 +4261,46 @@ browser_open/url>https://example.com"#;
     fn parse_glm_style_tool_call_integration() {
         // Integration test: GLM format should be parsed in parse_tool_calls
         let response = "Checking...\nbrowser_open/url>https://example.com\nDone";
-        let (text, calls) = parse_tool_calls(response);
+        let (text, calls) = parse_tool_calls(response, &[]);
         assert_eq!(calls.len(), 1);
         assert_eq!(calls[0].name, "shell");
         assert!(text.contains("Checking"));
         assert!(text.contains("Done"));
     }
 
+    #[test]
+    fn parse_tool_calls_honors_format_order() {
+        let fenced = r#"Reading both files.
+```json
+[{"name": "file_read", "arguments": {"path": "a.txt"}}, {"name": "file_read", "arguments": {"path": "b.txt"}}]
+```
+Example only:
+```json
+{"name": "Ada", "role": "admin"}
+```"#;
+        assert!(parse_tool_calls(fenced, &[]).1.is_empty());
+        let (text, calls) = parse_tool_calls(fenced, &[ToolCallFormat::JsonFence]);
+        assert_eq!(calls.len(), 2);
+        assert_eq!(calls[1].arguments["path"], "b.txt");
+        assert!(text.starts_with("Reading both files."));
+        assert!(text.contains(r#"{"name": "Ada", "role": "admin"}"#));
+
+        let openai_array = r#"[{"type": "function", "function": {"name": "shell", "arguments": "{\"command\": \"ls\"}"}}]"#;
+        let (_, calls) = parse_tool_calls(openai_array, &[ToolCallFormat::OpenaiJson]);
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].arguments["command"], "ls");
+        assert!(parse_tool_calls(openai_array, &[ToolCallFormat::Xml])
+            .1
+            .is_empty());
+
+        // The first format that yields calls wins.
+        let both =
+            "<function_call>{\"name\": \"a\", \"arguments\": {}}</function_call>\nshell/command>ls";
+        let order = [ToolCallFormat::Glm, ToolCallFormat::Xml];
+        assert_eq!(parse_tool_calls(both, &order).1[0].name, "shell");
+        assert_eq!(parse_tool_calls(both, &[]).1[0].name, "a");
+    }
+
     #[test]
     fn parse_glm_style_rejects_non_http_url_param() {
         let response = "browser_open/url>javascript:alert(1)";
@@ -2505,10 +4311,712 @@ browser_open/url>https://example.com"#;
     #[test]
     fn parse_tool_calls_handles_unclosed_tool_call_tag() {
         let response = "<tool_call>{\"name\":\"shell\",\"arguments\":{\"command\":\"pwd\"}}\nDone";
-        let (text, calls) = parse_tool_calls(response);
+        let (text, calls) = parse_tool_calls(response, &[]);
         assert_eq!(calls.len(), 1);
         assert_eq!(calls[0].name, "shell");
         assert_eq!(calls[0].arguments["command"], "pwd");
         assert_eq!(text, "Done");
     }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // Tool-call loop execution
+    // ═══════════════════════════════════════════════════════════════════════
+
+    /// Provider that replays a fixed sequence of text responses, then "done".
+    struct ScriptedTextProvider {
+        responses: std::sync::Mutex<Vec<String>>,
+    }
+
+    impl ScriptedTextProvider {
+        fn new(responses: Vec<&str>) -> Self {
+            Self {
+                responses: std::sync::Mutex::new(responses.into_iter().map(String::from).collect()),
+            }
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl Provider for ScriptedTextProvider {
+        async fn chat_with_system(
+            &self,
+            _system_prompt: Option<&str>,
+            _message: &str,
+            _model: &str,
+            _temperature: f64,
+        ) -> Result<String> {
+            let mut guard = self.responses.lock().unwrap();
+            if guard.is_empty() {
+                return Ok("done".into());
+            }
+            Ok(guard.remove(0))
+        }
+    }
+
+    /// `file_read` stand-in that counts how many times it was executed.
+    struct CountingReadTool {
+        calls: Arc<std::sync::atomic::AtomicUsize>,
+    }
+
+    #[async_trait::async_trait]
+    impl Tool for CountingReadTool {
+        fn name(&self) -> &str {
+            "file_read"
+        }
+
+        fn description(&self) -> &str {
+            "Counts executions"
+        }
+
+        fn parameters_schema(&self) -> serde_json::Value {
+            serde_json::json!({
+                "type": "object",
+                "properties": { "path": { "type": "string" } }
+            })
+        }
+
+        async fn execute(&self, _args: serde_json::Value) -> Result<crate::tools::ToolResult> {
+            self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            Ok(crate::tools::ToolResult {
+                success: true,
+                output: "file contents".into(),
+                error: None,
+                metadata: None,
+                content_type: None,
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn run_tool_call_loop_executes_duplicate_calls_once() {
+        let provider = ScriptedTextProvider::new(vec![
+            r#"<tool_call>
+{"name": "file_read", "arguments": {"path": "a.txt"}}
+</tool_call>
+<tool_call>
+{"name": "file_read", "arguments": {"path": "a.txt"}}
+</tool_call>"#,
+            "All done.",
+        ]);
+        let calls = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let tools: Vec<Box<dyn Tool>> = vec![Box::new(CountingReadTool {
+            calls: calls.clone(),
+        })];
+        let observer = crate::observability::NoopObserver;
+        let mut history = vec![ChatMessage::system("sys"), ChatMessage::user("read a.txt")];
+
+        let response = agent_turn(
+            &provider,
+            &mut history,
+            &tools,
+            &observer,
+            "test",
+            "test-model",
+            0.0,
+            true,
+            5,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(response, "All done.");
+        assert_eq!(calls.load(std::sync::atomic::Ordering::SeqCst), 1);
+
+        let results = history
+            .iter()
+            .find(|m| m.role == "user" && m.content.starts_with("[Tool results]"))
+            .expect("tool results message");
+        assert_eq!(results.content.matches("<tool_result").count(), 2);
+        assert_eq!(results.content.matches("file contents").count(), 2);
+    }
+
+    #[tokio::test]
+    async fn agent_turn_full_cycle_with_mock_provider() {
+        let provider = crate::providers::create_provider("mock", None).unwrap();
+        let reply = provider.simple_chat("ping", "mock", 0.0).await.unwrap();
+        assert_eq!(reply, "[mock] ping");
+
+        let provider = crate::providers::mock::MockProvider::with_responses([
+            r#"Let me check.
+<tool_call>
+{"name": "file_read", "arguments": {"path": "notes.txt"}}
+</tool_call>"#,
+            "The file says: file contents",
+        ]);
+        let calls = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let tools: Vec<Box<dyn Tool>> = vec![Box::new(CountingReadTool {
+            calls: calls.clone(),
+        })];
+        let observer = crate::observability::NoopObserver;
+        let mut history = vec![ChatMessage::system("sys"), ChatMessage::user("read notes")];
+
+        let response = agent_turn(
+            &provider,
+            &mut history,
+            &tools,
+            &observer,
+            "mock",
+            "mock-model",
+            0.0,
+            true,
+            5,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(response, "The file says: file contents");
+        assert_eq!(calls.load(std::sync::atomic::Ordering::SeqCst), 1);
+
+        // The second model call saw the tool output fed back into the conversation.
+        let requests = provider.requests();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[1]
+            .iter()
+            .any(|m| m.role == "user" && m.content.contains("file contents")));
+    }
+
+    async fn run_with_tool_result_role(role: ToolResultRole) -> Vec<ChatMessage> {
+        let provider = ScriptedTextProvider::new(vec![
+            r#"<tool_call>
+{"name": "file_read", "arguments": {"path": "a.txt"}}
+</tool_call>"#,
+            "All done.",
+        ]);
+        let tools: Vec<Box<dyn Tool>> = vec![Box::new(CountingReadTool {
+            calls: Arc::new(std::sync::atomic::AtomicUsize::new(0)),
+        })];
+        let mut history = vec![ChatMessage::system("sys"), ChatMessage::user("read a.txt")];
+
+        run_tool_call_loop(
+            &provider,
+            &mut history,
+            &tools,
+            &crate::observability::NoopObserver,
+            LoopOptions {
+                provider_name: "test",
+                model: "test-model",
+                temperature: 0.0,
+                params: None,
+                silent: true,
+                approval: None,
+                channel_name: "channel",
+                max_tool_iterations: 5,
+                no_progress_window: DEFAULT_NO_PROGRESS_WINDOW,
+                turn_retries: 0,
+                tool_result_role: role,
+                arg_validation: ToolArgValidation::default(),
+                tool_call_formats: &[],
+                on_delta: None,
+                audit_log: None,
+                secret_refs: &HashMap::new(),
+            },
+        )
+        .await
+        .unwrap();
+        history
+    }
+
+    #[tokio::test]
+    async fn tool_result_role_tool_pushes_named_tool_messages() {
+        let history = run_with_tool_result_role(ToolResultRole::Tool).await;
+
+        assert!(!history
+            .iter()
+            .any(|m| m.content.starts_with("[Tool results]")));
+        let tool_msg = history
+            .iter()
+            .find(|m| m.role == "tool")
+            .expect("tool role message");
+        let value: serde_json::Value = serde_json::from_str(&tool_msg.content).unwrap();
+        assert_eq!(value["tool_name"], "file_read");
+        assert_eq!(value["content"], "file contents");
+    }
+
+    #[tokio::test]
+    async fn tool_result_role_assistant_pushes_assistant_context() {
+        let history = run_with_tool_result_role(ToolResultRole::Assistant).await;
+
+        let results = history
+            .iter()
+            .find(|m| m.content.starts_with("[Tool results]"))
+            .expect("tool results message");
+        assert_eq!(results.role, "assistant");
+        assert!(results.content.contains("<tool_result name=\"file_read\">"));
+    }
+
+    /// Scripted provider whose `drop_on_call`-th call (1-based) fails with a
+    /// connection reset, as a proxy closing an idle stream would.
+    struct DroppingProvider {
+        inner: ScriptedTextProvider,
+        drop_on_call: usize,
+        calls: std::sync::atomic::AtomicUsize,
+    }
+
+    #[async_trait::async_trait]
+    impl Provider for DroppingProvider {
+        async fn chat_with_system(
+            &self,
+            system_prompt: Option<&str>,
+            message: &str,
+            model: &str,
+            temperature: f64,
+        ) -> Result<String> {
+            let call = self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst) + 1;
+            if call == self.drop_on_call {
+                anyhow::bail!("error sending request: connection reset by peer");
+            }
+            self.inner
+                .chat_with_system(system_prompt, message, model, temperature)
+                .await
+        }
+    }
+
+    #[tokio::test]
+    async fn dropped_connection_retries_model_call_without_rerunning_tools() {
+        let script = || {
+            ScriptedTextProvider::new(vec![
+                r#"<tool_call>
+{"name": "file_read", "arguments": {"path": "a.txt"}}
+</tool_call>"#,
+                "All done.",
+            ])
+        };
+        let run = |provider: DroppingProvider, turn_retries: u32| async move {
+            let calls = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+            let tools: Vec<Box<dyn Tool>> = vec![Box::new(CountingReadTool {
+                calls: calls.clone(),
+            })];
+            let mut history = vec![ChatMessage::system("sys"), ChatMessage::user("read a.txt")];
+            let result = run_tool_call_loop(
+                &provider,
+                &mut history,
+                &tools,
+                &crate::observability::NoopObserver,
+                LoopOptions {
+                    provider_name: "test",
+                    model: "test-model",
+                    temperature: 0.0,
+                    params: None,
+                    silent: true,
+                    approval: None,
+                    channel_name: "cli",
+                    max_tool_iterations: 5,
+                    no_progress_window: DEFAULT_NO_PROGRESS_WINDOW,
+                    turn_retries,
+                    tool_result_role: ToolResultRole::User,
+                    arg_validation: ToolArgValidation::default(),
+                    tool_call_formats: &[],
+                    on_delta: None,
+                    audit_log: None,
+                    secret_refs: &HashMap::new(),
+                },
+            )
+            .await;
+            (result, calls.load(std::sync::atomic::Ordering::SeqCst))
+        };
+
+        let (result, tool_runs) = run(
+            DroppingProvider {
+                inner: script(),
+                drop_on_call: 2,
+                calls: std::sync::atomic::AtomicUsize::new(0),
+            },
+            1,
+        )
+        .await;
+        assert_eq!(result.unwrap(), "All done.");
+        assert_eq!(tool_runs, 1);
+
+        let (result, tool_runs) = run(
+            DroppingProvider {
+                inner: script(),
+                drop_on_call: 2,
+                calls: std::sync::atomic::AtomicUsize::new(0),
+            },
+            0,
+        )
+        .await;
+        assert!(result.unwrap_err().to_string().contains("connection reset"));
+        assert_eq!(tool_runs, 1);
+    }
+
+    #[tokio::test]
+    async fn iteration_limit_returns_partial_answer_and_allows_continue() {
+        let provider = ScriptedTextProvider::new(vec![
+            r#"Reading the first file.
+<tool_call>
+{"name": "file_read", "arguments": {"path": "a.txt"}}
+</tool_call>"#,
+            r#"Now the second file.
+<tool_call>
+{"name": "file_read", "arguments": {"path": "b.txt"}}
+</tool_call>"#,
+            "Both files read.",
+        ]);
+        let tools: Vec<Box<dyn Tool>> = vec![Box::new(CountingReadTool {
+            calls: Arc::new(std::sync::atomic::AtomicUsize::new(0)),
+        })];
+        let observer = crate::observability::NoopObserver;
+        let mut history = vec![ChatMessage::system("sys"), ChatMessage::user("read both")];
+
+        let outcome = run_tool_call_loop_outcome(
+            &provider,
+            &mut history,
+            &tools,
+            &observer,
+            LoopOptions {
+                provider_name: "test",
+                model: "test-model",
+                temperature: 0.0,
+                params: None,
+                silent: true,
+                approval: None,
+                channel_name: "cli",
+                max_tool_iterations: 2,
+                no_progress_window: DEFAULT_NO_PROGRESS_WINDOW,
+                turn_retries: 0,
+                tool_result_role: ToolResultRole::User,
+                arg_validation: ToolArgValidation::default(),
+                tool_call_formats: &[],
+                on_delta: None,
+                audit_log: None,
+                secret_refs: &HashMap::new(),
+            },
+        )
+        .await
+        .unwrap();
+
+        assert!(outcome.truncated);
+        assert_eq!(outcome.text, "Now the second file.");
+        let rendered = render_turn_outcome(&outcome);
+        assert!(rendered.starts_with("Now the second file."));
+        assert!(rendered.contains("Stopped after 2 tool iterations"));
+
+        history.push(ChatMessage::user("continue"));
+        let resumed = run_tool_call_loop_outcome(
+            &provider,
+            &mut history,
+            &tools,
+            &observer,
+            LoopOptions {
+                provider_name: "test",
+                model: "test-model",
+                temperature: 0.0,
+                params: None,
+                silent: true,
+                approval: None,
+                channel_name: "cli",
+                max_tool_iterations: 2,
+                no_progress_window: DEFAULT_NO_PROGRESS_WINDOW,
+                turn_retries: 0,
+                tool_result_role: ToolResultRole::User,
+                arg_validation: ToolArgValidation::default(),
+                tool_call_formats: &[],
+                on_delta: None,
+                audit_log: None,
+                secret_refs: &HashMap::new(),
+            },
+        )
+        .await
+        .unwrap();
+        assert!(!resumed.truncated);
+        assert_eq!(resumed.text, "Both files read.");
+        assert_eq!(render_turn_outcome(&resumed), "Both files read.");
+    }
+
+    #[tokio::test]
+    async fn repeated_failing_tool_call_stops_early_and_summarizes() {
+        let call = r#"<tool_call>
+{"name": "missing_tool", "arguments": {}}
+</tool_call>"#;
+        let tools: Vec<Box<dyn Tool>> = Vec::new();
+        let tools = tools.as_slice();
+
+        let run = |window: usize| {
+            let provider =
+                ScriptedTextProvider::new(vec![call, call, call, "The tool is unavailable.", call]);
+            async move {
+                let mut history = vec![ChatMessage::system("sys"), ChatMessage::user("go")];
+                let outcome = run_tool_call_loop_outcome(
+                    &provider,
+                    &mut history,
+                    tools,
+                    &crate::observability::NoopObserver,
+                    LoopOptions {
+                        provider_name: "test",
+                        model: "test-model",
+                        temperature: 0.0,
+                        params: None,
+                        silent: true,
+                        approval: None,
+                        channel_name: "cli",
+                        max_tool_iterations: 3,
+                        no_progress_window: window,
+                        turn_retries: 0,
+                        tool_result_role: ToolResultRole::User,
+                        arg_validation: ToolArgValidation::default(),
+                        tool_call_formats: &[],
+                        on_delta: None,
+                        audit_log: None,
+                        secret_refs: &HashMap::new(),
+                    },
+                )
+                .await
+                .unwrap();
+                (outcome, history)
+            }
+        };
+
+        let (outcome, history) = run(3).await;
+        assert!(!outcome.truncated);
+        assert_eq!(outcome.text, "The tool is unavailable.");
+        assert!(history.iter().any(|m| m.content == NO_PROGRESS_PROMPT));
+
+        // Disabled window: the loop keeps going until the iteration cap.
+        let (outcome, history) = run(0).await;
+        assert!(outcome.truncated);
+        assert!(!history.iter().any(|m| m.content == NO_PROGRESS_PROMPT));
+    }
+
+    #[test]
+    fn response_cache_skips_tool_turns_and_normalizes_keys() {
+        let tmp = TempDir::new().unwrap();
+        let cache = memory::ResponseCache::new(tmp.path(), 60, 100).unwrap();
+        let observer = crate::observability::NoopObserver;
+
+        let key = turn_cache_key("m", 0.7, "sys", "what   is\n2+2?");
+        assert_eq!(key, turn_cache_key("m", 0.7, "sys", "what is 2+2?"));
+        assert_ne!(key, turn_cache_key("m", 0.2, "sys", "what is 2+2?"));
+        assert_ne!(key, turn_cache_key("m", 0.7, "other", "what is 2+2?"));
+
+        let tool_turn = vec![
+            ChatMessage::assistant("<tool_call>{\"name\":\"shell\",\"arguments\":{}}</tool_call>"),
+            ChatMessage::user("[Tool results]\n4"),
+            ChatMessage::assistant("4"),
+        ];
+        store_cached_turn(Some(&cache), &key, "m", "4", &tool_turn);
+        assert!(lookup_cached_turn(Some(&cache), &key, &observer, "m").is_none());
+
+        store_cached_turn(Some(&cache), &key, "m", "4", &[ChatMessage::assistant("4")]);
+        assert_eq!(
+            lookup_cached_turn(Some(&cache), &key, &observer, "m").as_deref(),
+            Some("4")
+        );
+        assert!(lookup_cached_turn(None, &key, &observer, "m").is_none());
+    }
+
+    #[test]
+    fn partial_answer_from_history_strips_tool_markup() {
+        let turn = vec![
+            ChatMessage::assistant(
+                "Checking the logs.\n<tool_call>\n{\"name\": \"shell\", \"arguments\": {}}\n</tool_call>",
+            ),
+            ChatMessage::user("[Tool results]\n<tool_result name=\"shell\">ok</tool_result>"),
+            ChatMessage::assistant(
+                r#"{"content":"Found two errors so far.","tool_calls":[{"id":"1","name":"shell","arguments":"{}"}]}"#,
+            ),
+            ChatMessage::assistant("[Tool results]\nignored"),
+        ];
+        assert_eq!(
+            partial_answer_from_history(&turn, &[]),
+            "Found two errors so far."
+        );
+        assert_eq!(
+            partial_answer_from_history(&turn[..2], &[]),
+            "Checking the logs."
+        );
+        assert!(partial_answer_from_history(&[], &[]).is_empty());
+    }
+
+    #[test]
+    fn json_output_reports_recorded_tool_calls() {
+        let recorder = ToolCallRecorder::new(Arc::new(crate::observability::NoopObserver));
+        for (tool, success) in [("shell", true), ("file_read", false)] {
+            recorder.record_event(&ObserverEvent::ToolCall {
+                tool: tool.into(),
+                duration: Duration::from_millis(5),
+                success,
+            });
+        }
+        recorder.record_event(&ObserverEvent::TurnComplete);
+
+        let rendered =
+            render_json_output("All done.", &recorder.calls(), Duration::from_millis(1234));
+        let parsed: serde_json::Value = serde_json::from_str(&rendered).unwrap();
+        assert_eq!(parsed["response"], "All done.");
+        assert_eq!(parsed["duration_ms"], 1234);
+        assert!(parsed["tokens"].is_null());
+        assert_eq!(
+            parsed["tool_calls"],
+            serde_json::json!([
+                {"name": "shell", "success": true},
+                {"name": "file_read", "success": false}
+            ])
+        );
+    }
+
+    #[test]
+    fn startup_breakdown_aligns_steps_and_sums_total() {
+        let rendered = render_startup_breakdown(&[
+            ("memory init", Duration::from_millis(12)),
+            ("hardware RAG load", Duration::from_millis(340)),
+        ]);
+        assert_eq!(
+            rendered,
+            "Startup timing:\n  memory init            12 ms\n  hardware RAG load     340 ms\n  total                 352 ms\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn run_tool_call_loop_still_errors_at_iteration_limit() {
+        let provider = ScriptedTextProvider::new(vec![
+            r#"<tool_call>
+{"name": "file_read", "arguments": {"path": "a.txt"}}
+</tool_call>"#,
+        ]);
+        let tools: Vec<Box<dyn Tool>> = vec![Box::new(CountingReadTool {
+            calls: Arc::new(std::sync::atomic::AtomicUsize::new(0)),
+        })];
+        let mut history = vec![ChatMessage::system("sys"), ChatMessage::user("read")];
+
+        let err = agent_turn(
+            &provider,
+            &mut history,
+            &tools,
+            &crate::observability::NoopObserver,
+            "test",
+            "test-model",
+            0.0,
+            true,
+            1,
+        )
+        .await
+        .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Agent exceeded maximum tool iterations (1)"));
+    }
+
+    #[tokio::test]
+    async fn agent_turn_outcome_reports_truncation_instead_of_failing() {
+        let provider = ScriptedTextProvider::new(vec![
+            r#"<tool_call>
+{"name": "file_read", "arguments": {"path": "a.txt"}}
+</tool_call>"#,
+        ]);
+        let tools: Vec<Box<dyn Tool>> = vec![Box::new(CountingReadTool {
+            calls: Arc::new(std::sync::atomic::AtomicUsize::new(0)),
+        })];
+        let mut history = vec![ChatMessage::system("sys"), ChatMessage::user("read")];
+
+        let outcome = agent_turn_outcome(
+            &provider,
+            &mut history,
+            &tools,
+            &crate::observability::NoopObserver,
+            "test",
+            "test-model",
+            0.0,
+            true,
+            1,
+        )
+        .await
+        .unwrap();
+        assert!(outcome.truncated);
+        assert_eq!(outcome.max_iterations, 1);
+    }
+
+    #[test]
+    fn narrated_tool_use_heuristic_is_conservative() {
+        let tools = ["shell", "file_read"];
+        assert!(looks_like_narrated_tool_use(
+            "I'll run `shell` with `ls -la` to check.",
+            &tools
+        ));
+        assert!(looks_like_narrated_tool_use(
+            "Let me use file_read on config.toml first.",
+            &tools
+        ));
+        // Tool mentioned without announcing an action.
+        assert!(!looks_like_narrated_tool_use(
+            "The file_read tool can open text files.",
+            &tools
+        ));
+        // Bare one-word tool name in prose.
+        assert!(!looks_like_narrated_tool_use(
+            "I'll run the tests in your shell later.",
+            &tools
+        ));
+        // Tool name only as part of a longer identifier.
+        assert!(!looks_like_narrated_tool_use(
+            "I'll use my_file_read_helper instead.",
+            &tools
+        ));
+    }
+
+    #[tokio::test]
+    async fn run_tool_call_loop_reprompts_once_when_tool_use_is_narrated() {
+        let provider = ScriptedTextProvider::new(vec![
+            "Let me use `file_read` to open a.txt.",
+            r#"<tool_call>
+{"name": "file_read", "arguments": {"path": "a.txt"}}
+</tool_call>"#,
+            "I'll use `file_read` again if needed.",
+        ]);
+        let calls = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let tools: Vec<Box<dyn Tool>> = vec![Box::new(CountingReadTool {
+            calls: calls.clone(),
+        })];
+        let mut history = vec![ChatMessage::system("sys"), ChatMessage::user("read a.txt")];
+
+        let response = agent_turn(
+            &provider,
+            &mut history,
+            &tools,
+            &crate::observability::NoopObserver,
+            "test",
+            "test-model",
+            0.0,
+            true,
+            5,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(calls.load(std::sync::atomic::Ordering::SeqCst), 1);
+        // Only one reminder per turn; the second narration is accepted as final.
+        assert_eq!(response, "I'll use `file_read` again if needed.");
+        assert_eq!(
+            history
+                .iter()
+                .filter(|m| m.content == TOOL_PROTOCOL_REMINDER)
+                .count(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn run_tool_call_loop_skips_reprompt_on_last_iteration() {
+        let provider = ScriptedTextProvider::new(vec!["Let me use `file_read` to open a.txt."]);
+        let tools: Vec<Box<dyn Tool>> = vec![Box::new(CountingReadTool {
+            calls: Arc::new(std::sync::atomic::AtomicUsize::new(0)),
+        })];
+        let mut history = vec![ChatMessage::system("sys"), ChatMessage::user("read a.txt")];
+
+        let response = agent_turn(
+            &provider,
+            &mut history,
+            &tools,
+            &crate::observability::NoopObserver,
+            "test",
+            "test-model",
+            0.0,
+            true,
+            1,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(response, "Let me use `file_read` to open a.txt.");
+        assert!(!history.iter().any(|m| m.content == TOOL_PROTOCOL_REMINDER));
+    }
 }
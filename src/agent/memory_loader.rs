@@ -38,7 +38,7 @@ impl MemoryLoader for DefaultMemoryLoader {
         memory: &dyn Memory,
         user_message: &str,
     ) -> anyhow::Result<String> {
-        let entries = memory.recall(user_message, self.limit, None).await?;
+        let entries = crate::memory::recall_for_context(memory, user_message, self.limit).await?;
         if entries.is_empty() {
             return Ok(String::new());
         }
@@ -2,6 +2,7 @@
 pub mod agent;
 pub mod classifier;
 pub mod dispatcher;
+pub mod file_tree;
 pub mod loop_;
 pub mod memory_loader;
 pub mod prompt;
@@ -12,4 +13,4 @@ mod tests;
 #[allow(unused_imports)]
 pub use agent::{Agent, AgentBuilder};
 #[allow(unused_imports)]
-pub use loop_::{process_message, run};
+pub use loop_::{process_message, run, OutputFormat, ReplayOptions, RunOptions};
@@ -37,6 +37,7 @@ impl SystemPromptBuilder {
                 Box::new(SafetySection),
                 Box::new(SkillsSection),
                 Box::new(WorkspaceSection),
+                Box::new(PreferencesSection),
                 Box::new(DateTimeSection),
                 Box::new(RuntimeSection),
             ],
@@ -48,6 +49,11 @@ impl SystemPromptBuilder {
         self
     }
 
+    pub fn without_section(mut self, name: &str) -> Self {
+        self.sections.retain(|section| section.name() != name);
+        self
+    }
+
     pub fn build(&self, ctx: &PromptContext<'_>) -> Result<String> {
         let mut output = String::new();
         for section in &self.sections {
@@ -67,6 +73,7 @@ pub struct ToolsSection;
 pub struct SafetySection;
 pub struct SkillsSection;
 pub struct WorkspaceSection;
+pub struct PreferencesSection;
 pub struct RuntimeSection;
 pub struct DateTimeSection;
 
@@ -120,14 +127,21 @@ impl PromptSection for ToolsSection {
 
     fn build(&self, ctx: &PromptContext<'_>) -> Result<String> {
         let mut out = String::from("## Tools\n\n");
-        for tool in ctx.tools {
-            let _ = writeln!(
-                out,
-                "- **{}**: {}\n  Parameters: `{}`",
-                tool.name(),
-                tool.description(),
-                tool.parameters_schema()
-            );
+        let groups = crate::tools::group_by_category(ctx.tools);
+        let show_headings = groups.len() > 1;
+        for (category, group) in groups {
+            if show_headings {
+                let _ = writeln!(out, "\n### {}\n", crate::tools::category_heading(category));
+            }
+            for tool in group {
+                let _ = writeln!(
+                    out,
+                    "- **{}**: {}\n  Parameters: `{}`",
+                    tool.name(),
+                    tool.description(),
+                    tool.parameters_schema()
+                );
+            }
         }
         if !ctx.dispatcher_instructions.is_empty() {
             out.push('\n');
@@ -191,6 +205,18 @@ impl PromptSection for WorkspaceSection {
     }
 }
 
+impl PromptSection for PreferencesSection {
+    fn name(&self) -> &str {
+        "preferences"
+    }
+
+    fn build(&self, ctx: &PromptContext<'_>) -> Result<String> {
+        Ok(crate::memory::preferences::prompt_section(
+            ctx.workspace_dir,
+        ))
+    }
+}
+
 impl PromptSection for RuntimeSection {
     fn name(&self) -> &str {
         "runtime"
@@ -286,6 +312,8 @@ mod tests {
                 success: true,
                 output: "ok".into(),
                 error: None,
+                metadata: None,
+                content_type: None,
             })
         }
     }
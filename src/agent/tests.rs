@@ -28,7 +28,9 @@ use crate::agent::agent::Agent;
 use crate::agent::dispatcher::{
     NativeToolDispatcher, ToolDispatcher, ToolExecutionResult, XmlToolDispatcher,
 };
-use crate::config::{AgentConfig, MemoryConfig};
+use crate::config::{
+    AgentConfig, ClassificationRule, MemoryConfig, ModelRouteConfig, QueryClassificationConfig,
+};
 use crate::memory::{self, Memory};
 use crate::observability::{NoopObserver, Observer};
 use crate::providers::{
@@ -156,6 +158,8 @@ impl Tool for EchoTool {
             success: true,
             output: msg,
             error: None,
+            metadata: None,
+            content_type: None,
         })
     }
 }
@@ -182,6 +186,8 @@ impl Tool for FailingTool {
             success: false,
             output: String::new(),
             error: Some("intentional failure".into()),
+            metadata: None,
+            content_type: None,
         })
     }
 }
@@ -246,6 +252,8 @@ impl Tool for CountingTool {
             success: true,
             output: format!("call #{}", *c),
             error: None,
+            metadata: None,
+            content_type: None,
         })
     }
 }
@@ -679,7 +687,7 @@ async fn xml_dispatcher_parses_and_loops() {
     let mut agent = build_agent_with(
         provider,
         vec![Box::new(EchoTool)],
-        Box::new(XmlToolDispatcher),
+        Box::new(XmlToolDispatcher::default()),
     );
 
     let response = agent.turn("test xml").await.unwrap();
@@ -707,7 +715,7 @@ async fn native_dispatcher_sends_tool_specs() {
 
 #[tokio::test]
 async fn xml_dispatcher_does_not_send_tool_specs() {
-    let dispatcher = XmlToolDispatcher;
+    let dispatcher = XmlToolDispatcher::default();
     assert!(!dispatcher.should_send_tool_specs());
 }
 
@@ -923,6 +931,107 @@ async fn history_contains_all_expected_entries_after_tool_loop() {
     );
 }
 
+#[tokio::test]
+async fn tool_output_credentials_are_scrubbed_like_the_tool_loop() {
+    let provider = Box::new(ScriptedProvider::new(vec![
+        tool_response(vec![ToolCall {
+            id: "tc1".into(),
+            name: "echo".into(),
+            arguments: r#"{"message": "api_key=sk_live_abcdefgh1234"}"#.into(),
+        }]),
+        text_response("done"),
+    ]));
+
+    let mut agent = build_agent_with(
+        provider,
+        vec![Box::new(EchoTool)],
+        Box::new(NativeToolDispatcher),
+    );
+    let _ = agent.turn("test").await.unwrap();
+
+    let result = agent
+        .history()
+        .iter()
+        .find_map(|msg| match msg {
+            ConversationMessage::ToolResults(results) => Some(results[0].content.clone()),
+            _ => None,
+        })
+        .expect("tool result in history");
+    assert!(result.contains("[REDACTED]"), "{result}");
+    assert!(!result.contains("abcdefgh1234"), "{result}");
+}
+
+/// Records the model and temperature of every `chat` call.
+#[derive(Default)]
+struct TemperatureRecordingProvider {
+    calls: Arc<Mutex<Vec<(String, f64)>>>,
+}
+
+#[async_trait]
+impl Provider for TemperatureRecordingProvider {
+    async fn chat_with_system(
+        &self,
+        _system_prompt: Option<&str>,
+        _message: &str,
+        _model: &str,
+        _temperature: f64,
+    ) -> Result<String> {
+        Ok("fallback".into())
+    }
+
+    async fn chat(
+        &self,
+        _request: ChatRequest<'_>,
+        model: &str,
+        temperature: f64,
+    ) -> Result<ChatResponse> {
+        self.calls
+            .lock()
+            .unwrap()
+            .push((model.to_string(), temperature));
+        Ok(text_response("done"))
+    }
+}
+
+#[tokio::test]
+async fn classified_turn_uses_the_route_temperature() {
+    let provider = TemperatureRecordingProvider::default();
+    let calls = Arc::clone(&provider.calls);
+    let mut agent = Agent::builder()
+        .provider(Box::new(provider))
+        .tools(vec![])
+        .memory(make_memory())
+        .observer(make_observer())
+        .tool_dispatcher(Box::new(NativeToolDispatcher))
+        .workspace_dir(std::env::temp_dir())
+        .temperature(0.7)
+        .model_routes(vec![ModelRouteConfig {
+            hint: "code".into(),
+            provider: "openrouter".into(),
+            model: "vendor/coder".into(),
+            api_key: None,
+            temperature: Some(0.1),
+        }])
+        .classification_config(QueryClassificationConfig {
+            enabled: true,
+            rules: vec![ClassificationRule {
+                hint: "code".into(),
+                keywords: vec!["refactor".into()],
+                ..ClassificationRule::default()
+            }],
+        })
+        .available_hints(vec!["code".into()])
+        .build()
+        .unwrap();
+
+    agent.turn("hello").await.unwrap();
+    agent.turn("refactor this").await.unwrap();
+
+    let calls = calls.lock().unwrap();
+    assert_eq!(calls[0].1, 0.7);
+    assert_eq!(calls[1], ("hint:code".to_string(), 0.1));
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // 16. Builder validation
 // ═══════════════════════════════════════════════════════════════════════════
@@ -1019,7 +1128,7 @@ fn xml_dispatcher_handles_nested_json() {
         tool_calls: vec![],
     };
 
-    let dispatcher = XmlToolDispatcher;
+    let dispatcher = XmlToolDispatcher::default();
     let (_, calls) = dispatcher.parse_response(&response);
     assert_eq!(calls.len(), 1);
     assert_eq!(calls[0].name, "file_write");
@@ -1036,7 +1145,7 @@ fn xml_dispatcher_handles_empty_tool_call_tag() {
         tool_calls: vec![],
     };
 
-    let dispatcher = XmlToolDispatcher;
+    let dispatcher = XmlToolDispatcher::default();
     let (text, calls) = dispatcher.parse_response(&response);
     assert!(calls.is_empty());
     assert!(text.contains("Some text"));
@@ -1049,7 +1158,7 @@ fn xml_dispatcher_handles_unclosed_tool_call() {
         tool_calls: vec![],
     };
 
-    let dispatcher = XmlToolDispatcher;
+    let dispatcher = XmlToolDispatcher::default();
     let (text, calls) = dispatcher.parse_response(&response);
     // Should not panic — just treat as text
     assert!(calls.is_empty());
@@ -1117,7 +1226,7 @@ fn conversation_message_serialization_roundtrip() {
 
 #[test]
 fn xml_format_results_includes_status_and_output() {
-    let dispatcher = XmlToolDispatcher;
+    let dispatcher = XmlToolDispatcher::default();
     let results = vec![
         ToolExecutionResult {
             name: "shell".into(),
@@ -1183,7 +1292,7 @@ fn native_format_results_maps_tool_call_ids() {
 
 #[test]
 fn xml_dispatcher_converts_history_to_provider_messages() {
-    let dispatcher = XmlToolDispatcher;
+    let dispatcher = XmlToolDispatcher::default();
     let history = vec![
         ConversationMessage::Chat(ChatMessage::system("sys")),
         ConversationMessage::Chat(ChatMessage::user("hi")),
@@ -1237,7 +1346,7 @@ fn native_dispatcher_converts_tool_results_to_tool_messages() {
 #[test]
 fn xml_dispatcher_generates_tool_instructions() {
     let tools: Vec<Box<dyn Tool>> = vec![Box::new(EchoTool)];
-    let dispatcher = XmlToolDispatcher;
+    let dispatcher = XmlToolDispatcher::default();
     let instructions = dispatcher.prompt_instructions(&tools);
 
     assert!(instructions.contains("## Tool Use Protocol"));
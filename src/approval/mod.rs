@@ -11,6 +11,14 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 use std::io::{self, BufRead, Write};
 
+/// Tools with physical side effects that get their own confirmation
+/// prompt whenever autonomy is below `full`.
+const HARDWARE_WRITE_TOOLS: &[&str] = &["gpio_write", "arduino_upload"];
+
+/// Tool result fed back to the model when the user declines a hardware
+/// write.
+pub const HARDWARE_DECLINED: &str = "[hardware action declined]";
+
 // ── Types ────────────────────────────────────────────────────────
 
 /// A request to approve a tool call before execution.
@@ -145,6 +153,32 @@ impl ApprovalManager {
         self.session_allowlist.lock().clone()
     }
 
+    /// Check whether a tool call drives hardware and must be confirmed.
+    ///
+    /// Unlike [`Self::needs_approval`], this ignores `auto_approve` and the
+    /// session allowlist: every write is confirmed unless autonomy is `full`.
+    pub fn needs_hardware_confirmation(&self, tool_name: &str) -> bool {
+        self.autonomy_level != AutonomyLevel::Full && HARDWARE_WRITE_TOOLS.contains(&tool_name)
+    }
+
+    /// Ask the user on the CLI to confirm a hardware write. Only `Yes` or
+    /// `No` is returned, so the answer never extends to later calls.
+    pub fn confirm_hardware_cli(&self, request: &ApprovalRequest) -> ApprovalResponse {
+        let action = describe_hardware_action(&request.tool_name, &request.arguments);
+        eprintln!();
+        eprint!("⚡ {action}? [y/N]: ");
+        let _ = io::stderr().flush();
+
+        let mut line = String::new();
+        if io::stdin().lock().read_line(&mut line).is_err() {
+            return ApprovalResponse::No;
+        }
+        match line.trim().to_ascii_lowercase().as_str() {
+            "y" | "yes" => ApprovalResponse::Yes,
+            _ => ApprovalResponse::No,
+        }
+    }
+
     /// Prompt the user on the CLI and return their decision.
     ///
     /// For non-CLI channels, returns `Yes` automatically (interactive
@@ -178,6 +212,37 @@ fn prompt_cli_interactive(request: &ApprovalRequest) -> ApprovalResponse {
     }
 }
 
+/// Describe a hardware write in physical terms, e.g.
+/// "Set pin 13 HIGH on nucleo-f401re".
+pub fn describe_hardware_action(tool_name: &str, args: &serde_json::Value) -> String {
+    let action = match tool_name {
+        "gpio_write" => {
+            let pin = args
+                .get("pin")
+                .map_or_else(|| "?".to_string(), ToString::to_string);
+            let level = match args.get("value").and_then(serde_json::Value::as_i64) {
+                Some(0) => "LOW".to_string(),
+                Some(1) => "HIGH".to_string(),
+                Some(other) => format!("to {other}"),
+                None => "to ?".to_string(),
+            };
+            format!("Set pin {pin} {level}")
+        }
+        "arduino_upload" => {
+            let lines = args
+                .get("code")
+                .and_then(serde_json::Value::as_str)
+                .map_or(0, |code| code.lines().count());
+            format!("Compile and upload a {lines}-line sketch to the Arduino")
+        }
+        other => format!("Run {other} ({})", summarize_args(args)),
+    };
+    match args.get("board").and_then(serde_json::Value::as_str) {
+        Some(board) if !board.trim().is_empty() => format!("{action} on {board}"),
+        _ => action,
+    }
+}
+
 /// Produce a short human-readable summary of tool arguments.
 fn summarize_args(args: &serde_json::Value) -> String {
     match args {
@@ -252,6 +317,12 @@ mod tests {
         assert!(mgr.needs_approval("shell"));
     }
 
+    #[test]
+    fn set_preference_always_prompts_by_default() {
+        let mgr = ApprovalManager::from_config(&AutonomyConfig::default());
+        assert!(mgr.needs_approval("set_preference"));
+    }
+
     #[test]
     fn unknown_tool_needs_approval_in_supervised() {
         let mgr = ApprovalManager::from_config(&supervised_config());
@@ -323,6 +394,51 @@ mod tests {
         assert!(mgr.needs_approval("file_write"));
     }
 
+    // ── hardware confirmation ────────────────────────────────
+
+    #[test]
+    fn hardware_writes_need_confirmation_unless_full_autonomy() {
+        let config = AutonomyConfig {
+            auto_approve: vec!["gpio_write".into()],
+            ..supervised_config()
+        };
+        let mgr = ApprovalManager::from_config(&config);
+        mgr.record_decision(
+            "arduino_upload",
+            &serde_json::json!({}),
+            ApprovalResponse::Always,
+            "cli",
+        );
+        assert!(mgr.needs_hardware_confirmation("gpio_write"));
+        assert!(mgr.needs_hardware_confirmation("arduino_upload"));
+        assert!(!mgr.needs_hardware_confirmation("gpio_read"));
+
+        let mgr = ApprovalManager::from_config(&full_config());
+        assert!(!mgr.needs_hardware_confirmation("gpio_write"));
+    }
+
+    #[test]
+    fn describe_hardware_action_names_pin_level_and_board() {
+        assert_eq!(
+            describe_hardware_action("gpio_write", &serde_json::json!({"pin": 13, "value": 1})),
+            "Set pin 13 HIGH"
+        );
+        assert_eq!(
+            describe_hardware_action(
+                "gpio_write",
+                &serde_json::json!({"pin": 5, "value": 0, "board": "nucleo-f401re"})
+            ),
+            "Set pin 5 LOW on nucleo-f401re"
+        );
+        assert_eq!(
+            describe_hardware_action(
+                "arduino_upload",
+                &serde_json::json!({"code": "void setup() {}\nvoid loop() {}"})
+            ),
+            "Compile and upload a 2-line sketch to the Arduino"
+        );
+    }
+
     // ── audit log ────────────────────────────────────────────
 
     #[test]
@@ -0,0 +1,151 @@
+//! Split long replies into platform-sized messages.
+//!
+//! Breaks prefer paragraph boundaries, then line breaks, then spaces, and
+//! fall back to a hard split on a character boundary. A fenced code block
+//! that straddles a split is closed at the end of one chunk and reopened
+//! (with its language tag) at the start of the next, so every chunk renders
+//! as valid markdown on its own.
+
+const FENCE: &str = "```";
+/// Worst-case bytes appended to close an open fence (`\n` + fence).
+const FENCE_CLOSE_RESERVE: usize = 4;
+
+/// Split `message` into chunks of at most `max_chars` characters.
+///
+/// Without code fences the chunks concatenate back to `message` exactly.
+pub(crate) fn split_reply(message: &str, max_chars: usize) -> Vec<String> {
+    if message.chars().count() <= max_chars {
+        return vec![message.to_string()];
+    }
+
+    let has_fences = message.contains(FENCE);
+    let mut chunks = Vec::new();
+    let mut remaining = message;
+    let mut open_fence: Option<String> = None;
+
+    while !remaining.is_empty() {
+        let prefix = open_fence
+            .as_ref()
+            .map(|opener| format!("{opener}\n"))
+            .unwrap_or_default();
+        let room = max_chars.saturating_sub(prefix.chars().count()).max(1);
+
+        if remaining.chars().count() <= room {
+            chunks.push(format!("{prefix}{remaining}"));
+            break;
+        }
+
+        let reserve = if has_fences { FENCE_CLOSE_RESERVE } else { 0 };
+        let budget = room.saturating_sub(reserve).max(1);
+        let hard_split = remaining
+            .char_indices()
+            .nth(budget)
+            .map_or(remaining.len(), |(idx, _)| idx);
+        let end = break_point(&remaining[..hard_split], budget);
+        let piece = &remaining[..end];
+
+        open_fence = fence_state_after(piece, open_fence);
+        let mut chunk = format!("{prefix}{piece}");
+        if open_fence.is_some() {
+            if !chunk.ends_with('\n') {
+                chunk.push('\n');
+            }
+            chunk.push_str(FENCE);
+        }
+        chunks.push(chunk);
+        remaining = &remaining[end..];
+    }
+
+    chunks
+}
+
+/// Byte offset to split `window` at: after a paragraph break, then a line
+/// break, then a space, else the whole window. Breaks in the first half of
+/// the window are skipped so chunks don't come out tiny.
+fn break_point(window: &str, budget: usize) -> usize {
+    let late_enough = |pos: usize| window[..pos].chars().count() >= budget / 2;
+
+    if let Some(pos) = window.rfind("\n\n").filter(|&pos| late_enough(pos)) {
+        return pos + 2;
+    }
+    if let Some(pos) = window.rfind('\n').filter(|&pos| late_enough(pos)) {
+        return pos + 1;
+    }
+    window
+        .rfind(' ')
+        .filter(|&pos| late_enough(pos))
+        .map_or(window.len(), |pos| pos + 1)
+}
+
+/// Track fence lines through `piece`: returns the opener line (e.g.
+/// ```` ```rust ````) of a block still open at its end.
+fn fence_state_after(piece: &str, mut open_fence: Option<String>) -> Option<String> {
+    for line in piece.lines() {
+        let line = line.trim_start();
+        if line.starts_with(FENCE) {
+            open_fence = match open_fence {
+                Some(_) => None,
+                None => Some(line.trim_end().to_string()),
+            };
+        }
+    }
+    open_fence
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prefers_paragraph_breaks() {
+        let msg = format!(
+            "{}\n\n{}\n{}",
+            "a".repeat(60),
+            "b".repeat(30),
+            "c".repeat(30)
+        );
+        let chunks = split_reply(&msg, 100);
+        assert_eq!(chunks.len(), 2);
+        assert!(chunks[0].ends_with("\n\n"));
+        assert!(chunks[1].starts_with('b'));
+        assert_eq!(chunks.concat(), msg);
+    }
+
+    #[test]
+    fn code_block_is_closed_and_reopened_across_chunks() {
+        let code: String = (0..40).map(|i| format!("let x{i} = {i};\n")).collect();
+        let msg = format!("Here you go:\n\n```rust\n{code}```\nDone.");
+        let chunks = split_reply(&msg, 200);
+        assert!(chunks.len() > 2);
+
+        for chunk in &chunks {
+            assert!(chunk.chars().count() <= 200, "too long: {chunk:?}");
+            assert_eq!(chunk.matches(FENCE).count() % 2, 0, "unbalanced: {chunk:?}");
+        }
+        assert!(chunks[1].starts_with("```rust\n"));
+        assert!(chunks.last().unwrap().ends_with("Done."));
+
+        let all_lines: String = chunks.concat();
+        for i in 0..40 {
+            assert!(all_lines.contains(&format!("let x{i} = {i};")));
+        }
+    }
+
+    #[test]
+    fn early_space_falls_back_to_a_hard_split() {
+        let msg = format!("a {}", "b".repeat(200));
+        let chunks = split_reply(&msg, 100);
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(chunks[0].chars().count(), 100);
+        assert_eq!(chunks.concat(), msg);
+    }
+
+    #[test]
+    fn hard_split_respects_char_boundaries() {
+        let msg = "🦀".repeat(250);
+        let chunks = split_reply(&msg, 100);
+        assert_eq!(chunks.len(), 3);
+        assert!(chunks.iter().all(|c| c.chars().count() <= 100));
+        assert_eq!(chunks.concat(), msg);
+    }
+}
@@ -1,3 +1,4 @@
+pub(crate) mod chunking;
 pub mod cli;
 pub mod dingtalk;
 pub mod discord;
@@ -38,19 +39,23 @@ pub use whatsapp::WhatsAppChannel;
 #[cfg(feature = "whatsapp-web")]
 pub use whatsapp_web::WhatsAppWebChannel;
 
-use crate::agent::loop_::{build_tool_instructions, run_tool_call_loop};
+use crate::agent::loop_::{
+    build_tool_instructions, render_turn_outcome, run_tool_call_loop_outcome, LoopOptions,
+};
 use crate::config::Config;
 use crate::identity;
 use crate::memory::{self, Memory};
 use crate::observability::{self, Observer};
 use crate::providers::{self, ChatMessage, Provider};
 use crate::runtime;
+use crate::security::moderation::ModerationGate;
+use crate::security::AuditLogger;
 use crate::security::SecurityPolicy;
 use crate::tools::{self, Tool};
 use crate::util::truncate_with_ellipsis;
 use anyhow::{Context, Result};
 use serde::Deserialize;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::fmt::Write;
 use std::path::{Path, PathBuf};
 use std::process::Command;
@@ -65,6 +70,8 @@ const MAX_CHANNEL_HISTORY: usize = 50;
 
 /// Maximum characters per injected workspace file (matches `OpenClaw` default).
 const BOOTSTRAP_MAX_CHARS: usize = 20_000;
+/// Bootstrap/prompt-file budget under `agent.compact_context`.
+const COMPACT_BOOTSTRAP_MAX_CHARS: usize = 6000;
 
 const DEFAULT_CHANNEL_INITIAL_BACKOFF_SECS: u64 = 2;
 const DEFAULT_CHANNEL_MAX_BACKOFF_SECS: u64 = 60;
@@ -77,6 +84,9 @@ const CHANNEL_MIN_IN_FLIGHT_MESSAGES: usize = 8;
 const CHANNEL_MAX_IN_FLIGHT_MESSAGES: usize = 64;
 const CHANNEL_TYPING_REFRESH_INTERVAL_SECS: u64 = 4;
 const MODEL_CACHE_FILE: &str = "models_cache.json";
+const WELCOMED_SENDERS_FILE: &str = "welcomed_senders.json";
+/// Senders remembered as welcomed; past this the oldest are forgotten.
+const MAX_WELCOMED_SENDERS: usize = 10_000;
 const MODEL_CACHE_PREVIEW_LIMIT: usize = 10;
 const MEMORY_CONTEXT_MAX_ENTRIES: usize = 4;
 const MEMORY_CONTEXT_ENTRY_MAX_CHARS: usize = 800;
@@ -86,6 +96,82 @@ const CHANNEL_HISTORY_COMPACT_CONTENT_CHARS: usize = 600;
 
 type ProviderCacheMap = Arc<Mutex<HashMap<String, Arc<dyn Provider>>>>;
 type RouteSelectionMap = Arc<Mutex<HashMap<String, ChannelRouteSelection>>>;
+type WelcomedSenderSet = Arc<Mutex<WelcomedSenders>>;
+
+/// Senders (history keys) already sent the welcome message, oldest first.
+/// Persisted to `<workspace>/state/welcomed_senders.json` so a restart
+/// doesn't greet everyone again, and capped so it can't grow without bound.
+struct WelcomedSenders {
+    path: Option<PathBuf>,
+    capacity: usize,
+    order: VecDeque<String>,
+    seen: HashSet<String>,
+}
+
+impl WelcomedSenders {
+    /// Not persisted; for tests.
+    #[cfg(test)]
+    fn in_memory(capacity: usize) -> Self {
+        Self {
+            path: None,
+            capacity,
+            order: VecDeque::new(),
+            seen: HashSet::new(),
+        }
+    }
+
+    /// Load the saved set; a missing or unreadable file starts empty.
+    fn load(workspace_dir: &Path, capacity: usize) -> Self {
+        let path = workspace_dir.join("state").join(WELCOMED_SENDERS_FILE);
+        let saved: Vec<String> = std::fs::read_to_string(&path)
+            .ok()
+            .and_then(|raw| serde_json::from_str(&raw).ok())
+            .unwrap_or_default();
+        let mut senders = Self {
+            path: Some(path),
+            capacity,
+            order: VecDeque::new(),
+            seen: HashSet::new(),
+        };
+        for key in saved {
+            senders.remember(key);
+        }
+        senders
+    }
+
+    fn remember(&mut self, key: String) -> bool {
+        if !self.seen.insert(key.clone()) {
+            return false;
+        }
+        self.order.push_back(key);
+        while self.order.len() > self.capacity {
+            if let Some(oldest) = self.order.pop_front() {
+                self.seen.remove(&oldest);
+            }
+        }
+        true
+    }
+
+    /// Record `key`, saving the set when it is new. True on first contact.
+    fn insert(&mut self, key: String) -> bool {
+        if !self.remember(key) {
+            return false;
+        }
+        if let Some(path) = &self.path {
+            let result = path
+                .parent()
+                .map_or(Ok(()), std::fs::create_dir_all)
+                .and_then(|()| {
+                    let json = serde_json::to_vec(&self.order).map_err(std::io::Error::other)?;
+                    std::fs::write(path, json)
+                });
+            if let Err(e) = result {
+                tracing::warn!("Failed to save welcomed senders: {e}");
+            }
+        }
+        true
+    }
+}
 
 fn effective_channel_message_timeout_secs(configured: u64) -> u64 {
     configured.max(MIN_CHANNEL_MESSAGE_TIMEOUT_SECS)
@@ -126,10 +212,20 @@ struct ChannelRuntimeContext {
     observer: Arc<dyn Observer>,
     system_prompt: Arc<String>,
     model: Arc<String>,
+    /// `default_temperature`; see [`ChannelRuntimeContext::temperature_for`].
     temperature: f64,
+    /// `[[model_routes]]`, for per-model temperatures.
+    model_routes: Arc<Vec<crate::config::ModelRouteConfig>>,
+    generation: providers::GenerationParams,
     auto_save_memory: bool,
     max_tool_iterations: usize,
+    no_progress_window: usize,
+    tool_arg_validation: crate::config::ToolArgValidation,
+    tool_call_formats: Arc<Vec<crate::config::ToolCallFormat>>,
+    tool_result_role: crate::config::ToolResultRole,
+    provider_tool_result_roles: Arc<HashMap<String, crate::config::ToolResultRole>>,
     min_relevance_score: f64,
+    memory_recall_limit: usize,
     conversation_histories: ConversationHistoryMap,
     provider_cache: ProviderCacheMap,
     route_overrides: RouteSelectionMap,
@@ -139,6 +235,37 @@ struct ChannelRuntimeContext {
     provider_runtime_options: providers::ProviderRuntimeOptions,
     workspace_dir: Arc<PathBuf>,
     message_timeout_secs: u64,
+    /// Channel name → `channels_config.max_reply_tokens` cap.
+    max_reply_tokens: Arc<HashMap<String, u32>>,
+    /// Channel name → rendered `welcome_message`.
+    welcome_messages: Arc<HashMap<String, String>>,
+    /// Senders already welcomed since startup (history keys).
+    welcomed_senders: WelcomedSenderSet,
+    /// `[moderation]` gate; `None` when moderation is disabled.
+    moderation: Option<Arc<ModerationGate>>,
+    /// `[security.audit]` sink for tool executions; `None` when disabled.
+    audit_log: Option<Arc<AuditLogger>>,
+    /// Decrypted `[secrets.refs]` for `{{secret:name}}` placeholders.
+    secret_refs: Arc<HashMap<String, String>>,
+}
+
+impl ChannelRuntimeContext {
+    fn tool_result_role_for(&self, provider: &str) -> crate::config::ToolResultRole {
+        self.provider_tool_result_roles
+            .get(provider)
+            .copied()
+            .unwrap_or(self.tool_result_role)
+    }
+
+    /// Temperature for `model`, resolved per call so a `/model` switch picks
+    /// up that model's `[[model_routes]]` temperature.
+    fn temperature_for(&self, model: &str) -> f64 {
+        self.model_routes
+            .iter()
+            .filter(|route| route.matches_model(model))
+            .find_map(|route| route.temperature)
+            .unwrap_or(self.temperature)
+    }
 }
 
 fn conversation_memory_key(msg: &traits::ChannelMessage) -> String {
@@ -158,6 +285,31 @@ fn channel_delivery_instructions(channel_name: &str) -> Option<&'static str> {
     }
 }
 
+/// Instruction asking the model to stay within a channel's reply cap.
+fn reply_length_instruction(max_tokens: u32) -> String {
+    format!(
+        "Keep your reply concise: at most about {} words. Longer replies are cut off.",
+        u64::from(max_tokens) * 3 / 4
+    )
+}
+
+/// Cut `response` to roughly `max_tokens` (~4 chars per token), at a word
+/// boundary where possible, and mark it as truncated.
+fn cap_reply_length(response: String, max_tokens: u32) -> String {
+    let max_chars = usize::try_from(max_tokens)
+        .unwrap_or(usize::MAX)
+        .saturating_mul(4);
+    let Some((cut, _)) = response.char_indices().nth(max_chars) else {
+        return response;
+    };
+    let head = &response[..cut];
+    let head = head
+        .rfind(char::is_whitespace)
+        .filter(|&i| i > cut / 2)
+        .map_or(head, |i| &head[..i]);
+    format!("{}…(truncated)", head.trim_end())
+}
+
 fn supports_runtime_model_switch(channel_name: &str) -> bool {
     matches!(channel_name, "telegram" | "discord")
 }
@@ -435,6 +587,79 @@ fn build_providers_help_response(current: &ChannelRouteSelection) -> String {
     response
 }
 
+/// Channel name → `welcome_message`, with `{tools}` expanded to the
+/// enabled tool names.
+fn build_welcome_messages(
+    channels: &crate::config::ChannelsConfig,
+    tools: &[Box<dyn Tool>],
+) -> HashMap<String, String> {
+    let tool_names = tools
+        .iter()
+        .map(|t| t.name())
+        .collect::<Vec<_>>()
+        .join(", ");
+    [
+        (
+            "telegram",
+            channels
+                .telegram
+                .as_ref()
+                .and_then(|c| c.welcome_message.as_ref()),
+        ),
+        (
+            "discord",
+            channels
+                .discord
+                .as_ref()
+                .and_then(|c| c.welcome_message.as_ref()),
+        ),
+    ]
+    .into_iter()
+    .filter_map(|(name, message)| {
+        let message = message?.trim();
+        (!message.is_empty()).then(|| (name.to_string(), message.replace("{tools}", &tool_names)))
+    })
+    .collect()
+}
+
+fn is_start_command(content: &str) -> bool {
+    let command = content.split_whitespace().next().unwrap_or_default();
+    command.split('@').next() == Some("/start")
+}
+
+/// Send the channel's welcome message on `/start` or on a sender's first
+/// message (see [`WelcomedSenders`]). Returns true when the message was `/start`, which
+/// needs no agent turn.
+async fn send_welcome_if_needed(
+    ctx: &ChannelRuntimeContext,
+    msg: &traits::ChannelMessage,
+    target_channel: Option<&Arc<dyn Channel>>,
+) -> bool {
+    let Some(welcome) = ctx.welcome_messages.get(&msg.channel) else {
+        return false;
+    };
+    let is_start = is_start_command(&msg.content);
+    let first_contact = ctx
+        .welcomed_senders
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .insert(conversation_history_key(msg));
+
+    if is_start || first_contact {
+        if let Some(channel) = target_channel {
+            if let Err(e) = channel
+                .send(
+                    &SendMessage::new(welcome, &msg.reply_target).in_thread(msg.thread_ts.clone()),
+                )
+                .await
+            {
+                tracing::warn!(channel = %msg.channel, "Failed to send welcome message: {e}");
+            }
+        }
+    }
+    is_start
+}
+
 async fn handle_runtime_command_if_needed(
     ctx: &ChannelRuntimeContext,
     msg: &traits::ChannelMessage,
@@ -516,11 +741,12 @@ async fn handle_runtime_command_if_needed(
 async fn build_memory_context(
     mem: &dyn Memory,
     user_msg: &str,
+    recall_limit: usize,
     min_relevance_score: f64,
 ) -> String {
     let mut context = String::new();
 
-    if let Ok(entries) = mem.recall(user_msg, 5, None).await {
+    if let Ok(entries) = memory::recall_for_context(mem, user_msg, recall_limit).await {
         let mut included = 0usize;
         let mut used_chars = 0usize;
 
@@ -662,6 +888,22 @@ async fn process_channel_message(ctx: Arc<ChannelRuntimeContext>, msg: traits::C
     if handle_runtime_command_if_needed(ctx.as_ref(), &msg, target_channel.as_ref()).await {
         return;
     }
+    if send_welcome_if_needed(ctx.as_ref(), &msg, target_channel.as_ref()).await {
+        return;
+    }
+    if let Some(gate) = ctx.moderation.as_ref() {
+        if let Some(blocked) = gate.screen_input(&msg.content).await {
+            if let Some(channel) = target_channel.as_ref() {
+                let _ = channel
+                    .send(
+                        &SendMessage::new(blocked, &msg.reply_target)
+                            .in_thread(msg.thread_ts.clone()),
+                    )
+                    .await;
+            }
+            return;
+        }
+    }
 
     let history_key = conversation_history_key(&msg);
     let route = get_route_selection(ctx.as_ref(), &history_key);
@@ -685,20 +927,23 @@ async fn process_channel_message(ctx: Arc<ChannelRuntimeContext>, msg: traits::C
         }
     };
 
-    let memory_context =
-        build_memory_context(ctx.memory.as_ref(), &msg.content, ctx.min_relevance_score).await;
+    let memory_context = build_memory_context(
+        ctx.memory.as_ref(),
+        &msg.content,
+        ctx.memory_recall_limit,
+        ctx.min_relevance_score,
+    )
+    .await;
 
     if ctx.auto_save_memory {
         let autosave_key = conversation_memory_key(&msg);
-        let _ = ctx
-            .memory
-            .store(
-                &autosave_key,
-                &msg.content,
-                crate::memory::MemoryCategory::Conversation,
-                None,
-            )
-            .await;
+        let _ = crate::memory::store_autosave(
+            ctx.memory.as_ref(),
+            &autosave_key,
+            &msg.content,
+            crate::memory::MemoryCategory::Conversation,
+        )
+        .await;
     }
 
     let enriched_message = if memory_context.is_empty() {
@@ -726,11 +971,21 @@ async fn process_channel_message(ctx: Arc<ChannelRuntimeContext>, msg: traits::C
     if let Some(instructions) = channel_delivery_instructions(&msg.channel) {
         history.push(ChatMessage::system(instructions));
     }
+    let reply_cap = ctx.max_reply_tokens.get(&msg.channel).copied();
+    if let Some(max_tokens) = reply_cap {
+        history.push(ChatMessage::system(reply_length_instruction(max_tokens)));
+    }
 
-    // Determine if this channel supports streaming draft updates
+    // Determine if this channel supports streaming draft updates. Streaming
+    // is skipped when responses are moderated, since drafts would show the
+    // response before it has been screened.
     let use_streaming = target_channel
         .as_ref()
-        .map_or(false, |ch| ch.supports_draft_updates());
+        .map_or(false, |ch| ch.supports_draft_updates())
+        && !ctx
+            .moderation
+            .as_ref()
+            .is_some_and(|gate| gate.checks_output());
 
     // Set up streaming channel if supported
     let (delta_tx, delta_rx) = if use_streaming {
@@ -799,19 +1054,29 @@ async fn process_channel_message(ctx: Arc<ChannelRuntimeContext>, msg: traits::C
 
     let llm_result = tokio::time::timeout(
         Duration::from_secs(ctx.message_timeout_secs),
-        run_tool_call_loop(
+        run_tool_call_loop_outcome(
             active_provider.as_ref(),
             &mut history,
             ctx.tools_registry.as_ref(),
             ctx.observer.as_ref(),
-            route.provider.as_str(),
-            route.model.as_str(),
-            ctx.temperature,
-            true,
-            None,
-            msg.channel.as_str(),
-            ctx.max_tool_iterations,
-            delta_tx,
+            LoopOptions {
+                provider_name: route.provider.as_str(),
+                model: route.model.as_str(),
+                temperature: ctx.temperature_for(&route.model),
+                params: Some(&ctx.generation),
+                silent: true,
+                approval: None,
+                channel_name: msg.channel.as_str(),
+                max_tool_iterations: ctx.max_tool_iterations,
+                no_progress_window: ctx.no_progress_window,
+                turn_retries: ctx.reliability.turn_retries,
+                tool_result_role: ctx.tool_result_role_for(route.provider.as_str()),
+                arg_validation: ctx.tool_arg_validation,
+                tool_call_formats: &ctx.tool_call_formats,
+                on_delta: delta_tx,
+                audit_log: ctx.audit_log.clone(),
+                secret_refs: ctx.secret_refs.as_ref(),
+            },
         ),
     )
     .await;
@@ -829,7 +1094,18 @@ async fn process_channel_message(ctx: Arc<ChannelRuntimeContext>, msg: traits::C
     }
 
     match llm_result {
-        Ok(Ok(response)) => {
+        Ok(Ok(outcome)) => {
+            // Hitting the iteration cap still produces a reply (the partial
+            // answer plus a note), so the turn is saved like any other.
+            let response = render_turn_outcome(&outcome);
+            let response = match ctx.moderation.as_ref() {
+                Some(gate) => gate.screen_output(&response).await.unwrap_or(response),
+                None => response,
+            };
+            let response = match reply_cap {
+                Some(max_tokens) => cap_reply_length(response, max_tokens),
+                None => response,
+            };
             // Save user + assistant turn to per-sender history
             {
                 let mut histories = ctx
@@ -1194,6 +1470,129 @@ pub fn build_system_prompt(
     }
 }
 
+/// Load `agent.system_prompt_file` as the base system prompt, if configured.
+///
+/// Replaces [`build_system_prompt`] entirely; callers still append tool
+/// instructions. Truncated like bootstrap files under `compact_context`.
+/// Returns `Ok(None)` when unset, so callers use the workspace-scanned
+/// prompt, and an error when set but unreadable or empty: silently running
+/// with a different persona is worse than not starting.
+pub fn load_system_prompt_file(config: &Config) -> Result<Option<String>> {
+    let Some(raw_path) = config
+        .agent
+        .system_prompt_file
+        .as_deref()
+        .map(str::trim)
+        .filter(|path| !path.is_empty())
+    else {
+        return Ok(None);
+    };
+    let path = shellexpand::tilde(raw_path).into_owned();
+    let path = config.workspace_dir.join(path);
+
+    let content = std::fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read agent.system_prompt_file {}", path.display()))?;
+    let trimmed = content.trim();
+    if trimmed.is_empty() {
+        anyhow::bail!("agent.system_prompt_file {} is empty", path.display());
+    }
+
+    let max_chars = COMPACT_BOOTSTRAP_MAX_CHARS;
+    if config.agent.compact_context && trimmed.chars().count() > max_chars {
+        let cut = trimmed
+            .char_indices()
+            .nth(max_chars)
+            .map_or(trimmed.len(), |(idx, _)| idx);
+        return Ok(Some(format!(
+            "{}\n\n[... truncated at {max_chars} chars]\n\n",
+            &trimmed[..cut]
+        )));
+    }
+    Ok(Some(format!("{trimmed}\n\n")))
+}
+
+/// Render the user's stored preferences as a prompt section.
+///
+/// Appended after the base prompt by every caller of
+/// [`load_system_prompt_file`], so preferences apply with a custom base
+/// prompt too. Empty for `agent.ephemeral` runs, which read nothing
+/// persistent.
+pub fn load_user_preferences(config: &Config) -> String {
+    if config.agent.ephemeral {
+        return String::new();
+    }
+    crate::memory::preferences::prompt_section(&config.workspace_dir)
+}
+
+/// Render `agent.context_files` as a prompt section, verbatim and in order.
+///
+/// Appended after the base prompt by every caller of
+/// [`load_system_prompt_file`]. The files share one bootstrap-sized budget
+/// (smaller under `compact_context`); the file that crosses it is truncated
+/// and later ones are skipped. Unreadable or empty files are skipped with a
+/// warning.
+pub fn load_context_files(config: &Config) -> String {
+    use std::fmt::Write;
+
+    let mut budget = if config.agent.compact_context {
+        COMPACT_BOOTSTRAP_MAX_CHARS
+    } else {
+        BOOTSTRAP_MAX_CHARS
+    };
+    let mut section = String::new();
+
+    for raw_path in &config.agent.context_files {
+        let raw_path = raw_path.trim();
+        if raw_path.is_empty() {
+            continue;
+        }
+        if budget == 0 {
+            tracing::warn!("agent.context_files budget exhausted; skipping {raw_path}");
+            continue;
+        }
+        let path = config
+            .workspace_dir
+            .join(shellexpand::tilde(raw_path).into_owned());
+        let content = match std::fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(e) => {
+                tracing::warn!(
+                    "Failed to read agent.context_files entry {}: {e}",
+                    path.display()
+                );
+                continue;
+            }
+        };
+        let trimmed = content.trim();
+        if trimmed.is_empty() {
+            tracing::warn!("agent.context_files entry {} is empty", path.display());
+            continue;
+        }
+
+        let _ = writeln!(section, "### {raw_path}\n");
+        match trimmed.char_indices().nth(budget) {
+            Some((cut, _)) => {
+                section.push_str(&trimmed[..cut]);
+                let _ = writeln!(
+                    section,
+                    "\n\n[... truncated: context file budget reached]\n"
+                );
+                budget = 0;
+            }
+            None => {
+                section.push_str(trimmed);
+                section.push_str("\n\n");
+                budget -= trimmed.chars().count();
+            }
+        }
+    }
+
+    if section.is_empty() {
+        return section;
+    }
+    format!("## Context Files\n\n{section}")
+}
+
 /// Inject a single workspace file into the prompt with truncation and missing-file markers.
 fn inject_workspace_file(
     prompt: &mut String,
@@ -1668,10 +2067,14 @@ pub async fn start_channels(config: Config) -> Result<()> {
         .default_provider
         .clone()
         .unwrap_or_else(|| "openrouter".into());
+    let observer: Arc<dyn Observer> =
+        Arc::from(observability::create_observer(&config.observability));
     let provider_runtime_options = providers::ProviderRuntimeOptions {
         auth_profile_override: None,
         zeroclaw_dir: config.config_path.parent().map(std::path::PathBuf::from),
         secrets_encrypt: config.secrets.encrypt,
+        observer: Some(observer.clone()),
+        azure_openai: config.azure_openai.clone(),
     };
     let provider: Arc<dyn Provider> = Arc::from(providers::create_resilient_provider_with_options(
         &provider_name,
@@ -1687,8 +2090,6 @@ pub async fn start_channels(config: Config) -> Result<()> {
         tracing::warn!("Provider warmup failed (non-fatal): {e}");
     }
 
-    let observer: Arc<dyn Observer> =
-        Arc::from(observability::create_observer(&config.observability));
     let runtime: Arc<dyn runtime::RuntimeAdapter> =
         Arc::from(runtime::create_runtime(&config.runtime)?);
     let security = Arc::new(SecurityPolicy::from_config(
@@ -1699,7 +2100,6 @@ pub async fn start_channels(config: Config) -> Result<()> {
         .default_model
         .clone()
         .unwrap_or_else(|| "anthropic/claude-sonnet-4-20250514".into());
-    let temperature = config.default_temperature;
     let mem: Arc<dyn Memory> = Arc::from(memory::create_memory_with_storage(
         &config.memory,
         Some(&config.storage.provider.config),
@@ -1716,7 +2116,7 @@ pub async fn start_channels(config: Config) -> Result<()> {
     };
     // Build system prompt from workspace identity files + skills
     let workspace = config.workspace_dir.clone();
-    let tools_registry = Arc::new(tools::all_tools_with_runtime(
+    let mut tools_registry = tools::all_tools_with_runtime(
         Arc::new(config.clone()),
         &security,
         runtime,
@@ -1729,7 +2129,9 @@ pub async fn start_channels(config: Config) -> Result<()> {
         &config.agents,
         config.api_key.as_deref(),
         &config,
-    ));
+    );
+    tools::register_self_info(&mut tools_registry, &config);
+    let tools_registry = Arc::new(tools_registry);
 
     let skills = crate::skills::load_skills(&workspace);
 
@@ -1759,7 +2161,14 @@ pub async fn start_channels(config: Config) -> Result<()> {
             "memory_forget",
             "Delete a memory entry. Use when: memory is incorrect/stale or explicitly requested for removal. Don't use when: impact is uncertain.",
         ),
+        (
+            "set_preference",
+            "Set or remove a durable user preference that is always shown in your instructions. Use when: the user states a standing preference (language, units, tone, timezone). Don't use when: it only applies to the current task.",
+        ),
     ];
+    if config.agent.ephemeral {
+        tool_descs.retain(|(name, _)| *name != "set_preference");
+    }
 
     if config.browser.enabled {
         tool_descs.push((
@@ -1767,6 +2176,12 @@ pub async fn start_channels(config: Config) -> Result<()> {
             "Open approved HTTPS URLs in Brave Browser (allowlist-only, no scraping)",
         ));
     }
+    if config.browser.fetch_enabled {
+        tool_descs.push((
+            "browser_fetch",
+            "Fetch an approved HTTPS page and return its readable text (allowlist-only).",
+        ));
+    }
     if config.composio.enabled {
         tool_descs.push((
             "composio",
@@ -1777,10 +2192,27 @@ pub async fn start_channels(config: Config) -> Result<()> {
         "schedule",
         "Manage scheduled tasks (create/list/get/cancel/pause/resume). Supports recurring cron and one-shot delays.",
     ));
+    tool_descs.push((
+        "wait",
+        "Wait a number of seconds (capped by agent.max_wait_secs). Use when: timing hardware actions or polling; prefer over shell sleep.",
+    ));
+    if cfg!(feature = "ocr") {
+        tool_descs.push(("image_ocr", "Extract text from an image file with OCR."));
+    }
+    if cfg!(feature = "clipboard") && config.autonomy.allow_clipboard {
+        tool_descs.push((
+            "clipboard",
+            "Read (action='get') or write (action='set') plain text on the system clipboard.",
+        ));
+    }
     tool_descs.push((
         "pushover",
         "Send a Pushover notification to your device. Requires PUSHOVER_TOKEN and PUSHOVER_USER_KEY in .env file.",
     ));
+    tool_descs.push((
+        "self_info",
+        "Show your own provider, model, enabled tools, boards and build features (no secrets). Use when: asked what you can do or which model you are.",
+    ));
     if !config.agents.is_empty() {
         tool_descs.push((
             "delegate",
@@ -1793,14 +2225,19 @@ pub async fn start_channels(config: Config) -> Result<()> {
     } else {
         None
     };
-    let mut system_prompt = build_system_prompt(
-        &workspace,
-        &model,
-        &tool_descs,
-        &skills,
-        Some(&config.identity),
-        bootstrap_max_chars,
-    );
+    let mut system_prompt = load_system_prompt_file(&config)?.unwrap_or_else(|| {
+        build_system_prompt(
+            &workspace,
+            &model,
+            &tool_descs,
+            &skills,
+            Some(&config.identity),
+            bootstrap_max_chars,
+        )
+    });
+    system_prompt.push_str(&load_context_files(&config));
+    system_prompt.push_str(&load_user_preferences(&config));
+    system_prompt.push_str(&crate::agent::file_tree::build_workspace_file_tree(&config));
     system_prompt.push_str(&build_tool_instructions(tools_registry.as_ref()));
 
     if !skills.is_empty() {
@@ -2039,6 +2476,8 @@ pub async fn start_channels(config: Config) -> Result<()> {
     let message_timeout_secs =
         effective_channel_message_timeout_secs(config.channels_config.message_timeout_secs);
 
+    let moderation = ModerationGate::from_config(&config.moderation)?.map(Arc::new);
+
     let runtime_ctx = Arc::new(ChannelRuntimeContext {
         channels_by_name,
         provider: Arc::clone(&provider),
@@ -2048,10 +2487,18 @@ pub async fn start_channels(config: Config) -> Result<()> {
         observer,
         system_prompt: Arc::new(system_prompt),
         model: Arc::new(model.clone()),
-        temperature,
+        temperature: config.default_temperature,
+        model_routes: Arc::new(config.model_routes.clone()),
+        generation: config.agent.generation_params(),
         auto_save_memory: config.memory.auto_save,
         max_tool_iterations: config.agent.max_tool_iterations,
+        no_progress_window: config.agent.no_progress_window,
+        tool_arg_validation: config.agent.tool_arg_validation,
+        tool_call_formats: Arc::new(config.agent.tool_call_formats.clone()),
+        tool_result_role: config.agent.tool_result_role,
+        provider_tool_result_roles: Arc::new(config.agent.provider_tool_result_roles.clone()),
         min_relevance_score: config.memory.min_relevance_score,
+        memory_recall_limit: config.memory.recall_limit,
         conversation_histories: Arc::new(Mutex::new(HashMap::new())),
         provider_cache: Arc::new(Mutex::new(provider_cache_seed)),
         route_overrides: Arc::new(Mutex::new(HashMap::new())),
@@ -2061,6 +2508,18 @@ pub async fn start_channels(config: Config) -> Result<()> {
         provider_runtime_options,
         workspace_dir: Arc::new(config.workspace_dir.clone()),
         message_timeout_secs,
+        max_reply_tokens: Arc::new(config.channels_config.max_reply_tokens.clone()),
+        welcome_messages: Arc::new(build_welcome_messages(
+            &config.channels_config,
+            &tools_registry,
+        )),
+        welcomed_senders: Arc::new(Mutex::new(WelcomedSenders::load(
+            &config.workspace_dir,
+            MAX_WELCOMED_SENDERS,
+        ))),
+        moderation,
+        audit_log: AuditLogger::for_run(&config.security.audit, &config.config_path)?,
+        secret_refs: Arc::new(config.secrets.refs.clone()),
     });
 
     run_message_dispatch_loop(rx, runtime_ctx, max_in_flight_messages).await;
@@ -2168,9 +2627,17 @@ mod tests {
             system_prompt: Arc::new("system".to_string()),
             model: Arc::new("test-model".to_string()),
             temperature: 0.0,
+            model_routes: Arc::new(Vec::new()),
+            generation: providers::GenerationParams::default(),
             auto_save_memory: false,
             max_tool_iterations: 5,
+            no_progress_window: 3,
+            tool_arg_validation: crate::config::ToolArgValidation::default(),
+            tool_call_formats: Arc::new(Vec::new()),
+            tool_result_role: crate::config::ToolResultRole::User,
+            provider_tool_result_roles: Arc::new(HashMap::new()),
             min_relevance_score: 0.0,
+            memory_recall_limit: 5,
             conversation_histories: Arc::new(Mutex::new(histories)),
             provider_cache: Arc::new(Mutex::new(HashMap::new())),
             route_overrides: Arc::new(Mutex::new(HashMap::new())),
@@ -2180,6 +2647,14 @@ mod tests {
             provider_runtime_options: providers::ProviderRuntimeOptions::default(),
             workspace_dir: Arc::new(std::env::temp_dir()),
             message_timeout_secs: CHANNEL_MESSAGE_TIMEOUT_SECS,
+            max_reply_tokens: Arc::new(HashMap::new()),
+            welcome_messages: Arc::new(HashMap::new()),
+            welcomed_senders: Arc::new(Mutex::new(WelcomedSenders::in_memory(
+                MAX_WELCOMED_SENDERS,
+            ))),
+            moderation: None,
+            audit_log: None,
+            secret_refs: Arc::new(HashMap::new()),
         };
 
         assert!(compact_sender_history(&ctx, &sender));
@@ -2517,6 +2992,8 @@ mod tests {
                     success: false,
                     output: String::new(),
                     error: Some("unexpected symbol".to_string()),
+                    metadata: None,
+                    content_type: None,
                 });
             }
 
@@ -2524,6 +3001,8 @@ mod tests {
                 success: true,
                 output: r#"{"symbol":"BTC","price_usd":65000}"#.to_string(),
                 error: None,
+                metadata: None,
+                content_type: None,
             })
         }
     }
@@ -2546,9 +3025,17 @@ mod tests {
             system_prompt: Arc::new("test-system-prompt".to_string()),
             model: Arc::new("test-model".to_string()),
             temperature: 0.0,
+            model_routes: Arc::new(Vec::new()),
+            generation: providers::GenerationParams::default(),
             auto_save_memory: false,
             max_tool_iterations: 10,
+            no_progress_window: 3,
+            tool_arg_validation: crate::config::ToolArgValidation::default(),
+            tool_call_formats: Arc::new(Vec::new()),
+            tool_result_role: crate::config::ToolResultRole::User,
+            provider_tool_result_roles: Arc::new(HashMap::new()),
             min_relevance_score: 0.0,
+            memory_recall_limit: 5,
             conversation_histories: Arc::new(Mutex::new(HashMap::new())),
             provider_cache: Arc::new(Mutex::new(HashMap::new())),
             route_overrides: Arc::new(Mutex::new(HashMap::new())),
@@ -2558,6 +3045,14 @@ mod tests {
             provider_runtime_options: providers::ProviderRuntimeOptions::default(),
             workspace_dir: Arc::new(std::env::temp_dir()),
             message_timeout_secs: CHANNEL_MESSAGE_TIMEOUT_SECS,
+            max_reply_tokens: Arc::new(HashMap::new()),
+            welcome_messages: Arc::new(HashMap::new()),
+            welcomed_senders: Arc::new(Mutex::new(WelcomedSenders::in_memory(
+                MAX_WELCOMED_SENDERS,
+            ))),
+            moderation: None,
+            audit_log: None,
+            secret_refs: Arc::new(HashMap::new()),
         });
 
         process_channel_message(
@@ -2582,6 +3077,137 @@ mod tests {
         assert!(!sent_messages[0].contains("mock_price"));
     }
 
+    #[tokio::test]
+    async fn welcome_message_answers_start_and_greets_first_contact_once() {
+        let channel_impl = Arc::new(RecordingChannel::default());
+        let channel: Arc<dyn Channel> = channel_impl.clone();
+        let provider_impl = Arc::new(ModelCaptureProvider::default());
+        let provider: Arc<dyn Provider> = provider_impl.clone();
+
+        let mut channels_by_name = HashMap::new();
+        channels_by_name.insert(channel.name().to_string(), channel);
+        let mut welcome_messages = HashMap::new();
+        welcome_messages.insert(
+            "test-channel".to_string(),
+            "Hi! I can use: mock_price".to_string(),
+        );
+
+        let runtime_ctx = Arc::new(ChannelRuntimeContext {
+            channels_by_name: Arc::new(channels_by_name),
+            provider,
+            default_provider: Arc::new("test-provider".to_string()),
+            memory: Arc::new(NoopMemory),
+            tools_registry: Arc::new(vec![Box::new(MockPriceTool)]),
+            observer: Arc::new(NoopObserver),
+            system_prompt: Arc::new("test-system-prompt".to_string()),
+            model: Arc::new("test-model".to_string()),
+            temperature: 0.0,
+            model_routes: Arc::new(Vec::new()),
+            generation: providers::GenerationParams::default(),
+            auto_save_memory: false,
+            max_tool_iterations: 10,
+            no_progress_window: 3,
+            tool_arg_validation: crate::config::ToolArgValidation::default(),
+            tool_call_formats: Arc::new(Vec::new()),
+            tool_result_role: crate::config::ToolResultRole::User,
+            provider_tool_result_roles: Arc::new(HashMap::new()),
+            min_relevance_score: 0.0,
+            memory_recall_limit: 5,
+            conversation_histories: Arc::new(Mutex::new(HashMap::new())),
+            provider_cache: Arc::new(Mutex::new(HashMap::new())),
+            route_overrides: Arc::new(Mutex::new(HashMap::new())),
+            api_key: None,
+            api_url: None,
+            reliability: Arc::new(crate::config::ReliabilityConfig::default()),
+            provider_runtime_options: providers::ProviderRuntimeOptions::default(),
+            workspace_dir: Arc::new(std::env::temp_dir()),
+            message_timeout_secs: CHANNEL_MESSAGE_TIMEOUT_SECS,
+            max_reply_tokens: Arc::new(HashMap::new()),
+            welcome_messages: Arc::new(welcome_messages),
+            welcomed_senders: Arc::new(Mutex::new(WelcomedSenders::in_memory(
+                MAX_WELCOMED_SENDERS,
+            ))),
+            moderation: None,
+            audit_log: None,
+            secret_refs: Arc::new(HashMap::new()),
+        });
+
+        let message = |id: &str, sender: &str, content: &str| traits::ChannelMessage {
+            id: id.to_string(),
+            sender: sender.to_string(),
+            reply_target: format!("chat-{sender}"),
+            content: content.to_string(),
+            channel: "test-channel".to_string(),
+            timestamp: 1,
+            thread_ts: None,
+        };
+
+        process_channel_message(runtime_ctx.clone(), message("1", "alice", "/start")).await;
+        assert_eq!(provider_impl.call_count.load(Ordering::SeqCst), 0);
+
+        process_channel_message(runtime_ctx.clone(), message("2", "alice", "hello")).await;
+        process_channel_message(runtime_ctx.clone(), message("3", "bob", "hello")).await;
+        assert_eq!(provider_impl.call_count.load(Ordering::SeqCst), 2);
+
+        let sent = channel_impl.sent_messages.lock().await;
+        assert_eq!(
+            *sent,
+            vec![
+                "chat-alice:Hi! I can use: mock_price".to_string(),
+                "chat-alice:ok".to_string(),
+                "chat-bob:Hi! I can use: mock_price".to_string(),
+                "chat-bob:ok".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn welcomed_senders_persist_and_forget_the_oldest() {
+        let tmp = TempDir::new().unwrap();
+        let mut senders = WelcomedSenders::load(tmp.path(), 2);
+        assert!(senders.insert("tg_alice".into()));
+        assert!(!senders.insert("tg_alice".into()));
+        assert!(senders.insert("tg_bob".into()));
+
+        let mut reloaded = WelcomedSenders::load(tmp.path(), 2);
+        assert!(!reloaded.insert("tg_alice".into()));
+        assert!(reloaded.insert("tg_carol".into()));
+        // alice was the oldest and is forgotten once carol is added.
+        let mut reloaded = WelcomedSenders::load(tmp.path(), 2);
+        assert!(reloaded.insert("tg_alice".into()));
+    }
+
+    #[test]
+    fn welcome_messages_expand_tool_names_and_skip_blank() {
+        let mut channels = crate::config::ChannelsConfig::default();
+        channels.telegram = Some(crate::config::TelegramConfig {
+            bot_token: "t".into(),
+            allowed_users: vec![],
+            stream_mode: crate::config::StreamMode::Off,
+            draft_update_interval_ms: 1000,
+            mention_only: false,
+            welcome_message: Some("Tools: {tools}".into()),
+        });
+        channels.discord = Some(crate::config::DiscordConfig {
+            bot_token: "d".into(),
+            guild_id: None,
+            allowed_users: vec![],
+            listen_to_bots: false,
+            mention_only: false,
+            welcome_message: Some("   ".into()),
+        });
+        let tools: Vec<Box<dyn Tool>> = vec![Box::new(MockPriceTool)];
+
+        let messages = build_welcome_messages(&channels, &tools);
+        assert_eq!(
+            messages.get("telegram").map(String::as_str),
+            Some("Tools: mock_price")
+        );
+        assert!(!messages.contains_key("discord"));
+        assert!(is_start_command("/start@zeroclaw_bot payload"));
+        assert!(!is_start_command("/started"));
+    }
+
     #[tokio::test]
     async fn process_channel_message_executes_tool_calls_with_alias_tags() {
         let channel_impl = Arc::new(RecordingChannel::default());
@@ -2600,9 +3226,17 @@ mod tests {
             system_prompt: Arc::new("test-system-prompt".to_string()),
             model: Arc::new("test-model".to_string()),
             temperature: 0.0,
+            model_routes: Arc::new(Vec::new()),
+            generation: providers::GenerationParams::default(),
             auto_save_memory: false,
             max_tool_iterations: 10,
+            no_progress_window: 3,
+            tool_arg_validation: crate::config::ToolArgValidation::default(),
+            tool_call_formats: Arc::new(Vec::new()),
+            tool_result_role: crate::config::ToolResultRole::User,
+            provider_tool_result_roles: Arc::new(HashMap::new()),
             min_relevance_score: 0.0,
+            memory_recall_limit: 5,
             conversation_histories: Arc::new(Mutex::new(HashMap::new())),
             provider_cache: Arc::new(Mutex::new(HashMap::new())),
             route_overrides: Arc::new(Mutex::new(HashMap::new())),
@@ -2612,6 +3246,14 @@ mod tests {
             provider_runtime_options: providers::ProviderRuntimeOptions::default(),
             workspace_dir: Arc::new(std::env::temp_dir()),
             message_timeout_secs: CHANNEL_MESSAGE_TIMEOUT_SECS,
+            max_reply_tokens: Arc::new(HashMap::new()),
+            welcome_messages: Arc::new(HashMap::new()),
+            welcomed_senders: Arc::new(Mutex::new(WelcomedSenders::in_memory(
+                MAX_WELCOMED_SENDERS,
+            ))),
+            moderation: None,
+            audit_log: None,
+            secret_refs: Arc::new(HashMap::new()),
         });
 
         process_channel_message(
@@ -2663,9 +3305,17 @@ mod tests {
             system_prompt: Arc::new("test-system-prompt".to_string()),
             model: Arc::new("default-model".to_string()),
             temperature: 0.0,
+            model_routes: Arc::new(Vec::new()),
+            generation: providers::GenerationParams::default(),
             auto_save_memory: false,
             max_tool_iterations: 5,
+            no_progress_window: 3,
+            tool_arg_validation: crate::config::ToolArgValidation::default(),
+            tool_call_formats: Arc::new(Vec::new()),
+            tool_result_role: crate::config::ToolResultRole::User,
+            provider_tool_result_roles: Arc::new(HashMap::new()),
             min_relevance_score: 0.0,
+            memory_recall_limit: 5,
             conversation_histories: Arc::new(Mutex::new(HashMap::new())),
             provider_cache: Arc::new(Mutex::new(provider_cache_seed)),
             route_overrides: Arc::new(Mutex::new(HashMap::new())),
@@ -2675,6 +3325,14 @@ mod tests {
             provider_runtime_options: providers::ProviderRuntimeOptions::default(),
             workspace_dir: Arc::new(std::env::temp_dir()),
             message_timeout_secs: CHANNEL_MESSAGE_TIMEOUT_SECS,
+            max_reply_tokens: Arc::new(HashMap::new()),
+            welcome_messages: Arc::new(HashMap::new()),
+            welcomed_senders: Arc::new(Mutex::new(WelcomedSenders::in_memory(
+                MAX_WELCOMED_SENDERS,
+            ))),
+            moderation: None,
+            audit_log: None,
+            secret_refs: Arc::new(HashMap::new()),
         });
 
         process_channel_message(
@@ -2747,9 +3405,17 @@ mod tests {
             system_prompt: Arc::new("test-system-prompt".to_string()),
             model: Arc::new("default-model".to_string()),
             temperature: 0.0,
+            model_routes: Arc::new(Vec::new()),
+            generation: providers::GenerationParams::default(),
             auto_save_memory: false,
             max_tool_iterations: 5,
+            no_progress_window: 3,
+            tool_arg_validation: crate::config::ToolArgValidation::default(),
+            tool_call_formats: Arc::new(Vec::new()),
+            tool_result_role: crate::config::ToolResultRole::User,
+            provider_tool_result_roles: Arc::new(HashMap::new()),
             min_relevance_score: 0.0,
+            memory_recall_limit: 5,
             conversation_histories: Arc::new(Mutex::new(HashMap::new())),
             provider_cache: Arc::new(Mutex::new(provider_cache_seed)),
             route_overrides: Arc::new(Mutex::new(route_overrides)),
@@ -2759,6 +3425,14 @@ mod tests {
             provider_runtime_options: providers::ProviderRuntimeOptions::default(),
             workspace_dir: Arc::new(std::env::temp_dir()),
             message_timeout_secs: CHANNEL_MESSAGE_TIMEOUT_SECS,
+            max_reply_tokens: Arc::new(HashMap::new()),
+            welcome_messages: Arc::new(HashMap::new()),
+            welcomed_senders: Arc::new(Mutex::new(WelcomedSenders::in_memory(
+                MAX_WELCOMED_SENDERS,
+            ))),
+            moderation: None,
+            audit_log: None,
+            secret_refs: Arc::new(HashMap::new()),
         });
 
         process_channel_message(
@@ -2807,9 +3481,17 @@ mod tests {
             system_prompt: Arc::new("test-system-prompt".to_string()),
             model: Arc::new("test-model".to_string()),
             temperature: 0.0,
+            model_routes: Arc::new(Vec::new()),
+            generation: providers::GenerationParams::default(),
             auto_save_memory: false,
             max_tool_iterations: 12,
+            no_progress_window: 3,
+            tool_arg_validation: crate::config::ToolArgValidation::default(),
+            tool_call_formats: Arc::new(Vec::new()),
+            tool_result_role: crate::config::ToolResultRole::User,
+            provider_tool_result_roles: Arc::new(HashMap::new()),
             min_relevance_score: 0.0,
+            memory_recall_limit: 5,
             conversation_histories: Arc::new(Mutex::new(HashMap::new())),
             provider_cache: Arc::new(Mutex::new(HashMap::new())),
             route_overrides: Arc::new(Mutex::new(HashMap::new())),
@@ -2819,6 +3501,14 @@ mod tests {
             provider_runtime_options: providers::ProviderRuntimeOptions::default(),
             workspace_dir: Arc::new(std::env::temp_dir()),
             message_timeout_secs: CHANNEL_MESSAGE_TIMEOUT_SECS,
+            max_reply_tokens: Arc::new(HashMap::new()),
+            welcome_messages: Arc::new(HashMap::new()),
+            welcomed_senders: Arc::new(Mutex::new(WelcomedSenders::in_memory(
+                MAX_WELCOMED_SENDERS,
+            ))),
+            moderation: None,
+            audit_log: None,
+            secret_refs: Arc::new(HashMap::new()),
         });
 
         process_channel_message(
@@ -2843,7 +3533,7 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn process_channel_message_reports_configured_max_tool_iterations_limit() {
+    async fn process_channel_message_replies_and_saves_history_at_max_tool_iterations() {
         let channel_impl = Arc::new(RecordingChannel::default());
         let channel: Arc<dyn Channel> = channel_impl.clone();
 
@@ -2862,9 +3552,17 @@ mod tests {
             system_prompt: Arc::new("test-system-prompt".to_string()),
             model: Arc::new("test-model".to_string()),
             temperature: 0.0,
+            model_routes: Arc::new(Vec::new()),
+            generation: providers::GenerationParams::default(),
             auto_save_memory: false,
             max_tool_iterations: 3,
+            no_progress_window: 3,
+            tool_arg_validation: crate::config::ToolArgValidation::default(),
+            tool_call_formats: Arc::new(Vec::new()),
+            tool_result_role: crate::config::ToolResultRole::User,
+            provider_tool_result_roles: Arc::new(HashMap::new()),
             min_relevance_score: 0.0,
+            memory_recall_limit: 5,
             conversation_histories: Arc::new(Mutex::new(HashMap::new())),
             provider_cache: Arc::new(Mutex::new(HashMap::new())),
             route_overrides: Arc::new(Mutex::new(HashMap::new())),
@@ -2874,10 +3572,18 @@ mod tests {
             provider_runtime_options: providers::ProviderRuntimeOptions::default(),
             workspace_dir: Arc::new(std::env::temp_dir()),
             message_timeout_secs: CHANNEL_MESSAGE_TIMEOUT_SECS,
+            max_reply_tokens: Arc::new(HashMap::new()),
+            welcome_messages: Arc::new(HashMap::new()),
+            welcomed_senders: Arc::new(Mutex::new(WelcomedSenders::in_memory(
+                MAX_WELCOMED_SENDERS,
+            ))),
+            moderation: None,
+            audit_log: None,
+            secret_refs: Arc::new(HashMap::new()),
         });
 
         process_channel_message(
-            runtime_ctx,
+            runtime_ctx.clone(),
             traits::ChannelMessage {
                 id: "msg-iter-fail".to_string(),
                 sender: "bob".to_string(),
@@ -2893,7 +3599,20 @@ mod tests {
         let sent_messages = channel_impl.sent_messages.lock().await;
         assert_eq!(sent_messages.len(), 1);
         assert!(sent_messages[0].starts_with("chat-iter-fail:"));
-        assert!(sent_messages[0].contains("⚠️ Error: Agent exceeded maximum tool iterations (3)"));
+        assert!(sent_messages[0].contains("Stopped after 3 tool iterations"));
+        assert!(!sent_messages[0].contains("⚠️ Error:"));
+
+        let histories = runtime_ctx
+            .conversation_histories
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        let turns = histories
+            .get("test-channel_bob")
+            .expect("truncated turn should be saved");
+        assert_eq!(turns.len(), 2);
+        assert_eq!(turns[0].role, "user");
+        assert_eq!(turns[1].role, "assistant");
+        assert!(turns[1].content.contains("Stopped after 3 tool iterations"));
     }
 
     struct NoopMemory;
@@ -2968,9 +3687,17 @@ mod tests {
             system_prompt: Arc::new("test-system-prompt".to_string()),
             model: Arc::new("test-model".to_string()),
             temperature: 0.0,
+            model_routes: Arc::new(Vec::new()),
+            generation: providers::GenerationParams::default(),
             auto_save_memory: false,
             max_tool_iterations: 10,
+            no_progress_window: 3,
+            tool_arg_validation: crate::config::ToolArgValidation::default(),
+            tool_call_formats: Arc::new(Vec::new()),
+            tool_result_role: crate::config::ToolResultRole::User,
+            provider_tool_result_roles: Arc::new(HashMap::new()),
             min_relevance_score: 0.0,
+            memory_recall_limit: 5,
             conversation_histories: Arc::new(Mutex::new(HashMap::new())),
             provider_cache: Arc::new(Mutex::new(HashMap::new())),
             route_overrides: Arc::new(Mutex::new(HashMap::new())),
@@ -2980,6 +3707,14 @@ mod tests {
             provider_runtime_options: providers::ProviderRuntimeOptions::default(),
             workspace_dir: Arc::new(std::env::temp_dir()),
             message_timeout_secs: CHANNEL_MESSAGE_TIMEOUT_SECS,
+            max_reply_tokens: Arc::new(HashMap::new()),
+            welcome_messages: Arc::new(HashMap::new()),
+            welcomed_senders: Arc::new(Mutex::new(WelcomedSenders::in_memory(
+                MAX_WELCOMED_SENDERS,
+            ))),
+            moderation: None,
+            audit_log: None,
+            secret_refs: Arc::new(HashMap::new()),
         });
 
         let (tx, rx) = tokio::sync::mpsc::channel::<traits::ChannelMessage>(4);
@@ -3041,9 +3776,17 @@ mod tests {
             system_prompt: Arc::new("test-system-prompt".to_string()),
             model: Arc::new("test-model".to_string()),
             temperature: 0.0,
+            model_routes: Arc::new(Vec::new()),
+            generation: providers::GenerationParams::default(),
             auto_save_memory: false,
             max_tool_iterations: 10,
+            no_progress_window: 3,
+            tool_arg_validation: crate::config::ToolArgValidation::default(),
+            tool_call_formats: Arc::new(Vec::new()),
+            tool_result_role: crate::config::ToolResultRole::User,
+            provider_tool_result_roles: Arc::new(HashMap::new()),
             min_relevance_score: 0.0,
+            memory_recall_limit: 5,
             conversation_histories: Arc::new(Mutex::new(HashMap::new())),
             provider_cache: Arc::new(Mutex::new(HashMap::new())),
             route_overrides: Arc::new(Mutex::new(HashMap::new())),
@@ -3053,6 +3796,14 @@ mod tests {
             provider_runtime_options: providers::ProviderRuntimeOptions::default(),
             workspace_dir: Arc::new(std::env::temp_dir()),
             message_timeout_secs: CHANNEL_MESSAGE_TIMEOUT_SECS,
+            max_reply_tokens: Arc::new(HashMap::new()),
+            welcome_messages: Arc::new(HashMap::new()),
+            welcomed_senders: Arc::new(Mutex::new(WelcomedSenders::in_memory(
+                MAX_WELCOMED_SENDERS,
+            ))),
+            moderation: None,
+            audit_log: None,
+            secret_refs: Arc::new(HashMap::new()),
         });
 
         process_channel_message(
@@ -3075,6 +3826,82 @@ mod tests {
         assert_eq!(stops, 1, "stop_typing should be called once");
     }
 
+    #[test]
+    fn system_prompt_file_overrides_workspace_prompt() {
+        let ws = make_workspace();
+        std::fs::write(ws.path().join("persona.md"), "You are Ferris.\n").unwrap();
+        let mut config = Config {
+            workspace_dir: ws.path().to_path_buf(),
+            ..Config::default()
+        };
+        assert!(load_system_prompt_file(&config).unwrap().is_none());
+
+        config.agent.system_prompt_file = Some("persona.md".into());
+        assert_eq!(
+            load_system_prompt_file(&config).unwrap().as_deref(),
+            Some("You are Ferris.\n\n")
+        );
+
+        config.agent.system_prompt_file = Some("missing.md".into());
+        assert!(load_system_prompt_file(&config).is_err());
+
+        std::fs::write(ws.path().join("blank.md"), " \n").unwrap();
+        config.agent.system_prompt_file = Some("blank.md".into());
+        let err = load_system_prompt_file(&config).unwrap_err();
+        assert!(err.to_string().contains("is empty"));
+    }
+
+    #[test]
+    fn context_files_are_appended_verbatim_within_budget() {
+        let ws = make_workspace();
+        std::fs::write(ws.path().join("conventions.md"), "Use snake_case.\n").unwrap();
+        std::fs::write(ws.path().join("glossary.md"), "y".repeat(7000)).unwrap();
+        let mut config = Config {
+            workspace_dir: ws.path().to_path_buf(),
+            ..Config::default()
+        };
+        assert!(load_context_files(&config).is_empty());
+
+        config.agent.context_files = vec![
+            "conventions.md".into(),
+            "missing.md".into(),
+            "glossary.md".into(),
+        ];
+        let section = load_context_files(&config);
+        assert!(
+            section.starts_with("## Context Files\n\n### conventions.md\n\nUse snake_case.\n\n")
+        );
+        assert!(!section.contains("missing.md"));
+        assert!(section.contains(&"y".repeat(7000)));
+
+        config.agent.compact_context = true;
+        let section = load_context_files(&config);
+        assert!(section.contains("Use snake_case."));
+        assert!(!section.contains(&"y".repeat(COMPACT_BOOTSTRAP_MAX_CHARS)));
+        assert!(section.contains("[... truncated: context file budget reached]"));
+    }
+
+    #[test]
+    fn system_prompt_file_truncated_under_compact_context() {
+        let ws = make_workspace();
+        std::fs::write(ws.path().join("long.md"), "x".repeat(7000)).unwrap();
+        let mut config = Config {
+            workspace_dir: ws.path().to_path_buf(),
+            ..Config::default()
+        };
+        config.agent.system_prompt_file = Some("long.md".into());
+        assert!(!load_system_prompt_file(&config)
+            .unwrap()
+            .unwrap()
+            .contains("truncated"));
+
+        config.agent.compact_context = true;
+        let prompt = load_system_prompt_file(&config).unwrap().unwrap();
+        assert!(prompt.starts_with(&"x".repeat(COMPACT_BOOTSTRAP_MAX_CHARS)));
+        assert!(!prompt.contains(&"x".repeat(COMPACT_BOOTSTRAP_MAX_CHARS + 1)));
+        assert!(prompt.contains("[... truncated at 6000 chars]"));
+    }
+
     #[test]
     fn prompt_contains_all_sections() {
         let ws = make_workspace();
@@ -3289,6 +4116,20 @@ mod tests {
         assert!(truncated.is_char_boundary(truncated.len()));
     }
 
+    #[test]
+    fn cap_reply_length_cuts_at_word_boundary_and_marks_truncation() {
+        let short = "Fine as is.".to_string();
+        assert_eq!(cap_reply_length(short.clone(), 10), short);
+
+        let long = "word ".repeat(40);
+        let capped = cap_reply_length(long, 5);
+        assert_eq!(capped, "word word word word…(truncated)");
+
+        let emoji = "🌍".repeat(50);
+        let capped = cap_reply_length(emoji, 2);
+        assert_eq!(capped, format!("{}…(truncated)", "🌍".repeat(8)));
+    }
+
     #[test]
     fn prompt_contains_channel_capabilities() {
         let ws = make_workspace();
@@ -3413,7 +4254,7 @@ mod tests {
             .await
             .unwrap();
 
-        let context = build_memory_context(&mem, "age", 0.0).await;
+        let context = build_memory_context(&mem, "age", 5, 0.0).await;
         assert!(context.contains("[Memory context]"));
         assert!(context.contains("Age is 45"));
     }
@@ -3438,9 +4279,17 @@ mod tests {
             system_prompt: Arc::new("test-system-prompt".to_string()),
             model: Arc::new("test-model".to_string()),
             temperature: 0.0,
+            model_routes: Arc::new(Vec::new()),
+            generation: providers::GenerationParams::default(),
             auto_save_memory: false,
             max_tool_iterations: 5,
+            no_progress_window: 3,
+            tool_arg_validation: crate::config::ToolArgValidation::default(),
+            tool_call_formats: Arc::new(Vec::new()),
+            tool_result_role: crate::config::ToolResultRole::User,
+            provider_tool_result_roles: Arc::new(HashMap::new()),
             min_relevance_score: 0.0,
+            memory_recall_limit: 5,
             conversation_histories: Arc::new(Mutex::new(HashMap::new())),
             provider_cache: Arc::new(Mutex::new(HashMap::new())),
             route_overrides: Arc::new(Mutex::new(HashMap::new())),
@@ -3450,6 +4299,14 @@ mod tests {
             provider_runtime_options: providers::ProviderRuntimeOptions::default(),
             workspace_dir: Arc::new(std::env::temp_dir()),
             message_timeout_secs: CHANNEL_MESSAGE_TIMEOUT_SECS,
+            max_reply_tokens: Arc::new(HashMap::new()),
+            welcome_messages: Arc::new(HashMap::new()),
+            welcomed_senders: Arc::new(Mutex::new(WelcomedSenders::in_memory(
+                MAX_WELCOMED_SENDERS,
+            ))),
+            moderation: None,
+            audit_log: None,
+            secret_refs: Arc::new(HashMap::new()),
         });
 
         process_channel_message(
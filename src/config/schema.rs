@@ -1,4 +1,5 @@
 use crate::providers::{is_glm_alias, is_zai_alias};
+pub use crate::rag::ChunkStrategy;
 use crate::security::AutonomyLevel;
 use anyhow::{Context, Result};
 use directories::UserDirs;
@@ -12,12 +13,14 @@ use tokio::io::AsyncWriteExt;
 
 const SUPPORTED_PROXY_SERVICE_KEYS: &[&str] = &[
     "provider.anthropic",
+    "provider.azure",
     "provider.compatible",
     "provider.copilot",
     "provider.gemini",
     "provider.glm",
     "provider.ollama",
     "provider.openai",
+    "provider.openai_codex",
     "provider.openrouter",
     "channel.dingtalk",
     "channel.discord",
@@ -118,18 +121,30 @@ pub struct Config {
     #[serde(default)]
     pub secrets: SecretsConfig,
 
+    #[serde(default)]
+    pub security: SecurityConfig,
+
     #[serde(default)]
     pub browser: BrowserConfig,
 
     #[serde(default)]
     pub http_request: HttpRequestConfig,
 
+    #[serde(default)]
+    pub shell: ShellConfig,
+
+    #[serde(default)]
+    pub moderation: ModerationConfig,
+
     #[serde(default)]
     pub web_search: WebSearchConfig,
 
     #[serde(default)]
     pub proxy: ProxyConfig,
 
+    #[serde(default)]
+    pub azure_openai: AzureOpenAiConfig,
+
     #[serde(default)]
     pub identity: IdentityConfig,
 
@@ -169,6 +184,10 @@ pub struct DelegateAgentConfig {
     /// Max recursion depth for nested delegation
     #[serde(default = "default_max_depth")]
     pub max_depth: u32,
+    /// Models a single `delegate` call may switch to via its `model`
+    /// argument (empty = any model on this agent's provider)
+    #[serde(default)]
+    pub allowed_models: Vec<String>,
 }
 
 fn default_max_depth() -> u32 {
@@ -250,20 +269,171 @@ pub struct AgentConfig {
     /// When true: bootstrap_max_chars=6000, rag_chunk_limit=2. Use for 13B or smaller models.
     #[serde(default)]
     pub compact_context: bool,
+    /// File whose contents replace the workspace-scanned system prompt
+    /// (relative paths resolve against the workspace). Tool instructions are
+    /// still appended.
+    #[serde(default)]
+    pub system_prompt_file: Option<String>,
+    /// Files (relative paths resolve against the workspace) appended
+    /// verbatim to the system prompt, e.g. coding conventions or a glossary.
+    /// They share the bootstrap-file character budget.
+    #[serde(default)]
+    pub context_files: Vec<String>,
+    /// Add a listing of the workspace to the system prompt (`.gitignore`
+    /// applied, capped in size). Refresh it with `/tree` in the CLI.
+    #[serde(default)]
+    pub include_file_tree: bool,
+    /// Directory levels listed by `include_file_tree`.
+    #[serde(default = "default_agent_file_tree_depth")]
+    pub file_tree_depth: usize,
+    /// Extra `.gitignore`-style patterns left out of the file tree.
+    #[serde(default)]
+    pub file_tree_ignore: Vec<String>,
     #[serde(default = "default_agent_max_tool_iterations")]
     pub max_tool_iterations: usize,
+    /// Stop the tool loop early once the same tool call(s) fail this many
+    /// iterations in a row, and ask the model to summarize instead. `0` disables.
+    #[serde(default = "default_agent_no_progress_window")]
+    pub no_progress_window: usize,
+    /// Tool calls allowed across one interactive `zeroclaw agent` session.
+    /// Once reached, tools are withheld until `/clear`. `0` means no limit.
+    #[serde(default)]
+    pub max_tool_calls_per_session: usize,
     #[serde(default = "default_agent_max_history_messages")]
     pub max_history_messages: usize,
+    /// In interactive `zeroclaw agent` sessions, shrink tool outputs older
+    /// than this many user turns to one-line summaries. `0` keeps them verbatim.
+    #[serde(default)]
+    pub compact_tool_results_after: usize,
+    /// Wall-clock limit (seconds) for a single-message `agent -m` run,
+    /// including cron jobs. `0` means no limit.
+    #[serde(default)]
+    pub run_timeout_secs: u64,
+    /// Estimated USD spend after which `zeroclaw agent` stops calling the
+    /// provider. Needs a `[cost.prices]` entry for the model. Unset = no cap.
+    #[serde(default)]
+    pub max_cost_usd: Option<f64>,
+    /// Run `zeroclaw agent` without memory: no recall, no auto-save, no
+    /// memory tool writes and no response cache.
+    #[serde(default)]
+    pub ephemeral: bool,
     #[serde(default)]
     pub parallel_tools: bool,
     #[serde(default = "default_agent_tool_dispatcher")]
     pub tool_dispatcher: String,
+    /// Nucleus sampling cutoff sent to providers that support it.
+    #[serde(default)]
+    pub top_p: Option<f64>,
+    /// Max tokens per model response. Unset uses the provider default.
+    #[serde(default)]
+    pub max_tokens: Option<u32>,
+    /// Stop sequences sent to providers that support them.
+    #[serde(default)]
+    pub stop: Vec<String>,
+    /// Upper bound (seconds) for a single `wait` tool call.
+    #[serde(default = "default_agent_max_wait_secs")]
+    pub max_wait_secs: u64,
+    /// Message role used for prompt-mode (XML) tool results.
+    #[serde(default)]
+    pub tool_result_role: ToolResultRole,
+    /// Per-provider overrides for `tool_result_role`, keyed by provider name.
+    #[serde(default)]
+    pub provider_tool_result_roles: HashMap<String, ToolResultRole>,
+    /// What to do when tool-call arguments don't match the tool's schema.
+    #[serde(default)]
+    pub tool_arg_validation: ToolArgValidation,
+    /// Text tool-call syntaxes to look for, tried in order; the first that
+    /// yields calls wins. Native provider tool calls are always used first.
+    #[serde(default = "ToolCallFormat::default_order")]
+    pub tool_call_formats: Vec<ToolCallFormat>,
+    /// Profile from `profiles` used by `zeroclaw agent` unless `--profile`
+    /// picks another.
+    #[serde(default)]
+    pub profile: Option<String>,
+    /// Named prompt profiles (e.g. `coding`, `brainstorm`) selectable with
+    /// `zeroclaw agent --profile <name>` or `/profile <name>`.
+    #[serde(default)]
+    pub profiles: HashMap<String, AgentProfileConfig>,
+}
+
+/// A named agent persona: its own system prompt, temperature and tool set.
+/// Unset fields fall back to the regular `[agent]` settings.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct AgentProfileConfig {
+    /// Replaces `agent.system_prompt_file` while the profile is active.
+    #[serde(default)]
+    pub system_prompt_file: Option<String>,
+    /// Sampling temperature; `--temperature` still takes precedence.
+    #[serde(default)]
+    pub temperature: Option<f64>,
+    /// Tool names to enable. Unset enables every configured tool.
+    #[serde(default)]
+    pub tools: Option<Vec<String>>,
+}
+
+/// How prompt-mode tool results are written back into the conversation.
+/// Native tool calling always uses `role: "tool"` messages with call IDs.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ToolResultRole {
+    /// One user message prefixed with `[Tool results]` (default).
+    #[default]
+    User,
+    /// One `role: "tool"` message per call, carrying `tool_name` and `content`.
+    /// Use with providers that accept name-attributed tool messages (e.g. Ollama).
+    Tool,
+    /// One assistant message prefixed with `[Tool results]`.
+    Assistant,
+}
+
+/// Handling of tool-call arguments that fail schema validation.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ToolArgValidation {
+    /// Don't run the tool; return the validation error to the model (default).
+    #[default]
+    Reject,
+    /// Log the validation error and run the tool anyway.
+    Warn,
+    /// Skip validation.
+    Off,
+}
+
+/// A syntax models use to call tools in plain text.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ToolCallFormat {
+    /// `<tool_call>{...}</tool_call>` and its tag aliases.
+    Xml,
+    /// A whole response that is an OpenAI-style `{"tool_calls": [...]}` body.
+    OpenaiJson,
+    /// ` ```tool_call ` / ` ```invoke ` fenced blocks.
+    MarkdownFence,
+    /// GLM line calls such as `shell/command>ls`.
+    Glm,
+    /// Plain ` ```json ` fences holding a call object. Not in the default
+    /// order, since a quoted JSON example could be taken for a call.
+    JsonFence,
+}
+
+impl ToolCallFormat {
+    pub fn default_order() -> Vec<Self> {
+        vec![Self::Xml, Self::OpenaiJson, Self::MarkdownFence, Self::Glm]
+    }
 }
 
 fn default_agent_max_tool_iterations() -> usize {
     10
 }
 
+fn default_agent_file_tree_depth() -> usize {
+    3
+}
+
+fn default_agent_no_progress_window() -> usize {
+    3
+}
+
 fn default_agent_max_history_messages() -> usize {
     50
 }
@@ -272,14 +442,81 @@ fn default_agent_tool_dispatcher() -> String {
     "auto".into()
 }
 
+fn default_agent_max_wait_secs() -> u64 {
+    60
+}
+
 impl Default for AgentConfig {
     fn default() -> Self {
         Self {
             compact_context: false,
+            system_prompt_file: None,
+            context_files: Vec::new(),
+            include_file_tree: false,
+            file_tree_depth: default_agent_file_tree_depth(),
+            file_tree_ignore: Vec::new(),
             max_tool_iterations: default_agent_max_tool_iterations(),
+            no_progress_window: default_agent_no_progress_window(),
+            max_tool_calls_per_session: 0,
             max_history_messages: default_agent_max_history_messages(),
+            compact_tool_results_after: 0,
+            run_timeout_secs: 0,
+            max_cost_usd: None,
+            ephemeral: false,
             parallel_tools: false,
             tool_dispatcher: default_agent_tool_dispatcher(),
+            top_p: None,
+            max_tokens: None,
+            stop: Vec::new(),
+            max_wait_secs: default_agent_max_wait_secs(),
+            tool_result_role: ToolResultRole::User,
+            provider_tool_result_roles: HashMap::new(),
+            tool_arg_validation: ToolArgValidation::Reject,
+            tool_call_formats: ToolCallFormat::default_order(),
+            profile: None,
+            profiles: HashMap::new(),
+        }
+    }
+}
+
+impl AgentConfig {
+    /// The profile selected by `agent.profile`, if any. Naming a profile
+    /// that is not defined is an error.
+    pub fn active_profile(&self) -> anyhow::Result<Option<&AgentProfileConfig>> {
+        let Some(name) = self.profile.as_deref() else {
+            return Ok(None);
+        };
+        match self.profiles.get(name) {
+            Some(profile) => Ok(Some(profile)),
+            None => {
+                let mut known: Vec<&str> = self.profiles.keys().map(String::as_str).collect();
+                known.sort_unstable();
+                let known = if known.is_empty() {
+                    "none defined".to_string()
+                } else {
+                    known.join(", ")
+                };
+                anyhow::bail!("Unknown agent profile '{name}' (available: {known})")
+            }
+        }
+    }
+
+    /// Tool result role for `provider`, honoring per-provider overrides.
+    pub fn tool_result_role_for(&self, provider: &str) -> ToolResultRole {
+        self.provider_tool_result_roles
+            .get(provider)
+            .copied()
+            .unwrap_or(self.tool_result_role)
+    }
+
+    /// Generation controls passed to the provider on every agent turn.
+    pub fn generation_params(&self) -> crate::providers::GenerationParams {
+        crate::providers::GenerationParams {
+            // Resolved per model and passed as the `temperature` argument.
+            temperature: None,
+            top_p: self.top_p,
+            max_tokens: self.max_tokens,
+            stop: self.stop.clone(),
         }
     }
 }
@@ -468,6 +705,13 @@ pub struct PeripheralsConfig {
     /// Place .md/.txt files named by board (e.g. nucleo-f401re.md, rpi-gpio.md).
     #[serde(default)]
     pub datasheet_dir: Option<String>,
+    /// Score bonus for chunks from the most recently modified datasheet,
+    /// scaled down to 0 for the oldest (default: 0, disabled)
+    #[serde(default)]
+    pub datasheet_recency_weight: f64,
+    /// How datasheets are split into retrieval chunks (default: fixed)
+    #[serde(default)]
+    pub datasheet_chunk_strategy: ChunkStrategy,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -636,11 +880,21 @@ pub struct SecretsConfig {
     /// Enable encryption for API keys and tokens in config.toml
     #[serde(default = "default_true")]
     pub encrypt: bool,
+    /// Named credentials tools can reference as `{{secret:<name>}}` in their
+    /// arguments. A value of `env:VAR` reads the environment variable at call
+    /// time, `keyring:<service>/<entry>` reads the OS keyring (needs the
+    /// `keyring` build feature); any other value is the secret itself (stored
+    /// encrypted).
+    #[serde(default)]
+    pub refs: HashMap<String, String>,
 }
 
 impl Default for SecretsConfig {
     fn default() -> Self {
-        Self { encrypt: true }
+        Self {
+            encrypt: true,
+            refs: HashMap::new(),
+        }
     }
 }
 
@@ -719,12 +973,23 @@ pub struct BrowserConfig {
     /// Computer-use sidecar configuration
     #[serde(default)]
     pub computer_use: BrowserComputerUseConfig,
+    /// Enable `browser_fetch` (returns readable page text for allowlisted
+    /// URLs). Independent of `enabled` so `browser_open` stays no-scrape.
+    #[serde(default)]
+    pub fetch_enabled: bool,
+    /// Maximum characters of page text returned by `browser_fetch`
+    #[serde(default = "default_browser_fetch_max_chars")]
+    pub fetch_max_chars: usize,
 }
 
 fn default_browser_backend() -> String {
     "agent_browser".into()
 }
 
+fn default_browser_fetch_max_chars() -> usize {
+    20_000
+}
+
 fn default_browser_webdriver_url() -> String {
     "http://127.0.0.1:9515".into()
 }
@@ -740,6 +1005,8 @@ impl Default for BrowserConfig {
             native_webdriver_url: default_browser_webdriver_url(),
             native_chrome_path: None,
             computer_use: BrowserComputerUseConfig::default(),
+            fetch_enabled: false,
+            fetch_max_chars: default_browser_fetch_max_chars(),
         }
     }
 }
@@ -760,6 +1027,17 @@ pub struct HttpRequestConfig {
     /// Request timeout in seconds (default: 30)
     #[serde(default = "default_http_timeout_secs")]
     pub timeout_secs: u64,
+    /// Allow requests to hosts that are (or resolve to) loopback, link-local
+    /// or private addresses (default: false)
+    #[serde(default)]
+    pub allow_private_ips: bool,
+    /// `User-Agent` for every request (default: `zeroclaw/<version>`)
+    #[serde(default)]
+    pub user_agent: Option<String>,
+    /// Headers sent with every request. Values may use `{{secret:name}}`;
+    /// such headers cannot be overridden by the model.
+    #[serde(default)]
+    pub default_headers: HashMap<String, String>,
 }
 
 fn default_http_max_response_size() -> usize {
@@ -770,6 +1048,100 @@ fn default_http_timeout_secs() -> u64 {
     30
 }
 
+// ── Shell tool ───────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ShellConfig {
+    /// Regexes matched against the full command line; a match refuses the
+    /// command at every autonomy level. Set to `[]` to disable.
+    #[serde(default = "default_shell_denied_patterns")]
+    pub denied_patterns: Vec<String>,
+}
+
+fn default_shell_denied_patterns() -> Vec<String> {
+    vec![
+        // rm -rf / (and friends) aimed at the filesystem root
+        r"\brm\s+(-\S+\s+)*/\*?(\s|[;&|]|$)".into(),
+        // classic fork bomb
+        r":\(\)\s*\{\s*:\s*\|\s*:\s*&\s*\}\s*;\s*:".into(),
+        r"\bmkfs(\.\w+)?\b".into(),
+        // raw writes to block devices
+        r"\bdd\b.*\bof=/dev/".into(),
+        // download piped straight into a shell
+        r"\b(curl|wget)\b[^|]*\|\s*(sudo\s+)?(ba|z|da)?sh\b".into(),
+    ]
+}
+
+impl Default for ShellConfig {
+    fn default() -> Self {
+        Self {
+            denied_patterns: default_shell_denied_patterns(),
+        }
+    }
+}
+
+impl ShellConfig {
+    /// Compile every `denied_patterns` entry so a typo fails config loading
+    /// instead of surfacing on the first shell command.
+    pub fn validate(&self) -> Result<()> {
+        for pattern in &self.denied_patterns {
+            regex::Regex::new(pattern)
+                .with_context(|| format!("Invalid [shell] denied_patterns entry '{pattern}'"))?;
+        }
+        Ok(())
+    }
+}
+
+// ── Moderation ───────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ModerationConfig {
+    /// Screen messages before and/or after the agent runs (default: false)
+    #[serde(default)]
+    pub enabled: bool,
+    /// Moderation backend: "denylist" (local regexes) or "openai"
+    /// (OpenAI moderation endpoint)
+    #[serde(default = "default_moderation_backend")]
+    pub backend: String,
+    /// Case-insensitive regexes for the "denylist" backend
+    #[serde(default)]
+    pub denied_patterns: Vec<String>,
+    /// API key for the "openai" backend (falls back to `OPENAI_API_KEY`)
+    #[serde(default)]
+    pub api_key: Option<String>,
+    /// Screen the user message before it reaches the model (default: true)
+    #[serde(default = "default_true")]
+    pub check_input: bool,
+    /// Screen the final response before it is delivered (default: false)
+    #[serde(default)]
+    pub check_output: bool,
+    /// Reply sent in place of a blocked message or response
+    #[serde(default = "default_moderation_blocked_message")]
+    pub blocked_message: String,
+}
+
+fn default_moderation_backend() -> String {
+    "denylist".into()
+}
+
+fn default_moderation_blocked_message() -> String {
+    "Sorry, I can't help with that request.".into()
+}
+
+impl Default for ModerationConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            backend: default_moderation_backend(),
+            denied_patterns: Vec::new(),
+            api_key: None,
+            check_input: true,
+            check_output: false,
+            blocked_message: default_moderation_blocked_message(),
+        }
+    }
+}
+
 // ── Web search ───────────────────────────────────────────────────
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -817,6 +1189,36 @@ impl Default for WebSearchConfig {
 
 // ── Proxy ───────────────────────────────────────────────────────
 
+// ── Azure OpenAI ────────────────────────────────────────────────
+
+/// Azure OpenAI Service settings (`[azure_openai]`), used by the `azure` provider.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AzureOpenAiConfig {
+    /// Resource endpoint, e.g. `https://my-resource.openai.azure.com`.
+    #[serde(default)]
+    pub endpoint: Option<String>,
+    /// `api-version` query parameter sent with every request.
+    #[serde(default = "default_azure_openai_api_version")]
+    pub api_version: String,
+    /// Model id → deployment name. Unmapped models use the model id as the deployment name.
+    #[serde(default)]
+    pub deployments: HashMap<String, String>,
+}
+
+fn default_azure_openai_api_version() -> String {
+    crate::providers::azure_openai::DEFAULT_AZURE_OPENAI_API_VERSION.into()
+}
+
+impl Default for AzureOpenAiConfig {
+    fn default() -> Self {
+        Self {
+            endpoint: None,
+            api_version: default_azure_openai_api_version(),
+            deployments: HashMap::new(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum ProxyScope {
@@ -948,19 +1350,38 @@ impl ProxyConfig {
 
     pub fn apply_to_reqwest_builder(
         &self,
-        mut builder: reqwest::ClientBuilder,
+        builder: reqwest::ClientBuilder,
         service_key: &str,
     ) -> reqwest::ClientBuilder {
+        self.proxies_for_service(service_key)
+            .into_iter()
+            .fold(builder, reqwest::ClientBuilder::proxy)
+    }
+
+    /// [`Self::apply_to_reqwest_builder`] for blocking clients (e.g. OAuth
+    /// token refreshes).
+    pub fn apply_to_blocking_builder(
+        &self,
+        builder: reqwest::blocking::ClientBuilder,
+        service_key: &str,
+    ) -> reqwest::blocking::ClientBuilder {
+        self.proxies_for_service(service_key)
+            .into_iter()
+            .fold(builder, reqwest::blocking::ClientBuilder::proxy)
+    }
+
+    fn proxies_for_service(&self, service_key: &str) -> Vec<reqwest::Proxy> {
         if !self.should_apply_to_service(service_key) {
-            return builder;
+            return Vec::new();
         }
 
         let no_proxy = self.no_proxy_value();
+        let mut proxies = Vec::new();
 
         if let Some(url) = normalize_proxy_url_option(self.all_proxy.as_deref()) {
             match reqwest::Proxy::all(&url) {
                 Ok(proxy) => {
-                    builder = builder.proxy(apply_no_proxy(proxy, no_proxy.clone()));
+                    proxies.push(apply_no_proxy(proxy, no_proxy.clone()));
                 }
                 Err(error) => {
                     tracing::warn!(
@@ -975,7 +1396,7 @@ impl ProxyConfig {
         if let Some(url) = normalize_proxy_url_option(self.http_proxy.as_deref()) {
             match reqwest::Proxy::http(&url) {
                 Ok(proxy) => {
-                    builder = builder.proxy(apply_no_proxy(proxy, no_proxy.clone()));
+                    proxies.push(apply_no_proxy(proxy, no_proxy.clone()));
                 }
                 Err(error) => {
                     tracing::warn!(
@@ -990,7 +1411,7 @@ impl ProxyConfig {
         if let Some(url) = normalize_proxy_url_option(self.https_proxy.as_deref()) {
             match reqwest::Proxy::https(&url) {
                 Ok(proxy) => {
-                    builder = builder.proxy(apply_no_proxy(proxy, no_proxy));
+                    proxies.push(apply_no_proxy(proxy, no_proxy));
                 }
                 Err(error) => {
                     tracing::warn!(
@@ -1002,7 +1423,7 @@ impl ProxyConfig {
             }
         }
 
-        builder
+        proxies
     }
 
     pub fn apply_to_process_env(&self) {
@@ -1217,6 +1638,13 @@ pub fn apply_runtime_proxy_to_builder(
     runtime_proxy_config().apply_to_reqwest_builder(builder, service_key)
 }
 
+pub fn apply_runtime_proxy_to_blocking_builder(
+    builder: reqwest::blocking::ClientBuilder,
+    service_key: &str,
+) -> reqwest::blocking::ClientBuilder {
+    runtime_proxy_config().apply_to_blocking_builder(builder, service_key)
+}
+
 pub fn build_runtime_proxy_client(service_key: &str) -> reqwest::Client {
     let cache_key = runtime_proxy_cache_key(service_key, None, None);
     if let Some(client) = runtime_proxy_cached_client(&cache_key) {
@@ -1376,8 +1804,11 @@ pub struct MemoryConfig {
     /// Minimum hybrid score (0.0–1.0) for a memory to be included in context.
     /// Memories scoring below this threshold are dropped to prevent irrelevant
     /// context from bleeding into conversations. Default: 0.4
-    #[serde(default = "default_min_relevance_score")]
+    #[serde(default = "default_min_relevance_score", alias = "recall_min_score")]
     pub min_relevance_score: f64,
+    /// Max memories recalled per turn for the `[Memory context]` preamble. Default: 5
+    #[serde(default = "default_recall_limit")]
+    pub recall_limit: usize,
     /// Max embedding cache entries before LRU eviction
     #[serde(default = "default_cache_size")]
     pub embedding_cache_size: usize,
@@ -1444,6 +1875,9 @@ fn default_keyword_weight() -> f64 {
 fn default_min_relevance_score() -> f64 {
     0.4
 }
+fn default_recall_limit() -> usize {
+    5
+}
 fn default_cache_size() -> usize {
     10_000
 }
@@ -1472,6 +1906,7 @@ impl Default for MemoryConfig {
             vector_weight: default_vector_weight(),
             keyword_weight: default_keyword_weight(),
             min_relevance_score: default_min_relevance_score(),
+            recall_limit: default_recall_limit(),
             embedding_cache_size: default_cache_size(),
             chunk_max_tokens: default_chunk_size(),
             response_cache_enabled: false,
@@ -1537,6 +1972,10 @@ pub struct AutonomyConfig {
     /// Tools that always require interactive approval, even after "Always".
     #[serde(default = "default_always_ask")]
     pub always_ask: Vec<String>,
+
+    /// Allow the `clipboard` tool to read/write the system clipboard.
+    #[serde(default)]
+    pub allow_clipboard: bool,
 }
 
 fn default_auto_approve() -> Vec<String> {
@@ -1544,7 +1983,7 @@ fn default_auto_approve() -> Vec<String> {
 }
 
 fn default_always_ask() -> Vec<String> {
-    vec![]
+    vec!["set_preference".into()]
 }
 
 impl Default for AutonomyConfig {
@@ -1592,6 +2031,7 @@ impl Default for AutonomyConfig {
             block_high_risk_commands: true,
             auto_approve: default_auto_approve(),
             always_ask: default_always_ask(),
+            allow_clipboard: false,
         }
     }
 }
@@ -1693,6 +2133,17 @@ pub struct ReliabilityConfig {
     /// Base backoff (ms) for provider retry delay.
     #[serde(default = "default_provider_backoff_ms")]
     pub provider_backoff_ms: u64,
+    /// Times the agent loop re-sends an in-flight model call after a dropped
+    /// connection, on top of `provider_retries`. Tool calls are never re-run.
+    #[serde(default = "default_turn_retries")]
+    pub turn_retries: u32,
+    /// Consecutive failed calls that open a provider's circuit, making calls
+    /// to it fail fast until the cooldown ends. `0` disables the breaker.
+    #[serde(default = "default_circuit_breaker_threshold")]
+    pub circuit_breaker_threshold: u32,
+    /// Seconds an open circuit waits before letting one probe call through.
+    #[serde(default = "default_circuit_breaker_cooldown_secs")]
+    pub circuit_breaker_cooldown_secs: u64,
     /// Fallback provider chain (e.g. `["anthropic", "openai"]`).
     #[serde(default)]
     pub fallback_providers: Vec<String>,
@@ -1716,6 +2167,27 @@ pub struct ReliabilityConfig {
     /// Max retries for cron job execution attempts.
     #[serde(default = "default_scheduler_retries")]
     pub scheduler_retries: u32,
+    /// Append every provider request and response to a JSONL file for debugging.
+    /// Logs full conversation content (credentials are redacted). Off by default.
+    #[serde(default)]
+    pub log_requests: bool,
+    /// Request log path when `log_requests` is enabled.
+    /// Default: `~/.zeroclaw/logs/provider-requests.jsonl`.
+    #[serde(default)]
+    pub request_log_path: Option<String>,
+    /// Providers whose requests get consecutive same-role messages merged
+    /// before sending (`"*"` for all), for APIs that require alternating roles.
+    #[serde(default)]
+    pub merge_consecutive_roles: Vec<String>,
+}
+
+impl ReliabilityConfig {
+    /// Whether `merge_consecutive_roles` applies to `provider`.
+    pub fn merges_consecutive_roles_for(&self, provider: &str) -> bool {
+        self.merge_consecutive_roles
+            .iter()
+            .any(|name| name == "*" || name == provider)
+    }
 }
 
 fn default_provider_retries() -> u32 {
@@ -1726,6 +2198,18 @@ fn default_provider_backoff_ms() -> u64 {
     500
 }
 
+fn default_turn_retries() -> u32 {
+    2
+}
+
+fn default_circuit_breaker_threshold() -> u32 {
+    5
+}
+
+fn default_circuit_breaker_cooldown_secs() -> u64 {
+    30
+}
+
 fn default_channel_backoff_secs() -> u64 {
     2
 }
@@ -1747,6 +2231,9 @@ impl Default for ReliabilityConfig {
         Self {
             provider_retries: default_provider_retries(),
             provider_backoff_ms: default_provider_backoff_ms(),
+            turn_retries: default_turn_retries(),
+            circuit_breaker_threshold: default_circuit_breaker_threshold(),
+            circuit_breaker_cooldown_secs: default_circuit_breaker_cooldown_secs(),
             fallback_providers: Vec::new(),
             api_keys: Vec::new(),
             model_fallbacks: std::collections::HashMap::new(),
@@ -1754,6 +2241,9 @@ impl Default for ReliabilityConfig {
             channel_max_backoff_secs: default_channel_backoff_max_secs(),
             scheduler_poll_secs: default_scheduler_poll_secs(),
             scheduler_retries: default_scheduler_retries(),
+            log_requests: false,
+            request_log_path: None,
+            merge_consecutive_roles: Vec::new(),
         }
     }
 }
@@ -1823,6 +2313,17 @@ pub struct ModelRouteConfig {
     /// Optional API key override for this route's provider
     #[serde(default)]
     pub api_key: Option<String>,
+    /// Default temperature when this route's model (or `hint:<hint>`) is the
+    /// session model and no explicit temperature was given
+    #[serde(default)]
+    pub temperature: Option<f64>,
+}
+
+impl ModelRouteConfig {
+    /// Whether `model` names this route, either directly or as `hint:<hint>`.
+    pub fn matches_model(&self, model: &str) -> bool {
+        model == self.model || model.strip_prefix("hint:") == Some(self.hint.as_str())
+    }
 }
 
 // ── Embedding routing ───────────────────────────────────────────
@@ -2019,6 +2520,10 @@ pub struct ChannelsConfig {
     /// Default: 300s for on-device LLMs (Ollama) which are slower than cloud APIs.
     #[serde(default = "default_channel_message_timeout_secs")]
     pub message_timeout_secs: u64,
+    /// Per-channel reply length cap in tokens, keyed by channel name
+    /// (e.g. `telegram = 300`). Unlisted channels are uncapped.
+    #[serde(default)]
+    pub max_reply_tokens: HashMap<String, u32>,
 }
 
 fn default_channel_message_timeout_secs() -> u64 {
@@ -2045,6 +2550,7 @@ impl Default for ChannelsConfig {
             dingtalk: None,
             qq: None,
             message_timeout_secs: default_channel_message_timeout_secs(),
+            max_reply_tokens: HashMap::new(),
         }
     }
 }
@@ -2078,6 +2584,10 @@ pub struct TelegramConfig {
     /// Direct messages are always processed.
     #[serde(default)]
     pub mention_only: bool,
+    /// Sent on `/start` and on a sender's first message, without running the
+    /// agent. `{tools}` expands to the enabled tool names.
+    #[serde(default)]
+    pub welcome_message: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -2094,6 +2604,10 @@ pub struct DiscordConfig {
     /// Other messages in the guild are silently ignored.
     #[serde(default)]
     pub mention_only: bool,
+    /// Sent on `/start` and on a sender's first message, without running the
+    /// agent. `{tools}` expands to the enabled tool names.
+    #[serde(default)]
+    pub welcome_message: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -2126,6 +2640,10 @@ pub struct MattermostConfig {
 pub struct WebhookConfig {
     pub port: u16,
     pub secret: Option<String>,
+    /// HMAC-SHA256 key for `X-Webhook-Signature-256` on `POST /api/webhook`.
+    /// When set, unsigned or mis-signed requests are rejected.
+    #[serde(default)]
+    pub signing_secret: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -2524,10 +3042,14 @@ impl Default for Config {
             gateway: GatewayConfig::default(),
             composio: ComposioConfig::default(),
             secrets: SecretsConfig::default(),
+            security: SecurityConfig::default(),
             browser: BrowserConfig::default(),
             http_request: HttpRequestConfig::default(),
+            shell: ShellConfig::default(),
+            moderation: ModerationConfig::default(),
             web_search: WebSearchConfig::default(),
             proxy: ProxyConfig::default(),
+            azure_openai: AzureOpenAiConfig::default(),
             identity: IdentityConfig::default(),
             cost: CostConfig::default(),
             peripherals: PeripheralsConfig::default(),
@@ -2783,6 +3305,36 @@ fn encrypt_optional_secret(
 }
 
 impl Config {
+    /// Make sure `workspace_dir` exists so skills, memory and datasheet RAG
+    /// all start from a known-good directory. Called once at load time, after
+    /// env overrides have settled the final path.
+    pub fn ensure_workspace_dir(&self) -> Result<()> {
+        let dir = &self.workspace_dir;
+        if dir.is_dir() {
+            return Ok(());
+        }
+        if dir.exists() {
+            anyhow::bail!(
+                "Workspace path {} exists but is not a directory",
+                dir.display()
+            );
+        }
+        std::fs::create_dir_all(dir)
+            .with_context(|| format!("Failed to create workspace directory {}", dir.display()))?;
+        tracing::info!(path = %dir.display(), "Created workspace directory");
+        Ok(())
+    }
+
+    /// Default temperature for `model`: the first matching `[[model_routes]]`
+    /// entry with a `temperature`, otherwise `default_temperature`.
+    pub fn default_temperature_for(&self, model: &str) -> f64 {
+        self.model_routes
+            .iter()
+            .filter(|route| route.matches_model(model))
+            .find_map(|route| route.temperature)
+            .unwrap_or(self.default_temperature)
+    }
+
     pub async fn load_or_init() -> Result<Self> {
         let (default_zeroclaw_dir, default_workspace_dir) = default_config_and_workspace_dirs()?;
 
@@ -2794,9 +3346,6 @@ impl Config {
         fs::create_dir_all(&zeroclaw_dir)
             .await
             .context("Failed to create config directory")?;
-        fs::create_dir_all(&workspace_dir)
-            .await
-            .context("Failed to create workspace directory")?;
 
         if config_path.exists() {
             // Warn if config file is world-readable (may contain API keys)
@@ -2821,6 +3370,7 @@ impl Config {
                 .context("Failed to read config file")?;
             let mut config: Config =
                 toml::from_str(&contents).context("Failed to parse config file")?;
+            config.shell.validate()?;
             // Set computed paths that are skipped during serialization
             config.config_path = config_path.clone();
             config.workspace_dir = workspace_dir;
@@ -2843,6 +3393,11 @@ impl Config {
                 &mut config.web_search.brave_api_key,
                 "config.web_search.brave_api_key",
             )?;
+            decrypt_optional_secret(
+                &store,
+                &mut config.moderation.api_key,
+                "config.moderation.api_key",
+            )?;
 
             decrypt_optional_secret(
                 &store,
@@ -2853,7 +3408,13 @@ impl Config {
             for agent in config.agents.values_mut() {
                 decrypt_optional_secret(&store, &mut agent.api_key, "config.agents.*.api_key")?;
             }
+            for value in config.secrets.refs.values_mut() {
+                let mut slot = Some(std::mem::take(value));
+                decrypt_optional_secret(&store, &mut slot, "config.secrets.refs.*")?;
+                *value = slot.unwrap_or_default();
+            }
             config.apply_env_overrides();
+            config.ensure_workspace_dir()?;
             tracing::info!(
                 path = %config.config_path.display(),
                 workspace = %config.workspace_dir.display(),
@@ -2876,6 +3437,7 @@ impl Config {
             }
 
             config.apply_env_overrides();
+            config.ensure_workspace_dir()?;
             tracing::info!(
                 path = %config.config_path.display(),
                 workspace = %config.workspace_dir.display(),
@@ -3120,6 +3682,18 @@ impl Config {
         }
 
         set_runtime_proxy_config(self.proxy.clone());
+
+        // Azure OpenAI endpoint and API version.
+        if let Ok(endpoint) = std::env::var("AZURE_OPENAI_ENDPOINT") {
+            if !endpoint.trim().is_empty() {
+                self.azure_openai.endpoint = Some(endpoint.trim().to_string());
+            }
+        }
+        if let Ok(api_version) = std::env::var("AZURE_OPENAI_API_VERSION") {
+            if !api_version.trim().is_empty() {
+                self.azure_openai.api_version = api_version.trim().to_string();
+            }
+        }
     }
 
     pub async fn save(&self) -> Result<()> {
@@ -3149,6 +3723,11 @@ impl Config {
             &mut config_to_save.web_search.brave_api_key,
             "config.web_search.brave_api_key",
         )?;
+        encrypt_optional_secret(
+            &store,
+            &mut config_to_save.moderation.api_key,
+            "config.moderation.api_key",
+        )?;
 
         encrypt_optional_secret(
             &store,
@@ -3160,6 +3739,16 @@ impl Config {
             encrypt_optional_secret(&store, &mut agent.api_key, "config.agents.*.api_key")?;
         }
 
+        // `env:` and `keyring:` refs are pointers, not secrets; only inline
+        // values are encrypted.
+        for value in config_to_save.secrets.refs.values_mut() {
+            if !crate::security::secret_refs::is_secret_pointer(value) {
+                let mut slot = Some(std::mem::take(value));
+                encrypt_optional_secret(&store, &mut slot, "config.secrets.refs.*")?;
+                *value = slot.unwrap_or_default();
+            }
+        }
+
         let toml_str =
             toml::to_string_pretty(&config_to_save).context("Failed to serialize config")?;
 
@@ -3389,6 +3978,14 @@ default_temperature = 0.7
         assert!(m.sqlite_open_timeout_secs.is_none());
     }
 
+    #[test]
+    async fn memory_config_recall_limit_and_min_score_alias() {
+        assert_eq!(MemoryConfig::default().recall_limit, 5);
+        let m: MemoryConfig = toml::from_str("recall_limit = 8\nrecall_min_score = 0.6").unwrap();
+        assert_eq!(m.recall_limit, 8);
+        assert!((m.min_relevance_score - 0.6).abs() < f64::EPSILON);
+    }
+
     #[test]
     async fn storage_provider_config_defaults() {
         let storage = StorageConfig::default();
@@ -3434,6 +4031,7 @@ default_temperature = 0.7
                 block_high_risk_commands: true,
                 auto_approve: vec!["file_read".into()],
                 always_ask: vec![],
+                allow_clipboard: false,
             },
             runtime: RuntimeConfig {
                 kind: "docker".into(),
@@ -3457,6 +4055,7 @@ default_temperature = 0.7
                     stream_mode: StreamMode::default(),
                     draft_update_interval_ms: default_draft_update_interval_ms(),
                     mention_only: false,
+                    welcome_message: None,
                 }),
                 discord: None,
                 slack: None,
@@ -3473,6 +4072,7 @@ default_temperature = 0.7
                 dingtalk: None,
                 qq: None,
                 message_timeout_secs: 300,
+                max_reply_tokens: HashMap::new(),
             },
             memory: MemoryConfig::default(),
             storage: StorageConfig::default(),
@@ -3480,10 +4080,14 @@ default_temperature = 0.7
             gateway: GatewayConfig::default(),
             composio: ComposioConfig::default(),
             secrets: SecretsConfig::default(),
+            security: SecurityConfig::default(),
             browser: BrowserConfig::default(),
             http_request: HttpRequestConfig::default(),
+            shell: ShellConfig::default(),
+            moderation: ModerationConfig::default(),
             web_search: WebSearchConfig::default(),
             proxy: ProxyConfig::default(),
+            azure_openai: AzureOpenAiConfig::default(),
             agent: AgentConfig::default(),
             identity: IdentityConfig::default(),
             cost: CostConfig::default(),
@@ -3564,8 +4168,12 @@ connect_timeout_secs = 12
     async fn agent_config_defaults() {
         let cfg = AgentConfig::default();
         assert!(!cfg.compact_context);
+        assert!(cfg.system_prompt_file.is_none());
         assert_eq!(cfg.max_tool_iterations, 10);
+        assert_eq!(cfg.no_progress_window, 3);
         assert_eq!(cfg.max_history_messages, 50);
+        assert_eq!(cfg.run_timeout_secs, 0);
+        assert!(!cfg.ephemeral);
         assert!(!cfg.parallel_tools);
         assert_eq!(cfg.tool_dispatcher, "auto");
     }
@@ -3576,19 +4184,95 @@ connect_timeout_secs = 12
 default_temperature = 0.7
 [agent]
 compact_context = true
+system_prompt_file = "prompts/persona.md"
 max_tool_iterations = 20
+no_progress_window = 0
 max_history_messages = 80
+run_timeout_secs = 300
+ephemeral = true
 parallel_tools = true
 tool_dispatcher = "xml"
 "#;
         let parsed: Config = toml::from_str(raw).unwrap();
         assert!(parsed.agent.compact_context);
+        assert_eq!(
+            parsed.agent.system_prompt_file.as_deref(),
+            Some("prompts/persona.md")
+        );
         assert_eq!(parsed.agent.max_tool_iterations, 20);
+        assert_eq!(parsed.agent.no_progress_window, 0);
         assert_eq!(parsed.agent.max_history_messages, 80);
+        assert_eq!(parsed.agent.run_timeout_secs, 300);
+        assert!(parsed.agent.ephemeral);
         assert!(parsed.agent.parallel_tools);
         assert_eq!(parsed.agent.tool_dispatcher, "xml");
     }
 
+    #[test]
+    async fn agent_profiles_deserialize_and_resolve() {
+        let raw = r#"
+default_temperature = 0.7
+[agent]
+profile = "coding"
+
+[agent.profiles.coding]
+system_prompt_file = "prompts/coding.md"
+temperature = 0.1
+tools = ["shell", "file_read"]
+
+[agent.profiles.brainstorm]
+temperature = 1.1
+"#;
+        let mut parsed: Config = toml::from_str(raw).unwrap();
+        let coding = parsed.agent.active_profile().unwrap().unwrap();
+        assert_eq!(
+            coding.system_prompt_file.as_deref(),
+            Some("prompts/coding.md")
+        );
+        assert_eq!(coding.temperature, Some(0.1));
+        assert_eq!(
+            coding.tools.as_deref(),
+            Some(&["shell".to_string(), "file_read".to_string()][..])
+        );
+
+        parsed.agent.profile = Some("brainstorm".into());
+        let brainstorm = parsed.agent.active_profile().unwrap().unwrap();
+        assert!(brainstorm.tools.is_none());
+
+        parsed.agent.profile = Some("poetry".into());
+        let err = parsed.agent.active_profile().unwrap_err().to_string();
+        assert!(err.contains("Unknown agent profile 'poetry'"));
+        assert!(err.contains("brainstorm, coding"));
+
+        parsed.agent.profile = None;
+        assert!(parsed.agent.active_profile().unwrap().is_none());
+    }
+
+    #[test]
+    async fn agent_tool_result_role_resolves_provider_overrides() {
+        let raw = r#"
+default_temperature = 0.7
+[agent]
+tool_result_role = "assistant"
+
+[agent.provider_tool_result_roles]
+ollama = "tool"
+"#;
+        let parsed: Config = toml::from_str(raw).unwrap();
+        assert_eq!(
+            parsed.agent.tool_result_role_for("openrouter"),
+            ToolResultRole::Assistant
+        );
+        assert_eq!(
+            parsed.agent.tool_result_role_for("ollama"),
+            ToolResultRole::Tool
+        );
+        assert_eq!(
+            AgentConfig::default().tool_result_role_for("ollama"),
+            ToolResultRole::User
+        );
+    }
+
     #[tokio::test]
     async fn config_save_and_load_tmpdir() {
         let dir = std::env::temp_dir().join("zeroclaw_test_config");
@@ -3621,10 +4305,14 @@ tool_dispatcher = "xml"
             gateway: GatewayConfig::default(),
             composio: ComposioConfig::default(),
             secrets: SecretsConfig::default(),
+            security: SecurityConfig::default(),
             browser: BrowserConfig::default(),
             http_request: HttpRequestConfig::default(),
+            shell: ShellConfig::default(),
+            moderation: ModerationConfig::default(),
             web_search: WebSearchConfig::default(),
             proxy: ProxyConfig::default(),
+            azure_openai: AzureOpenAiConfig::default(),
             agent: AgentConfig::default(),
             identity: IdentityConfig::default(),
             cost: CostConfig::default(),
@@ -3651,6 +4339,26 @@ tool_dispatcher = "xml"
         let _ = fs::remove_dir_all(&dir).await;
     }
 
+    #[test]
+    async fn ensure_workspace_dir_creates_missing_and_rejects_files() {
+        let tmp = tempfile::tempdir().unwrap();
+        let mut config = Config {
+            workspace_dir: tmp.path().join("nested").join("workspace"),
+            ..Config::default()
+        };
+
+        config.ensure_workspace_dir().unwrap();
+        assert!(config.workspace_dir.is_dir());
+        // Idempotent once the directory exists.
+        config.ensure_workspace_dir().unwrap();
+
+        let file = tmp.path().join("not-a-dir");
+        std::fs::write(&file, "x").unwrap();
+        config.workspace_dir = file;
+        let err = config.ensure_workspace_dir().unwrap_err().to_string();
+        assert!(err.contains("not a directory"));
+    }
+
     #[tokio::test]
     async fn config_save_encrypts_nested_credentials() {
         let dir = std::env::temp_dir().join(format!(
@@ -3667,6 +4375,14 @@ tool_dispatcher = "xml"
         config.browser.computer_use.api_key = Some("browser-credential".into());
         config.web_search.brave_api_key = Some("brave-credential".into());
         config.storage.provider.config.db_url = Some("postgres://user:pw@host/db".into());
+        config
+            .secrets
+            .refs
+            .insert("inline".into(), "ref-credential".into());
+        config
+            .secrets
+            .refs
+            .insert("from_env".into(), "env:GITHUB_TOKEN".into());
 
         config.agents.insert(
             "worker".into(),
@@ -3677,6 +4393,7 @@ tool_dispatcher = "xml"
                 api_key: Some("agent-credential".into()),
                 temperature: None,
                 max_depth: 3,
+                allowed_models: Vec::new(),
             },
         );
 
@@ -3731,6 +4448,11 @@ tool_dispatcher = "xml"
             "postgres://user:pw@host/db"
         );
 
+        let inline_ref = stored.secrets.refs.get("inline").unwrap();
+        assert!(crate::security::SecretStore::is_encrypted(inline_ref));
+        assert_eq!(store.decrypt(inline_ref).unwrap(), "ref-credential");
+        assert_eq!(stored.secrets.refs["from_env"], "env:GITHUB_TOKEN");
+
         let _ = fs::remove_dir_all(&dir).await;
     }
 
@@ -3774,6 +4496,7 @@ tool_dispatcher = "xml"
             stream_mode: StreamMode::Partial,
             draft_update_interval_ms: 500,
             mention_only: false,
+            welcome_message: None,
         };
         let json = serde_json::to_string(&tc).unwrap();
         let parsed: TelegramConfig = serde_json::from_str(&json).unwrap();
@@ -3799,6 +4522,7 @@ tool_dispatcher = "xml"
             allowed_users: vec![],
             listen_to_bots: false,
             mention_only: false,
+            welcome_message: None,
         };
         let json = serde_json::to_string(&dc).unwrap();
         let parsed: DiscordConfig = serde_json::from_str(&json).unwrap();
@@ -3814,6 +4538,7 @@ tool_dispatcher = "xml"
             allowed_users: vec![],
             listen_to_bots: false,
             mention_only: false,
+            welcome_message: None,
         };
         let json = serde_json::to_string(&dc).unwrap();
         let parsed: DiscordConfig = serde_json::from_str(&json).unwrap();
@@ -3981,6 +4706,7 @@ allowed_users = ["@ops:matrix.org"]
             dingtalk: None,
             qq: None,
             message_timeout_secs: 300,
+            max_reply_tokens: HashMap::new(),
         };
         let toml_str = toml::to_string_pretty(&c).unwrap();
         let parsed: ChannelsConfig = toml::from_str(&toml_str).unwrap();
@@ -4158,6 +4884,7 @@ channel_id = "C123"
             dingtalk: None,
             qq: None,
             message_timeout_secs: 300,
+            max_reply_tokens: HashMap::new(),
         };
         let toml_str = toml::to_string_pretty(&c).unwrap();
         let parsed: ChannelsConfig = toml::from_str(&toml_str).unwrap();
@@ -4351,7 +5078,10 @@ enabled = true
 
     #[test]
     async fn secrets_config_serde_roundtrip() {
-        let s = SecretsConfig { encrypt: false };
+        let s = SecretsConfig {
+            encrypt: false,
+            refs: HashMap::new(),
+        };
         let toml_str = toml::to_string(&s).unwrap();
         let parsed: SecretsConfig = toml::from_str(&toml_str).unwrap();
         assert!(!parsed.encrypt);
@@ -4417,6 +5147,8 @@ default_temperature = 0.7
                 max_coordinate_x: Some(3840),
                 max_coordinate_y: Some(2160),
             },
+            fetch_enabled: true,
+            fetch_max_chars: 5_000,
         };
         let toml_str = toml::to_string(&b).unwrap();
         let parsed: BrowserConfig = toml::from_str(&toml_str).unwrap();
@@ -5104,6 +5836,36 @@ default_model = "legacy-model"
         assert!(error.contains("proxy.scope='services'"));
     }
 
+    #[test]
+    async fn shell_config_rejects_invalid_denied_pattern() {
+        assert!(ShellConfig::default().validate().is_ok());
+        let shell = ShellConfig {
+            denied_patterns: vec![r"\bshutdown\b".into(), "(unclosed".into()],
+        };
+        let error = shell.validate().unwrap_err().to_string();
+        assert!(error.contains("'(unclosed'"));
+    }
+
+    #[test]
+    async fn proxies_for_service_follow_scope_and_skip_invalid_urls() {
+        let mut proxy = ProxyConfig {
+            enabled: true,
+            https_proxy: Some("http://proxy.corp:3128".into()),
+            all_proxy: Some("not a url".into()),
+            scope: ProxyScope::Services,
+            services: vec!["provider.*".into()],
+            ..ProxyConfig::default()
+        };
+        assert_eq!(proxy.proxies_for_service("provider.openai_codex").len(), 1);
+        assert!(proxy.proxies_for_service("tool.http_request").is_empty());
+
+        proxy.scope = ProxyScope::Zeroclaw;
+        assert_eq!(proxy.proxies_for_service("tool.http_request").len(), 1);
+
+        proxy.enabled = false;
+        assert!(proxy.proxies_for_service("provider.openai").is_empty());
+    }
+
     #[test]
     async fn env_override_proxy_scope_services() {
         let _env_guard = env_override_lock().await;
@@ -5252,12 +6014,15 @@ default_model = "legacy-model"
                 baud: 115_200,
             }],
             datasheet_dir: None,
+            datasheet_recency_weight: 0.0,
+            datasheet_chunk_strategy: ChunkStrategy::Heading,
         };
         let toml_str = toml::to_string(&p).unwrap();
         let parsed: PeripheralsConfig = toml::from_str(&toml_str).unwrap();
         assert!(parsed.enabled);
         assert_eq!(parsed.boards.len(), 1);
         assert_eq!(parsed.boards[0].board, "nucleo-f401re");
+        assert_eq!(parsed.datasheet_chunk_strategy, ChunkStrategy::Heading);
         assert_eq!(parsed.boards[0].path.as_deref(), Some("/dev/ttyACM0"));
     }
 
@@ -5374,4 +6139,29 @@ default_model = "legacy-model"
             "Test setup: file should be world-readable (mode {mode:o})"
         );
     }
+
+    #[test]
+    async fn default_temperature_for_prefers_matching_route() {
+        let toml_str = r#"
+workspace_dir = "/tmp/ws"
+config_path = "/tmp/config.toml"
+default_temperature = 0.7
+
+[[model_routes]]
+hint = "code"
+provider = "openrouter"
+model = "vendor/coder"
+temperature = 0.0
+
+[[model_routes]]
+hint = "fast"
+provider = "groq"
+model = "vendor/fast"
+"#;
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(config.default_temperature_for("vendor/coder"), 0.0);
+        assert_eq!(config.default_temperature_for("hint:code"), 0.0);
+        assert_eq!(config.default_temperature_for("vendor/fast"), 0.7);
+        assert_eq!(config.default_temperature_for("other/model"), 0.7);
+    }
 }
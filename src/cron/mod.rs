@@ -160,6 +160,19 @@ pub fn handle_command(command: crate::CronCommands, config: &Config) -> Result<(
             println!("▶️  Resumed cron job {id}");
             Ok(())
         }
+        crate::CronCommands::Runs { id, limit } => {
+            get_job(config, &id)?;
+            let runs = list_runs(config, &id, limit)?;
+            if runs.is_empty() {
+                println!("Job {id} has not run yet.");
+                return Ok(());
+            }
+            println!("📜 Last {} run(s) of job {id}, newest first:", runs.len());
+            for run in runs {
+                println!("{}", run.summary_line());
+            }
+            Ok(())
+        }
     }
 }
 
@@ -200,7 +213,7 @@ pub fn resume_job(config: &Config, id: &str) -> Result<CronJob> {
     )
 }
 
-fn parse_delay(input: &str) -> Result<chrono::Duration> {
+pub(crate) fn parse_delay(input: &str) -> Result<chrono::Duration> {
     let input = input.trim();
     if input.is_empty() {
         anyhow::bail!("delay must not be empty");
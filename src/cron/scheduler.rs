@@ -129,8 +129,9 @@ async fn run_agent_job(config: &Config, job: &CronJob) -> (bool, String) {
                 Some(prefixed_prompt),
                 None,
                 model_override,
-                config.default_temperature,
+                None,
                 vec![],
+                crate::agent::RunOptions::default(),
             )
             .await
         }
@@ -237,6 +238,9 @@ fn warn_if_high_frequency_agent_job(job: &CronJob) {
     }
 }
 
+/// Channels `deliver_if_configured` can announce to.
+pub const DELIVERY_CHANNELS: &[&str] = &["telegram", "discord", "slack", "mattermost"];
+
 async fn deliver_if_configured(config: &Config, job: &CronJob, output: &str) -> Result<()> {
     let delivery: &DeliveryConfig = &job.delivery;
     if !delivery.mode.eq_ignore_ascii_case("announce") {
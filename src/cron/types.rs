@@ -126,6 +126,35 @@ pub struct CronRun {
     pub duration_ms: Option<i64>,
 }
 
+/// Characters of output kept in [`CronRun::summary_line`].
+const SUMMARY_OUTPUT_CHARS: usize = 120;
+
+impl CronRun {
+    /// `- <started> <status> (<ms> ms): <first output line>`, for history
+    /// listings.
+    pub fn summary_line(&self) -> String {
+        let mut line = format!(
+            "- {} {} ({} ms)",
+            self.started_at.to_rfc3339(),
+            self.status,
+            self.duration_ms.unwrap_or_default()
+        );
+        if let Some(first) = self
+            .output
+            .as_deref()
+            .and_then(|out| out.lines().map(str::trim).find(|l| !l.is_empty()))
+        {
+            let mut excerpt: String = first.chars().take(SUMMARY_OUTPUT_CHARS).collect();
+            if excerpt.len() < first.len() {
+                excerpt.push('…');
+            }
+            line.push_str(": ");
+            line.push_str(&excerpt);
+        }
+        line
+    }
+}
+
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct CronJobPatch {
     pub schedule: Option<Schedule>,
@@ -195,9 +195,16 @@ async fn run_heartbeat_worker(config: Config) -> Result<()> {
 
         for task in tasks {
             let prompt = format!("[Heartbeat Task] {task}");
-            let temp = config.default_temperature;
-            if let Err(e) =
-                crate::agent::run(config.clone(), Some(prompt), None, None, temp, vec![]).await
+            if let Err(e) = crate::agent::run(
+                config.clone(),
+                Some(prompt),
+                None,
+                None,
+                None,
+                vec![],
+                crate::agent::RunOptions::default(),
+            )
+            .await
             {
                 crate::health::mark_component_error("heartbeat", e.to_string());
                 tracing::warn!("Heartbeat task failed: {e}");
@@ -322,6 +329,7 @@ mod tests {
             stream_mode: crate::config::StreamMode::default(),
             draft_update_interval_ms: 1000,
             mention_only: false,
+            welcome_message: None,
         });
         assert!(has_supervised_channels(&config));
     }
@@ -225,6 +225,84 @@ pub fn run_models(config: &Config, provider_override: Option<&str>, use_cache: b
     Ok(())
 }
 
+pub async fn run_delegates(config: &Config, agent_filter: Option<&str>, live: bool) -> Result<()> {
+    let mut names: Vec<&String> = config
+        .agents
+        .keys()
+        .filter(|name| agent_filter.is_none_or(|filter| name.as_str() == filter))
+        .collect();
+    names.sort();
+
+    if names.is_empty() {
+        match agent_filter {
+            Some(filter) => anyhow::bail!("No delegate agent named '{filter}' in [agents]"),
+            None => {
+                println!("No delegate agents configured ([agents] is empty).");
+                return Ok(());
+            }
+        }
+    }
+
+    println!("🩺 ZeroClaw Doctor — Delegate Agent Check");
+    println!("  Agents to check: {}", names.len());
+    println!(
+        "  Mode: {}",
+        if live {
+            "live (one model call per agent)"
+        } else {
+            "provider setup + warmup (credentials/model not verified)"
+        }
+    );
+    println!();
+
+    let fallback_credential = config
+        .api_key
+        .as_deref()
+        .map(str::trim)
+        .filter(|key| !key.is_empty());
+    let options = crate::providers::ProviderRuntimeOptions {
+        azure_openai: config.azure_openai.clone(),
+        ..crate::providers::ProviderRuntimeOptions::default()
+    };
+    let mut usable = 0usize;
+    for name in &names {
+        let agent = &config.agents[*name];
+        println!("  [{name}] {}/{}", agent.provider, agent.model);
+        match crate::tools::delegate::check_agent(agent, fallback_credential, &options, live).await
+        {
+            Ok(()) if live => {
+                usable += 1;
+                println!("    ✅ usable");
+            }
+            Ok(()) => {
+                usable += 1;
+                println!("    ✅ reachable (credentials/model not verified)");
+            }
+            Err(error) => {
+                let error_text = format_error_chain(&error);
+                println!("    ❌ {}", truncate_for_display(&error_text, 160));
+            }
+        }
+    }
+
+    let failing = names.len() - usable;
+    println!();
+    if live {
+        println!("  Summary: {usable} usable, {failing} failing");
+    } else {
+        println!("  Summary: {usable} reachable, {failing} failing");
+        if failing == 0 {
+            println!("  💡 Add --live to verify credentials and models with a one-line prompt.");
+        }
+    }
+
+    if failing > 0 {
+        anyhow::bail!("{failing} delegate agent(s) failed the check");
+    }
+
+    Ok(())
+}
+
 // ── Config semantic validation ───────────────────────────────────
 
 fn check_config_semantics(config: &Config, items: &mut Vec<DiagItem>) {
@@ -342,6 +420,15 @@ fn check_config_semantics(config: &Config, items: &mut Vec<DiagItem>) {
                 format!("model route \"{}\" has empty model", route.hint),
             ));
         }
+        if let Some(t) = route.temperature.filter(|t| !(0.0..=2.0).contains(t)) {
+            items.push(DiagItem::error(
+                cat,
+                format!(
+                    "model route \"{}\" temperature {t:.1} is out of range (expected 0.0–2.0)",
+                    route.hint
+                ),
+            ));
+        }
     }
 
     // Embedding routes validation
@@ -960,6 +1047,7 @@ mod tests {
             provider: "groq".into(),
             model: String::new(),
             api_key: None,
+            temperature: None,
         }];
         let mut items = Vec::new();
         check_config_semantics(&config, &mut items);
@@ -1072,6 +1160,7 @@ mod tests {
                 api_key: None,
                 temperature: None,
                 max_depth: 3,
+                allowed_models: Vec::new(),
             },
         );
         config.agents.insert(
@@ -1083,6 +1172,7 @@ mod tests {
                 api_key: None,
                 temperature: None,
                 max_depth: 3,
+                allowed_models: Vec::new(),
             },
         );
 
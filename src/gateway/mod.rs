@@ -12,6 +12,7 @@ use crate::config::Config;
 use crate::memory::{self, Memory, MemoryCategory};
 use crate::providers::{self, Provider};
 use crate::runtime;
+use crate::security::moderation::ModerationGate;
 use crate::security::pairing::{constant_time_eq, is_public_bind, PairingGuard};
 use crate::security::SecurityPolicy;
 use crate::tools;
@@ -20,8 +21,8 @@ use anyhow::{Context, Result};
 use axum::{
     body::Bytes,
     extract::{ConnectInfo, Query, State},
-    http::{header, HeaderMap, StatusCode},
-    response::{IntoResponse, Json},
+    http::{header, HeaderMap, HeaderValue, StatusCode},
+    response::{IntoResponse, Json, Response},
     routing::{get, post},
     Router,
 };
@@ -38,6 +39,8 @@ use uuid::Uuid;
 pub const MAX_BODY_SIZE: usize = 65_536;
 /// Request timeout (30s) — prevents slow-loris attacks
 pub const REQUEST_TIMEOUT_SECS: u64 = 30;
+/// Timeout for `POST /api/webhook`, which runs a full agent turn with tools.
+pub const AGENT_WEBHOOK_TIMEOUT_SECS: u64 = 300;
 /// Sliding window used by gateway rate limiting.
 pub const RATE_LIMIT_WINDOW_SECS: u64 = 60;
 /// Fallback max distinct client keys tracked in gateway rate limiter.
@@ -93,8 +96,14 @@ impl SlidingWindowRateLimiter {
     }
 
     fn allow(&self, key: &str) -> bool {
+        self.check(key).allowed
+    }
+
+    /// Record a request for `key` if it fits in the window, and report the
+    /// budget left afterwards.
+    fn check(&self, key: &str) -> RateLimitStatus {
         if self.limit_per_window == 0 {
-            return true;
+            return RateLimitStatus::UNLIMITED;
         }
 
         let now = Instant::now();
@@ -128,12 +137,56 @@ impl SlidingWindowRateLimiter {
         let entry = requests.entry(key.to_owned()).or_default();
         entry.retain(|instant| *instant > cutoff);
 
-        if entry.len() >= self.limit_per_window as usize {
-            return false;
+        let allowed = entry.len() < self.limit_per_window as usize;
+        if allowed {
+            entry.push(now);
         }
 
-        entry.push(now);
-        true
+        let used = u32::try_from(entry.len()).unwrap_or(u32::MAX);
+        // A slot frees up when the oldest request in the window ages out.
+        let reset_secs = entry.first().map_or(0, |oldest| {
+            let left = self.window.saturating_sub(now.duration_since(*oldest));
+            left.as_secs() + u64::from(left.subsec_nanos() > 0)
+        });
+        RateLimitStatus {
+            allowed,
+            limit: self.limit_per_window,
+            remaining: self.limit_per_window.saturating_sub(used),
+            reset_secs,
+        }
+    }
+}
+
+/// Result of one rate-limit check, echoed to clients as `X-RateLimit-*`
+/// headers so they can throttle themselves before hitting a 429.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct RateLimitStatus {
+    allowed: bool,
+    /// Requests allowed per window; `0` means the limiter is disabled.
+    limit: u32,
+    remaining: u32,
+    /// Seconds until another request fits in the window.
+    reset_secs: u64,
+}
+
+impl RateLimitStatus {
+    const UNLIMITED: Self = Self {
+        allowed: true,
+        limit: 0,
+        remaining: 0,
+        reset_secs: 0,
+    };
+
+    fn apply_headers(&self, headers: &mut HeaderMap) {
+        if self.limit == 0 {
+            return;
+        }
+        headers.insert("x-ratelimit-limit", HeaderValue::from(self.limit));
+        headers.insert("x-ratelimit-remaining", HeaderValue::from(self.remaining));
+        headers.insert("x-ratelimit-reset", HeaderValue::from(self.reset_secs));
+        if !self.allowed {
+            headers.insert(header::RETRY_AFTER, HeaderValue::from(self.reset_secs));
+        }
     }
 }
 
@@ -156,8 +209,8 @@ impl GatewayRateLimiter {
         self.pair.allow(key)
     }
 
-    fn allow_webhook(&self, key: &str) -> bool {
-        self.webhook.allow(key)
+    fn check_webhook(&self, key: &str) -> RateLimitStatus {
+        self.webhook.check(key)
     }
 }
 
@@ -271,6 +324,8 @@ pub struct AppState {
     pub auto_save: bool,
     /// SHA-256 hash of `X-Webhook-Secret` (hex-encoded), never plaintext.
     pub webhook_secret_hash: Option<Arc<str>>,
+    /// HMAC key for `X-Webhook-Signature-256` on `/api/webhook`
+    pub webhook_signing_secret: Option<Arc<str>>,
     pub pairing: Arc<PairingGuard>,
     pub trust_forwarded_headers: bool,
     pub rate_limiter: Arc<GatewayRateLimiter>,
@@ -283,6 +338,8 @@ pub struct AppState {
     pub linq_signing_secret: Option<Arc<str>>,
     /// Observability backend for metrics scraping
     pub observer: Arc<dyn crate::observability::Observer>,
+    /// `[moderation]` gate; `None` when moderation is disabled
+    pub moderation: Option<Arc<ModerationGate>>,
 }
 
 /// Run the HTTP gateway using axum with proper HTTP/1.1 compliance.
@@ -298,12 +355,17 @@ pub async fn run_gateway(host: &str, port: u16, config: Config) -> Result<()> {
         );
     }
     let config_state = Arc::new(Mutex::new(config.clone()));
+    let moderation = ModerationGate::from_config(&config.moderation)?.map(Arc::new);
+    // Fail at startup rather than on the first request.
+    crate::channels::load_system_prompt_file(&config)?;
 
     let addr: SocketAddr = format!("{host}:{port}").parse()?;
     let listener = tokio::net::TcpListener::bind(addr).await?;
     let actual_port = listener.local_addr()?.port();
     let display_addr = format!("{host}:{actual_port}");
 
+    let observer: Arc<dyn crate::observability::Observer> =
+        Arc::from(crate::observability::create_observer(&config.observability));
     let provider: Arc<dyn Provider> = Arc::from(providers::create_resilient_provider_with_options(
         config.default_provider.as_deref().unwrap_or("openrouter"),
         config.api_key.as_deref(),
@@ -313,13 +375,15 @@ pub async fn run_gateway(host: &str, port: u16, config: Config) -> Result<()> {
             auth_profile_override: None,
             zeroclaw_dir: config.config_path.parent().map(std::path::PathBuf::from),
             secrets_encrypt: config.secrets.encrypt,
+            observer: Some(observer.clone()),
+            azure_openai: config.azure_openai.clone(),
         },
     )?);
     let model = config
         .default_model
         .clone()
         .unwrap_or_else(|| "anthropic/claude-sonnet-4".into());
-    let temperature = config.default_temperature;
+    let temperature = config.default_temperature_for(&model);
     let mem: Arc<dyn Memory> = Arc::from(memory::create_memory_with_storage(
         &config.memory,
         Some(&config.storage.provider.config),
@@ -366,6 +430,26 @@ pub async fn run_gateway(host: &str, port: u16, config: Config) -> Result<()> {
             })
         });
 
+    // Signing secret for /api/webhook HMAC verification
+    // Priority: environment variable > config file
+    let webhook_signing_secret: Option<Arc<str>> = std::env::var("ZEROCLAW_WEBHOOK_SIGNING_SECRET")
+        .ok()
+        .and_then(|secret| {
+            let secret = secret.trim();
+            (!secret.is_empty()).then(|| secret.to_owned())
+        })
+        .or_else(|| {
+            config.channels_config.webhook.as_ref().and_then(|webhook| {
+                webhook
+                    .signing_secret
+                    .as_deref()
+                    .map(str::trim)
+                    .filter(|secret| !secret.is_empty())
+                    .map(ToOwned::to_owned)
+            })
+        })
+        .map(Arc::from);
+
     // WhatsApp channel (if configured)
     let whatsapp_channel: Option<Arc<WhatsAppChannel>> = config
         .channels_config
@@ -475,6 +559,7 @@ pub async fn run_gateway(host: &str, port: u16, config: Config) -> Result<()> {
     }
     println!("  POST /pair      — pair a new client (X-Pairing-Code header)");
     println!("  POST /webhook   — {{\"message\": \"your prompt\"}}");
+    println!("  POST /api/webhook — run the agent (with tools) and return its answer");
     if whatsapp_channel.is_some() {
         println!("  GET  /whatsapp  — Meta webhook verification");
         println!("  POST /whatsapp  — WhatsApp message webhook");
@@ -501,9 +586,6 @@ pub async fn run_gateway(host: &str, port: u16, config: Config) -> Result<()> {
     crate::health::mark_component_ok("gateway");
 
     // Build shared state
-    let observer: Arc<dyn crate::observability::Observer> =
-        Arc::from(crate::observability::create_observer(&config.observability));
-
     let state = AppState {
         config: config_state,
         provider,
@@ -512,6 +594,7 @@ pub async fn run_gateway(host: &str, port: u16, config: Config) -> Result<()> {
         mem,
         auto_save: config.memory.auto_save,
         webhook_secret_hash,
+        webhook_signing_secret,
         pairing,
         trust_forwarded_headers: config.gateway.trust_forwarded_headers,
         rate_limiter,
@@ -521,9 +604,17 @@ pub async fn run_gateway(host: &str, port: u16, config: Config) -> Result<()> {
         linq: linq_channel,
         linq_signing_secret,
         observer,
+        moderation,
     };
 
-    // Build router with middleware
+    // Build router with middleware. Agent runs get a longer timeout than the
+    // plain chat/webhook routes.
+    let agent_routes = Router::new()
+        .route("/api/webhook", post(handle_api_webhook))
+        .layer(TimeoutLayer::with_status_code(
+            StatusCode::REQUEST_TIMEOUT,
+            Duration::from_secs(AGENT_WEBHOOK_TIMEOUT_SECS),
+        ));
     let app = Router::new()
         .route("/health", get(handle_health))
         .route("/metrics", get(handle_metrics))
@@ -532,12 +623,13 @@ pub async fn run_gateway(host: &str, port: u16, config: Config) -> Result<()> {
         .route("/whatsapp", get(handle_whatsapp_verify))
         .route("/whatsapp", post(handle_whatsapp_message))
         .route("/linq", post(handle_linq_webhook))
-        .with_state(state)
-        .layer(RequestBodyLimitLayer::new(MAX_BODY_SIZE))
         .layer(TimeoutLayer::with_status_code(
             StatusCode::REQUEST_TIMEOUT,
             Duration::from_secs(REQUEST_TIMEOUT_SECS),
-        ));
+        ))
+        .merge(agent_routes)
+        .with_state(state)
+        .layer(RequestBodyLimitLayer::new(MAX_BODY_SIZE));
 
     // Run the server
     axum::serve(
@@ -671,24 +763,12 @@ pub struct WebhookBody {
     pub message: String,
 }
 
-/// POST /webhook — main webhook endpoint
-async fn handle_webhook(
-    State(state): State<AppState>,
-    ConnectInfo(peer_addr): ConnectInfo<SocketAddr>,
-    headers: HeaderMap,
-    body: Result<Json<WebhookBody>, axum::extract::rejection::JsonRejection>,
-) -> impl IntoResponse {
-    let rate_key =
-        client_key_from_request(Some(peer_addr), &headers, state.trust_forwarded_headers);
-    if !state.rate_limiter.allow_webhook(&rate_key) {
-        tracing::warn!("/webhook rate limit exceeded");
-        let err = serde_json::json!({
-            "error": "Too many webhook requests. Please retry later.",
-            "retry_after": RATE_LIMIT_WINDOW_SECS,
-        });
-        return (StatusCode::TOO_MANY_REQUESTS, Json(err));
-    }
-
+/// Bearer-token (pairing) and `X-Webhook-Secret` checks shared by the
+/// webhook routes.
+fn check_webhook_auth(
+    state: &AppState,
+    headers: &HeaderMap,
+) -> Result<(), (StatusCode, Json<serde_json::Value>)> {
     // ── Bearer token auth (pairing) ──
     if state.pairing.require_pairing() {
         let auth = headers
@@ -701,7 +781,7 @@ async fn handle_webhook(
             let err = serde_json::json!({
                 "error": "Unauthorized — pair first via POST /pair, then send Authorization: Bearer <token>"
             });
-            return (StatusCode::UNAUTHORIZED, Json(err));
+            return Err((StatusCode::UNAUTHORIZED, Json(err)));
         }
     }
 
@@ -718,11 +798,70 @@ async fn handle_webhook(
             _ => {
                 tracing::warn!("Webhook: rejected request — invalid or missing X-Webhook-Secret");
                 let err = serde_json::json!({"error": "Unauthorized — invalid or missing X-Webhook-Secret header"});
-                return (StatusCode::UNAUTHORIZED, Json(err));
+                return Err((StatusCode::UNAUTHORIZED, Json(err)));
             }
         }
     }
 
+    Ok(())
+}
+
+/// System prompt for the single-shot chat handlers: the configured prompt
+/// file or the workspace prompt (no tools or skills), plus context files and
+/// user preferences.
+fn gateway_system_prompt(config: &Config, model: &str) -> Result<String> {
+    let mut prompt = crate::channels::load_system_prompt_file(config)?.unwrap_or_else(|| {
+        crate::channels::build_system_prompt(
+            &config.workspace_dir,
+            model,
+            &[], // tools - empty for simple chat
+            &[], // skills
+            Some(&config.identity),
+            None, // bootstrap_max_chars - use default
+        )
+    });
+    prompt.push_str(&crate::channels::load_context_files(config));
+    prompt.push_str(&crate::channels::load_user_preferences(config));
+    Ok(prompt)
+}
+
+/// POST /webhook — main webhook endpoint
+async fn handle_webhook(
+    State(state): State<AppState>,
+    ConnectInfo(peer_addr): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+    body: Result<Json<WebhookBody>, axum::extract::rejection::JsonRejection>,
+) -> Response {
+    let rate_key =
+        client_key_from_request(Some(peer_addr), &headers, state.trust_forwarded_headers);
+    let rate = state.rate_limiter.check_webhook(&rate_key);
+    let mut response = if rate.allowed {
+        webhook_response(state, headers, body).await.into_response()
+    } else {
+        tracing::warn!("/webhook rate limit exceeded");
+        webhook_rate_limited(&rate).into_response()
+    };
+    rate.apply_headers(response.headers_mut());
+    response
+}
+
+fn webhook_rate_limited(rate: &RateLimitStatus) -> (StatusCode, Json<serde_json::Value>) {
+    let err = serde_json::json!({
+        "error": "Too many webhook requests. Please retry later.",
+        "retry_after": rate.reset_secs,
+    });
+    (StatusCode::TOO_MANY_REQUESTS, Json(err))
+}
+
+async fn webhook_response(
+    state: AppState,
+    headers: HeaderMap,
+    body: Result<Json<WebhookBody>, axum::extract::rejection::JsonRejection>,
+) -> impl IntoResponse {
+    if let Err(rejection) = check_webhook_auth(&state, &headers) {
+        return rejection;
+    }
+
     // ── Parse body ──
     let Json(webhook_body) = match body {
         Ok(b) => b,
@@ -755,6 +894,13 @@ async fn handle_webhook(
 
     let message = &webhook_body.message;
 
+    if let Some(gate) = state.moderation.as_ref() {
+        if let Some(blocked) = gate.screen_input(message).await {
+            let body = serde_json::json!({"response": blocked, "model": state.model});
+            return (StatusCode::OK, Json(body));
+        }
+    }
+
     if state.auto_save {
         let key = webhook_memory_key();
         let _ = state
@@ -787,16 +933,16 @@ async fn handle_webhook(
         });
 
     // Build system prompt with workspace context (IDENTITY.md, AGENTS.md, etc.)
-    let system_prompt = {
-        let config_guard = state.config.lock();
-        crate::channels::build_system_prompt(
-            &config_guard.workspace_dir,
-            &state.model,
-            &[], // tools - empty for simple chat
-            &[], // skills
-            Some(&config_guard.identity),
-            None, // bootstrap_max_chars - use default
-        )
+    let system_prompt = gateway_system_prompt(&state.config.lock(), &state.model);
+    let system_prompt = match system_prompt {
+        Ok(prompt) => prompt,
+        Err(e) => {
+            tracing::error!("Webhook system prompt unavailable: {e:#}");
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": "System prompt unavailable"})),
+            );
+        }
     };
 
     // Call the LLM with separate system prompt
@@ -812,6 +958,10 @@ async fn handle_webhook(
     {
         Ok(response) => {
             let duration = started_at.elapsed();
+            let response = match state.moderation.as_ref() {
+                Some(gate) => gate.screen_output(&response).await.unwrap_or(response),
+                None => response,
+            };
             state
                 .observer
                 .record_event(&crate::observability::ObserverEvent::LlmResponse {
@@ -870,7 +1020,115 @@ async fn handle_webhook(
                 });
 
             tracing::error!("Webhook provider error: {}", sanitized);
-            let err = serde_json::json!({"error": "LLM request failed"});
+            let mut err = serde_json::json!({"error": "LLM request failed"});
+            if let Some(provider_err) = providers::ProviderError::find(&e) {
+                err["kind"] = provider_err.kind().into();
+            }
+            (StatusCode::INTERNAL_SERVER_ERROR, Json(err))
+        }
+    }
+}
+
+/// `/api/webhook` request body
+#[derive(serde::Deserialize)]
+pub struct AgentWebhookBody {
+    pub message: String,
+    /// Echoed back so callers can correlate responses; each request is an
+    /// independent agent turn.
+    #[serde(default)]
+    pub session_id: Option<String>,
+}
+
+/// POST /api/webhook — run a full agent turn (tools included) and return the
+/// answer synchronously. For integrations that can't hold a WebSocket.
+async fn handle_api_webhook(
+    State(state): State<AppState>,
+    ConnectInfo(peer_addr): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Response {
+    let rate_key =
+        client_key_from_request(Some(peer_addr), &headers, state.trust_forwarded_headers);
+    let rate = state.rate_limiter.check_webhook(&rate_key);
+    let mut response = if rate.allowed {
+        api_webhook_response(state, headers, body)
+            .await
+            .into_response()
+    } else {
+        tracing::warn!("/api/webhook rate limit exceeded");
+        webhook_rate_limited(&rate).into_response()
+    };
+    rate.apply_headers(response.headers_mut());
+    response
+}
+
+async fn api_webhook_response(
+    state: AppState,
+    headers: HeaderMap,
+    body: Bytes,
+) -> impl IntoResponse {
+    if let Err(rejection) = check_webhook_auth(&state, &headers) {
+        return rejection;
+    }
+
+    // ── Security: Verify X-Webhook-Signature-256 if signing_secret is configured ──
+    if let Some(ref signing_secret) = state.webhook_signing_secret {
+        let signature = headers
+            .get("X-Webhook-Signature-256")
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or("");
+        if !verify_hmac_sha256_signature(signing_secret, &body, signature) {
+            tracing::warn!(
+                "/api/webhook signature verification failed (signature: {})",
+                if signature.is_empty() {
+                    "missing"
+                } else {
+                    "invalid"
+                }
+            );
+            return (
+                StatusCode::UNAUTHORIZED,
+                Json(serde_json::json!({"error": "Invalid signature"})),
+            );
+        }
+    }
+
+    let request: AgentWebhookBody = match serde_json::from_slice(&body) {
+        Ok(request) => request,
+        Err(e) => {
+            tracing::warn!("/api/webhook JSON parse error: {e}");
+            let err = serde_json::json!({
+                "error": "Invalid JSON body. Expected: {\"message\": \"...\", \"session_id\": \"optional\"}"
+            });
+            return (StatusCode::BAD_REQUEST, Json(err));
+        }
+    };
+    if request.message.trim().is_empty() {
+        let err = serde_json::json!({"error": "'message' must not be empty"});
+        return (StatusCode::BAD_REQUEST, Json(err));
+    }
+
+    let config = state.config.lock().clone();
+    match crate::agent::loop_::process_message_outcome(config, &request.message).await {
+        Ok(outcome) => {
+            let body = serde_json::json!({
+                "response": outcome.text,
+                "truncated": outcome.truncated,
+                "model": state.model,
+                "session_id": request.session_id,
+            });
+            (StatusCode::OK, Json(body))
+        }
+        Err(e) => {
+            let sanitized = providers::sanitize_api_error(&e.to_string());
+            tracing::error!("/api/webhook agent error: {sanitized}");
+            let mut err = serde_json::json!({
+                "error": "Agent run failed",
+                "session_id": request.session_id,
+            });
+            if let Some(provider_err) = providers::ProviderError::find(&e) {
+                err["kind"] = provider_err.kind().into();
+            }
             (StatusCode::INTERNAL_SERVER_ERROR, Json(err))
         }
     }
@@ -917,6 +1175,11 @@ async fn handle_whatsapp_verify(
 /// Returns true if the signature is valid, false otherwise.
 /// See: <https://developers.facebook.com/docs/graph-api/webhooks/getting-started#verification-requests>
 pub fn verify_whatsapp_signature(app_secret: &str, body: &[u8], signature_header: &str) -> bool {
+    verify_hmac_sha256_signature(app_secret, body, signature_header)
+}
+
+/// Verify a `sha256=<hex>` HMAC-SHA256 signature header over `body`.
+pub fn verify_hmac_sha256_signature(secret: &str, body: &[u8], signature_header: &str) -> bool {
     use hmac::{Hmac, Mac};
     use sha2::Sha256;
 
@@ -931,7 +1194,7 @@ pub fn verify_whatsapp_signature(app_secret: &str, body: &[u8], signature_header
     };
 
     // Compute HMAC-SHA256
-    let Ok(mut mac) = Hmac::<Sha256>::new_from_slice(app_secret.as_bytes()) else {
+    let Ok(mut mac) = Hmac::<Sha256>::new_from_slice(secret.as_bytes()) else {
         return false;
     };
     mac.update(body);
@@ -1000,6 +1263,13 @@ async fn handle_whatsapp_message(
             truncate_with_ellipsis(&msg.content, 50)
         );
 
+        if let Some(gate) = state.moderation.as_ref() {
+            if let Some(blocked) = gate.screen_input(&msg.content).await {
+                let _ = wa.send(&SendMessage::new(blocked, &msg.reply_target)).await;
+                continue;
+            }
+        }
+
         // Auto-save to memory
         if state.auto_save {
             let key = whatsapp_memory_key(msg);
@@ -1010,16 +1280,19 @@ async fn handle_whatsapp_message(
         }
 
         // Build system prompt with workspace context (IDENTITY.md, AGENTS.md, etc.)
-        let system_prompt = {
-            let config_guard = state.config.lock();
-            crate::channels::build_system_prompt(
-                &config_guard.workspace_dir,
-                &state.model,
-                &[], // tools - empty for simple chat
-                &[], // skills
-                Some(&config_guard.identity),
-                None, // bootstrap_max_chars - use default
-            )
+        let system_prompt = gateway_system_prompt(&state.config.lock(), &state.model);
+        let system_prompt = match system_prompt {
+            Ok(prompt) => prompt,
+            Err(e) => {
+                tracing::error!("WhatsApp system prompt unavailable: {e:#}");
+                let _ = wa
+                    .send(&SendMessage::new(
+                        "Sorry, I couldn't process your message right now.",
+                        &msg.reply_target,
+                    ))
+                    .await;
+                continue;
+            }
         };
 
         // Call the LLM with separate system prompt
@@ -1034,6 +1307,10 @@ async fn handle_whatsapp_message(
             .await
         {
             Ok(response) => {
+                let response = match state.moderation.as_ref() {
+                    Some(gate) => gate.screen_output(&response).await.unwrap_or(response),
+                    None => response,
+                };
                 // Send reply via WhatsApp
                 if let Err(e) = wa
                     .send(&SendMessage::new(response, &msg.reply_target))
@@ -1130,6 +1407,15 @@ async fn handle_linq_webhook(
             truncate_with_ellipsis(&msg.content, 50)
         );
 
+        if let Some(gate) = state.moderation.as_ref() {
+            if let Some(blocked) = gate.screen_input(&msg.content).await {
+                let _ = linq
+                    .send(&SendMessage::new(blocked, &msg.reply_target))
+                    .await;
+                continue;
+            }
+        }
+
         // Auto-save to memory
         if state.auto_save {
             let key = linq_memory_key(msg);
@@ -1146,6 +1432,10 @@ async fn handle_linq_webhook(
             .await
         {
             Ok(response) => {
+                let response = match state.moderation.as_ref() {
+                    Some(gate) => gate.screen_output(&response).await.unwrap_or(response),
+                    None => response,
+                };
                 // Send reply via Linq
                 if let Err(e) = linq
                     .send(&SendMessage::new(response, &msg.reply_target))
@@ -1238,6 +1528,7 @@ mod tests {
             mem: Arc::new(MockMemory),
             auto_save: false,
             webhook_secret_hash: None,
+            webhook_signing_secret: None,
             pairing: Arc::new(PairingGuard::new(false, &[])),
             trust_forwarded_headers: false,
             rate_limiter: Arc::new(GatewayRateLimiter::new(100, 100, 100)),
@@ -1247,6 +1538,7 @@ mod tests {
             linq: None,
             linq_signing_secret: None,
             observer: Arc::new(crate::observability::NoopObserver),
+            moderation: None,
         };
 
         let response = handle_metrics(State(state)).await.into_response();
@@ -1281,6 +1573,7 @@ mod tests {
             mem: Arc::new(MockMemory),
             auto_save: false,
             webhook_secret_hash: None,
+            webhook_signing_secret: None,
             pairing: Arc::new(PairingGuard::new(false, &[])),
             trust_forwarded_headers: false,
             rate_limiter: Arc::new(GatewayRateLimiter::new(100, 100, 100)),
@@ -1290,6 +1583,7 @@ mod tests {
             linq: None,
             linq_signing_secret: None,
             observer,
+            moderation: None,
         };
 
         let response = handle_metrics(State(state)).await.into_response();
@@ -1350,6 +1644,41 @@ mod tests {
         }
     }
 
+    #[test]
+    fn rate_limiter_reports_remaining_budget_as_headers() {
+        let limiter = SlidingWindowRateLimiter::new(2, Duration::from_secs(60), 10);
+        let first = limiter.check("ip-1");
+        assert!(first.allowed);
+        assert_eq!(first.remaining, 1);
+        assert_eq!(first.reset_secs, 60);
+
+        assert_eq!(limiter.check("ip-1").remaining, 0);
+        let rejected = limiter.check("ip-1");
+        assert!(!rejected.allowed);
+        assert_eq!(rejected.remaining, 0);
+
+        let mut headers = HeaderMap::new();
+        rejected.apply_headers(&mut headers);
+        assert_eq!(headers["x-ratelimit-limit"], "2");
+        assert_eq!(headers["x-ratelimit-remaining"], "0");
+        assert!(headers.contains_key("x-ratelimit-reset"));
+        assert!(headers.contains_key(header::RETRY_AFTER));
+
+        let (status, Json(body)) = webhook_rate_limited(&rejected);
+        assert_eq!(status, StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(body["retry_after"], rejected.reset_secs);
+        assert_eq!(
+            headers[header::RETRY_AFTER],
+            rejected.reset_secs.to_string().as_str()
+        );
+
+        let mut headers = HeaderMap::new();
+        SlidingWindowRateLimiter::new(0, Duration::from_secs(60), 10)
+            .check("ip-1")
+            .apply_headers(&mut headers);
+        assert!(headers.is_empty());
+    }
+
     #[test]
     fn idempotency_store_rejects_duplicate_key() {
         let store = IdempotencyStore::new(Duration::from_secs(30), 10);
@@ -1641,6 +1970,7 @@ mod tests {
             mem: memory,
             auto_save: false,
             webhook_secret_hash: None,
+            webhook_signing_secret: None,
             pairing: Arc::new(PairingGuard::new(false, &[])),
             trust_forwarded_headers: false,
             rate_limiter: Arc::new(GatewayRateLimiter::new(100, 100, 100)),
@@ -1650,6 +1980,7 @@ mod tests {
             linq: None,
             linq_signing_secret: None,
             observer: Arc::new(crate::observability::NoopObserver),
+            moderation: None,
         };
 
         let mut headers = HeaderMap::new();
@@ -1699,6 +2030,7 @@ mod tests {
             mem: memory,
             auto_save: true,
             webhook_secret_hash: None,
+            webhook_signing_secret: None,
             pairing: Arc::new(PairingGuard::new(false, &[])),
             trust_forwarded_headers: false,
             rate_limiter: Arc::new(GatewayRateLimiter::new(100, 100, 100)),
@@ -1708,6 +2040,7 @@ mod tests {
             linq: None,
             linq_signing_secret: None,
             observer: Arc::new(crate::observability::NoopObserver),
+            moderation: None,
         };
 
         let headers = HeaderMap::new();
@@ -1769,6 +2102,7 @@ mod tests {
             mem: memory,
             auto_save: false,
             webhook_secret_hash: Some(Arc::from(hash_webhook_secret(&secret))),
+            webhook_signing_secret: None,
             pairing: Arc::new(PairingGuard::new(false, &[])),
             trust_forwarded_headers: false,
             rate_limiter: Arc::new(GatewayRateLimiter::new(100, 100, 100)),
@@ -1778,6 +2112,7 @@ mod tests {
             linq: None,
             linq_signing_secret: None,
             observer: Arc::new(crate::observability::NoopObserver),
+            moderation: None,
         };
 
         let response = handle_webhook(
@@ -1811,6 +2146,7 @@ mod tests {
             mem: memory,
             auto_save: false,
             webhook_secret_hash: Some(Arc::from(hash_webhook_secret(&valid_secret))),
+            webhook_signing_secret: None,
             pairing: Arc::new(PairingGuard::new(false, &[])),
             trust_forwarded_headers: false,
             rate_limiter: Arc::new(GatewayRateLimiter::new(100, 100, 100)),
@@ -1820,6 +2156,7 @@ mod tests {
             linq: None,
             linq_signing_secret: None,
             observer: Arc::new(crate::observability::NoopObserver),
+            moderation: None,
         };
 
         let mut headers = HeaderMap::new();
@@ -1858,6 +2195,7 @@ mod tests {
             mem: memory,
             auto_save: false,
             webhook_secret_hash: Some(Arc::from(hash_webhook_secret(&secret))),
+            webhook_signing_secret: None,
             pairing: Arc::new(PairingGuard::new(false, &[])),
             trust_forwarded_headers: false,
             rate_limiter: Arc::new(GatewayRateLimiter::new(100, 100, 100)),
@@ -1867,6 +2205,7 @@ mod tests {
             linq: None,
             linq_signing_secret: None,
             observer: Arc::new(crate::observability::NoopObserver),
+            moderation: None,
         };
 
         let mut headers = HeaderMap::new();
@@ -1887,6 +2226,82 @@ mod tests {
         assert_eq!(provider_impl.calls.load(Ordering::SeqCst), 1);
     }
 
+    #[tokio::test]
+    async fn api_webhook_requires_valid_signature_and_message() {
+        let provider_impl = Arc::new(MockProvider::default());
+        let provider: Arc<dyn Provider> = provider_impl.clone();
+        let memory: Arc<dyn Memory> = Arc::new(MockMemory);
+        let signing_secret = generate_test_secret();
+
+        let state = AppState {
+            config: Arc::new(Mutex::new(Config::default())),
+            provider,
+            model: "test-model".into(),
+            temperature: 0.0,
+            mem: memory,
+            auto_save: false,
+            webhook_secret_hash: None,
+            webhook_signing_secret: Some(Arc::from(signing_secret.as_str())),
+            pairing: Arc::new(PairingGuard::new(false, &[])),
+            trust_forwarded_headers: false,
+            rate_limiter: Arc::new(GatewayRateLimiter::new(100, 100, 100)),
+            idempotency_store: Arc::new(IdempotencyStore::new(Duration::from_secs(300), 1000)),
+            whatsapp: None,
+            whatsapp_app_secret: None,
+            linq: None,
+            linq_signing_secret: None,
+            observer: Arc::new(crate::observability::NoopObserver),
+            moderation: None,
+        };
+
+        let call = |body: &'static [u8], signature: Option<String>| {
+            let state = state.clone();
+            async move {
+                let mut headers = HeaderMap::new();
+                if let Some(signature) = signature {
+                    headers.insert(
+                        "X-Webhook-Signature-256",
+                        HeaderValue::from_str(&signature).unwrap(),
+                    );
+                }
+                handle_api_webhook(
+                    State(state),
+                    test_connect_info(),
+                    headers,
+                    Bytes::from_static(body),
+                )
+                .await
+                .into_response()
+                .status()
+            }
+        };
+        let sign = |body: &[u8]| {
+            format!(
+                "sha256={}",
+                compute_whatsapp_signature_hex(&signing_secret, body)
+            )
+        };
+
+        let body: &[u8] = br#"{"message":"deploy status?","session_id":"ci-42"}"#;
+        assert_eq!(call(body, None).await, StatusCode::UNAUTHORIZED);
+        assert_eq!(
+            call(body, Some(sign(b"tampered"))).await,
+            StatusCode::UNAUTHORIZED
+        );
+
+        let bad_json: &[u8] = b"not json";
+        assert_eq!(
+            call(bad_json, Some(sign(bad_json))).await,
+            StatusCode::BAD_REQUEST
+        );
+        let empty: &[u8] = br#"{"message":"  "}"#;
+        assert_eq!(
+            call(empty, Some(sign(empty))).await,
+            StatusCode::BAD_REQUEST
+        );
+        assert_eq!(provider_impl.calls.load(Ordering::SeqCst), 0);
+    }
+
     // ══════════════════════════════════════════════════════════
     // WhatsApp Signature Verification Tests (CWE-345 Prevention)
     // ══════════════════════════════════════════════════════════
@@ -106,7 +106,12 @@ pub fn handle_command(cmd: crate::HardwareCommands, _config: &Config) -> Result<
     match cmd {
         crate::HardwareCommands::Discover => run_discover(),
         crate::HardwareCommands::Introspect { path } => run_introspect(&path),
-        crate::HardwareCommands::Info { chip } => run_info(&chip),
+        crate::HardwareCommands::Info { chip, json } => run_info(&chip, json),
+        crate::HardwareCommands::Flash {
+            chip,
+            elf,
+            base_address,
+        } => run_flash(&chip, &elf, base_address.as_deref()),
     }
 }
 
@@ -161,10 +166,10 @@ fn run_introspect(path: &str) -> Result<()> {
 }
 
 #[cfg(feature = "hardware")]
-fn run_info(chip: &str) -> Result<()> {
+fn run_info(chip: &str, json: bool) -> Result<()> {
     #[cfg(feature = "probe")]
     {
-        match info_via_probe(chip) {
+        match info_via_probe(chip, json) {
             Ok(()) => return Ok(()),
             Err(e) => {
                 println!("probe-rs attach failed: {}", e);
@@ -180,6 +185,7 @@ fn run_info(chip: &str) -> Result<()> {
 
     #[cfg(not(feature = "probe"))]
     {
+        let _ = json;
         println!("Chip info via USB requires the 'probe' feature.");
         println!();
         println!("Build with: cargo build --features hardware,probe");
@@ -192,39 +198,249 @@ fn run_info(chip: &str) -> Result<()> {
     }
 }
 
+/// Chip info read over SWD, as printed by `hardware info --json`.
 #[cfg(all(feature = "hardware", feature = "probe"))]
-fn info_via_probe(chip: &str) -> anyhow::Result<()> {
+#[derive(Debug, serde::Serialize)]
+struct ChipInfo {
+    chip: String,
+    architecture: String,
+    memory: Vec<ChipMemoryRegion>,
+}
+
+#[cfg(all(feature = "hardware", feature = "probe"))]
+#[derive(Debug, serde::Serialize)]
+struct ChipMemoryRegion {
+    /// "ram" or "flash"
+    kind: &'static str,
+    start: u64,
+    end: u64,
+    size_kb: u64,
+}
+
+#[cfg(all(feature = "hardware", feature = "probe"))]
+fn info_via_probe(chip: &str, json: bool) -> anyhow::Result<()> {
     use probe_rs::config::MemoryRegion;
     use probe_rs::{Session, SessionConfig};
 
-    println!("Connecting to {} via USB (ST-Link)...", chip);
+    if !json {
+        println!("Connecting to {} via USB (ST-Link)...", chip);
+    }
     let session = Session::auto_attach(chip, SessionConfig::default())
         .map_err(|e| anyhow::anyhow!("{}", e))?;
 
     let target = session.target();
+    let memory = target
+        .memory_map
+        .iter()
+        .filter_map(|region| {
+            let (kind, range) = match region {
+                MemoryRegion::Ram(ram) => ("ram", &ram.range),
+                MemoryRegion::Nvm(flash) => ("flash", &flash.range),
+                _ => return None,
+            };
+            Some(ChipMemoryRegion {
+                kind,
+                start: range.start,
+                end: range.end,
+                size_kb: (range.end - range.start) / 1024,
+            })
+        })
+        .collect();
+    let info = ChipInfo {
+        chip: target.name.clone(),
+        architecture: format!("{:?}", session.architecture()),
+        memory,
+    };
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&info)?);
+        return Ok(());
+    }
+
     println!();
-    println!("Chip: {}", target.name);
-    println!("Architecture: {:?}", session.architecture());
+    println!("Chip: {}", info.chip);
+    println!("Architecture: {}", info.architecture);
     println!();
     println!("Memory map:");
-    for region in target.memory_map.iter() {
-        match region {
-            MemoryRegion::Ram(ram) => {
-                let start = ram.range.start;
-                let end = ram.range.end;
-                let size_kb = (end - start) / 1024;
-                println!("  RAM: 0x{:08X} - 0x{:08X} ({} KB)", start, end, size_kb);
-            }
-            MemoryRegion::Nvm(flash) => {
-                let start = flash.range.start;
-                let end = flash.range.end;
-                let size_kb = (end - start) / 1024;
-                println!("  Flash: 0x{:08X} - 0x{:08X} ({} KB)", start, end, size_kb);
-            }
-            _ => {}
-        }
+    for region in &info.memory {
+        let label = if region.kind == "ram" { "RAM" } else { "Flash" };
+        println!(
+            "  {}: 0x{:08X} - 0x{:08X} ({} KB)",
+            label, region.start, region.end, region.size_kb
+        );
     }
     println!();
     println!("Info read via USB (SWD) — no firmware on target needed.");
     Ok(())
 }
+
+/// A firmware image checked by [`validate_firmware`], ready to program.
+#[cfg(feature = "hardware")]
+#[derive(Debug, PartialEq, Eq)]
+enum FirmwareImage {
+    Elf,
+    Bin { base_address: u64 },
+}
+
+/// Parse a load address given as hex (`0x08000000`) or decimal.
+#[cfg(feature = "hardware")]
+fn parse_address(raw: &str) -> Result<u64> {
+    let raw = raw.trim();
+    let parsed = match raw.strip_prefix("0x").or_else(|| raw.strip_prefix("0X")) {
+        Some(hex) => u64::from_str_radix(&hex.replace('_', ""), 16),
+        None => raw.replace('_', "").parse(),
+    };
+    parsed.map_err(|_| anyhow::anyhow!("Invalid --base-address: {raw}"))
+}
+
+/// Decide how to program `bytes` before anything on the target is touched:
+/// ELF images carry their own load addresses; anything else is a raw binary
+/// and needs `--base-address`.
+#[cfg(feature = "hardware")]
+fn validate_firmware(bytes: &[u8], base_address: Option<&str>) -> Result<FirmwareImage> {
+    if bytes.is_empty() {
+        anyhow::bail!("Firmware file is empty");
+    }
+    if bytes.starts_with(b"\x7fELF") {
+        if base_address.is_some() {
+            anyhow::bail!("--base-address only applies to raw .bin images; ELF files carry their own load addresses");
+        }
+        return Ok(FirmwareImage::Elf);
+    }
+    match base_address {
+        Some(raw) => Ok(FirmwareImage::Bin {
+            base_address: parse_address(raw)?,
+        }),
+        None => anyhow::bail!(
+            "Not an ELF file. To flash a raw binary, pass --base-address (e.g. 0x08000000)"
+        ),
+    }
+}
+
+#[cfg(feature = "hardware")]
+fn run_flash(chip: &str, path: &std::path::Path, base_address: Option<&str>) -> Result<()> {
+    let bytes = std::fs::read(path)
+        .map_err(|e| anyhow::anyhow!("Cannot read firmware {}: {e}", path.display()))?;
+    let image = validate_firmware(&bytes, base_address)?;
+
+    #[cfg(feature = "probe")]
+    {
+        flash_via_probe(chip, path, &image, bytes.len() as u64)
+    }
+
+    #[cfg(not(feature = "probe"))]
+    {
+        let _ = image;
+        println!("Flashing via USB requires the 'probe' feature.");
+        println!();
+        println!("Build with: cargo build --features hardware,probe");
+        println!();
+        println!(
+            "Then run: zeroclaw hardware flash --chip {} --elf {}",
+            chip,
+            path.display()
+        );
+        Ok(())
+    }
+}
+
+#[cfg(all(feature = "hardware", feature = "probe"))]
+fn flash_via_probe(
+    chip: &str,
+    path: &std::path::Path,
+    image: &FirmwareImage,
+    image_len: u64,
+) -> Result<()> {
+    use probe_rs::config::MemoryRegion;
+    use probe_rs::flashing::{
+        download_file_with_options, BinOptions, DownloadOptions, FlashProgress, Format,
+        ProgressEvent,
+    };
+    use probe_rs::{Session, SessionConfig};
+
+    println!("Connecting to {} via USB (ST-Link)...", chip);
+    // Attaching resolves the chip name, so an unknown chip fails here,
+    // before anything is erased.
+    let mut session = Session::auto_attach(chip, SessionConfig::default())
+        .map_err(|e| anyhow::anyhow!("{}", e))?;
+
+    let flash_size: u64 = session
+        .target()
+        .memory_map
+        .iter()
+        .filter_map(|region| match region {
+            MemoryRegion::Nvm(flash) => Some(flash.range.end - flash.range.start),
+            _ => None,
+        })
+        .sum();
+    if let FirmwareImage::Bin { .. } = image {
+        if image_len > flash_size {
+            anyhow::bail!(
+                "Image is {} KB but {} has {} KB of flash",
+                image_len / 1024,
+                chip,
+                flash_size / 1024
+            );
+        }
+    }
+
+    let format = match image {
+        FirmwareImage::Elf => Format::Elf(Default::default()),
+        FirmwareImage::Bin { base_address } => Format::Bin(BinOptions {
+            base_address: Some(*base_address),
+            skip: 0,
+        }),
+    };
+
+    let mut options = DownloadOptions::default();
+    options.verify = true;
+    options.progress = FlashProgress::new(|event| match event {
+        ProgressEvent::Started(operation) => println!("  {operation:?}..."),
+        ProgressEvent::Finished(operation) => println!("  {operation:?} done"),
+        ProgressEvent::Failed(operation) => println!("  {operation:?} failed"),
+        _ => {}
+    });
+
+    println!("Flashing {}...", path.display());
+    download_file_with_options(&mut session, path, format, options)
+        .map_err(|e| anyhow::anyhow!("Flashing failed: {}", e))?;
+
+    session
+        .core(0)
+        .and_then(|mut core| core.reset())
+        .map_err(|e| anyhow::anyhow!("Flashed and verified, but reset failed: {}", e))?;
+
+    println!();
+    println!(
+        "Flashed and verified {} on {}; target reset.",
+        path.display(),
+        chip
+    );
+    Ok(())
+}
+
+#[cfg(all(test, feature = "hardware"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_firmware_checks_format_before_flashing() {
+        let elf = b"\x7fELF\x01\x01\x01rest";
+        assert_eq!(validate_firmware(elf, None).unwrap(), FirmwareImage::Elf);
+        assert!(validate_firmware(elf, Some("0x08000000")).is_err());
+
+        let bin = [0x00, 0x50, 0x01, 0x20];
+        assert!(validate_firmware(&bin, None)
+            .unwrap_err()
+            .to_string()
+            .contains("--base-address"));
+        assert_eq!(
+            validate_firmware(&bin, Some("0x0800_0000")).unwrap(),
+            FirmwareImage::Bin {
+                base_address: 0x0800_0000
+            }
+        );
+        assert!(validate_firmware(&bin, Some("flash")).is_err());
+        assert!(validate_firmware(&[], None).is_err());
+    }
+}
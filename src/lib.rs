@@ -145,6 +145,37 @@ pub enum MigrateCommands {
     },
 }
 
+/// Memory management subcommands
+#[derive(Subcommand, Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum MemoryCommands {
+    /// Export all memories to a JSONL file (backup or migration)
+    Export {
+        /// Output file path
+        path: std::path::PathBuf,
+    },
+    /// Import memories from a JSONL (or JSON array) export into the current backend
+    Import {
+        /// Export file to load
+        path: std::path::PathBuf,
+
+        /// Replace entries whose key already exists (default: skip them)
+        #[arg(long)]
+        overwrite: bool,
+    },
+    /// Show entry counts per category, total size and age range
+    Stats,
+    /// List, show, set or remove always-injected user preferences
+    Prefs {
+        /// Preference key (omit to list all)
+        key: Option<String>,
+        /// New value for the key
+        value: Option<String>,
+        /// Remove the key instead of showing or setting it
+        #[arg(long, conflicts_with = "value")]
+        unset: bool,
+    },
+}
+
 /// Cron subcommands
 #[derive(Subcommand, Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub enum CronCommands {
@@ -213,6 +244,14 @@ pub enum CronCommands {
         /// Task ID
         id: String,
     },
+    /// Show recent runs of a task (status, duration, output excerpt)
+    Runs {
+        /// Task ID
+        id: String,
+        /// Number of most recent runs to show
+        #[arg(long, default_value_t = 10)]
+        limit: usize,
+    },
 }
 
 /// Integration subcommands
@@ -240,6 +279,21 @@ pub enum HardwareCommands {
         /// Chip name (e.g. STM32F401RETx). Default: STM32F401RETx for Nucleo-F401RE
         #[arg(long, default_value = "STM32F401RETx")]
         chip: String,
+        /// Print chip info as JSON (chip, architecture, memory regions)
+        #[arg(long)]
+        json: bool,
+    },
+    /// Program firmware to the target via USB (probe-rs over ST-Link), then verify it
+    Flash {
+        /// Chip name (e.g. STM32F401RETx). Default: STM32F401RETx for Nucleo-F401RE
+        #[arg(long, default_value = "STM32F401RETx")]
+        chip: String,
+        /// Firmware image: an ELF file, or a raw .bin together with --base-address
+        #[arg(long)]
+        elf: std::path::PathBuf,
+        /// Load address for a raw binary image (e.g. 0x08000000)
+        #[arg(long)]
+        base_address: Option<String>,
     },
 }
 
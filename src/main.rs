@@ -83,10 +83,24 @@ pub use zeroclaw::{HardwareCommands, PeripheralCommands};
 #[command(version = "0.1.0")]
 #[command(about = "The fastest, smallest AI assistant.", long_about = None)]
 struct Cli {
+    /// Increase log verbosity (-v info, -vv debug, -vvv trace); RUST_LOG overrides
+    #[arg(short, long, global = true, action = clap::ArgAction::Count)]
+    verbose: u8,
+
     #[command(subcommand)]
     command: Commands,
 }
 
+/// Default tracing filter for a `-v` count when RUST_LOG is unset.
+fn log_filter_for_verbosity(verbose: u8) -> &'static str {
+    match verbose {
+        0 => "warn",
+        1 => "info",
+        2 => "debug",
+        _ => "trace",
+    }
+}
+
 #[derive(Subcommand, Debug)]
 enum ServiceCommands {
     /// Install daemon service unit for auto-start and restart
@@ -142,13 +156,59 @@ enum Commands {
         #[arg(long)]
         model: Option<String>,
 
-        /// Temperature (0.0 - 2.0)
-        #[arg(short, long, default_value = "0.7")]
-        temperature: f64,
+        /// Temperature (0.0 - 2.0); defaults to the profile's, then default_temperature
+        #[arg(short, long)]
+        temperature: Option<f64>,
+
+        /// Prompt profile from [agent.profiles] (overrides agent.profile)
+        #[arg(long)]
+        profile: Option<String>,
+
+        /// Nucleus sampling cutoff (0.0 - 1.0); overrides agent.top_p
+        #[arg(long)]
+        top_p: Option<f64>,
+
+        /// Max tokens per model response; overrides agent.max_tokens
+        #[arg(long)]
+        max_tokens: Option<u32>,
+
+        /// Abort a single-message run after this many seconds; overrides agent.run_timeout_secs
+        #[arg(long)]
+        timeout: Option<u64>,
+
+        /// Stop once estimated spend exceeds this many USD; overrides agent.max_cost_usd
+        #[arg(long)]
+        max_cost: Option<f64>,
+
+        /// Don't read or write memory for this run; overrides agent.ephemeral
+        #[arg(long, alias = "no-memory")]
+        ephemeral: bool,
 
         /// Attach a peripheral (board:path, e.g. nucleo-f401re:/dev/ttyACM0)
         #[arg(long)]
         peripheral: Vec<String>,
+
+        /// Output format for single-message mode (text, json)
+        #[arg(long, value_enum, default_value_t = agent::OutputFormat::Text)]
+        output: agent::OutputFormat,
+
+        /// Print the fully assembled system prompt and exit without calling the provider
+        #[arg(long, alias = "system-prompt-dump")]
+        dump_prompt: bool,
+
+        /// Re-run a recorded provider request log (reliability.log_requests) offline:
+        /// recorded responses are fed back through the mock provider and tool calls
+        /// are answered with a stub instead of running
+        #[arg(long, value_name = "FILE", conflicts_with = "provider")]
+        replay: Option<std::path::PathBuf>,
+
+        /// With --replay, execute the recorded tool calls for real (shell, file writes, git, ...)
+        #[arg(long, requires = "replay")]
+        replay_exec_tools: bool,
+
+        /// Print how long each startup step took (memory, tools, RAG, prompt, provider)
+        #[arg(long)]
+        profile_startup: bool,
     },
 
     /// Start the gateway server (webhooks, websockets)
@@ -194,6 +254,12 @@ enum Commands {
         cron_command: CronCommands,
     },
 
+    /// Export or import memories (backup, backend migration)
+    Memory {
+        #[command(subcommand)]
+        memory_command: MemoryCommands,
+    },
+
     /// Manage provider model catalogs
     Models {
         #[command(subcommand)]
@@ -201,7 +267,10 @@ enum Commands {
     },
 
     /// List supported AI providers
-    Providers,
+    Providers {
+        #[command(subcommand)]
+        providers_command: Option<ProviderCommands>,
+    },
 
     /// Manage channels (telegram, discord, slack)
     Channel {
@@ -355,6 +424,36 @@ enum MigrateCommands {
     },
 }
 
+#[derive(Subcommand, Debug)]
+enum MemoryCommands {
+    /// Export all memories to a JSONL file (backup or migration)
+    Export {
+        /// Output file path
+        path: std::path::PathBuf,
+    },
+    /// Import memories from a JSONL (or JSON array) export into the current backend
+    Import {
+        /// Export file to load
+        path: std::path::PathBuf,
+
+        /// Replace entries whose key already exists (default: skip them)
+        #[arg(long)]
+        overwrite: bool,
+    },
+    /// Show entry counts per category, total size and age range
+    Stats,
+    /// List, show, set or remove always-injected user preferences
+    Prefs {
+        /// Preference key (omit to list all)
+        key: Option<String>,
+        /// New value for the key
+        value: Option<String>,
+        /// Remove the key instead of showing or setting it
+        #[arg(long, conflicts_with = "value")]
+        unset: bool,
+    },
+}
+
 #[derive(Subcommand, Debug)]
 enum CronCommands {
     /// List all scheduled tasks
@@ -422,6 +521,14 @@ enum CronCommands {
         /// Task ID
         id: String,
     },
+    /// Show recent runs of a task (status, duration, output excerpt)
+    Runs {
+        /// Task ID
+        id: String,
+        /// Number of most recent runs to show
+        #[arg(long, default_value_t = 10)]
+        limit: usize,
+    },
 }
 
 #[derive(Subcommand, Debug)]
@@ -438,6 +545,12 @@ enum ModelCommands {
     },
 }
 
+#[derive(Subcommand, Debug)]
+enum ProviderCommands {
+    /// List provider IDs with the credentials, api_url support and model format each expects
+    List,
+}
+
 #[derive(Subcommand, Debug)]
 enum DoctorCommands {
     /// Probe model catalogs across providers and report availability
@@ -450,6 +563,16 @@ enum DoctorCommands {
         #[arg(long)]
         use_cache: bool,
     },
+    /// Check that each configured delegate agent can be created and reached
+    Delegates {
+        /// Check a single agent only (default: all agents in [agents])
+        #[arg(long)]
+        agent: Option<String>,
+
+        /// Also send each agent's model a one-line prompt (costs one call per agent)
+        #[arg(long)]
+        live: bool,
+    },
 }
 
 #[derive(Subcommand, Debug)]
@@ -516,14 +639,28 @@ async fn main() -> Result<()> {
 
     let cli = Cli::parse();
 
-    // Initialize logging - respects RUST_LOG env var, defaults to INFO
-    let subscriber = fmt::Subscriber::builder()
-        .with_env_filter(
-            EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info")),
-        )
-        .finish();
-
-    tracing::subscriber::set_global_default(subscriber).expect("setting default subscriber failed");
+    // Initialize logging - RUST_LOG wins, otherwise -v/-vv/-vvv picks the level
+    let builder = fmt::Subscriber::builder().with_env_filter(
+        EnvFilter::try_from_default_env()
+            .unwrap_or_else(|_| EnvFilter::new(log_filter_for_verbosity(cli.verbose))),
+    );
+    // `agent --output json` and `--dump-prompt` own stdout, so logs go to stderr there.
+    let stdout_reserved = matches!(
+        cli.command,
+        Commands::Agent {
+            output: agent::OutputFormat::Json,
+            ..
+        } | Commands::Agent {
+            dump_prompt: true,
+            ..
+        }
+    );
+    let installed = if stdout_reserved {
+        tracing::subscriber::set_global_default(builder.with_writer(std::io::stderr).finish())
+    } else {
+        tracing::subscriber::set_global_default(builder.finish())
+    };
+    installed.expect("setting default subscriber failed");
 
     // Onboard runs quick setup by default, or the interactive wizard with --interactive.
     // The onboard wizard uses reqwest::blocking internally, which creates its own
@@ -585,10 +722,72 @@ async fn main() -> Result<()> {
             provider,
             model,
             temperature,
+            profile,
+            top_p,
+            max_tokens,
+            timeout,
+            max_cost,
+            ephemeral,
             peripheral,
-        } => agent::run(config, message, provider, model, temperature, peripheral)
+            output,
+            dump_prompt,
+            replay,
+            replay_exec_tools,
+            profile_startup,
+        } => {
+            let (message, provider, model, replay) = match replay {
+                Some(log) => {
+                    let recorded = providers::mock::load_request_log(&log)?;
+                    let message = message.or(recorded.message).ok_or_else(|| {
+                        anyhow::anyhow!("{} has no user message; pass --message", log.display())
+                    })?;
+                    (
+                        Some(message),
+                        Some("mock".to_string()),
+                        model.or(recorded.model),
+                        Some(agent::ReplayOptions {
+                            log,
+                            exec_tools: replay_exec_tools,
+                        }),
+                    )
+                }
+                None => (message, provider, model, None),
+            };
+            if top_p.is_some() {
+                config.agent.top_p = top_p;
+            }
+            if max_tokens.is_some() {
+                config.agent.max_tokens = max_tokens;
+            }
+            if let Some(timeout) = timeout {
+                config.agent.run_timeout_secs = timeout;
+            }
+            if max_cost.is_some() {
+                config.agent.max_cost_usd = max_cost;
+            }
+            if ephemeral {
+                config.agent.ephemeral = true;
+            }
+            if profile.is_some() {
+                config.agent.profile = profile;
+            }
+            agent::run(
+                config,
+                message,
+                provider,
+                model,
+                temperature,
+                peripheral,
+                agent::RunOptions {
+                    output,
+                    dump_prompt,
+                    profile_startup,
+                    replay,
+                },
+            )
             .await
-            .map(|_| ()),
+            .map(|_| ())
+        }
 
         Commands::Gateway { port, host } => {
             let port = port.unwrap_or(config.gateway.port);
@@ -698,6 +897,10 @@ async fn main() -> Result<()> {
 
         Commands::Cron { cron_command } => cron::handle_command(cron_command, &config),
 
+        Commands::Memory { memory_command } => {
+            memory::handle_command(memory_command, &config).await
+        }
+
         Commands::Models { model_command } => match model_command {
             ModelCommands::Refresh { provider, force } => {
                 let config_for_refresh = config.clone();
@@ -709,7 +912,9 @@ async fn main() -> Result<()> {
             }
         },
 
-        Commands::Providers => {
+        Commands::Providers {
+            providers_command: None | Some(ProviderCommands::List),
+        } => {
             let providers = providers::list_providers();
             let current = config
                 .default_provider
@@ -736,6 +941,18 @@ async fn main() -> Result<()> {
                     "  {:<19} {}{}{}{}",
                     p.name, p.display_name, local_tag, marker, aliases
                 );
+                let api_url = if p.accepts_api_url() {
+                    " · api_url supported"
+                } else {
+                    ""
+                };
+                println!(
+                    "  {:<19} credentials: {} · model: {}{}",
+                    "",
+                    p.credential_hint(),
+                    p.model_format(),
+                    api_url
+                );
             }
             println!("\n  custom:<URL>   Any OpenAI-compatible endpoint");
             println!("  anthropic-custom:<URL>  Any Anthropic-compatible endpoint");
@@ -756,6 +973,9 @@ async fn main() -> Result<()> {
                 .await
                 .map_err(|e| anyhow::anyhow!("doctor models task failed: {e}"))?
             }
+            Some(DoctorCommands::Delegates { agent, live }) => {
+                doctor::run_delegates(&config, agent.as_deref(), live).await
+            }
             None => doctor::run(&config),
         },
 
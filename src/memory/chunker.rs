@@ -106,6 +106,104 @@ pub fn chunk_markdown(text: &str, max_tokens: usize) -> Vec<Chunk> {
     chunks
 }
 
+/// Split markdown into one chunk per `#` / `##` section, keeping its `###`
+/// subsections with it while the whole section fits in `max_tokens`.
+///
+/// Oversized sections fall back to [`chunk_markdown`], with the section
+/// heading repeated on each piece so it keeps its context.
+pub fn chunk_by_headings(text: &str, max_tokens: usize) -> Vec<Chunk> {
+    if text.trim().is_empty() {
+        return Vec::new();
+    }
+
+    let max_chars = max_tokens * 4;
+    let mut chunks = Vec::new();
+
+    for (heading, body) in split_on_top_headings(text) {
+        let full = match heading {
+            Some(ref h) => format!("{h}\n{body}"),
+            None => body,
+        };
+
+        if full.len() <= max_chars {
+            chunks.push(Chunk {
+                index: chunks.len(),
+                content: full.trim().to_string(),
+                heading,
+            });
+            continue;
+        }
+
+        for piece in chunk_markdown(&full, max_tokens) {
+            let content = match heading {
+                Some(ref h) if !piece.content.starts_with(h.as_str()) => {
+                    format!("{h}\n{}", piece.content)
+                }
+                _ => piece.content,
+            };
+            chunks.push(Chunk {
+                index: chunks.len(),
+                content,
+                heading: piece.heading.or_else(|| heading.clone()),
+            });
+        }
+    }
+
+    chunks.retain(|c| !c.content.is_empty());
+    for (i, chunk) in chunks.iter_mut().enumerate() {
+        chunk.index = i;
+    }
+    chunks
+}
+
+/// Like [`chunk_by_headings`], but packs consecutive short sections into one
+/// chunk while they fit in `max_tokens`, so one-line sections don't become
+/// chunks of their own.
+pub fn chunk_semantic(text: &str, max_tokens: usize) -> Vec<Chunk> {
+    let max_chars = max_tokens * 4;
+    let mut chunks: Vec<Chunk> = Vec::new();
+
+    for chunk in chunk_by_headings(text, max_tokens) {
+        match chunks.last_mut() {
+            Some(prev) if prev.content.len() + chunk.content.len() + 2 <= max_chars => {
+                prev.content.push_str("\n\n");
+                prev.content.push_str(&chunk.content);
+            }
+            _ => chunks.push(Chunk {
+                index: chunks.len(),
+                ..chunk
+            }),
+        }
+    }
+
+    chunks
+}
+
+/// Split text into `(heading, body)` sections at `#` and `##` headings only.
+fn split_on_top_headings(text: &str) -> Vec<(Option<String>, String)> {
+    let mut sections = Vec::new();
+    let mut current_heading: Option<String> = None;
+    let mut current_body = String::new();
+
+    for line in text.lines() {
+        if line.starts_with("# ") || line.starts_with("## ") {
+            if !current_body.trim().is_empty() || current_heading.is_some() {
+                sections.push((current_heading.take(), std::mem::take(&mut current_body)));
+            }
+            current_heading = Some(line.to_string());
+        } else {
+            current_body.push_str(line);
+            current_body.push('\n');
+        }
+    }
+
+    if !current_body.trim().is_empty() || current_heading.is_some() {
+        sections.push((current_heading, current_body));
+    }
+
+    sections
+}
+
 /// Split text into `(heading, body)` sections.
 fn split_on_headings(text: &str) -> Vec<(Option<String>, String)> {
     let mut sections = Vec::new();
@@ -247,6 +345,49 @@ mod tests {
         }
     }
 
+    #[test]
+    fn strategies_place_boundaries_differently() {
+        let text = "# Nucleo-F401RE\nSTM32 board.\n\n\
+                    ## GPIO\nPins are 3.3V.\n\n\
+                    ### Port A\nPA5 drives LD2.\n\n\
+                    ### Port C\nPC13 reads B1.\n\n\
+                    ## Power\nUSB or VIN.";
+
+        // Fixed splits at every heading level.
+        let fixed: Vec<_> = chunk_markdown(text, 512)
+            .into_iter()
+            .map(|c| c.content)
+            .collect();
+        assert_eq!(fixed.len(), 5);
+        assert_eq!(fixed[2], "### Port A\nPA5 drives LD2.");
+
+        // Heading keeps the GPIO section and its ports together.
+        let heading = chunk_by_headings(text, 512);
+        assert_eq!(heading.len(), 3);
+        assert_eq!(heading[1].heading.as_deref(), Some("## GPIO"));
+        assert!(heading[1].content.contains("PA5 drives LD2."));
+        assert!(heading[1].content.contains("PC13 reads B1."));
+        assert_eq!(heading[2].content, "## Power\nUSB or VIN.");
+
+        // Semantic packs the short sections into one chunk.
+        let semantic = chunk_semantic(text, 512);
+        assert_eq!(semantic.len(), 1);
+        assert!(semantic[0].content.starts_with("# Nucleo-F401RE"));
+        assert!(semantic[0].content.ends_with("USB or VIN."));
+
+        // A section over the cap is split, each piece keeping its heading.
+        let mut big = String::from("## Registers\n");
+        for i in 0..60 {
+            use std::fmt::Write;
+            let _ = write!(big, "Register {i} controls something useful.\n\n");
+        }
+        let pieces = chunk_by_headings(&big, 100);
+        assert!(pieces.len() > 1);
+        assert!(pieces.iter().all(|c| c.content.starts_with("## Registers")));
+        let semantic = chunk_semantic(&big, 100);
+        assert!(semantic.iter().all(|c| c.content.len() <= 500));
+    }
+
     #[test]
     fn indexes_are_sequential() {
         let text = "# A\nContent A\n\n# B\nContent B\n\n# C\nContent C";
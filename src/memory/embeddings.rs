@@ -1,4 +1,5 @@
 use async_trait::async_trait;
+use std::path::PathBuf;
 
 /// Trait for embedding providers — convert text to vectors
 #[async_trait]
@@ -156,13 +157,130 @@ impl EmbeddingProvider for OpenAiEmbedding {
 
 // ── Factory ──────────────────────────────────────────────────
 
+// ── Local ONNX provider (feature: embedding-local) ───────────
+
+/// Models accepted as `embedding_model` with `embedding_provider = "local"`,
+/// with their output dimensions.
+pub const LOCAL_EMBEDDING_MODELS: &[(&str, usize)] = &[
+    ("all-MiniLM-L6-v2", 384),
+    ("bge-small-en-v1.5", 384),
+    ("bge-base-en-v1.5", 768),
+    ("nomic-embed-text-v1.5", 768),
+];
+
+/// Map a configured model name to a supported local model, case-insensitively.
+pub fn resolve_local_embedding_model(model: &str) -> Option<(&'static str, usize)> {
+    LOCAL_EMBEDDING_MODELS
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(model.trim()))
+        .copied()
+}
+
+/// Where local models are downloaded on first use: `~/.zeroclaw/models/embeddings`.
+pub fn local_embedding_cache_dir() -> PathBuf {
+    directories::UserDirs::new().map_or_else(
+        || PathBuf::from(".zeroclaw/models/embeddings"),
+        |dirs| dirs.home_dir().join(".zeroclaw/models/embeddings"),
+    )
+}
+
+#[cfg(feature = "embedding-local")]
+pub struct LocalEmbedding {
+    model: std::sync::Arc<std::sync::Mutex<fastembed::TextEmbedding>>,
+    dims: usize,
+}
+
+#[cfg(feature = "embedding-local")]
+impl LocalEmbedding {
+    /// `name` and `dims` come from [`LOCAL_EMBEDDING_MODELS`].
+    pub fn new(name: &str, dims: usize) -> anyhow::Result<Self> {
+        let kind = match name {
+            "bge-small-en-v1.5" => fastembed::EmbeddingModel::BGESmallENV15,
+            "bge-base-en-v1.5" => fastembed::EmbeddingModel::BGEBaseENV15,
+            "nomic-embed-text-v1.5" => fastembed::EmbeddingModel::NomicEmbedTextV15,
+            _ => fastembed::EmbeddingModel::AllMiniLML6V2,
+        };
+        let cache_dir = local_embedding_cache_dir();
+        std::fs::create_dir_all(&cache_dir)?;
+        let model = fastembed::TextEmbedding::try_new(
+            fastembed::InitOptions::new(kind)
+                .with_cache_dir(cache_dir)
+                .with_show_download_progress(false),
+        )?;
+        Ok(Self {
+            model: std::sync::Arc::new(std::sync::Mutex::new(model)),
+            dims,
+        })
+    }
+}
+
+#[cfg(feature = "embedding-local")]
+#[async_trait]
+impl EmbeddingProvider for LocalEmbedding {
+    fn name(&self) -> &str {
+        "local"
+    }
+
+    fn dimensions(&self) -> usize {
+        self.dims
+    }
+
+    async fn embed(&self, texts: &[&str]) -> anyhow::Result<Vec<Vec<f32>>> {
+        if texts.is_empty() {
+            return Ok(Vec::new());
+        }
+        let texts: Vec<String> = texts.iter().map(|t| (*t).to_string()).collect();
+        let model = std::sync::Arc::clone(&self.model);
+        // ONNX inference is CPU-bound; keep it off the async workers.
+        tokio::task::spawn_blocking(move || {
+            let model = model
+                .lock()
+                .map_err(|_| anyhow::anyhow!("local embedding model lock poisoned"))?;
+            model.embed(texts, None)
+        })
+        .await?
+    }
+}
+
+/// Load a local model. An unknown name, a dimension mismatch or a failed load
+/// is an error: falling back would silently change what memory search does
+/// and write vectors the configured index can't use.
+fn create_local_embedding(model: &str, dims: usize) -> anyhow::Result<Box<dyn EmbeddingProvider>> {
+    let Some((name, model_dims)) = resolve_local_embedding_model(model) else {
+        let supported: Vec<&str> = LOCAL_EMBEDDING_MODELS.iter().map(|(n, _)| *n).collect();
+        anyhow::bail!(
+            "Unknown local embedding model '{model}'; supported: {}",
+            supported.join(", ")
+        );
+    };
+    if dims != model_dims {
+        anyhow::bail!(
+            "embedding_dimensions = {dims} does not match local model {name} ({model_dims} dimensions)"
+        );
+    }
+    load_local_embedding(name, model_dims)
+}
+
+#[cfg(feature = "embedding-local")]
+fn load_local_embedding(name: &str, dims: usize) -> anyhow::Result<Box<dyn EmbeddingProvider>> {
+    use anyhow::Context;
+    let provider = LocalEmbedding::new(name, dims)
+        .with_context(|| format!("Failed to load local embedding model {name}"))?;
+    Ok(Box::new(provider))
+}
+
+#[cfg(not(feature = "embedding-local"))]
+fn load_local_embedding(_name: &str, _dims: usize) -> anyhow::Result<Box<dyn EmbeddingProvider>> {
+    anyhow::bail!("embedding_provider = \"local\" needs a build with --features embedding-local")
+}
+
 pub fn create_embedding_provider(
     provider: &str,
     api_key: Option<&str>,
     model: &str,
     dims: usize,
-) -> Box<dyn EmbeddingProvider> {
-    match provider {
+) -> anyhow::Result<Box<dyn EmbeddingProvider>> {
+    Ok(match provider {
         "openai" => {
             let key = api_key.unwrap_or("");
             Box::new(OpenAiEmbedding::new(
@@ -172,13 +290,14 @@ pub fn create_embedding_provider(
                 dims,
             ))
         }
+        "local" => create_local_embedding(model, dims)?,
         name if name.starts_with("custom:") => {
             let base_url = name.strip_prefix("custom:").unwrap_or("");
             let key = api_key.unwrap_or("");
             Box::new(OpenAiEmbedding::new(base_url, key, model, dims))
         }
         _ => Box::new(NoopEmbedding),
-    }
+    })
 }
 
 #[cfg(test)]
@@ -201,20 +320,22 @@ mod tests {
 
     #[test]
     fn factory_none() {
-        let p = create_embedding_provider("none", None, "model", 1536);
+        let p = create_embedding_provider("none", None, "model", 1536).unwrap();
         assert_eq!(p.name(), "none");
     }
 
     #[test]
     fn factory_openai() {
-        let p = create_embedding_provider("openai", Some("key"), "text-embedding-3-small", 1536);
+        let p = create_embedding_provider("openai", Some("key"), "text-embedding-3-small", 1536)
+            .unwrap();
         assert_eq!(p.name(), "openai");
         assert_eq!(p.dimensions(), 1536);
     }
 
     #[test]
     fn factory_custom_url() {
-        let p = create_embedding_provider("custom:http://localhost:1234", None, "model", 768);
+        let p =
+            create_embedding_provider("custom:http://localhost:1234", None, "model", 768).unwrap();
         assert_eq!(p.name(), "openai"); // uses OpenAiEmbedding internally
         assert_eq!(p.dimensions(), 768);
     }
@@ -245,26 +366,61 @@ mod tests {
 
     #[test]
     fn factory_empty_string_returns_noop() {
-        let p = create_embedding_provider("", None, "model", 1536);
+        let p = create_embedding_provider("", None, "model", 1536).unwrap();
         assert_eq!(p.name(), "none");
     }
 
     #[test]
     fn factory_unknown_provider_returns_noop() {
-        let p = create_embedding_provider("cohere", None, "model", 1536);
+        let p = create_embedding_provider("cohere", None, "model", 1536).unwrap();
         assert_eq!(p.name(), "none");
     }
 
+    #[test]
+    fn local_model_names_resolve_case_insensitively() {
+        assert_eq!(
+            resolve_local_embedding_model("BGE-base-en-v1.5"),
+            Some(("bge-base-en-v1.5", 768))
+        );
+        assert_eq!(
+            resolve_local_embedding_model("text-embedding-3-small"),
+            None
+        );
+        assert!(local_embedding_cache_dir().ends_with(".zeroclaw/models/embeddings"));
+    }
+
+    #[test]
+    fn factory_local_rejects_unknown_model_and_dimension_mismatch() {
+        let err = create_embedding_provider("local", None, "text-embedding-3-small", 384)
+            .err()
+            .unwrap();
+        assert!(err.to_string().contains("Unknown local embedding model"));
+
+        let err = create_embedding_provider("local", None, "bge-base-en-v1.5", 1536)
+            .err()
+            .unwrap();
+        assert!(err.to_string().contains("does not match"));
+    }
+
+    #[cfg(not(feature = "embedding-local"))]
+    #[test]
+    fn factory_local_without_feature_errors() {
+        let err = create_embedding_provider("local", None, "all-MiniLM-L6-v2", 384)
+            .err()
+            .unwrap();
+        assert!(err.to_string().contains("--features embedding-local"));
+    }
+
     #[test]
     fn factory_custom_empty_url() {
         // "custom:" with no URL — should still construct without panic
-        let p = create_embedding_provider("custom:", None, "model", 768);
+        let p = create_embedding_provider("custom:", None, "model", 768).unwrap();
         assert_eq!(p.name(), "openai");
     }
 
     #[test]
     fn factory_openai_no_api_key() {
-        let p = create_embedding_provider("openai", None, "text-embedding-3-small", 1536);
+        let p = create_embedding_provider("openai", None, "text-embedding-3-small", 1536).unwrap();
         assert_eq!(p.name(), "openai");
         assert_eq!(p.dimensions(), 1536);
     }
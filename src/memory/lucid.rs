@@ -1,5 +1,5 @@
 use super::sqlite::SqliteMemory;
-use super::traits::{Memory, MemoryCategory, MemoryEntry};
+use super::traits::{Memory, MemoryCategory, MemoryEntry, MemoryStats};
 use async_trait::async_trait;
 use chrono::Local;
 use parking_lot::Mutex;
@@ -379,6 +379,10 @@ impl Memory for LucidMemory {
         self.local.count().await
     }
 
+    async fn stats(&self) -> anyhow::Result<MemoryStats> {
+        self.local.stats().await
+    }
+
     async fn health_check(&self) -> bool {
         self.local.health_check().await
     }
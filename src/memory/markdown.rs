@@ -130,6 +130,39 @@ impl MarkdownMemory {
         entries.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
         Ok(entries)
     }
+
+    /// Score entries by the fraction of query keywords they contain.
+    fn keyword_rank(all: Vec<MemoryEntry>, query: &str, limit: usize) -> Vec<MemoryEntry> {
+        let query_lower = query.to_lowercase();
+        let keywords: Vec<&str> = query_lower.split_whitespace().collect();
+
+        let mut scored: Vec<MemoryEntry> = all
+            .into_iter()
+            .filter_map(|mut entry| {
+                let content_lower = entry.content.to_lowercase();
+                let matched = keywords
+                    .iter()
+                    .filter(|kw| content_lower.contains(**kw))
+                    .count();
+                if matched > 0 {
+                    #[allow(clippy::cast_precision_loss)]
+                    let score = matched as f64 / keywords.len() as f64;
+                    entry.score = Some(score);
+                    Some(entry)
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        scored.sort_by(|a, b| {
+            b.score
+                .partial_cmp(&a.score)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        scored.truncate(limit);
+        scored
+    }
 }
 
 #[async_trait]
@@ -160,35 +193,18 @@ impl Memory for MarkdownMemory {
         _session_id: Option<&str>,
     ) -> anyhow::Result<Vec<MemoryEntry>> {
         let all = self.read_all_entries().await?;
-        let query_lower = query.to_lowercase();
-        let keywords: Vec<&str> = query_lower.split_whitespace().collect();
-
-        let mut scored: Vec<MemoryEntry> = all
-            .into_iter()
-            .filter_map(|mut entry| {
-                let content_lower = entry.content.to_lowercase();
-                let matched = keywords
-                    .iter()
-                    .filter(|kw| content_lower.contains(**kw))
-                    .count();
-                if matched > 0 {
-                    #[allow(clippy::cast_precision_loss)]
-                    let score = matched as f64 / keywords.len() as f64;
-                    entry.score = Some(score);
-                    Some(entry)
-                } else {
-                    None
-                }
-            })
-            .collect();
+        Ok(Self::keyword_rank(all, query, limit))
+    }
 
-        scored.sort_by(|a, b| {
-            b.score
-                .partial_cmp(&a.score)
-                .unwrap_or(std::cmp::Ordering::Equal)
-        });
-        scored.truncate(limit);
-        Ok(scored)
+    async fn recall_in(
+        &self,
+        query: &str,
+        category: &MemoryCategory,
+        limit: usize,
+    ) -> anyhow::Result<Vec<MemoryEntry>> {
+        let mut all = self.read_all_entries().await?;
+        all.retain(|entry| entry.category == *category);
+        Ok(Self::keyword_rank(all, query, limit))
     }
 
     async fn get(&self, key: &str) -> anyhow::Result<Option<MemoryEntry>> {
@@ -290,6 +306,31 @@ mod tests {
             .all(|r| r.content.to_lowercase().contains("rust")));
     }
 
+    #[tokio::test]
+    async fn markdown_recall_in_filters_by_category() {
+        let (_tmp, mem) = temp_workspace();
+        mem.store("a", "Rust is preferred", MemoryCategory::Core, None)
+            .await
+            .unwrap();
+        mem.store("b", "Rust build fixed today", MemoryCategory::Daily, None)
+            .await
+            .unwrap();
+
+        let daily = mem
+            .recall_in("Rust", &MemoryCategory::Daily, 5)
+            .await
+            .unwrap();
+        assert_eq!(daily.len(), 1);
+        assert!(daily[0].content.contains("build fixed"));
+
+        let core = mem
+            .recall_in("Rust", &MemoryCategory::Core, 5)
+            .await
+            .unwrap();
+        assert_eq!(core.len(), 1);
+        assert!(core[0].content.contains("preferred"));
+    }
+
     #[tokio::test]
     async fn markdown_recall_no_match() {
         let (_tmp, mem) = temp_workspace();
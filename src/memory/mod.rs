@@ -5,7 +5,9 @@ pub mod hygiene;
 pub mod lucid;
 pub mod markdown;
 pub mod none;
+pub mod portable;
 pub mod postgres;
+pub mod preferences;
 pub mod response_cache;
 pub mod snapshot;
 pub mod sqlite;
@@ -25,9 +27,9 @@ pub use response_cache::ResponseCache;
 pub use sqlite::SqliteMemory;
 pub use traits::Memory;
 #[allow(unused_imports)]
-pub use traits::{MemoryCategory, MemoryEntry};
+pub use traits::{MemoryCategory, MemoryEntry, MemoryStats};
 
-use crate::config::{EmbeddingRouteConfig, MemoryConfig, StorageProviderConfig};
+use crate::config::{Config, EmbeddingRouteConfig, MemoryConfig, StorageProviderConfig};
 use anyhow::Context;
 use std::path::Path;
 use std::sync::Arc;
@@ -227,7 +229,7 @@ pub fn create_memory_with_storage_and_routes(
                 resolved_embedding.api_key.as_deref(),
                 &resolved_embedding.model,
                 resolved_embedding.dimensions,
-            ));
+            )?);
 
         #[allow(clippy::cast_possible_truncation)]
         let mem = SqliteMemory::with_embedder(
@@ -300,6 +302,180 @@ pub fn create_memory_for_migration(
     )
 }
 
+/// Recall per-turn context, preferring durable memories over daily logs.
+///
+/// Up to 3/5 of `limit` comes from non-`Daily` categories (core facts,
+/// conversation, custom); the remaining slots, plus any the durable pass left
+/// unused, are filled from `Daily` via [`Memory::recall_in`]. This keeps a
+/// burst of transient log entries from crowding out preferences and facts.
+pub async fn recall_for_context(
+    mem: &dyn Memory,
+    query: &str,
+    limit: usize,
+) -> anyhow::Result<Vec<MemoryEntry>> {
+    let durable_limit = limit - limit * 2 / 5;
+    let mut entries: Vec<MemoryEntry> = mem
+        .recall(query, limit.saturating_mul(2), None)
+        .await?
+        .into_iter()
+        .filter(|entry| entry.category != MemoryCategory::Daily)
+        .take(durable_limit)
+        .collect();
+
+    let daily_limit = limit - entries.len();
+    if daily_limit > 0 {
+        entries.extend(
+            mem.recall_in(query, &MemoryCategory::Daily, daily_limit)
+                .await?,
+        );
+    }
+    Ok(entries)
+}
+
+/// [`content_similarity`] at or above which an auto-saved turn counts as a
+/// near-duplicate of an existing entry and is not stored again.
+pub const AUTOSAVE_DEDUP_THRESHOLD: f64 = 0.9;
+
+/// Key for an auto-saved turn: `<prefix>_<uuid>`.
+///
+/// Every turn gets its own key, so upserting backends never overwrite an
+/// earlier turn with a later one.
+pub fn autosave_memory_key(prefix: &str) -> String {
+    format!("{prefix}_{}", uuid::Uuid::new_v4())
+}
+
+/// Jaccard similarity of the lowercase word bigrams of `a` and `b`
+/// (0.0–1.0). Bigrams keep word order, so "prefer tabs not spaces" and
+/// "prefer spaces not tabs" share nothing even though their words match.
+/// A one-word text is compared by that word.
+pub fn content_similarity(a: &str, b: &str) -> f64 {
+    let shingles = |text: &str| -> std::collections::HashSet<String> {
+        let words: Vec<String> = text
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|w| !w.is_empty())
+            .map(str::to_lowercase)
+            .collect();
+        if words.len() < 2 {
+            return words.into_iter().collect();
+        }
+        words.windows(2).map(|pair| pair.join(" ")).collect()
+    };
+    let (a, b) = (shingles(a), shingles(b));
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+    #[allow(clippy::cast_precision_loss)]
+    let similarity = a.intersection(&b).count() as f64 / a.union(&b).count() as f64;
+    similarity
+}
+
+/// Store an auto-saved turn unless a near-duplicate already exists.
+///
+/// Looks up the closest entries in the same category and skips the write when
+/// any reaches [`AUTOSAVE_DEDUP_THRESHOLD`], so repeated greetings, retries and
+/// identical summaries don't pile up. Returns whether the entry was stored.
+/// Explicit stores (`memory_store`, imports) are never deduplicated.
+pub async fn store_autosave(
+    mem: &dyn Memory,
+    key: &str,
+    content: &str,
+    category: MemoryCategory,
+) -> anyhow::Result<bool> {
+    let existing = mem
+        .recall_in(content, &category, 3)
+        .await
+        .unwrap_or_default();
+    let is_duplicate = existing.iter().any(|entry| {
+        // Markdown entries are stored as `**key**: content`.
+        let stored = entry
+            .content
+            .strip_prefix("**")
+            .and_then(|rest| rest.split_once("**: "))
+            .map_or(entry.content.as_str(), |(_, body)| body);
+        content_similarity(stored, content) >= AUTOSAVE_DEDUP_THRESHOLD
+    });
+    if is_duplicate {
+        tracing::debug!(key, "Skipping near-duplicate auto-saved memory");
+        return Ok(false);
+    }
+
+    mem.store(key, content, category, None).await?;
+    Ok(true)
+}
+
+/// Handle `zeroclaw memory` subcommands against the configured backend.
+pub async fn handle_command(command: crate::MemoryCommands, config: &Config) -> anyhow::Result<()> {
+    let memory = create_memory_with_storage(
+        &config.memory,
+        Some(&config.storage.provider.config),
+        &config.workspace_dir,
+        config.api_key.as_deref(),
+    )?;
+
+    match command {
+        crate::MemoryCommands::Export { path } => {
+            let count = portable::export_memories(memory.as_ref(), &path).await?;
+            println!(
+                "✅ Exported {count} memories from '{}' backend to {}",
+                memory.name(),
+                path.display()
+            );
+        }
+        crate::MemoryCommands::Import { path, overwrite } => {
+            let stats = portable::import_memories(memory.as_ref(), &path, overwrite).await?;
+            println!(
+                "✅ Imported memories into '{}' backend from {}",
+                memory.name(),
+                path.display()
+            );
+            println!("  Imported:         {}", stats.imported);
+            println!("  Overwritten:      {}", stats.overwritten);
+            println!("  Skipped existing: {}", stats.skipped_existing);
+        }
+        crate::MemoryCommands::Stats => {
+            let stats = memory.stats().await?;
+            println!("Memory backend: {}", memory.name());
+            println!("  Entries: {}", stats.total);
+            for (category, count) in &stats.by_category {
+                println!("    {category:<14} {count}");
+            }
+            println!("  Content: {} bytes", stats.content_bytes);
+            println!("  Oldest:  {}", stats.oldest.as_deref().unwrap_or("-"));
+            println!("  Newest:  {}", stats.newest.as_deref().unwrap_or("-"));
+        }
+        crate::MemoryCommands::Prefs { key, value, unset } => {
+            let workspace_dir = &config.workspace_dir;
+            match (key, value) {
+                (None, _) => {
+                    let prefs = preferences::load(workspace_dir);
+                    if prefs.is_empty() {
+                        println!("No preferences set.");
+                    }
+                    for (key, value) in &prefs {
+                        println!("{key}: {value}");
+                    }
+                }
+                (Some(key), _) if unset => {
+                    if preferences::remove(workspace_dir, &key)? {
+                        println!("Removed preference: {key}");
+                    } else {
+                        println!("No preference named '{key}'");
+                    }
+                }
+                (Some(key), Some(value)) => {
+                    preferences::set(workspace_dir, &key, &value)?;
+                    println!("Saved preference: {} = {}", key.trim(), value.trim());
+                }
+                (Some(key), None) => match preferences::load(workspace_dir).get(key.trim()) {
+                    Some(value) => println!("{value}"),
+                    None => anyhow::bail!("No preference named '{key}'"),
+                },
+            }
+        }
+    }
+    Ok(())
+}
+
 /// Factory: create an optional response cache from config.
 pub fn create_response_cache(config: &MemoryConfig, workspace_dir: &Path) -> Option<ResponseCache> {
     if !config.response_cache_enabled {
@@ -332,6 +508,137 @@ mod tests {
     use crate::config::{EmbeddingRouteConfig, StorageProviderConfig};
     use tempfile::TempDir;
 
+    #[tokio::test]
+    async fn recall_for_context_reserves_slots_for_durable_memories() {
+        let tmp = TempDir::new().unwrap();
+        let mem = SqliteMemory::new(tmp.path()).unwrap();
+        for i in 0..8 {
+            mem.store(
+                &format!("log{i}"),
+                &format!("deploy notes {i}"),
+                MemoryCategory::Daily,
+                None,
+            )
+            .await
+            .unwrap();
+        }
+        mem.store(
+            "deploy_target",
+            "deploy to fly.io",
+            MemoryCategory::Core,
+            None,
+        )
+        .await
+        .unwrap();
+
+        let entries = recall_for_context(&mem, "deploy", 5).await.unwrap();
+        assert_eq!(entries.len(), 5);
+        assert_eq!(entries[0].key, "deploy_target");
+        assert_eq!(
+            entries
+                .iter()
+                .filter(|e| e.category == MemoryCategory::Daily)
+                .count(),
+            4
+        );
+    }
+
+    #[test]
+    fn content_similarity_ignores_case_and_punctuation() {
+        assert!((content_similarity("Hello, world!", "hello world") - 1.0).abs() < f64::EPSILON);
+        assert!(content_similarity("deploy to fly", "deploy to render") < AUTOSAVE_DEDUP_THRESHOLD);
+        assert!(content_similarity("", "anything") < f64::EPSILON);
+        assert!((content_similarity("Thanks!", "thanks") - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[tokio::test]
+    async fn store_autosave_keeps_reordered_and_negated_sentences() {
+        let tmp = TempDir::new().unwrap();
+        let mem = SqliteMemory::new(tmp.path()).unwrap();
+
+        for content in [
+            "I prefer tabs not spaces",
+            "I prefer spaces not tabs",
+            "Do not deploy on Friday",
+            "Do deploy on Friday",
+        ] {
+            assert!(
+                store_autosave(
+                    &mem,
+                    &autosave_memory_key("user_msg"),
+                    content,
+                    MemoryCategory::Conversation,
+                )
+                .await
+                .unwrap(),
+                "{content} should be stored"
+            );
+        }
+        assert_eq!(mem.count().await.unwrap(), 4);
+    }
+
+    #[tokio::test]
+    async fn store_autosave_skips_near_duplicates_in_same_category() {
+        let tmp = TempDir::new().unwrap();
+        let mem = SqliteMemory::new(tmp.path()).unwrap();
+
+        let stored = store_autosave(
+            &mem,
+            &autosave_memory_key("user_msg"),
+            "What is the weather in Berlin today?",
+            MemoryCategory::Conversation,
+        )
+        .await
+        .unwrap();
+        assert!(stored);
+
+        let stored = store_autosave(
+            &mem,
+            &autosave_memory_key("user_msg"),
+            "what is the weather in Berlin today",
+            MemoryCategory::Conversation,
+        )
+        .await
+        .unwrap();
+        assert!(!stored);
+
+        // Same text in another category, and genuinely new text, are kept.
+        assert!(store_autosave(
+            &mem,
+            &autosave_memory_key("assistant_resp"),
+            "What is the weather in Berlin today?",
+            MemoryCategory::Daily,
+        )
+        .await
+        .unwrap());
+        assert!(store_autosave(
+            &mem,
+            &autosave_memory_key("user_msg"),
+            "What is the weather in Paris tomorrow?",
+            MemoryCategory::Conversation,
+        )
+        .await
+        .unwrap());
+        assert_eq!(mem.count().await.unwrap(), 3);
+    }
+
+    #[tokio::test]
+    async fn store_autosave_sees_through_markdown_key_prefix() {
+        let tmp = TempDir::new().unwrap();
+        let mem = MarkdownMemory::new(tmp.path());
+        let content = "Remember that the staging database moved to eu-west";
+        assert!(
+            store_autosave(&mem, "user_msg_a", content, MemoryCategory::Daily)
+                .await
+                .unwrap()
+        );
+        assert!(
+            !store_autosave(&mem, "user_msg_b", content, MemoryCategory::Daily)
+                .await
+                .unwrap()
+        );
+    }
+
     #[test]
     fn factory_sqlite() {
         let tmp = TempDir::new().unwrap();
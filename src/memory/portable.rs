@@ -0,0 +1,212 @@
+//! Backend-independent memory export/import.
+//!
+//! `export_memories` dumps every entry of the active backend to a JSONL file
+//! (one object per line); `import_memories` loads such a file (or a JSON
+//! array) into whatever backend is configured now. Used for backups and for
+//! moving between backends or machines (`zeroclaw memory export/import`).
+
+use super::traits::{Memory, MemoryCategory, MemoryEntry};
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// One exported memory. Timestamps are informational on import: backends
+/// stamp entries when they are stored.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExportedMemory {
+    pub key: String,
+    pub content: String,
+    #[serde(default = "default_category")]
+    pub category: MemoryCategory,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub session_id: Option<String>,
+}
+
+fn default_category() -> MemoryCategory {
+    MemoryCategory::Core
+}
+
+impl From<MemoryEntry> for ExportedMemory {
+    fn from(entry: MemoryEntry) -> Self {
+        Self {
+            key: entry.key,
+            content: entry.content,
+            category: entry.category,
+            timestamp: Some(entry.timestamp).filter(|t| !t.is_empty()),
+            session_id: entry.session_id,
+        }
+    }
+}
+
+/// Outcome of an import run.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ImportStats {
+    pub imported: usize,
+    pub overwritten: usize,
+    pub skipped_existing: usize,
+}
+
+/// Write every memory entry to `path` as JSONL. Returns the number exported.
+pub async fn export_memories(memory: &dyn Memory, path: &Path) -> Result<usize> {
+    let mut entries = memory.list(None, None).await?;
+    entries.sort_by(|a, b| a.key.cmp(&b.key));
+
+    let mut output = String::new();
+    for entry in &entries {
+        output.push_str(&serde_json::to_string(&ExportedMemory::from(
+            entry.clone(),
+        ))?);
+        output.push('\n');
+    }
+
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+    tokio::fs::write(path, output)
+        .await
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok(entries.len())
+}
+
+/// Parse an export file: JSONL, or a single JSON array of entries.
+pub fn parse_export(raw: &str) -> Result<Vec<ExportedMemory>> {
+    if raw.trim_start().starts_with('[') {
+        return serde_json::from_str(raw).context("Invalid memory export JSON array");
+    }
+
+    raw.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(idx, line)| {
+            serde_json::from_str(line)
+                .with_context(|| format!("Invalid memory export entry on line {}", idx + 1))
+        })
+        .collect()
+}
+
+/// Load entries from `path` into `memory`, de-duplicating by key: keys that
+/// already exist are skipped unless `overwrite` is set.
+pub async fn import_memories(
+    memory: &dyn Memory,
+    path: &Path,
+    overwrite: bool,
+) -> Result<ImportStats> {
+    let raw = tokio::fs::read_to_string(path)
+        .await
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    let entries = parse_export(&raw)?;
+
+    let mut stats = ImportStats::default();
+    for entry in entries {
+        let key = entry.key.trim();
+        if key.is_empty() {
+            continue;
+        }
+
+        if memory.get(key).await?.is_some() {
+            if !overwrite {
+                stats.skipped_existing += 1;
+                continue;
+            }
+            stats.overwritten += 1;
+        } else {
+            stats.imported += 1;
+        }
+
+        memory
+            .store(
+                key,
+                &entry.content,
+                entry.category,
+                entry.session_id.as_deref(),
+            )
+            .await?;
+    }
+    Ok(stats)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::SqliteMemory;
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn export_then_import_moves_entries_between_workspaces() {
+        let tmp = TempDir::new().unwrap();
+        let source = SqliteMemory::new(&tmp.path().join("source")).unwrap();
+        source
+            .store("lang", "Prefers Rust", MemoryCategory::Core, None)
+            .await
+            .unwrap();
+        source
+            .store("standup", "Fixed the build", MemoryCategory::Daily, None)
+            .await
+            .unwrap();
+
+        let export_path = tmp.path().join("backup/memories.jsonl");
+        let exported = export_memories(&source, &export_path).await.unwrap();
+        assert_eq!(exported, 2);
+
+        let target = SqliteMemory::new(&tmp.path().join("target")).unwrap();
+        let stats = import_memories(&target, &export_path, false).await.unwrap();
+        assert_eq!(stats.imported, 2);
+
+        let lang = target.get("lang").await.unwrap().unwrap();
+        assert_eq!(lang.content, "Prefers Rust");
+        assert_eq!(lang.category, MemoryCategory::Core);
+        let standup = target.get("standup").await.unwrap().unwrap();
+        assert_eq!(standup.category, MemoryCategory::Daily);
+    }
+
+    #[tokio::test]
+    async fn import_dedupes_by_key_unless_overwrite() {
+        let tmp = TempDir::new().unwrap();
+        let target = SqliteMemory::new(tmp.path()).unwrap();
+        target
+            .store("lang", "Prefers Go", MemoryCategory::Core, None)
+            .await
+            .unwrap();
+
+        let path = tmp.path().join("memories.json");
+        tokio::fs::write(
+            &path,
+            r#"[{"key":"lang","content":"Prefers Rust","category":"core"},{"key":"editor","content":"Helix"}]"#,
+        )
+        .await
+        .unwrap();
+
+        let stats = import_memories(&target, &path, false).await.unwrap();
+        assert_eq!(
+            stats,
+            ImportStats {
+                imported: 1,
+                overwritten: 0,
+                skipped_existing: 1
+            }
+        );
+        assert_eq!(
+            target.get("lang").await.unwrap().unwrap().content,
+            "Prefers Go"
+        );
+        assert_eq!(
+            target.get("editor").await.unwrap().unwrap().category,
+            MemoryCategory::Core
+        );
+
+        let stats = import_memories(&target, &path, true).await.unwrap();
+        assert_eq!(stats.overwritten, 2);
+        assert_eq!(
+            target.get("lang").await.unwrap().unwrap().content,
+            "Prefers Rust"
+        );
+    }
+
+    #[test]
+    fn parse_export_reports_bad_line() {
+        let err = parse_export("{\"key\":\"a\",\"content\":\"b\"}\nnot json\n").unwrap_err();
+        assert!(err.to_string().contains("line 2"));
+    }
+}
@@ -1,4 +1,4 @@
-use super::traits::{Memory, MemoryCategory, MemoryEntry};
+use super::traits::{Memory, MemoryCategory, MemoryEntry, MemoryStats};
 use anyhow::{Context, Result};
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
@@ -305,6 +305,39 @@ impl Memory for PostgresMemory {
         .await?
     }
 
+    async fn stats(&self) -> Result<MemoryStats> {
+        let client = self.client.clone();
+        let qualified_table = self.qualified_table.clone();
+
+        tokio::task::spawn_blocking(move || -> Result<MemoryStats> {
+            let mut client = client.lock();
+            let stmt = format!(
+                "
+                SELECT category, COUNT(*), COALESCE(SUM(octet_length(content)), 0)::BIGINT,
+                       MIN(created_at), MAX(created_at)
+                FROM {qualified_table}
+                GROUP BY category
+                "
+            );
+            let rows = client.query(&stmt, &[])?;
+            let rows = rows.iter().map(|row| {
+                let count: i64 = row.get(1);
+                let bytes: i64 = row.get(2);
+                let oldest: Option<DateTime<Utc>> = row.get(3);
+                let newest: Option<DateTime<Utc>> = row.get(4);
+                (
+                    Self::parse_category(&row.get::<_, String>(0)).to_string(),
+                    usize::try_from(count).unwrap_or(0),
+                    u64::try_from(bytes).unwrap_or(0),
+                    oldest.map(|ts| ts.to_rfc3339()),
+                    newest.map(|ts| ts.to_rfc3339()),
+                )
+            });
+            Ok(MemoryStats::from_category_rows(rows))
+        })
+        .await?
+    }
+
     async fn health_check(&self) -> bool {
         let client = self.client.clone();
         tokio::task::spawn_blocking(move || client.lock().simple_query("SELECT 1").is_ok())
@@ -1,5 +1,5 @@
 use super::embeddings::EmbeddingProvider;
-use super::traits::{Memory, MemoryCategory, MemoryEntry};
+use super::traits::{Memory, MemoryCategory, MemoryEntry, MemoryStats};
 use super::vector;
 use anyhow::Context;
 use async_trait::async_trait;
@@ -428,59 +428,15 @@ impl SqliteMemory {
 
         Ok(count)
     }
-}
-
-#[async_trait]
-impl Memory for SqliteMemory {
-    fn name(&self) -> &str {
-        "sqlite"
-    }
-
-    async fn store(
-        &self,
-        key: &str,
-        content: &str,
-        category: MemoryCategory,
-        session_id: Option<&str>,
-    ) -> anyhow::Result<()> {
-        // Compute embedding (async, before blocking work)
-        let embedding_bytes = self
-            .get_or_compute_embedding(content)
-            .await?
-            .map(|emb| vector::vec_to_bytes(&emb));
-
-        let conn = self.conn.clone();
-        let key = key.to_string();
-        let content = content.to_string();
-        let sid = session_id.map(String::from);
-
-        tokio::task::spawn_blocking(move || -> anyhow::Result<()> {
-            let conn = conn.lock();
-            let now = Local::now().to_rfc3339();
-            let cat = Self::category_to_str(&category);
-            let id = Uuid::new_v4().to_string();
-
-            conn.execute(
-                "INSERT INTO memories (id, key, content, category, embedding, created_at, updated_at, session_id)
-                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
-                 ON CONFLICT(key) DO UPDATE SET
-                    content = excluded.content,
-                    category = excluded.category,
-                    embedding = excluded.embedding,
-                    updated_at = excluded.updated_at,
-                    session_id = excluded.session_id",
-                params![id, key, content, cat, embedding_bytes, now, now, sid],
-            )?;
-            Ok(())
-        })
-        .await?
-    }
 
-    async fn recall(
+    /// Hybrid recall, optionally restricted to one category. With a category
+    /// filter the candidate pool is widened so filtering doesn't starve results.
+    async fn recall_filtered(
         &self,
         query: &str,
         limit: usize,
         session_id: Option<&str>,
+        category: Option<MemoryCategory>,
     ) -> anyhow::Result<Vec<MemoryEntry>> {
         if query.trim().is_empty() {
             return Ok(Vec::new());
@@ -494,17 +450,24 @@ impl Memory for SqliteMemory {
         let sid = session_id.map(String::from);
         let vector_weight = self.vector_weight;
         let keyword_weight = self.keyword_weight;
+        let fetch_limit = if category.is_some() {
+            limit.saturating_mul(4)
+        } else {
+            limit
+        };
 
         tokio::task::spawn_blocking(move || -> anyhow::Result<Vec<MemoryEntry>> {
             let conn = conn.lock();
             let session_ref = sid.as_deref();
 
             // FTS5 BM25 keyword search
-            let keyword_results = Self::fts5_search(&conn, &query, limit * 2).unwrap_or_default();
+            let keyword_results =
+                Self::fts5_search(&conn, &query, fetch_limit * 2).unwrap_or_default();
 
             // Vector similarity search (if embeddings available)
             let vector_results = if let Some(ref qe) = query_embedding {
-                Self::vector_search(&conn, qe, limit * 2, None, session_ref).unwrap_or_default()
+                Self::vector_search(&conn, qe, fetch_limit * 2, None, session_ref)
+                    .unwrap_or_default()
             } else {
                 Vec::new()
             };
@@ -526,7 +489,7 @@ impl Memory for SqliteMemory {
                     &keyword_results,
                     vector_weight,
                     keyword_weight,
-                    limit,
+                    fetch_limit,
                 )
             };
 
@@ -582,6 +545,9 @@ impl Memory for SqliteMemory {
                                 continue;
                             }
                         }
+                        if category.as_ref().is_some_and(|c| *c != entry.category) {
+                            continue;
+                        }
                         results.push(entry);
                     }
                 }
@@ -620,7 +586,7 @@ impl Memory for SqliteMemory {
                         param_values.push(Box::new(kw.clone()));
                     }
                     #[allow(clippy::cast_possible_wrap)]
-                    param_values.push(Box::new(limit as i64));
+                    param_values.push(Box::new(fetch_limit as i64));
                     let params_ref: Vec<&dyn rusqlite::types::ToSql> =
                         param_values.iter().map(AsRef::as_ref).collect();
                     let rows = stmt.query_map(params_ref.as_slice(), |row| {
@@ -641,6 +607,9 @@ impl Memory for SqliteMemory {
                                 continue;
                             }
                         }
+                        if category.as_ref().is_some_and(|c| *c != entry.category) {
+                            continue;
+                        }
                         results.push(entry);
                     }
                 }
@@ -651,6 +620,72 @@ impl Memory for SqliteMemory {
         })
         .await?
     }
+}
+
+#[async_trait]
+impl Memory for SqliteMemory {
+    fn name(&self) -> &str {
+        "sqlite"
+    }
+
+    async fn store(
+        &self,
+        key: &str,
+        content: &str,
+        category: MemoryCategory,
+        session_id: Option<&str>,
+    ) -> anyhow::Result<()> {
+        // Compute embedding (async, before blocking work)
+        let embedding_bytes = self
+            .get_or_compute_embedding(content)
+            .await?
+            .map(|emb| vector::vec_to_bytes(&emb));
+
+        let conn = self.conn.clone();
+        let key = key.to_string();
+        let content = content.to_string();
+        let sid = session_id.map(String::from);
+
+        tokio::task::spawn_blocking(move || -> anyhow::Result<()> {
+            let conn = conn.lock();
+            let now = Local::now().to_rfc3339();
+            let cat = Self::category_to_str(&category);
+            let id = Uuid::new_v4().to_string();
+
+            conn.execute(
+                "INSERT INTO memories (id, key, content, category, embedding, created_at, updated_at, session_id)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+                 ON CONFLICT(key) DO UPDATE SET
+                    content = excluded.content,
+                    category = excluded.category,
+                    embedding = excluded.embedding,
+                    updated_at = excluded.updated_at,
+                    session_id = excluded.session_id",
+                params![id, key, content, cat, embedding_bytes, now, now, sid],
+            )?;
+            Ok(())
+        })
+        .await?
+    }
+
+    async fn recall(
+        &self,
+        query: &str,
+        limit: usize,
+        session_id: Option<&str>,
+    ) -> anyhow::Result<Vec<MemoryEntry>> {
+        self.recall_filtered(query, limit, session_id, None).await
+    }
+
+    async fn recall_in(
+        &self,
+        query: &str,
+        category: &MemoryCategory,
+        limit: usize,
+    ) -> anyhow::Result<Vec<MemoryEntry>> {
+        self.recall_filtered(query, limit, None, Some(category.clone()))
+            .await
+    }
 
     async fn get(&self, key: &str) -> anyhow::Result<Option<MemoryEntry>> {
         let conn = self.conn.clone();
@@ -773,6 +808,34 @@ impl Memory for SqliteMemory {
         .await?
     }
 
+    async fn stats(&self) -> anyhow::Result<MemoryStats> {
+        let conn = self.conn.clone();
+
+        tokio::task::spawn_blocking(move || -> anyhow::Result<MemoryStats> {
+            let conn = conn.lock();
+            let mut stmt = conn.prepare(
+                "SELECT category, COUNT(*), COALESCE(SUM(LENGTH(CAST(content AS BLOB))), 0),
+                        MIN(created_at), MAX(created_at)
+                 FROM memories GROUP BY category",
+            )?;
+            let rows = stmt
+                .query_map([], |row| {
+                    let count: i64 = row.get(1)?;
+                    let bytes: i64 = row.get(2)?;
+                    Ok((
+                        Self::str_to_category(&row.get::<_, String>(0)?).to_string(),
+                        usize::try_from(count).unwrap_or(0),
+                        u64::try_from(bytes).unwrap_or(0),
+                        row.get::<_, Option<String>>(3)?,
+                        row.get::<_, Option<String>>(4)?,
+                    ))
+                })?
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(MemoryStats::from_category_rows(rows))
+        })
+        .await?
+    }
+
     async fn health_check(&self) -> bool {
         let conn = self.conn.clone();
         tokio::task::spawn_blocking(move || conn.lock().execute_batch("SELECT 1").is_ok())
@@ -859,6 +922,38 @@ mod tests {
             .all(|r| r.content.to_lowercase().contains("rust")));
     }
 
+    #[tokio::test]
+    async fn sqlite_recall_in_filters_by_category() {
+        let (_tmp, mem) = temp_sqlite();
+        mem.store("pref", "Rust is preferred", MemoryCategory::Core, None)
+            .await
+            .unwrap();
+        for i in 0..6 {
+            mem.store(
+                &format!("log{i}"),
+                &format!("Worked on Rust task {i}"),
+                MemoryCategory::Daily,
+                None,
+            )
+            .await
+            .unwrap();
+        }
+
+        let core = mem
+            .recall_in("Rust", &MemoryCategory::Core, 2)
+            .await
+            .unwrap();
+        assert_eq!(core.len(), 1);
+        assert_eq!(core[0].key, "pref");
+
+        let daily = mem
+            .recall_in("Rust", &MemoryCategory::Daily, 2)
+            .await
+            .unwrap();
+        assert_eq!(daily.len(), 2);
+        assert!(daily.iter().all(|e| e.category == MemoryCategory::Daily));
+    }
+
     #[tokio::test]
     async fn sqlite_recall_multi_keyword() {
         let (_tmp, mem) = temp_sqlite();
@@ -1897,4 +1992,30 @@ mod tests {
 
         assert_eq!(mem.count().await.unwrap(), 1);
     }
+
+    #[tokio::test]
+    async fn sqlite_stats_match_list_scan() {
+        let (_tmp, mem) = temp_sqlite();
+        assert_eq!(mem.stats().await.unwrap(), MemoryStats::default());
+
+        mem.store("a", "Rust is fast", MemoryCategory::Core, None)
+            .await
+            .unwrap();
+        mem.store("b", "standup notes", MemoryCategory::Daily, None)
+            .await
+            .unwrap();
+        mem.store("c", "héllo", MemoryCategory::Custom("misc".into()), None)
+            .await
+            .unwrap();
+
+        let stats = mem.stats().await.unwrap();
+        assert_eq!(stats.total, 3);
+        assert_eq!(stats.by_category.get("core"), Some(&1));
+        assert_eq!(stats.by_category.get("misc"), Some(&1));
+        assert_eq!(stats.content_bytes, 12 + 13 + 6);
+        assert!(stats.oldest <= stats.newest);
+
+        let entries = mem.list(None, None).await.unwrap();
+        assert_eq!(stats, MemoryStats::from_entries(&entries));
+    }
 }
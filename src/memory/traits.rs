@@ -1,5 +1,6 @@
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 /// A single memory entry
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -38,6 +39,59 @@ impl std::fmt::Display for MemoryCategory {
     }
 }
 
+/// Aggregate figures reported by `zeroclaw memory stats`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MemoryStats {
+    /// Total number of entries
+    pub total: usize,
+    /// Entry count per category name
+    pub by_category: BTreeMap<String, usize>,
+    /// Total size of stored content in bytes
+    pub content_bytes: u64,
+    /// Timestamp of the oldest entry
+    pub oldest: Option<String>,
+    /// Timestamp of the newest entry
+    pub newest: Option<String>,
+}
+
+impl MemoryStats {
+    /// Fold per-category rows (name, count, bytes, oldest, newest) into totals.
+    pub fn from_category_rows(
+        rows: impl IntoIterator<Item = (String, usize, u64, Option<String>, Option<String>)>,
+    ) -> Self {
+        let mut stats = Self::default();
+        for (category, count, bytes, oldest, newest) in rows {
+            stats.total += count;
+            stats.content_bytes += bytes;
+            *stats.by_category.entry(category).or_default() += count;
+            if let Some(ts) = oldest {
+                if stats.oldest.as_ref().is_none_or(|cur| ts < *cur) {
+                    stats.oldest = Some(ts);
+                }
+            }
+            if let Some(ts) = newest {
+                if stats.newest.as_ref().is_none_or(|cur| ts > *cur) {
+                    stats.newest = Some(ts);
+                }
+            }
+        }
+        stats
+    }
+
+    /// Compute stats by scanning full entries.
+    pub fn from_entries(entries: &[MemoryEntry]) -> Self {
+        Self::from_category_rows(entries.iter().map(|entry| {
+            (
+                entry.category.to_string(),
+                1,
+                entry.content.len() as u64,
+                Some(entry.timestamp.clone()),
+                Some(entry.timestamp.clone()),
+            )
+        }))
+    }
+}
+
 /// Core memory trait — implement for any persistence backend
 #[async_trait]
 pub trait Memory: Send + Sync {
@@ -61,6 +115,22 @@ pub trait Memory: Send + Sync {
         session_id: Option<&str>,
     ) -> anyhow::Result<Vec<MemoryEntry>>;
 
+    /// Recall memories matching a query within one category.
+    ///
+    /// The default over-fetches with [`Memory::recall`] and filters; backends
+    /// that can filter natively should override it.
+    async fn recall_in(
+        &self,
+        query: &str,
+        category: &MemoryCategory,
+        limit: usize,
+    ) -> anyhow::Result<Vec<MemoryEntry>> {
+        let mut entries = self.recall(query, limit.saturating_mul(4), None).await?;
+        entries.retain(|entry| entry.category == *category);
+        entries.truncate(limit);
+        Ok(entries)
+    }
+
     /// Get a specific memory by key
     async fn get(&self, key: &str) -> anyhow::Result<Option<MemoryEntry>>;
 
@@ -77,6 +147,15 @@ pub trait Memory: Send + Sync {
     /// Count total memories
     async fn count(&self) -> anyhow::Result<usize>;
 
+    /// Entry counts per category, content size and age range.
+    ///
+    /// The default scans [`Memory::list`]; backends that can aggregate
+    /// natively should override it.
+    async fn stats(&self) -> anyhow::Result<MemoryStats> {
+        let entries = self.list(None, None).await?;
+        Ok(MemoryStats::from_entries(&entries))
+    }
+
     /// Health check
     async fn health_check(&self) -> bool;
 }
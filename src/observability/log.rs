@@ -41,6 +41,25 @@ impl Observer for LogObserver {
             ObserverEvent::TurnComplete => {
                 info!("turn.complete");
             }
+            ObserverEvent::ToolBudgetExhausted { limit } => {
+                info!(limit = limit, "tool.budget_exhausted");
+            }
+            ObserverEvent::CostBudgetExceeded {
+                limit_usd,
+                spent_usd,
+            } => {
+                info!(
+                    limit_usd = limit_usd,
+                    spent_usd = spent_usd,
+                    "cost.budget_exceeded"
+                );
+            }
+            ObserverEvent::CacheHit { cache, model } => {
+                info!(cache = %cache, model = %model, "cache.hit");
+            }
+            ObserverEvent::ProviderCircuit { provider, state } => {
+                info!(provider = %provider, state = %state, "provider.circuit");
+            }
             ObserverEvent::ChannelMessage { channel, direction } => {
                 info!(channel = %channel, direction = %direction, "channel.message");
             }
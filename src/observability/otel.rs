@@ -186,7 +186,11 @@ impl Observer for OtelObserver {
             }
             ObserverEvent::LlmRequest { .. }
             | ObserverEvent::ToolCallStart { .. }
-            | ObserverEvent::TurnComplete => {}
+            | ObserverEvent::TurnComplete
+            | ObserverEvent::ToolBudgetExhausted { .. }
+            | ObserverEvent::CostBudgetExceeded { .. }
+            | ObserverEvent::CacheHit { .. }
+            | ObserverEvent::ProviderCircuit { .. } => {}
             ObserverEvent::LlmResponse {
                 provider,
                 model,
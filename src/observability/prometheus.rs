@@ -168,6 +168,10 @@ impl Observer for PrometheusObserver {
             }
             ObserverEvent::ToolCallStart { tool: _ }
             | ObserverEvent::TurnComplete
+            | ObserverEvent::ToolBudgetExhausted { .. }
+            | ObserverEvent::CostBudgetExceeded { .. }
+            | ObserverEvent::CacheHit { .. }
+            | ObserverEvent::ProviderCircuit { .. }
             | ObserverEvent::LlmRequest { .. }
             | ObserverEvent::LlmResponse { .. } => {}
             ObserverEvent::ToolCall {
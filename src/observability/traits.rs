@@ -42,6 +42,28 @@ pub enum ObserverEvent {
     },
     /// The agent produced a final answer for the current user message.
     TurnComplete,
+    /// An interactive session used up `agent.max_tool_calls_per_session`;
+    /// tools are withheld until the session is cleared.
+    ToolBudgetExhausted {
+        limit: usize,
+    },
+    /// Estimated spend reached `agent.max_cost_usd`; the run stopped before
+    /// the next provider call.
+    CostBudgetExceeded {
+        limit_usd: f64,
+        spent_usd: f64,
+    },
+    /// A turn was answered from a cache instead of calling the provider.
+    CacheHit {
+        cache: String,
+        model: String,
+    },
+    /// A provider's circuit breaker changed state (`open`, `half_open`,
+    /// `closed`).
+    ProviderCircuit {
+        provider: String,
+        state: String,
+    },
     ChannelMessage {
         channel: String,
         direction: String,
@@ -4,7 +4,8 @@ use crate::config::schema::{
 use crate::config::{
     AutonomyConfig, BrowserConfig, ChannelsConfig, ComposioConfig, Config, DiscordConfig,
     HeartbeatConfig, IMessageConfig, LarkConfig, MatrixConfig, MemoryConfig, ObservabilityConfig,
-    RuntimeConfig, SecretsConfig, SlackConfig, StorageConfig, TelegramConfig, WebhookConfig,
+    RuntimeConfig, SecretsConfig, SecurityConfig, SlackConfig, StorageConfig, TelegramConfig,
+    WebhookConfig,
 };
 use crate::hardware::{self, HardwareConfig};
 use crate::memory::{
@@ -171,10 +172,14 @@ pub async fn run_wizard() -> Result<Config> {
         gateway: crate::config::GatewayConfig::default(),
         composio: composio_config,
         secrets: secrets_config,
+        security: SecurityConfig::default(),
         browser: BrowserConfig::default(),
         http_request: crate::config::HttpRequestConfig::default(),
+        shell: crate::config::ShellConfig::default(),
+        moderation: crate::config::ModerationConfig::default(),
         web_search: crate::config::WebSearchConfig::default(),
         proxy: crate::config::ProxyConfig::default(),
+        azure_openai: crate::config::AzureOpenAiConfig::default(),
         identity: crate::config::IdentityConfig::default(),
         cost: crate::config::CostConfig::default(),
         peripherals: crate::config::PeripheralsConfig::default(),
@@ -308,6 +313,7 @@ fn memory_config_defaults_for_backend(backend: &str) -> MemoryConfig {
         vector_weight: 0.7,
         keyword_weight: 0.3,
         min_relevance_score: 0.4,
+        recall_limit: 5,
         embedding_cache_size: if profile.uses_sqlite_hygiene {
             10000
         } else {
@@ -389,10 +395,14 @@ pub async fn run_quick_setup(
         gateway: crate::config::GatewayConfig::default(),
         composio: ComposioConfig::default(),
         secrets: SecretsConfig::default(),
+        security: SecurityConfig::default(),
         browser: BrowserConfig::default(),
         http_request: crate::config::HttpRequestConfig::default(),
+        shell: crate::config::ShellConfig::default(),
+        moderation: crate::config::ModerationConfig::default(),
         web_search: crate::config::WebSearchConfig::default(),
         proxy: crate::config::ProxyConfig::default(),
+        azure_openai: crate::config::AzureOpenAiConfig::default(),
         identity: crate::config::IdentityConfig::default(),
         cost: crate::config::CostConfig::default(),
         peripherals: crate::config::PeripheralsConfig::default(),
@@ -2235,7 +2245,10 @@ fn setup_tool_mode() -> Result<(ComposioConfig, SecretsConfig)> {
         .default(true)
         .interact()?;
 
-    let secrets_config = SecretsConfig { encrypt };
+    let secrets_config = SecretsConfig {
+        encrypt,
+        refs: std::collections::HashMap::new(),
+    };
 
     if encrypt {
         println!(
@@ -2792,6 +2805,7 @@ fn setup_channels() -> Result<ChannelsConfig> {
                     stream_mode: StreamMode::default(),
                     draft_update_interval_ms: 1000,
                     mention_only: false,
+                    welcome_message: None,
                 });
             }
             1 => {
@@ -2891,6 +2905,7 @@ fn setup_channels() -> Result<ChannelsConfig> {
                     allowed_users,
                     listen_to_bots: false,
                     mention_only: false,
+                    welcome_message: None,
                 });
             }
             2 => {
@@ -3528,6 +3543,7 @@ fn setup_channels() -> Result<ChannelsConfig> {
                     } else {
                         Some(secret)
                     },
+                    signing_secret: None,
                 });
                 println!(
                     "  {} Webhook on port {}",
@@ -4254,7 +4270,10 @@ fn scaffold_workspace(workspace_dir: &Path, ctx: &ProjectContext) -> Result<()>
            - Don't use when: the answer is already in current files/conversation.\n\
          - **memory_forget** — Delete a memory entry\n\
            - Use when: memory is incorrect, stale, or explicitly requested to be removed.\n\
-           - Don't use when: uncertain about impact; verify before deleting.\n\n\
+           - Don't use when: uncertain about impact; verify before deleting.\n\
+         - **set_preference** — Set or remove a standing user preference\n\
+           - Use when: the user states how they always want things (language, units, tone, timezone).\n\
+           - Don't use when: the request only applies to the current task.\n\n\
          ---\n\
          *Add whatever helps you do your job. This is your cheat sheet.*\n";
 
@@ -6,7 +6,7 @@
 use anyhow::{Context, Result};
 use std::process::Command;
 
-/// ZeroClaw Arduino Uno base firmware (capabilities, gpio_read, gpio_write).
+/// ZeroClaw Arduino Uno base firmware (capabilities, gpio_read, gpio_write, i2c_scan).
 const FIRMWARE_INO: &str = include_str!("../../firmware/zeroclaw-arduino/zeroclaw-arduino.ino");
 
 const FQBN: &str = "arduino:avr:uno";
@@ -127,7 +127,7 @@ pub fn flash_arduino_firmware(port: &str) -> Result<()> {
     }
 
     println!("ZeroClaw firmware flashed successfully.");
-    println!("The Arduino now supports: capabilities, gpio_read, gpio_write.");
+    println!("The Arduino now supports: capabilities, gpio_read, gpio_write, i2c_scan.");
     Ok(())
 }
 
@@ -31,6 +31,10 @@ impl Tool for ArduinoUploadTool {
         "Generate Arduino sketch code and upload it to the connected Arduino. Use when: user asks to 'make a heart', 'blink LED', or run any custom pattern on Arduino. You MUST write the full .ino sketch code (setup + loop). Arduino Uno: pin 13 = built-in LED. Saves to temp dir, runs arduino-cli compile and upload. Requires arduino-cli installed."
     }
 
+    fn category(&self) -> &str {
+        "hardware"
+    }
+
     fn parameters_schema(&self) -> Value {
         json!({
             "type": "object",
@@ -55,6 +59,8 @@ impl Tool for ArduinoUploadTool {
                 success: false,
                 output: String::new(),
                 error: Some("Code cannot be empty".into()),
+                metadata: None,
+                content_type: None,
             });
         }
 
@@ -67,6 +73,8 @@ impl Tool for ArduinoUploadTool {
                     "arduino-cli not found. Install it: https://arduino.github.io/arduino-cli/"
                         .into(),
                 ),
+                metadata: None,
+                content_type: None,
             });
         }
 
@@ -80,6 +88,8 @@ impl Tool for ArduinoUploadTool {
                 success: false,
                 output: format!("Failed to create sketch dir: {}", e),
                 error: Some(e.to_string()),
+                metadata: None,
+                content_type: None,
             });
         }
 
@@ -89,6 +99,8 @@ impl Tool for ArduinoUploadTool {
                 success: false,
                 output: format!("Failed to write sketch: {}", e),
                 error: Some(e.to_string()),
+                metadata: None,
+                content_type: None,
             });
         }
 
@@ -108,6 +120,8 @@ impl Tool for ArduinoUploadTool {
                     success: false,
                     output: format!("arduino-cli compile failed: {}", e),
                     error: Some(e.to_string()),
+                    metadata: None,
+                    content_type: None,
                 });
             }
         };
@@ -119,6 +133,8 @@ impl Tool for ArduinoUploadTool {
                 success: false,
                 output: format!("Compile failed:\n{}", stderr),
                 error: Some("Arduino compile error".into()),
+                metadata: None,
+                content_type: None,
             });
         }
 
@@ -135,6 +151,8 @@ impl Tool for ArduinoUploadTool {
                     success: false,
                     output: format!("arduino-cli upload failed: {}", e),
                     error: Some(e.to_string()),
+                    metadata: None,
+                    content_type: None,
                 });
             }
         };
@@ -147,6 +165,8 @@ impl Tool for ArduinoUploadTool {
                 success: false,
                 output: format!("Upload failed:\n{}", stderr),
                 error: Some("Arduino upload error".into()),
+                metadata: None,
+                content_type: None,
             });
         }
 
@@ -156,6 +176,8 @@ impl Tool for ArduinoUploadTool {
                 "Sketch compiled and uploaded successfully. The Arduino is now running your code."
                     .into(),
             error: None,
+            metadata: None,
+            content_type: None,
         })
     }
 }
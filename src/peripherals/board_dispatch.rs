@@ -0,0 +1,263 @@
+//! Board dispatch — one tool per name across every connected board.
+//!
+//! Each connected board contributes its own `gpio_read`, `gpio_write`, etc.
+//! With several boards plugged in those names collide, so
+//! [`group_board_tools`] merges same-named tools into a single
+//! [`BoardDispatchTool`] that takes a `board` argument (board name or port)
+//! and forwards the call to the matching board's tool.
+
+use crate::tools::traits::{Tool, ToolResult};
+use async_trait::async_trait;
+use serde_json::Value;
+
+/// A connected board, as the model can refer to it.
+#[derive(Debug, Clone)]
+pub struct BoardTarget {
+    /// Board type from config (e.g. "nucleo-f401re").
+    pub board: String,
+    /// Serial port or transport path, if any (e.g. "/dev/ttyACM0").
+    pub path: Option<String>,
+}
+
+impl BoardTarget {
+    fn label(&self) -> String {
+        match &self.path {
+            Some(path) => format!("{} ({path})", self.board),
+            None => self.board.clone(),
+        }
+    }
+
+    fn matches(&self, selector: &str) -> bool {
+        self.board == selector || self.path.as_deref() == Some(selector)
+    }
+}
+
+/// Routes calls to the per-board tool selected by the `board` argument.
+pub struct BoardDispatchTool {
+    name: String,
+    description: String,
+    category: String,
+    schema: Value,
+    boards: Vec<(BoardTarget, Box<dyn Tool>)>,
+}
+
+impl BoardDispatchTool {
+    fn new(boards: Vec<(BoardTarget, Box<dyn Tool>)>) -> Self {
+        let first = &boards[0].1;
+        let name = first.name().to_string();
+        let description = first.description().to_string();
+        let category = first.category().to_string();
+        let mut schema = first.parameters_schema();
+        let labels: Vec<String> = boards.iter().map(|(t, _)| t.label()).collect();
+        if let Some(props) = schema.get_mut("properties").and_then(Value::as_object_mut) {
+            props.insert(
+                "board".into(),
+                serde_json::json!({
+                    "type": "string",
+                    "description": format!(
+                        "Target board name or port. Optional when only one board has this tool. Available: {}",
+                        labels.join(", ")
+                    )
+                }),
+            );
+        }
+        Self {
+            name,
+            description,
+            category,
+            schema,
+            boards,
+        }
+    }
+
+    fn select(&self, selector: Option<&str>) -> Result<&dyn Tool, String> {
+        let labels = || {
+            self.boards
+                .iter()
+                .map(|(t, _)| t.label())
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        let Some(selector) = selector else {
+            return match self.boards.as_slice() {
+                [(_, tool)] => Ok(tool.as_ref()),
+                _ => Err(format!(
+                    "Several boards support {}; pass 'board' as one of: {}",
+                    self.name,
+                    labels()
+                )),
+            };
+        };
+        let mut matching = self.boards.iter().filter(|(t, _)| t.matches(selector));
+        match (matching.next(), matching.next()) {
+            (Some((_, tool)), None) => Ok(tool.as_ref()),
+            (Some(_), Some(_)) => Err(format!(
+                "Board '{selector}' is ambiguous; use the port instead. Available: {}",
+                labels()
+            )),
+            (None, _) => Err(format!(
+                "No connected board '{selector}' supports {}. Available: {}",
+                self.name,
+                labels()
+            )),
+        }
+    }
+}
+
+#[async_trait]
+impl Tool for BoardDispatchTool {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn description(&self) -> &str {
+        &self.description
+    }
+
+    fn category(&self) -> &str {
+        &self.category
+    }
+
+    fn parameters_schema(&self) -> Value {
+        self.schema.clone()
+    }
+
+    async fn execute(&self, args: Value) -> anyhow::Result<ToolResult> {
+        let selector = args.get("board").and_then(Value::as_str);
+        match self.select(selector) {
+            Ok(tool) => tool.execute(args).await,
+            Err(error) => Ok(ToolResult {
+                success: false,
+                output: String::new(),
+                error: Some(error),
+                metadata: None,
+                content_type: None,
+            }),
+        }
+    }
+}
+
+/// Merge per-board tools into one [`BoardDispatchTool`] per tool name,
+/// keeping first-seen order.
+pub fn group_board_tools(per_board: Vec<(BoardTarget, Vec<Box<dyn Tool>>)>) -> Vec<Box<dyn Tool>> {
+    let mut groups: Vec<Vec<(BoardTarget, Box<dyn Tool>)>> = Vec::new();
+    for (target, tools) in per_board {
+        for tool in tools {
+            match groups.iter_mut().find(|g| g[0].1.name() == tool.name()) {
+                Some(group) => group.push((target.clone(), tool)),
+                None => groups.push(vec![(target.clone(), tool)]),
+            }
+        }
+    }
+    groups
+        .into_iter()
+        .map(|group| Box::new(BoardDispatchTool::new(group)) as Box<dyn Tool>)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct EchoTool(&'static str);
+
+    #[async_trait]
+    impl Tool for EchoTool {
+        fn name(&self) -> &str {
+            "gpio_read"
+        }
+
+        fn description(&self) -> &str {
+            "Read a pin"
+        }
+
+        fn parameters_schema(&self) -> Value {
+            serde_json::json!({"type": "object", "properties": {"pin": {"type": "integer"}}})
+        }
+
+        async fn execute(&self, _args: Value) -> anyhow::Result<ToolResult> {
+            Ok(ToolResult {
+                success: true,
+                output: self.0.into(),
+                error: None,
+                metadata: None,
+                content_type: None,
+            })
+        }
+    }
+
+    fn echo(output: &'static str) -> Vec<Box<dyn Tool>> {
+        vec![Box::new(EchoTool(output))]
+    }
+
+    fn target(board: &str, path: &str) -> BoardTarget {
+        BoardTarget {
+            board: board.into(),
+            path: Some(path.into()),
+        }
+    }
+
+    #[tokio::test]
+    async fn dispatches_by_board_name_or_port() {
+        let tools = group_board_tools(vec![
+            (target("nucleo-f401re", "/dev/ttyACM0"), echo("nucleo")),
+            (target("arduino-uno", "/dev/ttyUSB0"), echo("uno")),
+        ]);
+        assert_eq!(tools.len(), 1);
+        let tool = &tools[0];
+        assert!(
+            tool.parameters_schema()["properties"]["board"]["description"]
+                .as_str()
+                .unwrap()
+                .contains("arduino-uno (/dev/ttyUSB0)")
+        );
+
+        let by_name = tool
+            .execute(serde_json::json!({"pin": 13, "board": "arduino-uno"}))
+            .await
+            .unwrap();
+        assert_eq!(by_name.output, "uno");
+        let by_port = tool
+            .execute(serde_json::json!({"pin": 13, "board": "/dev/ttyACM0"}))
+            .await
+            .unwrap();
+        assert_eq!(by_port.output, "nucleo");
+
+        let ambiguous = tool.execute(serde_json::json!({"pin": 13})).await.unwrap();
+        assert!(!ambiguous.success);
+        assert!(ambiguous.error.unwrap().contains("pass 'board'"));
+        let unknown = tool
+            .execute(serde_json::json!({"pin": 13, "board": "esp32"}))
+            .await
+            .unwrap();
+        assert!(!unknown.success);
+    }
+
+    #[tokio::test]
+    async fn single_board_is_the_default_and_duplicate_types_need_a_port() {
+        let single = group_board_tools(vec![(
+            target("nucleo-f401re", "/dev/ttyACM0"),
+            echo("only"),
+        )]);
+        let result = single[0]
+            .execute(serde_json::json!({"pin": 5}))
+            .await
+            .unwrap();
+        assert_eq!(result.output, "only");
+
+        let twins = group_board_tools(vec![
+            (target("nucleo-f401re", "/dev/ttyACM0"), echo("a")),
+            (target("nucleo-f401re", "/dev/ttyACM1"), echo("b")),
+        ]);
+        let result = twins[0]
+            .execute(serde_json::json!({"board": "nucleo-f401re"}))
+            .await
+            .unwrap();
+        assert!(result.error.unwrap().contains("ambiguous"));
+        let result = twins[0]
+            .execute(serde_json::json!({"board": "/dev/ttyACM1"}))
+            .await
+            .unwrap();
+        assert_eq!(result.output, "b");
+    }
+}
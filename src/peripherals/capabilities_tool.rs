@@ -28,6 +28,10 @@ impl Tool for HardwareCapabilitiesTool {
         "Query connected hardware for reported GPIO pins and LED pin. Use when: user asks what pins are available."
     }
 
+    fn category(&self) -> &str {
+        "hardware"
+    }
+
     fn parameters_schema(&self) -> serde_json::Value {
         json!({
             "type": "object",
@@ -94,6 +98,8 @@ impl Tool for HardwareCapabilitiesTool {
             success: !outputs.is_empty(),
             output,
             error: None,
+            metadata: None,
+            content_type: None,
         })
     }
 }
@@ -0,0 +1,152 @@
+//! I2C bus scan tool — ask the board firmware which addresses respond.
+
+use super::serial::SerialTransport;
+use crate::tools::traits::{Tool, ToolResult};
+use async_trait::async_trait;
+use serde_json::json;
+use std::sync::Arc;
+
+/// Tool: scan the I2C bus of each connected serial board.
+pub struct HardwareScanI2cTool {
+    /// (board_name, transport) for each serial board.
+    boards: Vec<(String, Arc<SerialTransport>)>,
+}
+
+impl HardwareScanI2cTool {
+    pub(crate) fn new(boards: Vec<(String, Arc<SerialTransport>)>) -> Self {
+        Self { boards }
+    }
+}
+
+/// Best-effort chip names for common 7-bit I2C addresses. Many parts share
+/// addresses, so this is a hint, not an identification.
+fn guess_i2c_device(addr: u8) -> Option<&'static str> {
+    Some(match addr {
+        0x1E => "HMC5883L magnetometer",
+        0x20..=0x22 | 0x24..=0x26 => "PCF8574/MCP23017 I/O expander",
+        0x23 => "BH1750 light sensor or I/O expander",
+        0x27 => "PCF8574 LCD backpack or I/O expander",
+        0x29 => "VL53L0X distance / TSL2591 light sensor",
+        0x38 => "AHT10/AHT20 temperature-humidity sensor",
+        0x39 => "APDS-9960 gesture / TSL2561 light sensor",
+        0x3C | 0x3D => "SSD1306/SH1106 OLED display",
+        0x40 => "INA219 current sensor / PCA9685 PWM / HTU21D",
+        0x44 | 0x45 => "SHT3x temperature-humidity sensor",
+        0x48..=0x4B => "ADS1115 ADC / TMP102 temperature sensor",
+        0x50..=0x52 | 0x54..=0x56 => "AT24Cxx EEPROM",
+        0x53 => "ADXL345 accelerometer or EEPROM",
+        0x57 => "EEPROM (e.g. on DS3231 module)",
+        0x5A => "MLX90614 IR thermometer / CCS811 air quality",
+        0x60 => "MCP4725 DAC / Si5351 clock",
+        0x68 => "DS3231/DS1307 RTC or MPU-6050 IMU",
+        0x69 => "MPU-6050 IMU (AD0 high)",
+        0x76 => "BME280/BMP280 pressure sensor",
+        0x77 => "BME280/BMP280/BMP180 pressure sensor",
+        _ => return None,
+    })
+}
+
+/// Format the firmware's address list (`[60,118]`) one device per line.
+fn format_scan(board: &str, raw: &str) -> String {
+    let Ok(addrs) = serde_json::from_str::<Vec<u8>>(raw.trim()) else {
+        return format!("{board}: unexpected scan result: {raw}");
+    };
+    if addrs.is_empty() {
+        return format!("{board}: no I2C devices found");
+    }
+
+    let mut lines = vec![format!("{board}: {} I2C device(s)", addrs.len())];
+    for addr in addrs {
+        match guess_i2c_device(addr) {
+            Some(guess) => lines.push(format!("  0x{addr:02X} — likely {guess}")),
+            None => lines.push(format!("  0x{addr:02X} — unknown")),
+        }
+    }
+    lines.join("\n")
+}
+
+#[async_trait]
+impl Tool for HardwareScanI2cTool {
+    fn name(&self) -> &str {
+        "hardware_scan_i2c"
+    }
+
+    fn description(&self) -> &str {
+        "Scan the I2C bus on connected hardware and list responding addresses with likely chip names. Use when: starting sensor work or checking what is wired to the board."
+    }
+
+    fn category(&self) -> &str {
+        "hardware"
+    }
+
+    fn parameters_schema(&self) -> serde_json::Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "board": {
+                    "type": "string",
+                    "description": "Optional board name. If omitted, scans all."
+                }
+            }
+        })
+    }
+
+    async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
+        let filter = args.get("board").and_then(|v| v.as_str());
+        let mut outputs = Vec::new();
+
+        for (board_name, transport) in &self.boards {
+            if filter.is_some_and(|b| b != board_name) {
+                continue;
+            }
+            match transport.i2c_scan().await {
+                Ok(result) if result.success => {
+                    outputs.push(format_scan(board_name, &result.output));
+                }
+                Ok(result) => outputs.push(format!(
+                    "{}: {}",
+                    board_name,
+                    result
+                        .error
+                        .as_deref()
+                        .unwrap_or("I2C scan not supported by firmware")
+                )),
+                Err(e) => outputs.push(format!("{board_name}: error - {e}")),
+            }
+        }
+
+        let output = if outputs.is_empty() {
+            "No matching serial board configured.".to_string()
+        } else {
+            outputs.join("\n")
+        };
+
+        Ok(ToolResult {
+            success: !outputs.is_empty(),
+            output,
+            error: None,
+            metadata: None,
+            content_type: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_scan_lists_addresses_with_guesses() {
+        let out = format_scan("arduino-uno", "[60,118,17]");
+        assert!(out.starts_with("arduino-uno: 3 I2C device(s)"));
+        assert!(out.contains("0x3C — likely SSD1306/SH1106 OLED display"));
+        assert!(out.contains("0x76 — likely BME280/BMP280 pressure sensor"));
+        assert!(out.contains("0x11 — unknown"));
+    }
+
+    #[test]
+    fn format_scan_handles_empty_and_garbage() {
+        assert_eq!(format_scan("uno", "[]"), "uno: no I2C devices found");
+        assert!(format_scan("uno", "oops").contains("unexpected scan result"));
+    }
+}
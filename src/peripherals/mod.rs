@@ -13,8 +13,12 @@ pub mod arduino_flash;
 #[cfg(feature = "hardware")]
 pub mod arduino_upload;
 #[cfg(feature = "hardware")]
+pub mod board_dispatch;
+#[cfg(feature = "hardware")]
 pub mod capabilities_tool;
 #[cfg(feature = "hardware")]
+pub mod i2c_scan_tool;
+#[cfg(feature = "hardware")]
 pub mod nucleo_flash;
 #[cfg(feature = "hardware")]
 pub mod uno_q_bridge;
@@ -134,6 +138,17 @@ pub async fn handle_command(cmd: crate::PeripheralCommands, config: &Config) ->
     Ok(())
 }
 
+/// How to get the companion firmware onto a board, for startup warnings.
+#[cfg(feature = "hardware")]
+fn firmware_flash_hint(board: &str) -> &'static str {
+    match board {
+        "nucleo-f401re" | "nucleo" => "run `zeroclaw peripheral flash-nucleo`",
+        "arduino-uno" | "uno" => "run `zeroclaw peripheral flash`",
+        "esp32" => "see firmware/zeroclaw-esp32/README.md",
+        _ => "see the firmware/ directory",
+    }
+}
+
 /// Create and connect peripherals from config, returning their tools.
 /// Returns empty vec if peripherals disabled or hardware feature off.
 #[cfg(feature = "hardware")]
@@ -143,14 +158,25 @@ pub async fn create_peripheral_tools(config: &PeripheralsConfig) -> Result<Vec<B
     }
 
     let mut tools: Vec<Box<dyn Tool>> = Vec::new();
+    // Per-board tools (gpio_read, ...) are merged by name below so several
+    // boards can be connected at once.
+    let mut per_board: Vec<(board_dispatch::BoardTarget, Vec<Box<dyn Tool>>)> = Vec::new();
     let mut serial_transports: Vec<(String, std::sync::Arc<serial::SerialTransport>)> = Vec::new();
 
     for board in &config.boards {
+        let target = board_dispatch::BoardTarget {
+            board: board.board.clone(),
+            path: board.path.clone(),
+        };
+
         // Arduino Uno Q: Bridge transport (socket to local Bridge app)
         if board.transport == "bridge" && (board.board == "arduino-uno-q" || board.board == "uno-q")
         {
-            tools.push(Box::new(uno_q_bridge::UnoQGpioReadTool));
-            tools.push(Box::new(uno_q_bridge::UnoQGpioWriteTool));
+            let bridge_tools: Vec<Box<dyn Tool>> = vec![
+                Box::new(uno_q_bridge::UnoQGpioReadTool),
+                Box::new(uno_q_bridge::UnoQGpioWriteTool),
+            ];
+            per_board.push((target, bridge_tools));
             tracing::info!(board = %board.board, "Uno Q Bridge GPIO tools added");
             continue;
         }
@@ -162,7 +188,7 @@ pub async fn create_peripheral_tools(config: &PeripheralsConfig) -> Result<Vec<B
         {
             match rpi::RpiGpioPeripheral::connect_from_config(board).await {
                 Ok(peripheral) => {
-                    tools.extend(peripheral.tools());
+                    per_board.push((target, peripheral.tools()));
                     tracing::info!(board = %board.board, "RPi GPIO peripheral connected");
                 }
                 Err(e) => {
@@ -187,16 +213,28 @@ pub async fn create_peripheral_tools(config: &PeripheralsConfig) -> Result<Vec<B
                 if p.connect().await.is_err() {
                     tracing::warn!("Peripheral {} connect warning (continuing)", p.name());
                 }
+                // An open port only proves the device exists; GPIO tools need
+                // the companion firmware, so don't register them without it.
+                if !p.transport().probe_firmware().await {
+                    tracing::warn!(
+                        "Board {} present at {} but firmware not detected — flash zeroclaw-firmware ({}); skipping its tools",
+                        board.board,
+                        board.path.as_deref().unwrap_or("?"),
+                        firmware_flash_hint(&board.board)
+                    );
+                    continue;
+                }
                 serial_transports.push((board.board.clone(), p.transport()));
-                tools.extend(p.tools());
+                let mut board_tools = p.tools();
                 if board.board == "arduino-uno" {
                     if let Some(ref path) = board.path {
-                        tools.push(Box::new(arduino_upload::ArduinoUploadTool::new(
+                        board_tools.push(Box::new(arduino_upload::ArduinoUploadTool::new(
                             path.clone(),
                         )));
                         tracing::info!("Arduino upload tool added (port: {})", path);
                     }
                 }
+                per_board.push((target, board_tools));
                 tracing::info!(board = %board.board, "Serial peripheral connected");
             }
             Err(e) => {
@@ -205,6 +243,8 @@ pub async fn create_peripheral_tools(config: &PeripheralsConfig) -> Result<Vec<B
         }
     }
 
+    tools.extend(board_dispatch::group_board_tools(per_board));
+
     // Phase B: Add hardware tools when any boards configured
     if !tools.is_empty() {
         let board_names: Vec<String> = config.boards.iter().map(|b| b.board.clone()).collect();
@@ -219,6 +259,9 @@ pub async fn create_peripheral_tools(config: &PeripheralsConfig) -> Result<Vec<B
 
     // Phase C: Add hardware_capabilities tool when any serial boards
     if !serial_transports.is_empty() {
+        tools.push(Box::new(i2c_scan_tool::HardwareScanI2cTool::new(
+            serial_transports.clone(),
+        )));
         tools.push(Box::new(capabilities_tool::HardwareCapabilitiesTool::new(
             serial_transports,
         )));
@@ -73,6 +73,10 @@ impl Tool for RpiGpioReadTool {
         "Read the value (0 or 1) of a GPIO pin on Raspberry Pi. Uses BCM pin numbers (e.g. 17, 27)."
     }
 
+    fn category(&self) -> &str {
+        "hardware"
+    }
+
     fn parameters_schema(&self) -> Value {
         json!({
             "type": "object",
@@ -107,6 +111,8 @@ impl Tool for RpiGpioReadTool {
             success: true,
             output: format!("pin {} = {}", pin, value),
             error: None,
+            metadata: None,
+            content_type: None,
         })
     }
 }
@@ -124,6 +130,10 @@ impl Tool for RpiGpioWriteTool {
         "Set a GPIO pin high (1) or low (0) on Raspberry Pi. Uses BCM pin numbers."
     }
 
+    fn category(&self) -> &str {
+        "hardware"
+    }
+
     fn parameters_schema(&self) -> Value {
         json!({
             "type": "object",
@@ -168,6 +178,8 @@ impl Tool for RpiGpioWriteTool {
             success: true,
             output: format!("pin {} = {}", pin, value),
             error: None,
+            metadata: None,
+            content_type: None,
         })
     }
 }
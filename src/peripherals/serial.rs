@@ -71,6 +71,11 @@ pub(crate) struct SerialTransport {
 /// Timeout for serial request/response (seconds).
 const SERIAL_TIMEOUT_SECS: u64 = 5;
 
+/// Startup firmware probe: a few short pings, since boards like the Uno
+/// reset when the port opens and drop anything sent during the bootloader.
+const FIRMWARE_PROBE_ATTEMPTS: u32 = 3;
+const FIRMWARE_PROBE_TIMEOUT_SECS: u64 = 2;
+
 impl SerialTransport {
     async fn request(&self, cmd: &str, args: Value) -> anyhow::Result<ToolResult> {
         let mut port = self.port.lock().await;
@@ -94,6 +99,8 @@ impl SerialTransport {
             success: ok,
             output: result,
             error,
+            metadata: None,
+            content_type: None,
         })
     }
 
@@ -101,6 +108,29 @@ impl SerialTransport {
     pub async fn capabilities(&self) -> anyhow::Result<ToolResult> {
         self.request("capabilities", json!({})).await
     }
+
+    /// Ask the firmware to scan its I2C bus; result is a JSON array of addresses.
+    pub async fn i2c_scan(&self) -> anyhow::Result<ToolResult> {
+        self.request("i2c_scan", json!({})).await
+    }
+
+    /// True when the ZeroClaw firmware answers `ping`.
+    pub(crate) async fn probe_firmware(&self) -> bool {
+        let mut port = self.port.lock().await;
+        for _ in 0..FIRMWARE_PROBE_ATTEMPTS {
+            let resp = tokio::time::timeout(
+                std::time::Duration::from_secs(FIRMWARE_PROBE_TIMEOUT_SECS),
+                send_request(&mut port, "ping", json!({})),
+            )
+            .await;
+            if let Ok(Ok(resp)) = resp {
+                if resp["ok"].as_bool().unwrap_or(false) {
+                    return true;
+                }
+            }
+        }
+        false
+    }
 }
 
 /// Serial peripheral for STM32, Arduino, etc. over USB CDC.
@@ -203,6 +233,10 @@ impl Tool for GpioReadTool {
         "Read the value (0 or 1) of a GPIO pin on a connected peripheral (e.g. STM32 Nucleo)"
     }
 
+    fn category(&self) -> &str {
+        "hardware"
+    }
+
     fn parameters_schema(&self) -> Value {
         json!({
             "type": "object",
@@ -242,6 +276,10 @@ impl Tool for GpioWriteTool {
         "Set a GPIO pin high (1) or low (0) on a connected peripheral (e.g. turn on/off LED)"
     }
 
+    fn category(&self) -> &str {
+        "hardware"
+    }
+
     fn parameters_schema(&self) -> Value {
         json!({
             "type": "object",
@@ -43,6 +43,10 @@ impl Tool for UnoQGpioReadTool {
         "Read GPIO pin value (0 or 1) on Arduino Uno Q. Requires zeroclaw-uno-q-bridge app running."
     }
 
+    fn category(&self) -> &str {
+        "hardware"
+    }
+
     fn parameters_schema(&self) -> Value {
         json!({
             "type": "object",
@@ -68,12 +72,16 @@ impl Tool for UnoQGpioReadTool {
                         success: false,
                         output: resp.clone(),
                         error: Some(resp),
+                        metadata: None,
+                        content_type: None,
                     })
                 } else {
                     Ok(ToolResult {
                         success: true,
                         output: resp,
                         error: None,
+                        metadata: None,
+                        content_type: None,
                     })
                 }
             }
@@ -81,6 +89,8 @@ impl Tool for UnoQGpioReadTool {
                 success: false,
                 output: format!("Bridge error: {}", e),
                 error: Some(e.to_string()),
+                metadata: None,
+                content_type: None,
             }),
         }
     }
@@ -99,6 +109,10 @@ impl Tool for UnoQGpioWriteTool {
         "Set GPIO pin high (1) or low (0) on Arduino Uno Q. Requires zeroclaw-uno-q-bridge app running."
     }
 
+    fn category(&self) -> &str {
+        "hardware"
+    }
+
     fn parameters_schema(&self) -> Value {
         json!({
             "type": "object",
@@ -132,12 +146,16 @@ impl Tool for UnoQGpioWriteTool {
                         success: false,
                         output: resp.clone(),
                         error: Some(resp),
+                        metadata: None,
+                        content_type: None,
                     })
                 } else {
                     Ok(ToolResult {
                         success: true,
                         output: "done".into(),
                         error: None,
+                        metadata: None,
+                        content_type: None,
                     })
                 }
             }
@@ -145,6 +163,8 @@ impl Tool for UnoQGpioWriteTool {
                 success: false,
                 output: format!("Bridge error: {}", e),
                 error: Some(e.to_string()),
+                metadata: None,
+                content_type: None,
             }),
         }
     }
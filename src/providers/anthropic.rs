@@ -51,6 +51,10 @@ struct NativeChatRequest {
     temperature: f64,
     #[serde(skip_serializing_if = "Option::is_none")]
     tools: Option<Vec<NativeToolSpec>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    top_p: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stop_sequences: Option<Vec<String>>,
 }
 
 #[derive(Debug, Serialize)]
@@ -439,7 +443,7 @@ impl Provider for AnthropicProvider {
 
         request = self.apply_auth(request, credential);
 
-        let response = request.send().await?;
+        let response = request.send().await.map_err(super::ProviderError::from)?;
 
         if !response.status().is_success() {
             return Err(super::api_error("Anthropic", response).await);
@@ -468,13 +472,16 @@ impl Provider for AnthropicProvider {
             Self::apply_cache_to_last_message(&mut messages);
         }
 
+        let params = request.params.cloned().unwrap_or_default();
         let native_request = NativeChatRequest {
             model: model.to_string(),
-            max_tokens: 4096,
+            max_tokens: params.max_tokens.unwrap_or(4096),
             system: system_prompt,
             messages,
-            temperature,
+            temperature: params.temperature_or(temperature),
             tools: Self::convert_tools(request.tools),
+            top_p: params.top_p,
+            stop_sequences: params.stop_sequences(),
         };
 
         let req = self
@@ -484,7 +491,11 @@ impl Provider for AnthropicProvider {
             .header("content-type", "application/json")
             .json(&native_request);
 
-        let response = self.apply_auth(req, credential).send().await?;
+        let response = self
+            .apply_auth(req, credential)
+            .send()
+            .await
+            .map_err(super::ProviderError::from)?;
         if !response.status().is_success() {
             return Err(super::api_error("Anthropic", response).await);
         }
@@ -879,14 +890,17 @@ mod tests {
             ChatMessage {
                 role: "system".to_string(),
                 content: "System prompt".to_string(),
+                pinned: false,
             },
             ChatMessage {
                 role: "user".to_string(),
                 content: "Hello".to_string(),
+                pinned: false,
             },
             ChatMessage {
                 role: "assistant".to_string(),
                 content: "Hi".to_string(),
+                pinned: false,
             },
         ];
         // Only 2 non-system messages
@@ -898,12 +912,14 @@ mod tests {
         let mut messages = vec![ChatMessage {
             role: "system".to_string(),
             content: "System prompt".to_string(),
+            pinned: false,
         }];
         // Add 5 non-system messages
         for i in 0..5 {
             messages.push(ChatMessage {
                 role: if i % 2 == 0 { "user" } else { "assistant" }.to_string(),
                 content: format!("Message {i}"),
+                pinned: false,
             });
         }
         assert!(AnthropicProvider::should_cache_conversation(&messages));
@@ -917,6 +933,7 @@ mod tests {
             messages.push(ChatMessage {
                 role: if i % 2 == 0 { "user" } else { "assistant" }.to_string(),
                 content: format!("Message {i}"),
+                pinned: false,
             });
         }
         assert!(!AnthropicProvider::should_cache_conversation(&messages));
@@ -925,6 +942,7 @@ mod tests {
         messages.push(ChatMessage {
             role: "user".to_string(),
             content: "One more".to_string(),
+            pinned: false,
         });
         assert!(AnthropicProvider::should_cache_conversation(&messages));
     }
@@ -1042,6 +1060,7 @@ mod tests {
         let messages = vec![ChatMessage {
             role: "system".to_string(),
             content: "Short system prompt".to_string(),
+            pinned: false,
         }];
 
         let (system_prompt, _) = AnthropicProvider::convert_messages(&messages);
@@ -1060,6 +1079,7 @@ mod tests {
         let messages = vec![ChatMessage {
             role: "system".to_string(),
             content: large_content.clone(),
+            pinned: false,
         }];
 
         let (system_prompt, _) = AnthropicProvider::convert_messages(&messages);
@@ -1090,10 +1110,14 @@ mod tests {
             }],
             temperature: 0.7,
             tools: None,
+            top_p: None,
+            stop_sequences: None,
         };
 
         let json = serde_json::to_string(&req).unwrap();
         assert!(!json.contains("cache_control"));
+        assert!(!json.contains("top_p"));
+        assert!(!json.contains("stop_sequences"));
         assert!(json.contains(r#""system":"System""#));
     }
 
@@ -0,0 +1,301 @@
+use crate::config::AzureOpenAiConfig;
+use crate::providers::openai::{
+    NativeChatRequest, NativeChatResponse, NativeToolSpec, OpenAiProvider,
+};
+use crate::providers::traits::{
+    ChatMessage, ChatRequest as ProviderChatRequest, ChatResponse as ProviderChatResponse, Provider,
+};
+use async_trait::async_trait;
+use reqwest::Client;
+use std::collections::HashMap;
+
+/// Default `api-version` query parameter (latest GA chat completions API).
+pub const DEFAULT_AZURE_OPENAI_API_VERSION: &str = "2024-10-21";
+
+/// Azure OpenAI Service provider.
+///
+/// Azure speaks the OpenAI Chat Completions wire format, but addresses
+/// models by *deployment name* in the URL path
+/// (`/openai/deployments/<name>/chat/completions?api-version=...`) and
+/// authenticates with an `api-key` header instead of a bearer token.
+pub struct AzureOpenAiProvider {
+    endpoint: Option<String>,
+    api_version: String,
+    deployments: HashMap<String, String>,
+    credential: Option<String>,
+}
+
+impl AzureOpenAiProvider {
+    /// Build from config. The endpoint resolves from `config.endpoint`, then
+    /// the top-level `api_url` override.
+    pub fn new(
+        config: &AzureOpenAiConfig,
+        api_url: Option<&str>,
+        credential: Option<&str>,
+    ) -> Self {
+        let endpoint = config
+            .endpoint
+            .as_deref()
+            .or(api_url)
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(|value| value.trim_end_matches('/').to_string());
+        let api_version = if config.api_version.trim().is_empty() {
+            DEFAULT_AZURE_OPENAI_API_VERSION.to_string()
+        } else {
+            config.api_version.trim().to_string()
+        };
+
+        Self {
+            endpoint,
+            api_version,
+            deployments: config.deployments.clone(),
+            credential: credential.map(ToString::to_string),
+        }
+    }
+
+    /// Map a model id to its deployment name. Unmapped models are assumed to
+    /// be deployed under their own name.
+    fn deployment_for<'a>(&'a self, model: &'a str) -> &'a str {
+        self.deployments
+            .get(model)
+            .map(String::as_str)
+            .unwrap_or(model)
+    }
+
+    fn chat_completions_url(&self, model: &str) -> anyhow::Result<String> {
+        let endpoint = self.endpoint.as_deref().ok_or_else(|| {
+            anyhow::anyhow!(
+                "Azure OpenAI endpoint not set. Set [azure_openai].endpoint, api_url, or AZURE_OPENAI_ENDPOINT."
+            )
+        })?;
+        Ok(format!(
+            "{endpoint}/openai/deployments/{}/chat/completions?api-version={}",
+            self.deployment_for(model),
+            self.api_version
+        ))
+    }
+
+    fn credential(&self) -> anyhow::Result<&str> {
+        self.credential.as_deref().ok_or_else(|| {
+            anyhow::anyhow!(
+                "Azure OpenAI API key not set. Set AZURE_OPENAI_API_KEY or edit config.toml."
+            )
+        })
+    }
+
+    fn http_client(&self) -> Client {
+        crate::config::build_runtime_proxy_client_with_timeouts("provider.azure", 120, 10)
+    }
+
+    async fn send(
+        &self,
+        request: &NativeChatRequest,
+        model: &str,
+    ) -> anyhow::Result<ProviderChatResponse> {
+        let credential = self.credential()?;
+        let url = self.chat_completions_url(model)?;
+
+        let response = self
+            .http_client()
+            .post(url)
+            .header("api-key", credential)
+            .json(request)
+            .send()
+            .await
+            .map_err(super::ProviderError::from)?;
+
+        if !response.status().is_success() {
+            return Err(super::api_error("Azure OpenAI", response).await);
+        }
+
+        let native_response: NativeChatResponse = response.json().await?;
+        let message = native_response
+            .choices
+            .into_iter()
+            .next()
+            .map(|c| c.message)
+            .ok_or_else(|| anyhow::anyhow!("No response from Azure OpenAI"))?;
+        Ok(OpenAiProvider::parse_native_response(message))
+    }
+}
+
+#[async_trait]
+impl Provider for AzureOpenAiProvider {
+    async fn chat_with_system(
+        &self,
+        system_prompt: Option<&str>,
+        message: &str,
+        model: &str,
+        temperature: f64,
+    ) -> anyhow::Result<String> {
+        let mut messages = Vec::new();
+        if let Some(sys) = system_prompt {
+            messages.push(ChatMessage::system(sys));
+        }
+        messages.push(ChatMessage::user(message));
+        self.chat_with_history(&messages, model, temperature).await
+    }
+
+    async fn chat_with_history(
+        &self,
+        messages: &[ChatMessage],
+        model: &str,
+        temperature: f64,
+    ) -> anyhow::Result<String> {
+        let request = NativeChatRequest {
+            model: model.to_string(),
+            messages: OpenAiProvider::convert_messages(messages),
+            temperature,
+            tools: None,
+            tool_choice: None,
+            top_p: None,
+            max_tokens: None,
+            stop: None,
+        };
+        let response = self.send(&request, model).await?;
+        Ok(response.text.unwrap_or_default())
+    }
+
+    async fn chat(
+        &self,
+        request: ProviderChatRequest<'_>,
+        model: &str,
+        temperature: f64,
+    ) -> anyhow::Result<ProviderChatResponse> {
+        let tools = OpenAiProvider::convert_tools(request.tools);
+        let params = request.params.cloned().unwrap_or_default();
+        let native_request = NativeChatRequest {
+            model: model.to_string(),
+            messages: OpenAiProvider::convert_messages(request.messages),
+            temperature: params.temperature_or(temperature),
+            tool_choice: tools.as_ref().map(|_| "auto".to_string()),
+            tools,
+            top_p: params.top_p,
+            max_tokens: params.max_tokens,
+            stop: params.stop_sequences(),
+        };
+        self.send(&native_request, model).await
+    }
+
+    fn supports_native_tools(&self) -> bool {
+        true
+    }
+
+    async fn chat_with_tools(
+        &self,
+        messages: &[ChatMessage],
+        tools: &[serde_json::Value],
+        model: &str,
+        temperature: f64,
+    ) -> anyhow::Result<ProviderChatResponse> {
+        let native_tools: Option<Vec<NativeToolSpec>> = if tools.is_empty() {
+            None
+        } else {
+            Some(
+                tools
+                    .iter()
+                    .cloned()
+                    .map(serde_json::from_value::<NativeToolSpec>)
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(|e| anyhow::anyhow!("Invalid Azure OpenAI tool specification: {e}"))?,
+            )
+        };
+
+        let native_request = NativeChatRequest {
+            model: model.to_string(),
+            messages: OpenAiProvider::convert_messages(messages),
+            temperature,
+            tool_choice: native_tools.as_ref().map(|_| "auto".to_string()),
+            tools: native_tools,
+            top_p: None,
+            max_tokens: None,
+            stop: None,
+        };
+        self.send(&native_request, model).await
+    }
+
+    async fn warmup(&self) -> anyhow::Result<()> {
+        if let (Some(endpoint), Some(credential)) =
+            (self.endpoint.as_deref(), self.credential.as_deref())
+        {
+            self.http_client()
+                .get(format!(
+                    "{endpoint}/openai/models?api-version={}",
+                    self.api_version
+                ))
+                .header("api-key", credential)
+                .send()
+                .await?
+                .error_for_status()?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_config() -> AzureOpenAiConfig {
+        let mut deployments = HashMap::new();
+        deployments.insert("gpt-4o".to_string(), "prod-gpt4o".to_string());
+        AzureOpenAiConfig {
+            endpoint: Some("https://my-resource.openai.azure.com/".into()),
+            api_version: "2024-06-01".into(),
+            deployments,
+        }
+    }
+
+    #[test]
+    fn url_uses_mapped_deployment_and_api_version() {
+        let p = AzureOpenAiProvider::new(&sample_config(), None, Some("azure-test-credential"));
+        assert_eq!(
+            p.chat_completions_url("gpt-4o").unwrap(),
+            "https://my-resource.openai.azure.com/openai/deployments/prod-gpt4o/chat/completions?api-version=2024-06-01"
+        );
+    }
+
+    #[test]
+    fn unmapped_model_is_used_as_deployment_name() {
+        let p = AzureOpenAiProvider::new(&sample_config(), None, None);
+        assert!(p
+            .chat_completions_url("gpt-4o-mini")
+            .unwrap()
+            .contains("/deployments/gpt-4o-mini/"));
+    }
+
+    #[test]
+    fn endpoint_falls_back_to_api_url_and_default_version() {
+        let config = AzureOpenAiConfig {
+            endpoint: None,
+            api_version: String::new(),
+            deployments: HashMap::new(),
+        };
+        let p = AzureOpenAiProvider::new(&config, Some("https://fallback.openai.azure.com"), None);
+        let url = p.chat_completions_url("m").unwrap();
+        assert!(url.starts_with("https://fallback.openai.azure.com/openai/deployments/m/"));
+        assert!(url.ends_with(&format!("api-version={DEFAULT_AZURE_OPENAI_API_VERSION}")));
+    }
+
+    #[test]
+    fn missing_endpoint_errors() {
+        let p = AzureOpenAiProvider::new(&AzureOpenAiConfig::default(), None, None);
+        let err = p.chat_completions_url("gpt-4o").unwrap_err();
+        assert!(err.to_string().contains("endpoint not set"));
+    }
+
+    #[tokio::test]
+    async fn chat_fails_without_key() {
+        let p = AzureOpenAiProvider::new(&sample_config(), None, None);
+        let result = p.chat_with_system(None, "hello", "gpt-4o", 0.7).await;
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("API key not set"));
+    }
+
+    #[tokio::test]
+    async fn warmup_without_key_is_noop() {
+        let p = AzureOpenAiProvider::new(&sample_config(), None, None);
+        assert!(p.warmup().await.is_ok());
+    }
+}
@@ -263,6 +263,10 @@ enum SystemBlock {
 struct InferenceConfig {
     max_tokens: u32,
     temperature: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    top_p: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stop_sequences: Option<Vec<String>>,
 }
 
 #[derive(Debug, Serialize)]
@@ -621,7 +625,11 @@ impl BedrockProvider {
             request = request.header("x-amz-security-token", token);
         }
 
-        let response: reqwest::Response = request.body(payload).send().await?;
+        let response: reqwest::Response = request
+            .body(payload)
+            .send()
+            .await
+            .map_err(super::ProviderError::from)?;
 
         if !response.status().is_success() {
             return Err(super::api_error("Bedrock", response).await);
@@ -694,6 +702,8 @@ impl Provider for BedrockProvider {
             inference_config: Some(InferenceConfig {
                 max_tokens: DEFAULT_MAX_TOKENS,
                 temperature,
+                top_p: None,
+                stop_sequences: None,
             }),
             tool_config: None,
         };
@@ -743,12 +753,15 @@ impl Provider for BedrockProvider {
 
         let tool_config = Self::convert_tools_to_converse(request.tools);
 
+        let params = request.params.cloned().unwrap_or_default();
         let converse_request = ConverseRequest {
             system,
             messages: converse_messages,
             inference_config: Some(InferenceConfig {
-                max_tokens: DEFAULT_MAX_TOKENS,
-                temperature,
+                max_tokens: params.max_tokens.unwrap_or(DEFAULT_MAX_TOKENS),
+                temperature: params.temperature_or(temperature),
+                top_p: params.top_p,
+                stop_sequences: params.stop_sequences(),
             }),
             tool_config,
         };
@@ -1077,6 +1090,7 @@ mod tests {
             messages.push(ChatMessage {
                 role: if i % 2 == 0 { "user" } else { "assistant" }.to_string(),
                 content: format!("Message {i}"),
+                pinned: false,
             });
         }
         assert!(BedrockProvider::should_cache_conversation(&messages));
@@ -1119,6 +1133,8 @@ mod tests {
             inference_config: Some(InferenceConfig {
                 max_tokens: 4096,
                 temperature: 0.7,
+                top_p: None,
+                stop_sequences: None,
             }),
             tool_config: None,
         };
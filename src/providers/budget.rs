@@ -0,0 +1,299 @@
+use super::traits::{
+    ChatMessage, ChatRequest, ChatResponse, ProviderCapabilities, StreamChunk, StreamOptions,
+    StreamResult, ToolsPayload,
+};
+use super::Provider;
+use crate::config::schema::ModelPricing;
+use crate::tools::ToolSpec;
+use async_trait::async_trait;
+use futures_util::stream;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Rough characters-per-token ratio used until providers report usage.
+const CHARS_PER_TOKEN: usize = 4;
+
+/// Returned instead of calling the provider once the run's estimated spend
+/// has reached `agent.max_cost_usd`.
+#[derive(Debug, thiserror::Error)]
+#[error("Cost budget of ${limit_usd:.2} reached (estimated spend ${spent_usd:.4}); stopped before the next provider call")]
+pub struct CostBudgetExceeded {
+    pub limit_usd: f64,
+    pub spent_usd: f64,
+}
+
+/// Find the `[cost.prices]` entry for a model: exact key, `provider/model`,
+/// or any `vendor/model` key whose model part matches.
+pub fn lookup_pricing<'a>(
+    prices: &'a HashMap<String, ModelPricing>,
+    provider: &str,
+    model: &str,
+) -> Option<&'a ModelPricing> {
+    prices
+        .get(model)
+        .or_else(|| prices.get(&format!("{provider}/{model}")))
+        .or_else(|| {
+            prices
+                .iter()
+                .find(|(key, _)| key.rsplit_once('/').is_some_and(|(_, m)| m == model))
+                .map(|(_, pricing)| pricing)
+        })
+}
+
+/// Estimated spend for one run, shared between [`CostBudgetProvider`] and
+/// the agent loop that reports it.
+pub struct CostBudget {
+    limit_usd: f64,
+    provider: String,
+    prices: HashMap<String, ModelPricing>,
+    fallback: ModelPricing,
+    spent_usd: parking_lot::Mutex<f64>,
+}
+
+impl CostBudget {
+    /// Fails when the run's model has no price, since the cap could then
+    /// never trigger.
+    pub fn new(
+        limit_usd: f64,
+        provider: &str,
+        model: &str,
+        prices: &HashMap<String, ModelPricing>,
+    ) -> anyhow::Result<Self> {
+        let fallback = lookup_pricing(prices, provider, model)
+            .cloned()
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "--max-cost needs a price for '{model}'; add it under [cost.prices] (USD per 1M tokens)"
+                )
+            })?;
+        Ok(Self {
+            limit_usd,
+            provider: provider.to_string(),
+            prices: prices.clone(),
+            fallback,
+            spent_usd: parking_lot::Mutex::new(0.0),
+        })
+    }
+
+    pub fn spent_usd(&self) -> f64 {
+        *self.spent_usd.lock()
+    }
+
+    fn check(&self) -> anyhow::Result<()> {
+        let spent_usd = self.spent_usd();
+        if spent_usd >= self.limit_usd {
+            return Err(CostBudgetExceeded {
+                limit_usd: self.limit_usd,
+                spent_usd,
+            }
+            .into());
+        }
+        Ok(())
+    }
+
+    fn record(&self, model: &str, input_chars: usize, output_chars: usize) {
+        let pricing = lookup_pricing(&self.prices, &self.provider, model).unwrap_or(&self.fallback);
+        let input_tokens = input_chars.div_ceil(CHARS_PER_TOKEN) as f64;
+        let output_tokens = output_chars.div_ceil(CHARS_PER_TOKEN) as f64;
+        let cost_usd = (input_tokens * pricing.input.max(0.0)
+            + output_tokens * pricing.output.max(0.0))
+            / 1_000_000.0;
+        *self.spent_usd.lock() += cost_usd;
+    }
+}
+
+fn messages_chars(messages: &[ChatMessage]) -> usize {
+    messages.iter().map(|m| m.content.len()).sum()
+}
+
+fn response_chars(response: &ChatResponse) -> usize {
+    response.text.as_deref().map_or(0, str::len)
+        + response
+            .tool_calls
+            .iter()
+            .map(|call| call.name.len() + call.arguments.len())
+            .sum::<usize>()
+}
+
+fn json_chars<T: serde::Serialize + ?Sized>(value: &T) -> usize {
+    serde_json::to_string(value).map_or(0, |s| s.len())
+}
+
+/// Enforces a [`CostBudget`] around another provider: each call is refused
+/// with [`CostBudgetExceeded`] once the budget is spent, and successful calls
+/// add their estimated cost. Streaming calls pass through uncounted.
+pub struct CostBudgetProvider {
+    inner: Box<dyn Provider>,
+    budget: Arc<CostBudget>,
+}
+
+impl CostBudgetProvider {
+    pub fn new(inner: Box<dyn Provider>, budget: Arc<CostBudget>) -> Self {
+        Self { inner, budget }
+    }
+}
+
+#[async_trait]
+impl Provider for CostBudgetProvider {
+    fn capabilities(&self) -> ProviderCapabilities {
+        self.inner.capabilities()
+    }
+
+    fn convert_tools(&self, tools: &[ToolSpec]) -> ToolsPayload {
+        self.inner.convert_tools(tools)
+    }
+
+    async fn chat_with_system(
+        &self,
+        system_prompt: Option<&str>,
+        message: &str,
+        model: &str,
+        temperature: f64,
+    ) -> anyhow::Result<String> {
+        self.budget.check()?;
+        let text = self
+            .inner
+            .chat_with_system(system_prompt, message, model, temperature)
+            .await?;
+        let input = system_prompt.map_or(0, str::len) + message.len();
+        self.budget.record(model, input, text.len());
+        Ok(text)
+    }
+
+    async fn chat_with_history(
+        &self,
+        messages: &[ChatMessage],
+        model: &str,
+        temperature: f64,
+    ) -> anyhow::Result<String> {
+        self.budget.check()?;
+        let text = self
+            .inner
+            .chat_with_history(messages, model, temperature)
+            .await?;
+        self.budget
+            .record(model, messages_chars(messages), text.len());
+        Ok(text)
+    }
+
+    async fn chat(
+        &self,
+        request: ChatRequest<'_>,
+        model: &str,
+        temperature: f64,
+    ) -> anyhow::Result<ChatResponse> {
+        self.budget.check()?;
+        let response = self.inner.chat(request, model, temperature).await?;
+        let input = messages_chars(request.messages) + request.tools.map_or(0, json_chars);
+        self.budget.record(model, input, response_chars(&response));
+        Ok(response)
+    }
+
+    fn supports_native_tools(&self) -> bool {
+        self.inner.supports_native_tools()
+    }
+
+    async fn warmup(&self) -> anyhow::Result<()> {
+        self.inner.warmup().await
+    }
+
+    async fn chat_with_tools(
+        &self,
+        messages: &[ChatMessage],
+        tools: &[serde_json::Value],
+        model: &str,
+        temperature: f64,
+    ) -> anyhow::Result<ChatResponse> {
+        self.budget.check()?;
+        let response = self
+            .inner
+            .chat_with_tools(messages, tools, model, temperature)
+            .await?;
+        self.budget.record(
+            model,
+            messages_chars(messages) + json_chars(tools),
+            response_chars(&response),
+        );
+        Ok(response)
+    }
+
+    fn supports_streaming(&self) -> bool {
+        self.inner.supports_streaming()
+    }
+
+    fn stream_chat_with_system(
+        &self,
+        system_prompt: Option<&str>,
+        message: &str,
+        model: &str,
+        temperature: f64,
+        options: StreamOptions,
+    ) -> stream::BoxStream<'static, StreamResult<StreamChunk>> {
+        self.inner
+            .stream_chat_with_system(system_prompt, message, model, temperature, options)
+    }
+
+    fn stream_chat_with_history(
+        &self,
+        messages: &[ChatMessage],
+        model: &str,
+        temperature: f64,
+        options: StreamOptions,
+    ) -> stream::BoxStream<'static, StreamResult<StreamChunk>> {
+        self.inner
+            .stream_chat_with_history(messages, model, temperature, options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct FixedProvider;
+
+    #[async_trait]
+    impl Provider for FixedProvider {
+        async fn chat_with_system(
+            &self,
+            _system_prompt: Option<&str>,
+            _message: &str,
+            _model: &str,
+            _temperature: f64,
+        ) -> anyhow::Result<String> {
+            Ok("x".repeat(4_000))
+        }
+    }
+
+    #[tokio::test]
+    async fn refuses_calls_once_the_budget_is_spent() {
+        let prices = HashMap::from([(
+            "acme/big-model".to_string(),
+            ModelPricing {
+                input: 0.0,
+                output: 1_000.0,
+            },
+        )]);
+        assert!(CostBudget::new(1.0, "acme", "unpriced", &prices).is_err());
+
+        // 4000 output chars ~ 1000 tokens at $1000/1M = $1 per call.
+        let budget = Arc::new(CostBudget::new(1.5, "acme", "big-model", &prices).unwrap());
+        let provider = CostBudgetProvider::new(Box::new(FixedProvider), budget.clone());
+
+        provider
+            .chat_with_system(None, "hi", "big-model", 0.0)
+            .await
+            .unwrap();
+        provider
+            .chat_with_system(None, "hi", "big-model", 0.0)
+            .await
+            .unwrap();
+        assert!((budget.spent_usd() - 2.0).abs() < 1e-9);
+
+        let err = provider
+            .chat_with_system(None, "hi", "big-model", 0.0)
+            .await
+            .unwrap_err();
+        assert!(err.downcast_ref::<CostBudgetExceeded>().is_some());
+        assert!(err.to_string().contains("$1.50"));
+    }
+}
@@ -0,0 +1,195 @@
+//! Per-provider circuit breaker used by [`ReliableProvider`](super::reliable::ReliableProvider).
+//!
+//! After `threshold` consecutive failed calls a provider's circuit opens and
+//! calls to it fail fast for `cooldown`. The first call after the cooldown is
+//! let through as a probe (half-open): success closes the circuit, failure
+//! opens it for another cooldown.
+
+use crate::observability::{Observer, ObserverEvent};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy)]
+enum CircuitState {
+    Closed {
+        failures: u32,
+    },
+    Open {
+        until: Instant,
+    },
+    /// A probe call has been let through and has not reported back yet.
+    HalfOpen {
+        since: Instant,
+    },
+}
+
+pub(crate) struct CircuitBreaker {
+    /// Consecutive failures that open the circuit; `0` disables the breaker.
+    threshold: u32,
+    cooldown: Duration,
+    states: Mutex<HashMap<String, CircuitState>>,
+    observer: Option<Arc<dyn Observer>>,
+}
+
+impl CircuitBreaker {
+    pub(crate) fn new(
+        threshold: u32,
+        cooldown: Duration,
+        observer: Option<Arc<dyn Observer>>,
+    ) -> Self {
+        Self {
+            threshold,
+            cooldown,
+            states: Mutex::new(HashMap::new()),
+            observer,
+        }
+    }
+
+    pub(crate) fn disabled() -> Self {
+        Self::new(0, Duration::ZERO, None)
+    }
+
+    /// `None` if a call to `provider` may proceed, otherwise the seconds
+    /// until the circuit will let a probe through.
+    pub(crate) fn check(&self, provider: &str) -> Option<u64> {
+        if self.threshold == 0 {
+            return None;
+        }
+        let now = Instant::now();
+        let mut states = self.lock();
+        let probe_since = match states.get(provider).copied() {
+            None | Some(CircuitState::Closed { .. }) => return None,
+            Some(CircuitState::Open { until }) if now < until => {
+                return Some(ceil_secs(until - now));
+            }
+            Some(CircuitState::Open { .. }) => None,
+            Some(CircuitState::HalfOpen { since }) => Some(since),
+        };
+        if let Some(since) = probe_since {
+            let probing_for = now.duration_since(since);
+            if probing_for < self.cooldown {
+                return Some(ceil_secs(self.cooldown - probing_for));
+            }
+            // The probe never reported back (e.g. it was cancelled); let
+            // another one through.
+        }
+        states.insert(provider.to_string(), CircuitState::HalfOpen { since: now });
+        drop(states);
+        if probe_since.is_none() {
+            self.emit(provider, "half_open");
+        }
+        None
+    }
+
+    /// The provider answered. Errors that prove the endpoint is reachable
+    /// (bad request, auth, context window) count as success here.
+    pub(crate) fn record_success(&self, provider: &str) {
+        if self.threshold == 0 {
+            return;
+        }
+        let previous = self
+            .lock()
+            .insert(provider.to_string(), CircuitState::Closed { failures: 0 });
+        if matches!(
+            previous,
+            Some(CircuitState::Open { .. } | CircuitState::HalfOpen { .. })
+        ) {
+            self.emit(provider, "closed");
+        }
+    }
+
+    /// Record a failed call. Returns `true` if the circuit is now open.
+    pub(crate) fn record_failure(&self, provider: &str) -> bool {
+        if self.threshold == 0 {
+            return false;
+        }
+        let mut states = self.lock();
+        let failures = match states.get(provider).copied() {
+            // A concurrent call already opened it.
+            Some(CircuitState::Open { .. }) => return true,
+            Some(CircuitState::Closed { failures }) => failures + 1,
+            None => 1,
+            // The probe failed.
+            Some(CircuitState::HalfOpen { .. }) => self.threshold,
+        };
+        if failures < self.threshold {
+            states.insert(provider.to_string(), CircuitState::Closed { failures });
+            return false;
+        }
+        states.insert(
+            provider.to_string(),
+            CircuitState::Open {
+                until: Instant::now() + self.cooldown,
+            },
+        );
+        drop(states);
+        tracing::warn!(
+            provider,
+            cooldown_secs = self.cooldown.as_secs(),
+            "Provider circuit opened after repeated failures"
+        );
+        self.emit(provider, "open");
+        true
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, CircuitState>> {
+        self.states
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    fn emit(&self, provider: &str, state: &str) {
+        if let Some(observer) = &self.observer {
+            observer.record_event(&ObserverEvent::ProviderCircuit {
+                provider: provider.to_string(),
+                state: state.to_string(),
+            });
+        }
+    }
+}
+
+fn ceil_secs(duration: Duration) -> u64 {
+    duration.as_secs() + u64::from(duration.subsec_nanos() > 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn opens_after_threshold_then_half_opens_and_closes() {
+        let breaker = CircuitBreaker::new(2, Duration::from_millis(50), None);
+        assert_eq!(breaker.check("a"), None);
+        assert!(!breaker.record_failure("a"));
+        assert!(breaker.record_failure("a"));
+
+        let retry_in = breaker.check("a").expect("circuit should be open");
+        assert_eq!(retry_in, 1);
+        assert_eq!(breaker.check("b"), None, "circuits are per provider");
+
+        std::thread::sleep(Duration::from_millis(60));
+        assert_eq!(breaker.check("a"), None, "probe allowed after cooldown");
+        assert!(breaker.check("a").is_some(), "only one probe at a time");
+
+        breaker.record_success("a");
+        assert_eq!(breaker.check("a"), None);
+        assert!(!breaker.record_failure("a"), "failure count was reset");
+    }
+
+    #[test]
+    fn failed_probe_reopens_and_zero_threshold_disables() {
+        let breaker = CircuitBreaker::new(1, Duration::from_millis(20), None);
+        assert!(breaker.record_failure("a"));
+        std::thread::sleep(Duration::from_millis(30));
+        assert_eq!(breaker.check("a"), None);
+        assert!(breaker.record_failure("a"));
+        assert!(breaker.check("a").is_some());
+
+        let disabled = CircuitBreaker::disabled();
+        for _ in 0..10 {
+            assert!(!disabled.record_failure("a"));
+        }
+        assert_eq!(disabled.check("a"), None);
+    }
+}
@@ -377,6 +377,12 @@ struct NativeChatRequest {
     tools: Option<Vec<serde_json::Value>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     tool_choice: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    top_p: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_tokens: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stop: Option<Vec<String>>,
 }
 
 #[derive(Debug, Serialize)]
@@ -674,7 +680,8 @@ impl OpenAiCompatibleProvider {
         let response = self
             .apply_auth_header(self.http_client().post(&url).json(&request), credential)
             .send()
-            .await?;
+            .await
+            .map_err(super::ProviderError::from)?;
 
         if !response.status().is_success() {
             let error = response.text().await?;
@@ -929,7 +936,7 @@ impl Provider for OpenAiCompatibleProvider {
                         });
                 }
 
-                return Err(chat_error.into());
+                return Err(super::ProviderError::from(chat_error).into());
             }
         };
 
@@ -950,7 +957,11 @@ impl Provider for OpenAiCompatibleProvider {
                     });
             }
 
-            anyhow::bail!("{} API error ({status}): {sanitized}", self.name);
+            return Err(super::ProviderError::from_status(
+                status.as_u16(),
+                format!("{} API error ({status}): {sanitized}", self.name),
+            )
+            .into());
         }
 
         let body = response.text().await?;
@@ -1044,7 +1055,7 @@ impl Provider for OpenAiCompatibleProvider {
                     }
                 }
 
-                return Err(chat_error.into());
+                return Err(super::ProviderError::from(chat_error).into());
             }
         };
 
@@ -1219,13 +1230,17 @@ impl Provider for OpenAiCompatibleProvider {
         } else {
             request.messages.to_vec()
         };
+        let params = request.params.cloned().unwrap_or_default();
         let native_request = NativeChatRequest {
             model: model.to_string(),
             messages: Self::convert_messages_for_native(&effective_messages),
-            temperature,
+            temperature: params.temperature_or(temperature),
             stream: Some(false),
             tool_choice: tools.as_ref().map(|_| "auto".to_string()),
             tools,
+            top_p: params.top_p,
+            max_tokens: params.max_tokens,
+            stop: params.stop_sequences(),
         };
 
         let url = self.chat_completions_url();
@@ -1265,7 +1280,7 @@ impl Provider for OpenAiCompatibleProvider {
                     }
                 }
 
-                return Err(chat_error.into());
+                return Err(super::ProviderError::from(chat_error).into());
             }
         };
 
@@ -1311,7 +1326,11 @@ impl Provider for OpenAiCompatibleProvider {
                 }
             }
 
-            anyhow::bail!("{} API error ({status}): {sanitized}", self.name);
+            return Err(super::ProviderError::from_status(
+                status.as_u16(),
+                format!("{} API error ({status}): {sanitized}", self.name),
+            )
+            .into());
         }
 
         let native_response: ApiChatResponse = response.json().await?;
@@ -2102,6 +2121,7 @@ mod tests {
         let messages = vec![ChatMessage {
             role: "user".to_string(),
             content: "hello".to_string(),
+            pinned: false,
         }];
         let tools = vec![serde_json::json!({
             "type": "function",
@@ -333,7 +333,7 @@ impl CopilotProvider {
             req = req.header(*header, *value);
         }
 
-        let response = req.send().await?;
+        let response = req.send().await.map_err(super::ProviderError::from)?;
 
         if !response.status().is_success() {
             return Err(super::api_error("GitHub Copilot", response).await);
@@ -3,7 +3,10 @@
 //! - Gemini CLI OAuth tokens (reuse existing ~/.gemini/ authentication)
 //! - Google Cloud ADC (`GOOGLE_APPLICATION_CREDENTIALS`)
 
-use crate::providers::traits::{ChatMessage, Provider};
+use crate::providers::traits::{
+    build_tool_instructions_text, with_tool_instructions, ChatMessage,
+    ChatRequest as ProviderChatRequest, ChatResponse, GenerationParams, Provider,
+};
 use async_trait::async_trait;
 use directories::UserDirs;
 use reqwest::Client;
@@ -96,6 +99,22 @@ struct GenerationConfig {
     temperature: f64,
     #[serde(rename = "maxOutputTokens")]
     max_output_tokens: u32,
+    #[serde(rename = "topP", skip_serializing_if = "Option::is_none")]
+    top_p: Option<f64>,
+    #[serde(rename = "stopSequences", skip_serializing_if = "Option::is_none")]
+    stop_sequences: Option<Vec<String>>,
+}
+
+impl GenerationConfig {
+    fn new(temperature: f64, params: Option<&GenerationParams>) -> Self {
+        let params = params.cloned().unwrap_or_default();
+        Self {
+            temperature: params.temperature_or(temperature),
+            max_output_tokens: params.max_tokens.unwrap_or(8192),
+            top_p: params.top_p,
+            stop_sequences: params.stop_sequences(),
+        }
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -122,6 +141,8 @@ struct ResponsePart {
 #[derive(Debug, Deserialize)]
 struct ApiError {
     message: String,
+    #[serde(default)]
+    code: Option<u16>,
 }
 
 // ══════════════════════════════════════════════════════════════════════════════
@@ -327,12 +348,57 @@ impl GeminiProvider {
 }
 
 impl GeminiProvider {
+    /// Split chat history into Gemini `contents` and a joined system instruction.
+    fn convert_messages(messages: &[ChatMessage]) -> (Vec<Content>, Option<Content>) {
+        let mut system_parts: Vec<&str> = Vec::new();
+        let mut contents: Vec<Content> = Vec::new();
+
+        for msg in messages {
+            match msg.role.as_str() {
+                "system" => {
+                    system_parts.push(&msg.content);
+                }
+                "user" => {
+                    contents.push(Content {
+                        role: Some("user".to_string()),
+                        parts: vec![Part {
+                            text: msg.content.clone(),
+                        }],
+                    });
+                }
+                "assistant" => {
+                    // Gemini API uses "model" role instead of "assistant"
+                    contents.push(Content {
+                        role: Some("model".to_string()),
+                        parts: vec![Part {
+                            text: msg.content.clone(),
+                        }],
+                    });
+                }
+                _ => {}
+            }
+        }
+
+        let system_instruction = if system_parts.is_empty() {
+            None
+        } else {
+            Some(Content {
+                role: None,
+                parts: vec![Part {
+                    text: system_parts.join("\n\n"),
+                }],
+            })
+        };
+
+        (contents, system_instruction)
+    }
+
     async fn send_generate_content(
         &self,
         contents: Vec<Content>,
         system_instruction: Option<Content>,
         model: &str,
-        temperature: f64,
+        generation_config: GenerationConfig,
     ) -> anyhow::Result<String> {
         let auth = self.auth.as_ref().ok_or_else(|| {
             anyhow::anyhow!(
@@ -347,10 +413,7 @@ impl GeminiProvider {
         let request = GenerateContentRequest {
             contents,
             system_instruction,
-            generation_config: GenerationConfig {
-                temperature,
-                max_output_tokens: 8192,
-            },
+            generation_config,
         };
 
         let url = Self::build_generate_content_url(model, auth);
@@ -358,18 +421,21 @@ impl GeminiProvider {
         let response = self
             .build_generate_content_request(auth, &url, &request, model)
             .send()
-            .await?;
+            .await
+            .map_err(super::ProviderError::from)?;
 
         if !response.status().is_success() {
-            let status = response.status();
-            let error_text = response.text().await.unwrap_or_default();
-            anyhow::bail!("Gemini API error ({status}): {error_text}");
+            return Err(super::api_error("Gemini", response).await);
         }
 
         let result: GenerateContentResponse = response.json().await?;
 
         if let Some(err) = result.error {
-            anyhow::bail!("Gemini API error: {}", err.message);
+            return Err(super::ProviderError::from_status(
+                err.code.unwrap_or(500),
+                format!("Gemini API error: {}", err.message),
+            )
+            .into());
         }
 
         result
@@ -404,8 +470,13 @@ impl Provider for GeminiProvider {
             }],
         }];
 
-        self.send_generate_content(contents, system_instruction, model, temperature)
-            .await
+        self.send_generate_content(
+            contents,
+            system_instruction,
+            model,
+            GenerationConfig::new(temperature, None),
+        )
+        .await
     }
 
     async fn chat_with_history(
@@ -414,48 +485,42 @@ impl Provider for GeminiProvider {
         model: &str,
         temperature: f64,
     ) -> anyhow::Result<String> {
-        let mut system_parts: Vec<&str> = Vec::new();
-        let mut contents: Vec<Content> = Vec::new();
+        let (contents, system_instruction) = Self::convert_messages(messages);
+        self.send_generate_content(
+            contents,
+            system_instruction,
+            model,
+            GenerationConfig::new(temperature, None),
+        )
+        .await
+    }
 
-        for msg in messages {
-            match msg.role.as_str() {
-                "system" => {
-                    system_parts.push(&msg.content);
-                }
-                "user" => {
-                    contents.push(Content {
-                        role: Some("user".to_string()),
-                        parts: vec![Part {
-                            text: msg.content.clone(),
-                        }],
-                    });
-                }
-                "assistant" => {
-                    // Gemini API uses "model" role instead of "assistant"
-                    contents.push(Content {
-                        role: Some("model".to_string()),
-                        parts: vec![Part {
-                            text: msg.content.clone(),
-                        }],
-                    });
-                }
-                _ => {}
+    async fn chat(
+        &self,
+        request: ProviderChatRequest<'_>,
+        model: &str,
+        temperature: f64,
+    ) -> anyhow::Result<ChatResponse> {
+        // No native tool calling: tools are described in the system prompt.
+        let messages = match request.tools {
+            Some(tools) if !tools.is_empty() => {
+                with_tool_instructions(request.messages, &build_tool_instructions_text(tools))
             }
-        }
-
-        let system_instruction = if system_parts.is_empty() {
-            None
-        } else {
-            Some(Content {
-                role: None,
-                parts: vec![Part {
-                    text: system_parts.join("\n\n"),
-                }],
-            })
+            _ => request.messages.to_vec(),
         };
-
-        self.send_generate_content(contents, system_instruction, model, temperature)
-            .await
+        let (contents, system_instruction) = Self::convert_messages(&messages);
+        let text = self
+            .send_generate_content(
+                contents,
+                system_instruction,
+                model,
+                GenerationConfig::new(temperature, request.params),
+            )
+            .await?;
+        Ok(ChatResponse {
+            text: Some(text),
+            tool_calls: Vec::new(),
+        })
     }
 
     async fn warmup(&self) -> anyhow::Result<()> {
@@ -601,10 +666,7 @@ mod tests {
                 }],
             }],
             system_instruction: None,
-            generation_config: GenerationConfig {
-                temperature: 0.7,
-                max_output_tokens: 8192,
-            },
+            generation_config: GenerationConfig::new(0.7, None),
         };
 
         let request = provider
@@ -636,10 +698,7 @@ mod tests {
                 }],
             }],
             system_instruction: None,
-            generation_config: GenerationConfig {
-                temperature: 0.7,
-                max_output_tokens: 8192,
-            },
+            generation_config: GenerationConfig::new(0.7, None),
         };
 
         let request = provider
@@ -665,10 +724,7 @@ mod tests {
                     text: "You are helpful".to_string(),
                 }],
             }),
-            generation_config: GenerationConfig {
-                temperature: 0.7,
-                max_output_tokens: 8192,
-            },
+            generation_config: GenerationConfig::new(0.7, None),
         };
 
         let json = serde_json::to_string(&request).unwrap();
@@ -678,14 +734,31 @@ mod tests {
         assert!(json.contains("\"maxOutputTokens\":8192"));
     }
 
+    #[test]
+    fn generation_config_maps_generation_params() {
+        let json = serde_json::to_string(&GenerationConfig::new(0.7, None)).unwrap();
+        assert!(json.contains("\"maxOutputTokens\":8192"));
+        assert!(!json.contains("topP"));
+        assert!(!json.contains("stopSequences"));
+
+        let params = GenerationParams {
+            temperature: Some(0.2),
+            top_p: Some(0.9),
+            max_tokens: Some(512),
+            stop: vec!["END".into()],
+        };
+        let json = serde_json::to_string(&GenerationConfig::new(0.7, Some(&params))).unwrap();
+        assert!(json.contains("\"temperature\":0.2"));
+        assert!(json.contains("\"maxOutputTokens\":512"));
+        assert!(json.contains("\"topP\":0.9"));
+        assert!(json.contains("\"stopSequences\":[\"END\"]"));
+    }
+
     #[test]
     fn internal_request_includes_model() {
         let request = InternalGenerateContentRequest {
             model: "models/gemini-3-pro-preview".to_string(),
-            generation_config: GenerationConfig {
-                temperature: 0.7,
-                max_output_tokens: 8192,
-            },
+            generation_config: GenerationConfig::new(0.7, None),
             contents: vec![Content {
                 role: Some("user".to_string()),
                 parts: vec![Part {
@@ -188,11 +188,11 @@ impl Provider for GlmProvider {
             .header("Authorization", format!("Bearer {token}"))
             .json(&request)
             .send()
-            .await?;
+            .await
+            .map_err(super::ProviderError::from)?;
 
         if !response.status().is_success() {
-            let error = response.text().await?;
-            anyhow::bail!("GLM API error: {error}");
+            return Err(super::api_error("GLM", response).await);
         }
 
         let chat_response: ChatResponse = response.json().await?;
@@ -235,11 +235,11 @@ impl Provider for GlmProvider {
             .header("Authorization", format!("Bearer {token}"))
             .json(&request)
             .send()
-            .await?;
+            .await
+            .map_err(super::ProviderError::from)?;
 
         if !response.status().is_success() {
-            let error = response.text().await?;
-            anyhow::bail!("GLM API error: {error}");
+            return Err(super::api_error("GLM", response).await);
         }
 
         let chat_response: ChatResponse = response.json().await?;
@@ -0,0 +1,452 @@
+use super::traits::{
+    ChatMessage, ChatRequest, ChatResponse, ProviderCapabilities, StreamChunk, StreamOptions,
+    StreamResult, ToolsPayload,
+};
+use super::Provider;
+use crate::tools::ToolSpec;
+use async_trait::async_trait;
+use futures_util::stream;
+use serde_json::json;
+use std::path::PathBuf;
+use tokio::io::AsyncWriteExt;
+
+/// Default request log file name, relative to `~/.zeroclaw/logs/`.
+const DEFAULT_REQUEST_LOG_FILE: &str = "provider-requests.jsonl";
+
+/// Resolve the request log path: explicit config value, or
+/// `~/.zeroclaw/logs/provider-requests.jsonl`.
+pub fn resolve_request_log_path(configured: Option<&str>) -> PathBuf {
+    if let Some(path) = configured.map(str::trim).filter(|p| !p.is_empty()) {
+        return PathBuf::from(shellexpand::tilde(path).as_ref());
+    }
+    directories::UserDirs::new().map_or_else(
+        || PathBuf::from(".zeroclaw/logs").join(DEFAULT_REQUEST_LOG_FILE),
+        |dirs| {
+            dirs.home_dir()
+                .join(".zeroclaw/logs")
+                .join(DEFAULT_REQUEST_LOG_FILE)
+        },
+    )
+}
+
+/// Debug wrapper that appends every outgoing request and the provider's
+/// response (or error) to a JSONL file.
+///
+/// Opt-in only (`reliability.log_requests = true`): entries contain full
+/// conversation content. Known credentials and secret-like tokens are
+/// redacted before anything is written.
+pub struct LoggingProvider {
+    inner: Box<dyn Provider>,
+    provider_name: String,
+    path: PathBuf,
+    secrets: Vec<String>,
+}
+
+impl LoggingProvider {
+    pub fn new(
+        provider_name: impl Into<String>,
+        inner: Box<dyn Provider>,
+        path: PathBuf,
+        secrets: Vec<String>,
+    ) -> Self {
+        let secrets = secrets
+            .into_iter()
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+            .collect();
+        Self {
+            inner,
+            provider_name: provider_name.into(),
+            path,
+            secrets,
+        }
+    }
+
+    fn redact(&self, input: &str) -> String {
+        let mut redacted = input.to_string();
+        for secret in &self.secrets {
+            redacted = redacted.replace(secret.as_str(), "[REDACTED]");
+        }
+        super::scrub_secret_patterns(&redacted)
+    }
+
+    /// Redact every string in `value`. Works on the parsed strings rather
+    /// than the serialized line, where a secret containing `"`, `\` or a
+    /// control character is escaped and would no longer match.
+    fn redact_value(&self, value: serde_json::Value) -> serde_json::Value {
+        match value {
+            serde_json::Value::String(s) => serde_json::Value::String(self.redact(&s)),
+            serde_json::Value::Array(items) => {
+                serde_json::Value::Array(items.into_iter().map(|v| self.redact_value(v)).collect())
+            }
+            serde_json::Value::Object(map) => serde_json::Value::Object(
+                map.into_iter()
+                    .map(|(k, v)| (k, self.redact_value(v)))
+                    .collect(),
+            ),
+            other => other,
+        }
+    }
+
+    fn build_entry(
+        &self,
+        call: &str,
+        model: &str,
+        temperature: f64,
+        request: serde_json::Value,
+        outcome: Result<serde_json::Value, &anyhow::Error>,
+    ) -> String {
+        let mut entry = json!({
+            "timestamp": chrono::Utc::now().to_rfc3339(),
+            "provider": self.provider_name,
+            "call": call,
+            "model": model,
+            "temperature": temperature,
+            "request": request,
+        });
+        match outcome {
+            Ok(response) => entry["response"] = response,
+            Err(error) => entry["error"] = json!(error.to_string()),
+        }
+        self.redact_value(entry).to_string()
+    }
+
+    async fn append(&self, line: String) {
+        if let Some(parent) = self.path.parent() {
+            if let Err(error) = tokio::fs::create_dir_all(parent).await {
+                tracing::warn!("Failed to create provider request log directory: {error}");
+                return;
+            }
+        }
+
+        let mut options = tokio::fs::OpenOptions::new();
+        options.create(true).append(true);
+        #[cfg(unix)]
+        options.mode(0o600);
+
+        let result = async {
+            let mut file = options.open(&self.path).await?;
+            file.write_all(line.as_bytes()).await?;
+            file.write_all(b"\n").await
+        }
+        .await;
+
+        if let Err(error) = result {
+            tracing::warn!(
+                path = %self.path.display(),
+                "Failed to write provider request log: {error}"
+            );
+        }
+    }
+
+    async fn log(
+        &self,
+        call: &str,
+        model: &str,
+        temperature: f64,
+        request: serde_json::Value,
+        outcome: Result<serde_json::Value, &anyhow::Error>,
+    ) {
+        let line = self.build_entry(call, model, temperature, request, outcome);
+        self.append(line).await;
+    }
+}
+
+fn response_json(response: &ChatResponse) -> serde_json::Value {
+    json!({
+        "text": response.text,
+        "tool_calls": response.tool_calls,
+    })
+}
+
+#[async_trait]
+impl Provider for LoggingProvider {
+    fn capabilities(&self) -> ProviderCapabilities {
+        self.inner.capabilities()
+    }
+
+    fn convert_tools(&self, tools: &[ToolSpec]) -> ToolsPayload {
+        self.inner.convert_tools(tools)
+    }
+
+    async fn chat_with_system(
+        &self,
+        system_prompt: Option<&str>,
+        message: &str,
+        model: &str,
+        temperature: f64,
+    ) -> anyhow::Result<String> {
+        let result = self
+            .inner
+            .chat_with_system(system_prompt, message, model, temperature)
+            .await;
+        let request = json!({ "system": system_prompt, "message": message });
+        match &result {
+            Ok(text) => {
+                self.log(
+                    "chat_with_system",
+                    model,
+                    temperature,
+                    request,
+                    Ok(json!(text)),
+                )
+                .await;
+            }
+            Err(error) => {
+                self.log("chat_with_system", model, temperature, request, Err(error))
+                    .await;
+            }
+        }
+        result
+    }
+
+    async fn chat_with_history(
+        &self,
+        messages: &[ChatMessage],
+        model: &str,
+        temperature: f64,
+    ) -> anyhow::Result<String> {
+        let result = self
+            .inner
+            .chat_with_history(messages, model, temperature)
+            .await;
+        let request = json!({ "messages": messages });
+        match &result {
+            Ok(text) => {
+                self.log(
+                    "chat_with_history",
+                    model,
+                    temperature,
+                    request,
+                    Ok(json!(text)),
+                )
+                .await;
+            }
+            Err(error) => {
+                self.log("chat_with_history", model, temperature, request, Err(error))
+                    .await;
+            }
+        }
+        result
+    }
+
+    async fn chat(
+        &self,
+        request: ChatRequest<'_>,
+        model: &str,
+        temperature: f64,
+    ) -> anyhow::Result<ChatResponse> {
+        let result = self.inner.chat(request, model, temperature).await;
+        let logged_request = json!({
+            "messages": request.messages,
+            "tools": request.tools,
+            "params": request.params,
+        });
+        match &result {
+            Ok(response) => {
+                self.log(
+                    "chat",
+                    model,
+                    temperature,
+                    logged_request,
+                    Ok(response_json(response)),
+                )
+                .await;
+            }
+            Err(error) => {
+                self.log("chat", model, temperature, logged_request, Err(error))
+                    .await;
+            }
+        }
+        result
+    }
+
+    fn supports_native_tools(&self) -> bool {
+        self.inner.supports_native_tools()
+    }
+
+    async fn warmup(&self) -> anyhow::Result<()> {
+        self.inner.warmup().await
+    }
+
+    async fn chat_with_tools(
+        &self,
+        messages: &[ChatMessage],
+        tools: &[serde_json::Value],
+        model: &str,
+        temperature: f64,
+    ) -> anyhow::Result<ChatResponse> {
+        let result = self
+            .inner
+            .chat_with_tools(messages, tools, model, temperature)
+            .await;
+        let request = json!({ "messages": messages, "tools": tools });
+        match &result {
+            Ok(response) => {
+                self.log(
+                    "chat_with_tools",
+                    model,
+                    temperature,
+                    request,
+                    Ok(response_json(response)),
+                )
+                .await;
+            }
+            Err(error) => {
+                self.log("chat_with_tools", model, temperature, request, Err(error))
+                    .await;
+            }
+        }
+        result
+    }
+
+    fn supports_streaming(&self) -> bool {
+        self.inner.supports_streaming()
+    }
+
+    // Streaming calls pass through unlogged: chunks arrive after this
+    // method returns, so there is no single response to record.
+    fn stream_chat_with_system(
+        &self,
+        system_prompt: Option<&str>,
+        message: &str,
+        model: &str,
+        temperature: f64,
+        options: StreamOptions,
+    ) -> stream::BoxStream<'static, StreamResult<StreamChunk>> {
+        self.inner
+            .stream_chat_with_system(system_prompt, message, model, temperature, options)
+    }
+
+    fn stream_chat_with_history(
+        &self,
+        messages: &[ChatMessage],
+        model: &str,
+        temperature: f64,
+        options: StreamOptions,
+    ) -> stream::BoxStream<'static, StreamResult<StreamChunk>> {
+        self.inner
+            .stream_chat_with_history(messages, model, temperature, options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct EchoProvider;
+
+    #[async_trait]
+    impl Provider for EchoProvider {
+        async fn chat_with_system(
+            &self,
+            _system_prompt: Option<&str>,
+            message: &str,
+            _model: &str,
+            _temperature: f64,
+        ) -> anyhow::Result<String> {
+            if message == "fail" {
+                anyhow::bail!("upstream rejected key sk-live-abcdef123456");
+            }
+            Ok(format!("echo: {message}"))
+        }
+    }
+
+    fn logging_provider(dir: &tempfile::TempDir) -> LoggingProvider {
+        LoggingProvider::new(
+            "echo",
+            Box::new(EchoProvider),
+            dir.path().join("logs/requests.jsonl"),
+            vec!["super-secret-key".into(), "  ".into()],
+        )
+    }
+
+    fn read_entries(dir: &tempfile::TempDir) -> Vec<serde_json::Value> {
+        std::fs::read_to_string(dir.path().join("logs/requests.jsonl"))
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn logs_request_and_response() {
+        let dir = tempfile::tempdir().unwrap();
+        let provider = logging_provider(&dir);
+
+        let text = provider
+            .chat_with_system(Some("be brief"), "hello", "test-model", 0.2)
+            .await
+            .unwrap();
+        assert_eq!(text, "echo: hello");
+
+        let entries = read_entries(&dir);
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0]["provider"], "echo");
+        assert_eq!(entries[0]["call"], "chat_with_system");
+        assert_eq!(entries[0]["model"], "test-model");
+        assert_eq!(entries[0]["request"]["system"], "be brief");
+        assert_eq!(entries[0]["response"], "echo: hello");
+    }
+
+    #[tokio::test]
+    async fn redacts_configured_secrets_and_token_patterns() {
+        let dir = tempfile::tempdir().unwrap();
+        let provider = logging_provider(&dir);
+
+        provider
+            .chat_with_system(None, "my key is super-secret-key", "m", 0.0)
+            .await
+            .unwrap();
+        assert!(provider
+            .chat_with_system(None, "fail", "m", 0.0)
+            .await
+            .is_err());
+
+        let raw = std::fs::read_to_string(dir.path().join("logs/requests.jsonl")).unwrap();
+        assert!(!raw.contains("super-secret-key"));
+        assert!(!raw.contains("sk-live-abcdef123456"));
+        assert!(raw.contains("[REDACTED]"));
+
+        let entries = read_entries(&dir);
+        assert_eq!(entries.len(), 2);
+        assert!(entries[1]["error"]
+            .as_str()
+            .unwrap()
+            .contains("upstream rejected"));
+        assert!(entries[1].get("response").is_none());
+    }
+
+    #[tokio::test]
+    async fn redacts_secrets_that_json_would_escape() {
+        let dir = tempfile::tempdir().unwrap();
+        let secret = r#"pa"ss\wörd-42"#;
+        let provider = LoggingProvider::new(
+            "echo",
+            Box::new(EchoProvider),
+            dir.path().join("logs/requests.jsonl"),
+            vec![secret.into()],
+        );
+
+        provider
+            .chat_with_system(None, &format!("login with {secret}"), "m", 0.0)
+            .await
+            .unwrap();
+
+        let entries = read_entries(&dir);
+        assert_eq!(entries[0]["request"]["message"], "login with [REDACTED]");
+        assert_eq!(entries[0]["response"], "echo: login with [REDACTED]");
+        let raw = std::fs::read_to_string(dir.path().join("logs/requests.jsonl")).unwrap();
+        assert!(!raw.contains("wörd-42"));
+    }
+
+    #[test]
+    fn resolve_request_log_path_prefers_configured_value() {
+        assert_eq!(
+            resolve_request_log_path(Some("/tmp/zc-requests.jsonl")),
+            PathBuf::from("/tmp/zc-requests.jsonl")
+        );
+        assert!(resolve_request_log_path(None).ends_with("logs/provider-requests.jsonl"));
+        assert!(resolve_request_log_path(Some("  ")).ends_with("logs/provider-requests.jsonl"));
+    }
+}
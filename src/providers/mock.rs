@@ -0,0 +1,429 @@
+//! Offline provider that replays scripted responses.
+//!
+//! Select it with `default_provider = "mock"` (or `create_provider("mock", ..)`)
+//! to exercise the agent loop and tools without network access. Responses may
+//! contain `<tool_call>` blocks; the provider is prompt-guided, so the loop
+//! parses them exactly as it would for a real model.
+//!
+//! A `.jsonl` script is read as a `reliability.log_requests` transcript and
+//! its recorded responses are replayed in order (`zeroclaw agent --replay`).
+
+use crate::providers::traits::{ChatMessage, Provider, StreamChunk, StreamOptions, StreamResult};
+use crate::tools::{Tool, ToolResult};
+use async_trait::async_trait;
+use futures_util::{stream, StreamExt};
+use std::collections::VecDeque;
+use std::path::Path;
+use std::sync::Mutex;
+
+/// Returned once the script is exhausted.
+const SCRIPT_EXHAUSTED_RESPONSE: &str = "[mock] script exhausted";
+
+/// A provider request log (`reliability.log_requests`) reduced to what a
+/// replay needs.
+#[derive(Debug, Clone, Default)]
+pub struct RecordedRun {
+    /// Last user message of the first recorded request.
+    pub message: Option<String>,
+    /// Model of the first recorded request.
+    pub model: Option<String>,
+    /// Successful responses, in call order, as prompt-guided text.
+    pub responses: Vec<String>,
+}
+
+/// Parse a provider request log. Failed calls are skipped (the reliable
+/// provider retried them), and native tool calls are rewritten as
+/// `<tool_call>` blocks so the replay runs through the same loop.
+pub fn load_request_log(path: &Path) -> anyhow::Result<RecordedRun> {
+    let raw = std::fs::read_to_string(path)
+        .map_err(|e| anyhow::anyhow!("Failed to read request log {}: {e}", path.display()))?;
+    let mut run = RecordedRun::default();
+
+    for (index, line) in raw.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let entry: serde_json::Value = serde_json::from_str(line).map_err(|e| {
+            anyhow::anyhow!("{}:{}: invalid log entry: {e}", path.display(), index + 1)
+        })?;
+        let Some(response) = entry.get("response") else {
+            continue;
+        };
+
+        if run.responses.is_empty() {
+            run.model = entry["model"].as_str().map(str::to_string);
+            run.message = recorded_user_message(&entry["request"]);
+        }
+        run.responses.push(response_as_text(response));
+    }
+
+    if run.responses.is_empty() {
+        anyhow::bail!("Request log {} has no recorded responses", path.display());
+    }
+    Ok(run)
+}
+
+fn recorded_user_message(request: &serde_json::Value) -> Option<String> {
+    if let Some(message) = request["message"].as_str() {
+        return Some(message.to_string());
+    }
+    request["messages"]
+        .as_array()?
+        .iter()
+        .rfind(|m| m["role"] == "user")
+        .and_then(|m| m["content"].as_str())
+        .map(str::to_string)
+}
+
+/// Plain-string responses pass through; `{text, tool_calls}` responses
+/// become text followed by one `<tool_call>` block per call.
+fn response_as_text(response: &serde_json::Value) -> String {
+    if let Some(text) = response.as_str() {
+        return text.to_string();
+    }
+    let mut parts: Vec<String> = response["text"]
+        .as_str()
+        .filter(|t| !t.is_empty())
+        .map(str::to_string)
+        .into_iter()
+        .collect();
+    for call in response["tool_calls"].as_array().into_iter().flatten() {
+        let arguments = call["arguments"]
+            .as_str()
+            .and_then(|a| serde_json::from_str::<serde_json::Value>(a).ok())
+            .unwrap_or_else(|| call["arguments"].clone());
+        let payload = serde_json::json!({ "name": call["name"], "arguments": arguments });
+        parts.push(format!("<tool_call>\n{payload}\n</tool_call>"));
+    }
+    parts.join("\n")
+}
+
+/// Stand-in for a real tool during a replay: it keeps the tool's name,
+/// description and schema, so the prompt and argument checks match the
+/// recording, but never runs it.
+pub struct ReplayedTool {
+    inner: Box<dyn Tool>,
+}
+
+impl ReplayedTool {
+    pub fn wrap(inner: Box<dyn Tool>) -> Box<dyn Tool> {
+        Box::new(Self { inner })
+    }
+}
+
+#[async_trait]
+impl Tool for ReplayedTool {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn description(&self) -> &str {
+        self.inner.description()
+    }
+
+    fn category(&self) -> &str {
+        self.inner.category()
+    }
+
+    fn parameters_schema(&self) -> serde_json::Value {
+        self.inner.parameters_schema()
+    }
+
+    async fn execute(&self, _args: serde_json::Value) -> anyhow::Result<ToolResult> {
+        Ok(ToolResult {
+            success: true,
+            output: format!(
+                "[replay] {} was not executed (pass --replay-exec-tools to run tools)",
+                self.inner.name()
+            ),
+            error: None,
+            metadata: None,
+            content_type: None,
+        })
+    }
+}
+
+pub struct MockProvider {
+    /// Remaining scripted responses; `None` echoes the last user message.
+    script: Option<Mutex<VecDeque<String>>>,
+    /// Every conversation the provider was asked to answer, in order.
+    requests: Mutex<Vec<Vec<ChatMessage>>>,
+}
+
+impl MockProvider {
+    /// Echo provider: replies `[mock] <last user message>`.
+    pub fn echo() -> Self {
+        Self {
+            script: None,
+            requests: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Replay `responses` in order, one per model call.
+    pub fn with_responses<I, S>(responses: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            script: Some(Mutex::new(responses.into_iter().map(Into::into).collect())),
+            requests: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Replay the recorded responses of a provider request log
+    /// (`zeroclaw agent --replay`).
+    pub fn from_request_log(path: &Path) -> anyhow::Result<Self> {
+        Ok(Self::with_responses(load_request_log(path)?.responses))
+    }
+
+    /// Load a script from a JSON file holding an array of response strings,
+    /// or replay the responses of a `.jsonl` provider request log.
+    pub fn from_script_file(path: &Path) -> anyhow::Result<Self> {
+        if path.extension().is_some_and(|ext| ext == "jsonl") {
+            return Self::from_request_log(path);
+        }
+        let raw = std::fs::read_to_string(path)
+            .map_err(|e| anyhow::anyhow!("Failed to read mock script {}: {e}", path.display()))?;
+        let responses: Vec<String> = serde_json::from_str(&raw).map_err(|e| {
+            anyhow::anyhow!(
+                "Mock script {} must be a JSON array of strings: {e}",
+                path.display()
+            )
+        })?;
+        Ok(Self::with_responses(responses))
+    }
+
+    /// Conversations received so far (for assertions in tests).
+    pub fn requests(&self) -> Vec<Vec<ChatMessage>> {
+        self.requests
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .clone()
+    }
+
+    fn respond(&self, messages: &[ChatMessage]) -> String {
+        self.requests
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .push(messages.to_vec());
+
+        match &self.script {
+            Some(script) => script
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner)
+                .pop_front()
+                .unwrap_or_else(|| SCRIPT_EXHAUSTED_RESPONSE.to_string()),
+            None => {
+                let last_user = messages
+                    .iter()
+                    .rfind(|m| m.role == "user")
+                    .map_or("", |m| m.content.as_str());
+                format!("[mock] {last_user}")
+            }
+        }
+    }
+
+    fn single_chunk_stream(
+        text: String,
+        options: StreamOptions,
+    ) -> stream::BoxStream<'static, StreamResult<StreamChunk>> {
+        let delta = if options.count_tokens {
+            StreamChunk::delta(text).with_token_estimate()
+        } else {
+            StreamChunk::delta(text)
+        };
+        stream::iter(vec![Ok(delta), Ok(StreamChunk::final_chunk())]).boxed()
+    }
+}
+
+#[async_trait]
+impl Provider for MockProvider {
+    async fn chat_with_system(
+        &self,
+        system_prompt: Option<&str>,
+        message: &str,
+        _model: &str,
+        _temperature: f64,
+    ) -> anyhow::Result<String> {
+        let mut messages = Vec::with_capacity(2);
+        if let Some(system) = system_prompt {
+            messages.push(ChatMessage::system(system));
+        }
+        messages.push(ChatMessage::user(message));
+        Ok(self.respond(&messages))
+    }
+
+    async fn chat_with_history(
+        &self,
+        messages: &[ChatMessage],
+        _model: &str,
+        _temperature: f64,
+    ) -> anyhow::Result<String> {
+        Ok(self.respond(messages))
+    }
+
+    fn supports_streaming(&self) -> bool {
+        true
+    }
+
+    fn stream_chat_with_system(
+        &self,
+        system_prompt: Option<&str>,
+        message: &str,
+        _model: &str,
+        _temperature: f64,
+        options: StreamOptions,
+    ) -> stream::BoxStream<'static, StreamResult<StreamChunk>> {
+        let mut messages = Vec::with_capacity(2);
+        if let Some(system) = system_prompt {
+            messages.push(ChatMessage::system(system));
+        }
+        messages.push(ChatMessage::user(message));
+        Self::single_chunk_stream(self.respond(&messages), options)
+    }
+
+    fn stream_chat_with_history(
+        &self,
+        messages: &[ChatMessage],
+        _model: &str,
+        _temperature: f64,
+        options: StreamOptions,
+    ) -> stream::BoxStream<'static, StreamResult<StreamChunk>> {
+        Self::single_chunk_stream(self.respond(messages), options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn replays_script_then_reports_exhaustion() {
+        let provider = MockProvider::with_responses(["first", "second"]);
+        assert_eq!(provider.simple_chat("a", "m", 0.0).await.unwrap(), "first");
+        assert_eq!(provider.simple_chat("b", "m", 0.0).await.unwrap(), "second");
+        assert_eq!(
+            provider.simple_chat("c", "m", 0.0).await.unwrap(),
+            SCRIPT_EXHAUSTED_RESPONSE
+        );
+        assert_eq!(provider.requests().len(), 3);
+        assert_eq!(provider.requests()[1][0].content, "b");
+    }
+
+    #[tokio::test]
+    async fn echo_mode_and_streaming() {
+        let provider = MockProvider::echo();
+        let history = vec![ChatMessage::system("sys"), ChatMessage::user("hello")];
+        assert_eq!(
+            provider
+                .chat_with_history(&history, "m", 0.0)
+                .await
+                .unwrap(),
+            "[mock] hello"
+        );
+
+        let chunks: Vec<_> = provider
+            .stream_chat_with_history(&history, "m", 0.0, StreamOptions::new(true))
+            .collect()
+            .await;
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[0].as_ref().unwrap().delta, "[mock] hello");
+        assert!(chunks[1].as_ref().unwrap().is_final);
+    }
+
+    #[test]
+    fn script_file_must_be_string_array() {
+        let dir = tempfile::tempdir().unwrap();
+        let good = dir.path().join("script.json");
+        std::fs::write(&good, r#"["one", "<tool_call>{}</tool_call>"]"#).unwrap();
+        assert!(MockProvider::from_script_file(&good).is_ok());
+
+        let bad = dir.path().join("bad.json");
+        std::fs::write(&bad, r#"{"responses": []}"#).unwrap();
+        let err = MockProvider::from_script_file(&bad).err().unwrap();
+        assert!(err.to_string().contains("JSON array of strings"));
+        assert!(MockProvider::from_script_file(&dir.path().join("missing.json")).is_err());
+    }
+
+    #[tokio::test]
+    async fn request_log_replays_recorded_responses() {
+        let dir = tempfile::tempdir().unwrap();
+        let log = dir.path().join("provider-requests.jsonl");
+        let entries = [
+            serde_json::json!({
+                "call": "chat",
+                "model": "anthropic/claude-sonnet-4",
+                "request": {"messages": [
+                    {"role": "system", "content": "sys"},
+                    {"role": "user", "content": "list the files"}
+                ]},
+                "response": {"text": "Checking.", "tool_calls": [
+                    {"id": "c1", "name": "shell", "arguments": "{\"command\":\"ls\"}"}
+                ]}
+            }),
+            serde_json::json!({"call": "chat", "model": "m", "request": {}, "error": "429"}),
+            serde_json::json!({
+                "call": "chat_with_history",
+                "model": "m",
+                "request": {},
+                "response": "Two files."
+            }),
+        ];
+        let raw: Vec<String> = entries.iter().map(ToString::to_string).collect();
+        std::fs::write(&log, raw.join("\n")).unwrap();
+
+        let run = load_request_log(&log).unwrap();
+        assert_eq!(run.message.as_deref(), Some("list the files"));
+        assert_eq!(run.model.as_deref(), Some("anthropic/claude-sonnet-4"));
+        assert_eq!(run.responses.len(), 2);
+        assert!(run.responses[0].starts_with("Checking.\n<tool_call>"));
+        assert!(run.responses[0].contains(r#""command":"ls""#));
+
+        let provider = MockProvider::from_script_file(&log).unwrap();
+        assert!(provider
+            .simple_chat("x", "m", 0.0)
+            .await
+            .unwrap()
+            .contains("<tool_call>"));
+        assert_eq!(
+            provider.simple_chat("y", "m", 0.0).await.unwrap(),
+            "Two files."
+        );
+    }
+
+    struct ExplodingTool;
+
+    #[async_trait]
+    impl Tool for ExplodingTool {
+        fn name(&self) -> &str {
+            "shell"
+        }
+
+        fn description(&self) -> &str {
+            "Runs commands"
+        }
+
+        fn parameters_schema(&self) -> serde_json::Value {
+            serde_json::json!({"type": "object"})
+        }
+
+        async fn execute(&self, _args: serde_json::Value) -> anyhow::Result<ToolResult> {
+            panic!("replayed tool must not run");
+        }
+    }
+
+    #[tokio::test]
+    async fn replayed_tool_keeps_the_spec_but_never_executes() {
+        let tool = ReplayedTool::wrap(Box::new(ExplodingTool));
+        assert_eq!(tool.name(), "shell");
+        assert_eq!(tool.description(), "Runs commands");
+
+        let result = tool
+            .execute(serde_json::json!({"command": "rm -rf /"}))
+            .await
+            .unwrap();
+        assert!(result.success);
+        assert!(result.output.contains("--replay-exec-tools"));
+    }
+}
@@ -1,8 +1,14 @@
 pub mod anthropic;
+pub mod azure_openai;
 pub mod bedrock;
+pub mod budget;
+pub mod circuit_breaker;
 pub mod compatible;
 pub mod copilot;
 pub mod gemini;
+pub mod logging;
+pub mod mock;
+pub mod normalize;
 pub mod ollama;
 pub mod openai;
 pub mod openai_codex;
@@ -13,14 +19,15 @@ pub mod traits;
 
 #[allow(unused_imports)]
 pub use traits::{
-    ChatMessage, ChatRequest, ChatResponse, ConversationMessage, Provider, ToolCall,
-    ToolResultMessage,
+    ChatMessage, ChatRequest, ChatResponse, ConversationMessage, GenerationParams, Provider,
+    ProviderError, ToolCall, ToolResultMessage,
 };
 
 use compatible::{AuthStyle, OpenAiCompatibleProvider};
 use reliable::ReliableProvider;
 use serde::Deserialize;
 use std::path::PathBuf;
+use std::sync::Arc;
 
 const MAX_API_ERROR_CHARS: usize = 200;
 const MINIMAX_INTL_BASE_URL: &str = "https://api.minimax.io/v1";
@@ -311,11 +318,13 @@ fn qwen_oauth_token_expired(credentials: &QwenOauthCredentials) -> bool {
 
 fn refresh_qwen_oauth_access_token(refresh_token: &str) -> anyhow::Result<QwenOauthCredentials> {
     let client_id = qwen_oauth_client_id();
-    let client = reqwest::blocking::Client::builder()
+    let builder = reqwest::blocking::Client::builder()
         .timeout(std::time::Duration::from_secs(15))
-        .connect_timeout(std::time::Duration::from_secs(5))
-        .build()
-        .unwrap_or_else(|_| reqwest::blocking::Client::new());
+        .connect_timeout(std::time::Duration::from_secs(5));
+    let client =
+        crate::config::apply_runtime_proxy_to_blocking_builder(builder, "provider.compatible")
+            .build()
+            .unwrap_or_else(|_| reqwest::blocking::Client::new());
 
     let response = client
         .post(QWEN_OAUTH_TOKEN_ENDPOINT)
@@ -483,11 +492,13 @@ fn refresh_minimax_oauth_access_token(name: &str, refresh_token: &str) -> anyhow
     let region = minimax_oauth_region(name);
     let endpoint = region.token_endpoint();
     let client_id = minimax_oauth_client_id();
-    let client = reqwest::blocking::Client::builder()
+    let builder = reqwest::blocking::Client::builder()
         .timeout(std::time::Duration::from_secs(15))
-        .connect_timeout(std::time::Duration::from_secs(5))
-        .build()
-        .unwrap_or_else(|_| reqwest::blocking::Client::new());
+        .connect_timeout(std::time::Duration::from_secs(5));
+    let client =
+        crate::config::apply_runtime_proxy_to_blocking_builder(builder, "provider.compatible")
+            .build()
+            .unwrap_or_else(|_| reqwest::blocking::Client::new());
 
     let response = client
         .post(endpoint)
@@ -625,11 +636,30 @@ fn zai_base_url(name: &str) -> Option<&'static str> {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct ProviderRuntimeOptions {
     pub auth_profile_override: Option<String>,
     pub zeroclaw_dir: Option<PathBuf>,
     pub secrets_encrypt: bool,
+    /// Receives provider circuit breaker state changes.
+    pub observer: Option<Arc<dyn crate::observability::Observer>>,
+    /// `[azure_openai]` endpoint, API version and deployment map.
+    pub azure_openai: crate::config::AzureOpenAiConfig,
+}
+
+impl std::fmt::Debug for ProviderRuntimeOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ProviderRuntimeOptions")
+            .field("auth_profile_override", &self.auth_profile_override)
+            .field("zeroclaw_dir", &self.zeroclaw_dir)
+            .field("secrets_encrypt", &self.secrets_encrypt)
+            .field(
+                "observer",
+                &self.observer.as_ref().map(|o| o.name().to_string()),
+            )
+            .field("azure_openai", &self.azure_openai)
+            .finish()
+    }
 }
 
 impl Default for ProviderRuntimeOptions {
@@ -638,6 +668,8 @@ impl Default for ProviderRuntimeOptions {
             auth_profile_override: None,
             zeroclaw_dir: None,
             secrets_encrypt: true,
+            observer: None,
+            azure_openai: crate::config::AzureOpenAiConfig::default(),
         }
     }
 }
@@ -724,7 +756,11 @@ pub async fn api_error(provider: &str, response: reqwest::Response) -> anyhow::E
         .await
         .unwrap_or_else(|_| "<failed to read provider error body>".to_string());
     let sanitized = sanitize_api_error(&body);
-    anyhow::anyhow!("{provider} API error ({status}): {sanitized}")
+    ProviderError::from_status(
+        status.as_u16(),
+        format!("{provider} API error ({status}): {sanitized}"),
+    )
+    .into()
 }
 
 /// Resolve API key for a provider from config and environment variables.
@@ -760,43 +796,13 @@ fn resolve_provider_credential(name: &str, credential_override: Option<&str>) ->
         }
     }
 
-    let provider_env_candidates: Vec<&str> = match name {
-        "anthropic" => vec!["ANTHROPIC_OAUTH_TOKEN", "ANTHROPIC_API_KEY"],
-        "openrouter" => vec!["OPENROUTER_API_KEY"],
-        "openai" => vec!["OPENAI_API_KEY"],
-        "ollama" => vec!["OLLAMA_API_KEY"],
-        "venice" => vec!["VENICE_API_KEY"],
-        "groq" => vec!["GROQ_API_KEY"],
-        "mistral" => vec!["MISTRAL_API_KEY"],
-        "deepseek" => vec!["DEEPSEEK_API_KEY"],
-        "xai" | "grok" => vec!["XAI_API_KEY"],
-        "together" | "together-ai" => vec!["TOGETHER_API_KEY"],
-        "fireworks" | "fireworks-ai" => vec!["FIREWORKS_API_KEY"],
-        "perplexity" => vec!["PERPLEXITY_API_KEY"],
-        "cohere" => vec!["COHERE_API_KEY"],
-        name if is_moonshot_alias(name) => vec!["MOONSHOT_API_KEY"],
-        "kimi-code" | "kimi_coding" | "kimi_for_coding" => {
-            vec!["KIMI_CODE_API_KEY", "MOONSHOT_API_KEY"]
-        }
-        name if is_glm_alias(name) => vec!["GLM_API_KEY"],
-        name if is_minimax_alias(name) => vec![MINIMAX_OAUTH_TOKEN_ENV, MINIMAX_API_KEY_ENV],
-        // Bedrock uses AWS AKSK from env vars (AWS_ACCESS_KEY_ID + AWS_SECRET_ACCESS_KEY),
-        // not a single API key. Credential resolution happens inside BedrockProvider.
-        "bedrock" | "aws-bedrock" => return None,
-        name if is_qianfan_alias(name) => vec!["QIANFAN_API_KEY"],
-        name if is_qwen_alias(name) => vec!["DASHSCOPE_API_KEY"],
-        name if is_zai_alias(name) => vec!["ZAI_API_KEY"],
-        "nvidia" | "nvidia-nim" | "build.nvidia.com" => vec!["NVIDIA_API_KEY"],
-        "synthetic" => vec!["SYNTHETIC_API_KEY"],
-        "opencode" | "opencode-zen" => vec!["OPENCODE_API_KEY"],
-        "vercel" | "vercel-ai" => vec!["VERCEL_API_KEY"],
-        "cloudflare" | "cloudflare-ai" => vec!["CLOUDFLARE_API_KEY"],
-        "ovhcloud" | "ovh" => vec!["OVH_AI_ENDPOINTS_ACCESS_TOKEN"],
-        "astrai" => vec!["ASTRAI_API_KEY"],
-        _ => vec![],
-    };
+    // Bedrock uses AWS AKSK from env vars (AWS_ACCESS_KEY_ID + AWS_SECRET_ACCESS_KEY),
+    // not a single API key. Credential resolution happens inside BedrockProvider.
+    if matches!(name, "bedrock" | "aws-bedrock") {
+        return None;
+    }
 
-    for env_var in provider_env_candidates {
+    for env_var in provider_credential_env_vars(name) {
         if let Ok(value) = std::env::var(env_var) {
             let value = value.trim();
             if !value.is_empty() {
@@ -827,6 +833,65 @@ fn resolve_provider_credential(name: &str, credential_override: Option<&str>) ->
     None
 }
 
+/// Whether `name` is a known provider that needs an API key and none can be
+/// resolved from `credential_override` or the environment. Local providers
+/// and those with their own login flow (OAuth profiles, AWS keys, CLI
+/// logins) never count as missing one.
+pub fn is_missing_required_credential(name: &str, credential_override: Option<&str>) -> bool {
+    let Some(info) = list_providers()
+        .into_iter()
+        .find(|info| info.name == name || info.aliases.contains(&name))
+    else {
+        return false;
+    };
+    if info.local
+        || matches!(
+            info.name,
+            "mock" | "bedrock" | "openai-codex" | "copilot" | "gemini"
+        )
+    {
+        return false;
+    }
+    resolve_provider_credential(name, credential_override).is_none()
+}
+
+/// Provider-specific environment variables checked for a credential, in order.
+fn provider_credential_env_vars(name: &str) -> &'static [&'static str] {
+    match name {
+        "anthropic" => &["ANTHROPIC_OAUTH_TOKEN", "ANTHROPIC_API_KEY"],
+        "openrouter" => &["OPENROUTER_API_KEY"],
+        "openai" => &["OPENAI_API_KEY"],
+        "azure" | "azure-openai" | "azure_openai" => &["AZURE_OPENAI_API_KEY"],
+        "ollama" => &["OLLAMA_API_KEY"],
+        "venice" => &["VENICE_API_KEY"],
+        "groq" => &["GROQ_API_KEY"],
+        "mistral" => &["MISTRAL_API_KEY"],
+        "deepseek" => &["DEEPSEEK_API_KEY"],
+        "xai" | "grok" => &["XAI_API_KEY"],
+        "together" | "together-ai" => &["TOGETHER_API_KEY"],
+        "fireworks" | "fireworks-ai" => &["FIREWORKS_API_KEY"],
+        "perplexity" => &["PERPLEXITY_API_KEY"],
+        "cohere" => &["COHERE_API_KEY"],
+        name if is_moonshot_alias(name) => &["MOONSHOT_API_KEY"],
+        "kimi-code" | "kimi_coding" | "kimi_for_coding" => {
+            &["KIMI_CODE_API_KEY", "MOONSHOT_API_KEY"]
+        }
+        name if is_glm_alias(name) => &["GLM_API_KEY"],
+        name if is_minimax_alias(name) => &[MINIMAX_OAUTH_TOKEN_ENV, MINIMAX_API_KEY_ENV],
+        name if is_qianfan_alias(name) => &["QIANFAN_API_KEY"],
+        name if is_qwen_alias(name) => &["DASHSCOPE_API_KEY"],
+        name if is_zai_alias(name) => &["ZAI_API_KEY"],
+        "nvidia" | "nvidia-nim" | "build.nvidia.com" => &["NVIDIA_API_KEY"],
+        "synthetic" => &["SYNTHETIC_API_KEY"],
+        "opencode" | "opencode-zen" => &["OPENCODE_API_KEY"],
+        "vercel" | "vercel-ai" => &["VERCEL_API_KEY"],
+        "cloudflare" | "cloudflare-ai" => &["CLOUDFLARE_API_KEY"],
+        "ovhcloud" | "ovh" => &["OVH_AI_ENDPOINTS_ACCESS_TOKEN"],
+        "astrai" => &["ASTRAI_API_KEY"],
+        _ => &[],
+    }
+}
+
 fn parse_custom_provider_url(
     raw_url: &str,
     provider_label: &str,
@@ -865,16 +930,25 @@ pub fn create_provider_with_options(
         "openai-codex" | "openai_codex" | "codex" => {
             Ok(Box::new(openai_codex::OpenAiCodexProvider::new(options)))
         }
-        _ => create_provider_with_url(name, api_key, None),
+        _ => create_provider_with_url_and_options(name, api_key, None, options),
     }
 }
 
 /// Factory: create the right provider from config with optional custom base URL
-#[allow(clippy::too_many_lines)]
 pub fn create_provider_with_url(
     name: &str,
     api_key: Option<&str>,
     api_url: Option<&str>,
+) -> anyhow::Result<Box<dyn Provider>> {
+    create_provider_with_url_and_options(name, api_key, api_url, &ProviderRuntimeOptions::default())
+}
+
+#[allow(clippy::too_many_lines)]
+fn create_provider_with_url_and_options(
+    name: &str,
+    api_key: Option<&str>,
+    api_url: Option<&str>,
+    options: &ProviderRuntimeOptions,
 ) -> anyhow::Result<Box<dyn Provider>> {
     let qwen_oauth_context = is_qwen_oauth_alias(name).then(|| resolve_qwen_oauth_context(api_key));
 
@@ -891,9 +965,18 @@ pub fn create_provider_with_url(
     let key = resolved_credential.as_ref().map(String::as_str);
     match name {
         // ── Primary providers (custom implementations) ───────
-        "openrouter" => Ok(Box::new(openrouter::OpenRouterProvider::new(key))),
+        "openrouter" => Ok(Box::new(openrouter::OpenRouterProvider::with_base_url(
+            api_url, key,
+        ))),
         "anthropic" => Ok(Box::new(anthropic::AnthropicProvider::new(key))),
         "openai" => Ok(Box::new(openai::OpenAiProvider::with_base_url(api_url, key))),
+        "azure" | "azure-openai" | "azure_openai" => {
+            Ok(Box::new(azure_openai::AzureOpenAiProvider::new(
+                &options.azure_openai,
+                api_url,
+                key,
+            )))
+        }
         // Ollama uses api_url for custom base URL (e.g. remote Ollama instance)
         "ollama" => Ok(Box::new(ollama::OllamaProvider::new(api_url, key))),
         "gemini" | "google" | "google-gemini" => {
@@ -1041,6 +1124,15 @@ pub fn create_provider_with_url(
             key,
         ))),
 
+        // ── Offline testing ──────────────────────────────────
+        // api_url, when set, is the path to a JSON array of scripted responses.
+        "mock" => match api_url.map(str::trim).filter(|v| !v.is_empty()) {
+            Some(script) => Ok(Box::new(mock::MockProvider::from_script_file(
+                std::path::Path::new(script),
+            )?)),
+            None => Ok(Box::new(mock::MockProvider::echo())),
+        },
+
         // ── Bring Your Own Provider (custom URL) ───────────
         // Format: "custom:https://your-api.com" or "custom:http://localhost:1234"
         name if name.starts_with("custom:") => {
@@ -1109,7 +1201,7 @@ pub fn create_resilient_provider_with_options(
         "openai-codex" | "openai_codex" | "codex" => {
             create_provider_with_options(primary_name, api_key, options)?
         }
-        _ => create_provider_with_url(primary_name, api_key, api_url)?,
+        _ => create_provider_with_url_and_options(primary_name, api_key, api_url, options)?,
     };
     providers.push((primary_name.to_string(), primary_provider));
 
@@ -1138,13 +1230,60 @@ pub fn create_resilient_provider_with_options(
         }
     }
 
+    if reliability.log_requests {
+        let path = logging::resolve_request_log_path(reliability.request_log_path.as_deref());
+        tracing::warn!(
+            path = %path.display(),
+            "Provider request logging enabled; conversation content will be written to disk"
+        );
+        let mut secrets: Vec<String> = reliability.api_keys.clone();
+        secrets.extend(api_key.map(ToString::to_string));
+        secrets.extend(
+            providers
+                .iter()
+                .filter_map(|(name, _)| resolve_provider_credential(name, None)),
+        );
+        providers = providers
+            .into_iter()
+            .map(|(name, provider)| {
+                let logged: Box<dyn Provider> = Box::new(logging::LoggingProvider::new(
+                    name.clone(),
+                    provider,
+                    path.clone(),
+                    secrets.clone(),
+                ));
+                (name, logged)
+            })
+            .collect();
+    }
+
+    if !reliability.merge_consecutive_roles.is_empty() {
+        providers = providers
+            .into_iter()
+            .map(|(name, provider)| {
+                if reliability.merges_consecutive_roles_for(&name) {
+                    let merged: Box<dyn Provider> =
+                        Box::new(normalize::RoleMergingProvider::new(provider));
+                    (name, merged)
+                } else {
+                    (name, provider)
+                }
+            })
+            .collect();
+    }
+
     let reliable = ReliableProvider::new(
         providers,
         reliability.provider_retries,
         reliability.provider_backoff_ms,
     )
     .with_api_keys(reliability.api_keys.clone())
-    .with_model_fallbacks(reliability.model_fallbacks.clone());
+    .with_model_fallbacks(reliability.model_fallbacks.clone())
+    .with_circuit_breaker(
+        reliability.circuit_breaker_threshold,
+        std::time::Duration::from_secs(reliability.circuit_breaker_cooldown_secs),
+        options.observer.clone(),
+    );
 
     Ok(Box::new(reliable))
 }
@@ -1159,9 +1298,36 @@ pub fn create_routed_provider(
     reliability: &crate::config::ReliabilityConfig,
     model_routes: &[crate::config::ModelRouteConfig],
     default_model: &str,
+) -> anyhow::Result<Box<dyn Provider>> {
+    create_routed_provider_with_options(
+        primary_name,
+        api_key,
+        api_url,
+        reliability,
+        model_routes,
+        default_model,
+        &ProviderRuntimeOptions::default(),
+    )
+}
+
+/// Create a routed provider with runtime options applied to every route.
+pub fn create_routed_provider_with_options(
+    primary_name: &str,
+    api_key: Option<&str>,
+    api_url: Option<&str>,
+    reliability: &crate::config::ReliabilityConfig,
+    model_routes: &[crate::config::ModelRouteConfig],
+    default_model: &str,
+    options: &ProviderRuntimeOptions,
 ) -> anyhow::Result<Box<dyn Provider>> {
     if model_routes.is_empty() {
-        return create_resilient_provider(primary_name, api_key, api_url, reliability);
+        return create_resilient_provider_with_options(
+            primary_name,
+            api_key,
+            api_url,
+            reliability,
+            options,
+        );
     }
 
     // Collect unique provider names needed
@@ -1187,7 +1353,7 @@ pub fn create_routed_provider(
         let key = routed_credential.or(api_key);
         // Only use api_url for the primary provider
         let url = if name == primary_name { api_url } else { None };
-        match create_resilient_provider(name, key, url, reliability) {
+        match create_resilient_provider_with_options(name, key, url, reliability, options) {
             Ok(provider) => providers.push((name.clone(), provider)),
             Err(e) => {
                 if name == primary_name {
@@ -1234,6 +1400,49 @@ pub struct ProviderInfo {
     pub local: bool,
 }
 
+impl ProviderInfo {
+    /// How this provider is authenticated, for `zeroclaw providers list`.
+    pub fn credential_hint(&self) -> String {
+        match self.name {
+            "mock" => return "none".to_string(),
+            "bedrock" => return "AWS_ACCESS_KEY_ID + AWS_SECRET_ACCESS_KEY".to_string(),
+            "openai-codex" => return "OAuth profile (`zeroclaw auth login`)".to_string(),
+            "copilot" => return "api_key (GitHub token) or device login".to_string(),
+            "gemini" => return "api_key or GEMINI_API_KEY or Gemini CLI login".to_string(),
+            _ => {}
+        }
+        let env_vars = provider_credential_env_vars(self.name);
+        let required = if self.local { "optional " } else { "" };
+        if env_vars.is_empty() {
+            format!("{required}api_key")
+        } else {
+            format!("{required}api_key or {}", env_vars.join(" / "))
+        }
+    }
+
+    /// Whether the top-level `api_url` is honored (custom endpoint / script path).
+    pub fn accepts_api_url(&self) -> bool {
+        matches!(
+            self.name,
+            "openrouter" | "openai" | "azure" | "ollama" | "mock"
+        )
+    }
+
+    /// Shape of the `default_model` value this provider expects.
+    pub fn model_format(&self) -> &'static str {
+        match self.name {
+            "openrouter" | "vercel" | "together" | "nvidia" => {
+                "vendor/model (e.g. anthropic/claude-sonnet-4)"
+            }
+            "azure" => "deployment name",
+            "bedrock" => "Bedrock model ID (e.g. anthropic.claude-3-5-sonnet-20240620-v1:0)",
+            "ollama" | "lmstudio" => "locally installed model tag",
+            "mock" => "any",
+            _ => "provider model ID",
+        }
+    }
+}
+
 /// Return the list of all known providers for display in `zeroclaw providers list`.
 ///
 /// This is intentionally separate from the factory match in `create_provider`
@@ -1259,6 +1468,12 @@ pub fn list_providers() -> Vec<ProviderInfo> {
             aliases: &[],
             local: false,
         },
+        ProviderInfo {
+            name: "azure",
+            display_name: "Azure OpenAI",
+            aliases: &["azure-openai", "azure_openai"],
+            local: false,
+        },
         ProviderInfo {
             name: "openai-codex",
             display_name: "OpenAI Codex (OAuth)",
@@ -1447,6 +1662,13 @@ pub fn list_providers() -> Vec<ProviderInfo> {
             aliases: &["ovh"],
             local: false,
         },
+        // ── Offline testing ──────────────────────────────────
+        ProviderInfo {
+            name: "mock",
+            display_name: "Mock (scripted, offline)",
+            aliases: &[],
+            local: true,
+        },
     ]
 }
 
@@ -1489,6 +1711,15 @@ mod tests {
             .expect("env lock poisoned")
     }
 
+    #[test]
+    fn missing_required_credential_ignores_local_and_login_providers() {
+        assert!(!is_missing_required_credential("ollama", None));
+        assert!(!is_missing_required_credential("mock", None));
+        assert!(!is_missing_required_credential("bedrock", None));
+        assert!(!is_missing_required_credential("openai", Some("sk-test")));
+        assert!(!is_missing_required_credential("not-a-provider", None));
+    }
+
     #[test]
     fn resolve_provider_credential_prefers_explicit_argument() {
         let resolved = resolve_provider_credential("openrouter", Some("  explicit-key  "));
@@ -1726,6 +1957,13 @@ mod tests {
         assert!(create_provider("openai", Some("provider-test-credential")).is_ok());
     }
 
+    #[test]
+    fn factory_azure_openai() {
+        assert!(create_provider("azure", Some("provider-test-credential")).is_ok());
+        assert!(create_provider("azure-openai", Some("provider-test-credential")).is_ok());
+        assert!(create_provider("azure_openai", None).is_ok());
+    }
+
     #[test]
     fn factory_openai_codex() {
         let options = ProviderRuntimeOptions::default();
@@ -1935,6 +2173,17 @@ mod tests {
 
     // ── Custom / BYOP provider ─────────────────────────────
 
+    #[test]
+    fn factory_mock() {
+        assert!(create_provider("mock", None).is_ok());
+
+        let dir = tempfile::tempdir().unwrap();
+        let script = dir.path().join("script.json");
+        std::fs::write(&script, r#"["scripted"]"#).unwrap();
+        assert!(create_provider_with_url("mock", None, script.to_str()).is_ok());
+        assert!(create_provider_with_url("mock", None, Some("/nonexistent/script.json")).is_err());
+    }
+
     #[test]
     fn factory_custom_url() {
         let p = create_provider("custom:https://my-llm.example.com", Some("key"));
@@ -2061,11 +2310,32 @@ mod tests {
         assert!(create_provider("", None).is_err());
     }
 
+    #[test]
+    fn resilient_provider_with_request_logging() {
+        let dir = tempfile::tempdir().unwrap();
+        let reliability = crate::config::ReliabilityConfig {
+            log_requests: true,
+            request_log_path: Some(dir.path().join("requests.jsonl").display().to_string()),
+            ..crate::config::ReliabilityConfig::default()
+        };
+
+        let provider = create_resilient_provider(
+            "openrouter",
+            Some("provider-test-credential"),
+            None,
+            &reliability,
+        );
+        assert!(provider.is_ok());
+    }
+
     #[test]
     fn resilient_provider_ignores_duplicate_and_invalid_fallbacks() {
         let reliability = crate::config::ReliabilityConfig {
             provider_retries: 1,
             provider_backoff_ms: 100,
+            turn_retries: 2,
+            circuit_breaker_threshold: 5,
+            circuit_breaker_cooldown_secs: 30,
             fallback_providers: vec![
                 "openrouter".into(),
                 "nonexistent-provider".into(),
@@ -2078,6 +2348,9 @@ mod tests {
             channel_max_backoff_secs: 60,
             scheduler_poll_secs: 15,
             scheduler_retries: 2,
+            log_requests: false,
+            request_log_path: None,
+            merge_consecutive_roles: Vec::new(),
         };
 
         let provider = create_resilient_provider(
@@ -2110,6 +2383,9 @@ mod tests {
         let reliability = crate::config::ReliabilityConfig {
             provider_retries: 1,
             provider_backoff_ms: 100,
+            turn_retries: 2,
+            circuit_breaker_threshold: 5,
+            circuit_breaker_cooldown_secs: 30,
             fallback_providers: vec!["lmstudio".into(), "ollama".into()],
             api_keys: Vec::new(),
             model_fallbacks: std::collections::HashMap::new(),
@@ -2117,6 +2393,9 @@ mod tests {
             channel_max_backoff_secs: 60,
             scheduler_poll_secs: 15,
             scheduler_retries: 2,
+            log_requests: false,
+            request_log_path: None,
+            merge_consecutive_roles: Vec::new(),
         };
 
         // Primary uses a ZAI key; fallbacks (lmstudio, ollama) should NOT
@@ -2132,6 +2411,9 @@ mod tests {
         let reliability = crate::config::ReliabilityConfig {
             provider_retries: 1,
             provider_backoff_ms: 100,
+            turn_retries: 2,
+            circuit_breaker_threshold: 5,
+            circuit_breaker_cooldown_secs: 30,
             fallback_providers: vec!["custom:http://host.docker.internal:1234/v1".into()],
             api_keys: Vec::new(),
             model_fallbacks: std::collections::HashMap::new(),
@@ -2139,6 +2421,9 @@ mod tests {
             channel_max_backoff_secs: 60,
             scheduler_poll_secs: 15,
             scheduler_retries: 2,
+            log_requests: false,
+            request_log_path: None,
+            merge_consecutive_roles: Vec::new(),
         };
 
         let provider =
@@ -2153,6 +2438,9 @@ mod tests {
         let reliability = crate::config::ReliabilityConfig {
             provider_retries: 1,
             provider_backoff_ms: 100,
+            turn_retries: 2,
+            circuit_breaker_threshold: 5,
+            circuit_breaker_cooldown_secs: 30,
             fallback_providers: vec![
                 "deepseek".into(),
                 "custom:http://localhost:8080/v1".into(),
@@ -2165,6 +2453,9 @@ mod tests {
             channel_max_backoff_secs: 60,
             scheduler_poll_secs: 15,
             scheduler_retries: 2,
+            log_requests: false,
+            request_log_path: None,
+            merge_consecutive_roles: Vec::new(),
         };
 
         let provider = create_resilient_provider("zai", Some("zai-test-key"), None, &reliability);
@@ -2266,6 +2557,37 @@ mod tests {
         }
     }
 
+    #[test]
+    fn listed_providers_describe_their_config() {
+        for provider in list_providers() {
+            assert!(!provider.credential_hint().is_empty(), "{}", provider.name);
+            assert!(!provider.model_format().is_empty(), "{}", provider.name);
+        }
+
+        let find = |name: &str| {
+            list_providers()
+                .into_iter()
+                .find(|p| p.name == name)
+                .unwrap()
+        };
+        assert_eq!(
+            find("openrouter").credential_hint(),
+            "api_key or OPENROUTER_API_KEY"
+        );
+        assert_eq!(
+            find("ollama").credential_hint(),
+            "optional api_key or OLLAMA_API_KEY"
+        );
+        assert!(find("bedrock")
+            .credential_hint()
+            .contains("AWS_ACCESS_KEY_ID"));
+        assert!(find("ollama").accepts_api_url());
+        assert!(!find("groq").accepts_api_url());
+        assert!(find("openrouter")
+            .model_format()
+            .starts_with("vendor/model"));
+    }
+
     #[test]
     fn listed_providers_and_aliases_are_constructible() {
         for provider in list_providers() {
@@ -0,0 +1,211 @@
+//! Pre-send history normalization for providers with strict role rules.
+//!
+//! The agent loop appends an assistant reply and a `[Tool results]` user
+//! message per iteration, and reminders or memory context can add more user
+//! messages in a row. Some APIs reject consecutive messages with the same
+//! role ("roles must alternate"); [`RoleMergingProvider`] folds them into one
+//! before the request is sent.
+
+use super::traits::{
+    ChatMessage, ChatRequest, ChatResponse, ProviderCapabilities, StreamChunk, StreamOptions,
+    StreamResult, ToolsPayload,
+};
+use super::Provider;
+use crate::tools::ToolSpec;
+use async_trait::async_trait;
+use futures_util::stream;
+
+/// Whether `message` can be folded into a neighbour with the same role.
+///
+/// Native tool calling encodes assistant tool calls and `tool` results as
+/// JSON payloads that reference call IDs; merging those would corrupt them.
+fn is_mergeable(message: &ChatMessage) -> bool {
+    if !matches!(message.role.as_str(), "system" | "user" | "assistant") {
+        return false;
+    }
+    let trimmed = message.content.trim_start();
+    if !trimmed.starts_with('{') {
+        return true;
+    }
+    serde_json::from_str::<serde_json::Value>(trimmed)
+        .ok()
+        .is_none_or(|value| {
+            value.get("tool_calls").is_none() && value.get("tool_call_id").is_none()
+        })
+}
+
+/// Merge runs of consecutive `system`, `user` or `assistant` messages into a
+/// single message per run, joining contents with a blank line. Empty messages
+/// are dropped from a run. Order and every other message are preserved.
+pub fn merge_consecutive_roles(messages: &[ChatMessage]) -> Vec<ChatMessage> {
+    let mut merged: Vec<ChatMessage> = Vec::with_capacity(messages.len());
+    for message in messages {
+        if let Some(last) = merged.last_mut() {
+            if last.role == message.role && is_mergeable(last) && is_mergeable(message) {
+                if message.content.trim().is_empty() {
+                    continue;
+                }
+                if !last.content.trim().is_empty() {
+                    last.content.push_str("\n\n");
+                }
+                last.content.push_str(&message.content);
+                last.pinned |= message.pinned;
+                continue;
+            }
+        }
+        merged.push(message.clone());
+    }
+    merged
+}
+
+/// Provider wrapper that applies [`merge_consecutive_roles`] to every
+/// history-based request.
+pub struct RoleMergingProvider {
+    inner: Box<dyn Provider>,
+}
+
+impl RoleMergingProvider {
+    pub fn new(inner: Box<dyn Provider>) -> Self {
+        Self { inner }
+    }
+}
+
+#[async_trait]
+impl Provider for RoleMergingProvider {
+    fn capabilities(&self) -> ProviderCapabilities {
+        self.inner.capabilities()
+    }
+
+    fn convert_tools(&self, tools: &[ToolSpec]) -> ToolsPayload {
+        self.inner.convert_tools(tools)
+    }
+
+    async fn chat_with_system(
+        &self,
+        system_prompt: Option<&str>,
+        message: &str,
+        model: &str,
+        temperature: f64,
+    ) -> anyhow::Result<String> {
+        self.inner
+            .chat_with_system(system_prompt, message, model, temperature)
+            .await
+    }
+
+    async fn chat_with_history(
+        &self,
+        messages: &[ChatMessage],
+        model: &str,
+        temperature: f64,
+    ) -> anyhow::Result<String> {
+        let messages = merge_consecutive_roles(messages);
+        self.inner
+            .chat_with_history(&messages, model, temperature)
+            .await
+    }
+
+    async fn chat(
+        &self,
+        request: ChatRequest<'_>,
+        model: &str,
+        temperature: f64,
+    ) -> anyhow::Result<ChatResponse> {
+        let messages = merge_consecutive_roles(request.messages);
+        let request = ChatRequest {
+            messages: &messages,
+            ..request
+        };
+        self.inner.chat(request, model, temperature).await
+    }
+
+    fn supports_native_tools(&self) -> bool {
+        self.inner.supports_native_tools()
+    }
+
+    async fn warmup(&self) -> anyhow::Result<()> {
+        self.inner.warmup().await
+    }
+
+    async fn chat_with_tools(
+        &self,
+        messages: &[ChatMessage],
+        tools: &[serde_json::Value],
+        model: &str,
+        temperature: f64,
+    ) -> anyhow::Result<ChatResponse> {
+        let messages = merge_consecutive_roles(messages);
+        self.inner
+            .chat_with_tools(&messages, tools, model, temperature)
+            .await
+    }
+
+    fn supports_streaming(&self) -> bool {
+        self.inner.supports_streaming()
+    }
+
+    fn stream_chat_with_system(
+        &self,
+        system_prompt: Option<&str>,
+        message: &str,
+        model: &str,
+        temperature: f64,
+        options: StreamOptions,
+    ) -> stream::BoxStream<'static, StreamResult<StreamChunk>> {
+        self.inner
+            .stream_chat_with_system(system_prompt, message, model, temperature, options)
+    }
+
+    fn stream_chat_with_history(
+        &self,
+        messages: &[ChatMessage],
+        model: &str,
+        temperature: f64,
+        options: StreamOptions,
+    ) -> stream::BoxStream<'static, StreamResult<StreamChunk>> {
+        let messages = merge_consecutive_roles(messages);
+        self.inner
+            .stream_chat_with_history(&messages, model, temperature, options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merges_same_role_runs_but_keeps_native_tool_payloads() {
+        let native_call =
+            r#"{"content":"","tool_calls":[{"id":"call_1","name":"shell","arguments":"{}"}]}"#;
+        let messages = vec![
+            ChatMessage::system("sys"),
+            ChatMessage::user("hello"),
+            ChatMessage::user("[Memory context] likes rust"),
+            ChatMessage::assistant("Let me check."),
+            ChatMessage::assistant(""),
+            ChatMessage::user("[Tool results] ok"),
+            ChatMessage::assistant(native_call),
+            ChatMessage::assistant("done"),
+            ChatMessage::tool(r#"{"tool_call_id":"call_1","content":"a"}"#),
+            ChatMessage::tool(r#"{"tool_call_id":"call_2","content":"b"}"#),
+        ];
+
+        let merged = merge_consecutive_roles(&messages);
+        let roles: Vec<&str> = merged.iter().map(|m| m.role.as_str()).collect();
+        assert_eq!(
+            roles,
+            [
+                "system",
+                "user",
+                "assistant",
+                "user",
+                "assistant",
+                "assistant",
+                "tool",
+                "tool"
+            ]
+        );
+        assert_eq!(merged[1].content, "hello\n\n[Memory context] likes rust");
+        assert_eq!(merged[2].content, "Let me check.");
+        assert_eq!(merged[4].content, native_call);
+    }
+}
@@ -1,4 +1,7 @@
-use crate::providers::traits::{ChatMessage, ChatResponse, Provider, ToolCall};
+use crate::providers::traits::{
+    ChatMessage, ChatRequest as ProviderChatRequest, ChatResponse, GenerationParams, Provider,
+    ToolCall,
+};
 use async_trait::async_trait;
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
@@ -48,6 +51,24 @@ struct OutgoingFunction {
 #[derive(Debug, Serialize)]
 struct Options {
     temperature: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    top_p: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    num_predict: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stop: Option<Vec<String>>,
+}
+
+impl Options {
+    fn new(temperature: f64, params: Option<&GenerationParams>) -> Self {
+        let params = params.cloned().unwrap_or_default();
+        Self {
+            temperature: params.temperature_or(temperature),
+            top_p: params.top_p,
+            num_predict: params.max_tokens,
+            stop: params.stop_sequences(),
+        }
+    }
 }
 
 // ─── Response Structures ──────────────────────────────────────────────────────
@@ -225,13 +246,90 @@ impl OllamaProvider {
             .collect()
     }
 
+    /// Native function-calling request shared by `chat` and `chat_with_tools`.
+    async fn chat_native(
+        &self,
+        messages: &[ChatMessage],
+        tools: &[serde_json::Value],
+        model: &str,
+        options: Options,
+    ) -> anyhow::Result<ChatResponse> {
+        let (normalized_model, should_auth) = self.resolve_request_details(model)?;
+
+        let api_messages = self.convert_messages(messages);
+
+        // Tools arrive pre-formatted in OpenAI/Ollama-compatible JSON — pass
+        // them through directly.
+        let tools_opt = if tools.is_empty() { None } else { Some(tools) };
+
+        let response = self
+            .send_request(
+                api_messages,
+                &normalized_model,
+                options,
+                should_auth,
+                tools_opt,
+            )
+            .await?;
+
+        // Native tool calls returned by the model.
+        if !response.message.tool_calls.is_empty() {
+            let tool_calls: Vec<ToolCall> = response
+                .message
+                .tool_calls
+                .iter()
+                .map(|tc| {
+                    let (name, args) = self.extract_tool_name_and_args(tc);
+                    ToolCall {
+                        id: tc
+                            .id
+                            .clone()
+                            .unwrap_or_else(|| uuid::Uuid::new_v4().to_string()),
+                        name,
+                        arguments: serde_json::to_string(&args)
+                            .unwrap_or_else(|_| "{}".to_string()),
+                    }
+                })
+                .collect();
+            let text = if response.message.content.is_empty() {
+                None
+            } else {
+                Some(response.message.content)
+            };
+            return Ok(ChatResponse { text, tool_calls });
+        }
+
+        // Plain text response.
+        let content = response.message.content;
+        if content.is_empty() {
+            if let Some(thinking) = &response.message.thinking {
+                tracing::warn!(
+                    "Ollama returned empty content with only thinking: '{}'. Model may have stopped prematurely.",
+                    if thinking.len() > 100 { &thinking[..100] } else { thinking }
+                );
+                return Ok(ChatResponse {
+                    text: Some(format!(
+                        "I was thinking about this: {}... but I didn't complete my response. Could you try asking again?",
+                        if thinking.len() > 200 { &thinking[..200] } else { thinking }
+                    )),
+                    tool_calls: vec![],
+                });
+            }
+            tracing::warn!("Ollama returned empty content with no tool calls");
+        }
+        Ok(ChatResponse {
+            text: Some(content),
+            tool_calls: vec![],
+        })
+    }
+
     /// Send a request to Ollama and get the parsed response.
     /// Pass `tools` to enable native function-calling for models that support it.
     async fn send_request(
         &self,
         messages: Vec<Message>,
         model: &str,
-        temperature: f64,
+        options: Options,
         should_auth: bool,
         tools: Option<&[serde_json::Value]>,
     ) -> anyhow::Result<ApiChatResponse> {
@@ -239,7 +337,7 @@ impl OllamaProvider {
             model: model.to_string(),
             messages,
             stream: false,
-            options: Options { temperature },
+            options,
             tools: tools.map(|t| t.to_vec()),
         };
 
@@ -250,7 +348,7 @@ impl OllamaProvider {
             url,
             model,
             request.messages.len(),
-            temperature,
+            request.options.temperature,
             request.tools.as_ref().map_or(0, |t| t.len()),
         );
 
@@ -262,7 +360,10 @@ impl OllamaProvider {
             }
         }
 
-        let response = request_builder.send().await?;
+        let response = request_builder
+            .send()
+            .await
+            .map_err(super::ProviderError::from)?;
         let status = response.status();
         tracing::debug!("Ollama response status: {}", status);
 
@@ -277,11 +378,13 @@ impl OllamaProvider {
                 status,
                 sanitized
             );
-            anyhow::bail!(
-                "Ollama API error ({}): {}. Is Ollama running? (brew install ollama && ollama serve)",
-                status,
-                sanitized
-            );
+            return Err(super::ProviderError::from_status(
+                status.as_u16(),
+                format!(
+                    "Ollama API error ({status}): {sanitized}. Is Ollama running? (brew install ollama && ollama serve)"
+                ),
+            )
+            .into());
         }
 
         let chat_response: ApiChatResponse = match serde_json::from_slice(&body) {
@@ -402,7 +505,13 @@ impl Provider for OllamaProvider {
         });
 
         let response = self
-            .send_request(messages, &normalized_model, temperature, should_auth, None)
+            .send_request(
+                messages,
+                &normalized_model,
+                Options::new(temperature, None),
+                should_auth,
+                None,
+            )
             .await?;
 
         // If model returned tool calls, format them for loop_.rs's parse_tool_calls
@@ -449,7 +558,7 @@ impl Provider for OllamaProvider {
             .send_request(
                 api_messages,
                 &normalized_model,
-                temperature,
+                Options::new(temperature, None),
                 should_auth,
                 None,
             )
@@ -487,80 +596,45 @@ impl Provider for OllamaProvider {
         Ok(content)
     }
 
-    async fn chat_with_tools(
+    async fn chat(
         &self,
-        messages: &[ChatMessage],
-        tools: &[serde_json::Value],
+        request: ProviderChatRequest<'_>,
         model: &str,
         temperature: f64,
     ) -> anyhow::Result<ChatResponse> {
-        let (normalized_model, should_auth) = self.resolve_request_details(model)?;
-
-        let api_messages = self.convert_messages(messages);
-
-        // Tools arrive pre-formatted in OpenAI/Ollama-compatible JSON from
-        // tools_to_openai_format() in loop_.rs — pass them through directly.
-        let tools_opt = if tools.is_empty() { None } else { Some(tools) };
-
-        let response = self
-            .send_request(
-                api_messages,
-                &normalized_model,
-                temperature,
-                should_auth,
-                tools_opt,
-            )
-            .await?;
-
-        // Native tool calls returned by the model.
-        if !response.message.tool_calls.is_empty() {
-            let tool_calls: Vec<ToolCall> = response
-                .message
-                .tool_calls
-                .iter()
-                .map(|tc| {
-                    let (name, args) = self.extract_tool_name_and_args(tc);
-                    ToolCall {
-                        id: tc
-                            .id
-                            .clone()
-                            .unwrap_or_else(|| uuid::Uuid::new_v4().to_string()),
-                        name,
-                        arguments: serde_json::to_string(&args)
-                            .unwrap_or_else(|_| "{}".to_string()),
+        let tools: Vec<serde_json::Value> = request
+            .tools
+            .unwrap_or_default()
+            .iter()
+            .map(|tool| {
+                serde_json::json!({
+                    "type": "function",
+                    "function": {
+                        "name": tool.name,
+                        "description": tool.description,
+                        "parameters": tool.parameters,
                     }
                 })
-                .collect();
-            let text = if response.message.content.is_empty() {
-                None
-            } else {
-                Some(response.message.content)
-            };
-            return Ok(ChatResponse { text, tool_calls });
-        }
+            })
+            .collect();
+        self.chat_native(
+            request.messages,
+            &tools,
+            model,
+            Options::new(temperature, request.params),
+        )
+        .await
+    }
 
-        // Plain text response.
-        let content = response.message.content;
-        if content.is_empty() {
-            if let Some(thinking) = &response.message.thinking {
-                tracing::warn!(
-                    "Ollama returned empty content with only thinking: '{}'. Model may have stopped prematurely.",
-                    if thinking.len() > 100 { &thinking[..100] } else { thinking }
-                );
-                return Ok(ChatResponse {
-                    text: Some(format!(
-                        "I was thinking about this: {}... but I didn't complete my response. Could you try asking again?",
-                        if thinking.len() > 200 { &thinking[..200] } else { thinking }
-                    )),
-                    tool_calls: vec![],
-                });
-            }
-            tracing::warn!("Ollama returned empty content with no tool calls");
-        }
-        Ok(ChatResponse {
-            text: Some(content),
-            tool_calls: vec![],
-        })
+    async fn chat_with_tools(
+        &self,
+        messages: &[ChatMessage],
+        tools: &[serde_json::Value],
+        model: &str,
+        temperature: f64,
+    ) -> anyhow::Result<ChatResponse> {
+        self.chat_native(messages, tools, model, Options::new(temperature, None))
+            .await
     }
 
     fn supports_native_tools(&self) -> bool {
@@ -645,6 +719,32 @@ mod tests {
         assert!(!should_auth);
     }
 
+    #[test]
+    fn request_options_map_generation_params() {
+        let mut request = ChatRequest {
+            model: "llama3".to_string(),
+            messages: vec![],
+            stream: false,
+            options: Options::new(0.7, None),
+            tools: None,
+        };
+        let json = serde_json::to_string(&request).unwrap();
+        assert!(json.contains("\"options\":{\"temperature\":0.7}"));
+
+        let params = GenerationParams {
+            temperature: Some(0.2),
+            top_p: Some(0.9),
+            max_tokens: Some(512),
+            stop: vec!["END".into()],
+        };
+        request.options = Options::new(0.7, Some(&params));
+        let json = serde_json::to_string(&request).unwrap();
+        assert!(json.contains("\"temperature\":0.2"));
+        assert!(json.contains("\"top_p\":0.9"));
+        assert!(json.contains("\"num_predict\":512"));
+        assert!(json.contains("\"stop\":[\"END\"]"));
+    }
+
     #[test]
     fn response_deserializes() {
         let json = r#"{"message":{"role":"assistant","content":"Hello from Ollama!"}}"#;
@@ -760,7 +860,7 @@ mod tests {
         let provider = OllamaProvider::new(None, None);
         let messages = vec![ChatMessage {
             role: "assistant".into(),
-            content: r#"{"content":null,"tool_calls":[{"id":"call_1","name":"shell","arguments":"{\"command\":\"ls\"}"}]}"#.into(),
+            content: r#"{"content":null,"tool_calls":[{"id":"call_1","name":"shell","arguments":"{\"command\":\"ls\"}"}]}"#.into(), pinned: false,
         }];
 
         let converted = provider.convert_messages(&messages);
@@ -784,11 +884,11 @@ mod tests {
         let messages = vec![
             ChatMessage {
                 role: "assistant".into(),
-                content: r#"{"content":null,"tool_calls":[{"id":"call_7","name":"file_read","arguments":"{\"path\":\"README.md\"}"}]}"#.into(),
+                content: r#"{"content":null,"tool_calls":[{"id":"call_7","name":"file_read","arguments":"{\"path\":\"README.md\"}"}]}"#.into(), pinned: false,
             },
             ChatMessage {
                 role: "tool".into(),
-                content: r#"{"tool_call_id":"call_7","content":"ok"}"#.into(),
+                content: r#"{"tool_call_id":"call_7","content":"ok"}"#.into(), pinned: false,
             },
         ];
 
@@ -54,18 +54,24 @@ impl ResponseMessage {
 }
 
 #[derive(Debug, Serialize)]
-struct NativeChatRequest {
-    model: String,
-    messages: Vec<NativeMessage>,
-    temperature: f64,
+pub(super) struct NativeChatRequest {
+    pub(super) model: String,
+    pub(super) messages: Vec<NativeMessage>,
+    pub(super) temperature: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(super) tools: Option<Vec<NativeToolSpec>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(super) tool_choice: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    tools: Option<Vec<NativeToolSpec>>,
+    pub(super) top_p: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    tool_choice: Option<String>,
+    pub(super) max_tokens: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(super) stop: Option<Vec<String>>,
 }
 
 #[derive(Debug, Serialize)]
-struct NativeMessage {
+pub(super) struct NativeMessage {
     role: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     content: Option<String>,
@@ -76,21 +82,21 @@ struct NativeMessage {
 }
 
 #[derive(Debug, Serialize, Deserialize)]
-struct NativeToolSpec {
+pub(super) struct NativeToolSpec {
     #[serde(rename = "type")]
     kind: String,
     function: NativeToolFunctionSpec,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
-struct NativeToolFunctionSpec {
+pub(super) struct NativeToolFunctionSpec {
     name: String,
     description: String,
     parameters: serde_json::Value,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
-struct NativeToolCall {
+pub(super) struct NativeToolCall {
     #[serde(skip_serializing_if = "Option::is_none")]
     id: Option<String>,
     #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
@@ -99,23 +105,23 @@ struct NativeToolCall {
 }
 
 #[derive(Debug, Serialize, Deserialize)]
-struct NativeFunctionCall {
+pub(super) struct NativeFunctionCall {
     name: String,
     arguments: String,
 }
 
 #[derive(Debug, Deserialize)]
-struct NativeChatResponse {
-    choices: Vec<NativeChoice>,
+pub(super) struct NativeChatResponse {
+    pub(super) choices: Vec<NativeChoice>,
 }
 
 #[derive(Debug, Deserialize)]
-struct NativeChoice {
-    message: NativeResponseMessage,
+pub(super) struct NativeChoice {
+    pub(super) message: NativeResponseMessage,
 }
 
 #[derive(Debug, Deserialize)]
-struct NativeResponseMessage {
+pub(super) struct NativeResponseMessage {
     #[serde(default)]
     content: Option<String>,
     /// Reasoning/thinking models may return output in `reasoning_content`.
@@ -150,7 +156,7 @@ impl OpenAiProvider {
         }
     }
 
-    fn convert_tools(tools: Option<&[ToolSpec]>) -> Option<Vec<NativeToolSpec>> {
+    pub(super) fn convert_tools(tools: Option<&[ToolSpec]>) -> Option<Vec<NativeToolSpec>> {
         tools.map(|items| {
             items
                 .iter()
@@ -166,7 +172,7 @@ impl OpenAiProvider {
         })
     }
 
-    fn convert_messages(messages: &[ChatMessage]) -> Vec<NativeMessage> {
+    pub(super) fn convert_messages(messages: &[ChatMessage]) -> Vec<NativeMessage> {
         messages
             .iter()
             .map(|m| {
@@ -233,7 +239,7 @@ impl OpenAiProvider {
             .collect()
     }
 
-    fn parse_native_response(message: NativeResponseMessage) -> ProviderChatResponse {
+    pub(super) fn parse_native_response(message: NativeResponseMessage) -> ProviderChatResponse {
         let text = message.effective_content();
         let tool_calls = message
             .tool_calls
@@ -293,7 +299,8 @@ impl Provider for OpenAiProvider {
             .header("Authorization", format!("Bearer {credential}"))
             .json(&request)
             .send()
-            .await?;
+            .await
+            .map_err(super::ProviderError::from)?;
 
         if !response.status().is_success() {
             return Err(super::api_error("OpenAI", response).await);
@@ -320,12 +327,16 @@ impl Provider for OpenAiProvider {
         })?;
 
         let tools = Self::convert_tools(request.tools);
+        let params = request.params.cloned().unwrap_or_default();
         let native_request = NativeChatRequest {
             model: model.to_string(),
             messages: Self::convert_messages(request.messages),
-            temperature,
+            temperature: params.temperature_or(temperature),
             tool_choice: tools.as_ref().map(|_| "auto".to_string()),
             tools,
+            top_p: params.top_p,
+            max_tokens: params.max_tokens,
+            stop: params.stop_sequences(),
         };
 
         let response = self
@@ -334,7 +345,8 @@ impl Provider for OpenAiProvider {
             .header("Authorization", format!("Bearer {credential}"))
             .json(&native_request)
             .send()
-            .await?;
+            .await
+            .map_err(super::ProviderError::from)?;
 
         if !response.status().is_success() {
             return Err(super::api_error("OpenAI", response).await);
@@ -384,6 +396,9 @@ impl Provider for OpenAiProvider {
             temperature,
             tool_choice: native_tools.as_ref().map(|_| "auto".to_string()),
             tools: native_tools,
+            top_p: None,
+            max_tokens: None,
+            stop: None,
         };
 
         let response = self
@@ -392,7 +407,8 @@ impl Provider for OpenAiProvider {
             .header("Authorization", format!("Bearer {credential}"))
             .json(&native_request)
             .send()
-            .await?;
+            .await
+            .map_err(super::ProviderError::from)?;
 
         if !response.status().is_success() {
             return Err(super::api_error("OpenAI", response).await);
@@ -482,6 +498,32 @@ mod tests {
         assert!(json.contains("gpt-4o"));
     }
 
+    #[test]
+    fn native_request_serializes_generation_params_only_when_set() {
+        let mut req = NativeChatRequest {
+            model: "gpt-4o".to_string(),
+            messages: OpenAiProvider::convert_messages(&[ChatMessage::user("hi")]),
+            temperature: 0.2,
+            tools: None,
+            tool_choice: None,
+            top_p: None,
+            max_tokens: None,
+            stop: None,
+        };
+        let json = serde_json::to_string(&req).unwrap();
+        assert!(!json.contains("top_p"));
+        assert!(!json.contains("max_tokens"));
+        assert!(!json.contains("\"stop\""));
+
+        req.top_p = Some(0.9);
+        req.max_tokens = Some(512);
+        req.stop = Some(vec!["END".into()]);
+        let json = serde_json::to_string(&req).unwrap();
+        assert!(json.contains("\"top_p\":0.9"));
+        assert!(json.contains("\"max_tokens\":512"));
+        assert!(json.contains("\"stop\":[\"END\"]"));
+    }
+
     #[test]
     fn request_serializes_without_system() {
         let req = ChatRequest {
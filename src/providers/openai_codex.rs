@@ -88,11 +88,14 @@ impl OpenAiCodexProvider {
         Self {
             auth,
             auth_profile_override: options.auth_profile_override.clone(),
-            client: Client::builder()
-                .timeout(std::time::Duration::from_secs(120))
-                .connect_timeout(std::time::Duration::from_secs(10))
-                .build()
-                .unwrap_or_else(|_| Client::new()),
+            client: crate::config::apply_runtime_proxy_to_builder(
+                Client::builder()
+                    .timeout(std::time::Duration::from_secs(120))
+                    .connect_timeout(std::time::Duration::from_secs(10)),
+                "provider.openai_codex",
+            )
+            .build()
+            .unwrap_or_else(|_| Client::new()),
         }
     }
 }
@@ -431,7 +434,8 @@ impl OpenAiCodexProvider {
             .header("Content-Type", "application/json")
             .json(&request)
             .send()
-            .await?;
+            .await
+            .map_err(super::ProviderError::from)?;
 
         if !response.status().is_success() {
             return Err(super::api_error("OpenAI Codex", response).await);
@@ -585,18 +589,22 @@ data: [DONE]
             ChatMessage {
                 role: "system".into(),
                 content: "You are helpful.".into(),
+                pinned: false,
             },
             ChatMessage {
                 role: "user".into(),
                 content: "Hi".into(),
+                pinned: false,
             },
             ChatMessage {
                 role: "assistant".into(),
                 content: "Hello!".into(),
+                pinned: false,
             },
             ChatMessage {
                 role: "user".into(),
                 content: "Thanks".into(),
+                pinned: false,
             },
         ];
         let (instructions, input) = build_responses_input(&messages);
@@ -620,6 +628,7 @@ data: [DONE]
         let messages = vec![ChatMessage {
             role: "user".into(),
             content: "Hello".into(),
+            pinned: false,
         }];
         let (instructions, input) = build_responses_input(&messages);
         assert_eq!(instructions, DEFAULT_CODEX_INSTRUCTIONS);
@@ -632,10 +641,12 @@ data: [DONE]
             ChatMessage {
                 role: "tool".into(),
                 content: "result".into(),
+                pinned: false,
             },
             ChatMessage {
                 role: "user".into(),
                 content: "Go".into(),
+                pinned: false,
             },
         ];
         let (instructions, input) = build_responses_input(&messages);
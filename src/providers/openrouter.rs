@@ -7,7 +7,11 @@ use async_trait::async_trait;
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
 
+/// Default OpenRouter API base.
+const OPENROUTER_BASE_URL: &str = "https://openrouter.ai/api/v1";
+
 pub struct OpenRouterProvider {
+    base_url: String,
     credential: Option<String>,
 }
 
@@ -48,6 +52,12 @@ struct NativeChatRequest {
     tools: Option<Vec<NativeToolSpec>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     tool_choice: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    top_p: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_tokens: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stop: Option<Vec<String>>,
 }
 
 #[derive(Debug, Serialize)]
@@ -110,7 +120,19 @@ struct NativeResponseMessage {
 
 impl OpenRouterProvider {
     pub fn new(credential: Option<&str>) -> Self {
+        Self::with_base_url(None, credential)
+    }
+
+    /// Create a provider with an optional custom base URL (e.g. an
+    /// OpenRouter-compatible gateway or a local mock server).
+    /// Defaults to `https://openrouter.ai/api/v1` when `base_url` is `None`.
+    pub fn with_base_url(base_url: Option<&str>, credential: Option<&str>) -> Self {
         Self {
+            base_url: base_url
+                .map(str::trim)
+                .filter(|u| !u.is_empty())
+                .map(|u| u.trim_end_matches('/').to_string())
+                .unwrap_or_else(|| OPENROUTER_BASE_URL.to_string()),
             credential: credential.map(ToString::to_string),
         }
     }
@@ -232,7 +254,7 @@ impl Provider for OpenRouterProvider {
         // This prevents the first real chat request from timing out on cold start.
         if let Some(credential) = self.credential.as_ref() {
             self.http_client()
-                .get("https://openrouter.ai/api/v1/auth/key")
+                .get(format!("{}/auth/key", self.base_url))
                 .header("Authorization", format!("Bearer {credential}"))
                 .send()
                 .await?
@@ -273,7 +295,7 @@ impl Provider for OpenRouterProvider {
 
         let response = self
             .http_client()
-            .post("https://openrouter.ai/api/v1/chat/completions")
+            .post(format!("{}/chat/completions", self.base_url))
             .header("Authorization", format!("Bearer {credential}"))
             .header(
                 "HTTP-Referer",
@@ -282,7 +304,8 @@ impl Provider for OpenRouterProvider {
             .header("X-Title", "ZeroClaw")
             .json(&request)
             .send()
-            .await?;
+            .await
+            .map_err(super::ProviderError::from)?;
 
         if !response.status().is_success() {
             return Err(super::api_error("OpenRouter", response).await);
@@ -323,7 +346,7 @@ impl Provider for OpenRouterProvider {
 
         let response = self
             .http_client()
-            .post("https://openrouter.ai/api/v1/chat/completions")
+            .post(format!("{}/chat/completions", self.base_url))
             .header("Authorization", format!("Bearer {credential}"))
             .header(
                 "HTTP-Referer",
@@ -332,7 +355,8 @@ impl Provider for OpenRouterProvider {
             .header("X-Title", "ZeroClaw")
             .json(&request)
             .send()
-            .await?;
+            .await
+            .map_err(super::ProviderError::from)?;
 
         if !response.status().is_success() {
             return Err(super::api_error("OpenRouter", response).await);
@@ -361,17 +385,21 @@ impl Provider for OpenRouterProvider {
         })?;
 
         let tools = Self::convert_tools(request.tools);
+        let params = request.params.cloned().unwrap_or_default();
         let native_request = NativeChatRequest {
             model: model.to_string(),
             messages: Self::convert_messages(request.messages),
-            temperature,
+            temperature: params.temperature_or(temperature),
             tool_choice: tools.as_ref().map(|_| "auto".to_string()),
             tools,
+            top_p: params.top_p,
+            max_tokens: params.max_tokens,
+            stop: params.stop_sequences(),
         };
 
         let response = self
             .http_client()
-            .post("https://openrouter.ai/api/v1/chat/completions")
+            .post(format!("{}/chat/completions", self.base_url))
             .header("Authorization", format!("Bearer {credential}"))
             .header(
                 "HTTP-Referer",
@@ -380,7 +408,8 @@ impl Provider for OpenRouterProvider {
             .header("X-Title", "ZeroClaw")
             .json(&native_request)
             .send()
-            .await?;
+            .await
+            .map_err(super::ProviderError::from)?;
 
         if !response.status().is_success() {
             return Err(super::api_error("OpenRouter", response).await);
@@ -455,11 +484,14 @@ impl Provider for OpenRouterProvider {
             temperature,
             tool_choice: native_tools.as_ref().map(|_| "auto".to_string()),
             tools: native_tools,
+            top_p: None,
+            max_tokens: None,
+            stop: None,
         };
 
         let response = self
             .http_client()
-            .post("https://openrouter.ai/api/v1/chat/completions")
+            .post(format!("{}/chat/completions", self.base_url))
             .header("Authorization", format!("Bearer {credential}"))
             .header(
                 "HTTP-Referer",
@@ -468,7 +500,8 @@ impl Provider for OpenRouterProvider {
             .header("X-Title", "ZeroClaw")
             .json(&native_request)
             .send()
-            .await?;
+            .await
+            .map_err(super::ProviderError::from)?;
 
         if !response.status().is_success() {
             return Err(super::api_error("OpenRouter", response).await);
@@ -490,6 +523,18 @@ mod tests {
     use super::*;
     use crate::providers::traits::{ChatMessage, Provider};
 
+    #[test]
+    fn base_url_defaults_and_overrides() {
+        let provider = OpenRouterProvider::new(None);
+        assert_eq!(provider.base_url, "https://openrouter.ai/api/v1");
+
+        let provider = OpenRouterProvider::with_base_url(Some("http://127.0.0.1:8080/v1/"), None);
+        assert_eq!(provider.base_url, "http://127.0.0.1:8080/v1");
+
+        let provider = OpenRouterProvider::with_base_url(Some("  "), None);
+        assert_eq!(provider.base_url, "https://openrouter.ai/api/v1");
+    }
+
     #[test]
     fn creates_with_key() {
         let provider = OpenRouterProvider::new(Some("openrouter-test-credential"));
@@ -530,10 +575,12 @@ mod tests {
             ChatMessage {
                 role: "system".into(),
                 content: "be concise".into(),
+                pinned: false,
             },
             ChatMessage {
                 role: "user".into(),
                 content: "hello".into(),
+                pinned: false,
             },
         ];
 
@@ -576,10 +623,12 @@ mod tests {
             ChatMessage {
                 role: "assistant".into(),
                 content: "Previous answer".into(),
+                pinned: false,
             },
             ChatMessage {
                 role: "user".into(),
                 content: "Follow-up".into(),
+                pinned: false,
             },
         ];
 
@@ -626,6 +675,7 @@ mod tests {
         let messages = vec![ChatMessage {
             role: "user".into(),
             content: "What is the date?".into(),
+            pinned: false,
         }];
         let tools = vec![serde_json::json!({
             "type": "function",
@@ -719,7 +769,7 @@ mod tests {
         let messages = vec![ChatMessage {
             role: "assistant".into(),
             content: r#"{"content":"Using tool","tool_calls":[{"id":"call_abc","name":"shell","arguments":"{\"command\":\"pwd\"}"}]}"#
-                .into(),
+                .into(), pinned: false,
         }];
 
         let converted = OpenRouterProvider::convert_messages(&messages);
@@ -739,6 +789,7 @@ mod tests {
         let messages = vec![ChatMessage {
             role: "tool".into(),
             content: r#"{"tool_call_id":"call_xyz","content":"done"}"#.into(),
+            pinned: false,
         }];
 
         let converted = OpenRouterProvider::convert_messages(&messages);
@@ -1,11 +1,64 @@
-use super::traits::{ChatMessage, ChatResponse, StreamChunk, StreamOptions, StreamResult};
+use super::circuit_breaker::CircuitBreaker;
+use super::traits::{
+    ChatMessage, ChatRequest, ChatResponse, ProviderError, StreamChunk, StreamOptions, StreamResult,
+};
 use super::Provider;
+use crate::observability::Observer;
 use async_trait::async_trait;
 use futures_util::{stream, StreamExt};
 use std::collections::HashMap;
 use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
 use std::time::Duration;
 
+/// How [`ReliableProvider`] treats a failed provider call. Every provider
+/// wrapped by `create_resilient_provider` is classified the same way.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RetryClassification {
+    /// Transient: 5xx, 408, timeouts, dropped connections. Retry with backoff.
+    Retryable,
+    /// 429 that may clear. Retry with backoff, rotating API keys if configured.
+    RateLimited,
+    /// Retrying cannot help: other 4xx, auth failures, unknown model, context
+    /// window overflow, or a quota/plan 429. Move on to the next fallback.
+    Terminal,
+}
+
+impl RetryClassification {
+    /// Classification from an HTTP status code alone.
+    pub fn from_status(code: u16) -> Self {
+        match code {
+            429 => Self::RateLimited,
+            408 => Self::Retryable,
+            400..=499 => Self::Terminal,
+            _ => Self::Retryable,
+        }
+    }
+
+    /// Classify a provider error (typed [`ProviderError`], status, transport
+    /// failure or message text).
+    pub fn of(err: &anyhow::Error) -> Self {
+        match ProviderError::find(err) {
+            Some(ProviderError::Auth(_) | ProviderError::BadRequest(_)) => return Self::Terminal,
+            Some(ProviderError::Timeout(_) | ProviderError::Network(_)) => return Self::Retryable,
+            // Rate limits may be quota errors and 5xx bodies may name an
+            // unknown model; both still need the message checks below.
+            _ => {}
+        }
+        if is_non_retryable(err) || is_non_retryable_rate_limit(err) {
+            Self::Terminal
+        } else if is_rate_limited(err) {
+            Self::RateLimited
+        } else {
+            Self::Retryable
+        }
+    }
+
+    pub fn is_retryable(self) -> bool {
+        self != Self::Terminal
+    }
+}
+
 /// Check if an error is non-retryable (client errors that won't resolve with retries).
 fn is_non_retryable(err: &anyhow::Error) -> bool {
     if is_context_window_exceeded(err) {
@@ -14,15 +67,15 @@ fn is_non_retryable(err: &anyhow::Error) -> bool {
 
     if let Some(reqwest_err) = err.downcast_ref::<reqwest::Error>() {
         if let Some(status) = reqwest_err.status() {
-            let code = status.as_u16();
-            return status.is_client_error() && code != 429 && code != 408;
+            return RetryClassification::from_status(status.as_u16())
+                == RetryClassification::Terminal;
         }
     }
     let msg = err.to_string();
     for word in msg.split(|c: char| !c.is_ascii_digit()) {
         if let Ok(code) = word.parse::<u16>() {
             if (400..500).contains(&code) {
-                return code != 429 && code != 408;
+                return RetryClassification::from_status(code) == RetryClassification::Terminal;
             }
         }
     }
@@ -73,8 +126,54 @@ fn is_context_window_exceeded(err: &anyhow::Error) -> bool {
     hints.iter().any(|hint| lower.contains(hint))
 }
 
+/// Check if an error is a dropped or failed connection rather than an API
+/// response, i.e. sending the same request again may well succeed.
+pub(crate) fn is_transport_error(err: &anyhow::Error) -> bool {
+    if matches!(ProviderError::find(err), Some(ProviderError::Network(_))) {
+        return true;
+    }
+    for cause in err.chain() {
+        if let Some(reqwest_err) = cause.downcast_ref::<reqwest::Error>() {
+            if reqwest_err.is_connect()
+                || reqwest_err.is_timeout()
+                || reqwest_err.is_request()
+                || reqwest_err.is_body()
+            {
+                return true;
+            }
+        }
+        if let Some(io_err) = cause.downcast_ref::<std::io::Error>() {
+            if matches!(
+                io_err.kind(),
+                std::io::ErrorKind::ConnectionReset
+                    | std::io::ErrorKind::ConnectionAborted
+                    | std::io::ErrorKind::BrokenPipe
+                    | std::io::ErrorKind::TimedOut
+                    | std::io::ErrorKind::UnexpectedEof
+            ) {
+                return true;
+            }
+        }
+    }
+
+    let lower = format!("{err:#}").to_lowercase();
+    let hints = [
+        "connection reset",
+        "connection closed",
+        "connection refused",
+        "broken pipe",
+        "error sending request",
+        "timed out",
+        "unexpected eof",
+    ];
+    hints.iter().any(|hint| lower.contains(hint))
+}
+
 /// Check if an error is a rate-limit (429) error.
 fn is_rate_limited(err: &anyhow::Error) -> bool {
+    if let Some(provider_err) = ProviderError::find(err) {
+        return matches!(provider_err, ProviderError::RateLimited(_));
+    }
     if let Some(reqwest_err) = err.downcast_ref::<reqwest::Error>() {
         if let Some(status) = reqwest_err.status() {
             return status.as_u16() == 429;
@@ -183,6 +282,33 @@ fn compact_error_detail(err: &anyhow::Error) -> String {
         .join(" ")
 }
 
+/// Final error once every provider/model was tried or skipped by an open
+/// circuit. The last typed [`ProviderError`] stays in the cause chain so
+/// callers can still match on it.
+fn exhausted_error(
+    failures: &[String],
+    circuit_retry_in: Option<u64>,
+    last_error: Option<ProviderError>,
+) -> anyhow::Error {
+    let summary = match circuit_retry_in {
+        Some(secs) if failures.is_empty() => {
+            anyhow::anyhow!("Provider circuit open, retry in {secs}s")
+        }
+        Some(secs) => anyhow::anyhow!(
+            "All providers/models failed (others skipped: circuit open, retry in {secs}s). Attempts:\n{}",
+            failures.join("\n")
+        ),
+        None => anyhow::anyhow!(
+            "All providers/models failed. Attempts:\n{}",
+            failures.join("\n")
+        ),
+    };
+    match last_error {
+        Some(cause) => anyhow::Error::new(cause).context(summary.to_string()),
+        None => summary,
+    }
+}
+
 fn push_failure(
     failures: &mut Vec<String>,
     provider_name: &str,
@@ -207,6 +333,7 @@ pub struct ReliableProvider {
     key_index: AtomicUsize,
     /// Per-model fallback chains: model_name → [fallback_model_1, fallback_model_2, ...]
     model_fallbacks: HashMap<String, Vec<String>>,
+    circuit: CircuitBreaker,
 }
 
 impl ReliableProvider {
@@ -222,6 +349,7 @@ impl ReliableProvider {
             api_keys: Vec::new(),
             key_index: AtomicUsize::new(0),
             model_fallbacks: HashMap::new(),
+            circuit: CircuitBreaker::disabled(),
         }
     }
 
@@ -237,6 +365,18 @@ impl ReliableProvider {
         self
     }
 
+    /// Fail fast on a provider after `threshold` consecutive failed calls,
+    /// for `cooldown`. State changes are reported to `observer`.
+    pub fn with_circuit_breaker(
+        mut self,
+        threshold: u32,
+        cooldown: Duration,
+        observer: Option<Arc<dyn Observer>>,
+    ) -> Self {
+        self.circuit = CircuitBreaker::new(threshold, cooldown, observer);
+        self
+    }
+
     /// Build the list of models to try: [original, fallback1, fallback2, ...]
     fn model_chain<'a>(&'a self, model: &'a str) -> Vec<&'a str> {
         let mut chain = vec![model];
@@ -287,9 +427,15 @@ impl Provider for ReliableProvider {
     ) -> anyhow::Result<String> {
         let models = self.model_chain(model);
         let mut failures = Vec::new();
+        let mut last_error: Option<ProviderError> = None;
+        let mut circuit_retry_in: Option<u64> = None;
 
         for current_model in &models {
             for (provider_name, provider) in &self.providers {
+                if let Some(secs) = self.circuit.check(provider_name) {
+                    circuit_retry_in = Some(circuit_retry_in.map_or(secs, |s| s.min(secs)));
+                    continue;
+                }
                 let mut backoff_ms = self.base_backoff_ms;
 
                 for attempt in 0..=self.max_retries {
@@ -307,14 +453,24 @@ impl Provider for ReliableProvider {
                                     "Provider recovered (failover/retry)"
                                 );
                             }
+                            self.circuit.record_success(provider_name);
                             return Ok(resp);
                         }
                         Err(e) => {
-                            let non_retryable_rate_limit = is_non_retryable_rate_limit(&e);
-                            let non_retryable = is_non_retryable(&e) || non_retryable_rate_limit;
+                            let classification = RetryClassification::of(&e);
+                            if let Some(provider_err) = ProviderError::find(&e) {
+                                last_error = Some(provider_err.clone());
+                            }
+                            let non_retryable = !classification.is_retryable();
                             let rate_limited = is_rate_limited(&e);
                             let failure_reason = failure_reason(rate_limited, non_retryable);
                             let error_detail = compact_error_detail(&e);
+                            let circuit_opened = if non_retryable {
+                                self.circuit.record_success(provider_name);
+                                false
+                            } else {
+                                self.circuit.record_failure(provider_name)
+                            };
 
                             push_failure(
                                 &mut failures,
@@ -327,7 +483,7 @@ impl Provider for ReliableProvider {
                             );
 
                             // On rate-limit, try rotating API key
-                            if rate_limited && !non_retryable_rate_limit {
+                            if classification == RetryClassification::RateLimited {
                                 if let Some(new_key) = self.rotate_key() {
                                     tracing::info!(
                                         provider = provider_name,
@@ -356,6 +512,10 @@ impl Provider for ReliableProvider {
                                 break;
                             }
 
+                            if circuit_opened {
+                                break;
+                            }
+
                             if attempt < self.max_retries {
                                 let wait = self.compute_backoff(backoff_ms, &e);
                                 tracing::warn!(
@@ -390,10 +550,7 @@ impl Provider for ReliableProvider {
             }
         }
 
-        anyhow::bail!(
-            "All providers/models failed. Attempts:\n{}",
-            failures.join("\n")
-        )
+        Err(exhausted_error(&failures, circuit_retry_in, last_error))
     }
 
     async fn chat_with_history(
@@ -404,9 +561,15 @@ impl Provider for ReliableProvider {
     ) -> anyhow::Result<String> {
         let models = self.model_chain(model);
         let mut failures = Vec::new();
+        let mut last_error: Option<ProviderError> = None;
+        let mut circuit_retry_in: Option<u64> = None;
 
         for current_model in &models {
             for (provider_name, provider) in &self.providers {
+                if let Some(secs) = self.circuit.check(provider_name) {
+                    circuit_retry_in = Some(circuit_retry_in.map_or(secs, |s| s.min(secs)));
+                    continue;
+                }
                 let mut backoff_ms = self.base_backoff_ms;
 
                 for attempt in 0..=self.max_retries {
@@ -424,14 +587,24 @@ impl Provider for ReliableProvider {
                                     "Provider recovered (failover/retry)"
                                 );
                             }
+                            self.circuit.record_success(provider_name);
                             return Ok(resp);
                         }
                         Err(e) => {
-                            let non_retryable_rate_limit = is_non_retryable_rate_limit(&e);
-                            let non_retryable = is_non_retryable(&e) || non_retryable_rate_limit;
+                            let classification = RetryClassification::of(&e);
+                            if let Some(provider_err) = ProviderError::find(&e) {
+                                last_error = Some(provider_err.clone());
+                            }
+                            let non_retryable = !classification.is_retryable();
                             let rate_limited = is_rate_limited(&e);
                             let failure_reason = failure_reason(rate_limited, non_retryable);
                             let error_detail = compact_error_detail(&e);
+                            let circuit_opened = if non_retryable {
+                                self.circuit.record_success(provider_name);
+                                false
+                            } else {
+                                self.circuit.record_failure(provider_name)
+                            };
 
                             push_failure(
                                 &mut failures,
@@ -443,7 +616,7 @@ impl Provider for ReliableProvider {
                                 &error_detail,
                             );
 
-                            if rate_limited && !non_retryable_rate_limit {
+                            if classification == RetryClassification::RateLimited {
                                 if let Some(new_key) = self.rotate_key() {
                                     tracing::info!(
                                         provider = provider_name,
@@ -472,6 +645,10 @@ impl Provider for ReliableProvider {
                                 break;
                             }
 
+                            if circuit_opened {
+                                break;
+                            }
+
                             if attempt < self.max_retries {
                                 let wait = self.compute_backoff(backoff_ms, &e);
                                 tracing::warn!(
@@ -498,10 +675,7 @@ impl Provider for ReliableProvider {
             }
         }
 
-        anyhow::bail!(
-            "All providers/models failed. Attempts:\n{}",
-            failures.join("\n")
-        )
+        Err(exhausted_error(&failures, circuit_retry_in, last_error))
     }
 
     fn supports_native_tools(&self) -> bool {
@@ -520,9 +694,15 @@ impl Provider for ReliableProvider {
     ) -> anyhow::Result<ChatResponse> {
         let models = self.model_chain(model);
         let mut failures = Vec::new();
+        let mut last_error: Option<ProviderError> = None;
+        let mut circuit_retry_in: Option<u64> = None;
 
         for current_model in &models {
             for (provider_name, provider) in &self.providers {
+                if let Some(secs) = self.circuit.check(provider_name) {
+                    circuit_retry_in = Some(circuit_retry_in.map_or(secs, |s| s.min(secs)));
+                    continue;
+                }
                 let mut backoff_ms = self.base_backoff_ms;
 
                 for attempt in 0..=self.max_retries {
@@ -540,14 +720,24 @@ impl Provider for ReliableProvider {
                                     "Provider recovered (failover/retry)"
                                 );
                             }
+                            self.circuit.record_success(provider_name);
                             return Ok(resp);
                         }
                         Err(e) => {
-                            let non_retryable_rate_limit = is_non_retryable_rate_limit(&e);
-                            let non_retryable = is_non_retryable(&e) || non_retryable_rate_limit;
+                            let classification = RetryClassification::of(&e);
+                            if let Some(provider_err) = ProviderError::find(&e) {
+                                last_error = Some(provider_err.clone());
+                            }
+                            let non_retryable = !classification.is_retryable();
                             let rate_limited = is_rate_limited(&e);
                             let failure_reason = failure_reason(rate_limited, non_retryable);
                             let error_detail = compact_error_detail(&e);
+                            let circuit_opened = if non_retryable {
+                                self.circuit.record_success(provider_name);
+                                false
+                            } else {
+                                self.circuit.record_failure(provider_name)
+                            };
 
                             push_failure(
                                 &mut failures,
@@ -559,7 +749,7 @@ impl Provider for ReliableProvider {
                                 &error_detail,
                             );
 
-                            if rate_limited && !non_retryable_rate_limit {
+                            if classification == RetryClassification::RateLimited {
                                 if let Some(new_key) = self.rotate_key() {
                                     tracing::info!(
                                         provider = provider_name,
@@ -588,6 +778,10 @@ impl Provider for ReliableProvider {
                                 break;
                             }
 
+                            if circuit_opened {
+                                break;
+                            }
+
                             if attempt < self.max_retries {
                                 let wait = self.compute_backoff(backoff_ms, &e);
                                 tracing::warn!(
@@ -614,10 +808,129 @@ impl Provider for ReliableProvider {
             }
         }
 
-        anyhow::bail!(
-            "All providers/models failed. Attempts:\n{}",
-            failures.join("\n")
-        )
+        Err(exhausted_error(&failures, circuit_retry_in, last_error))
+    }
+
+    async fn chat(
+        &self,
+        request: ChatRequest<'_>,
+        model: &str,
+        temperature: f64,
+    ) -> anyhow::Result<ChatResponse> {
+        let models = self.model_chain(model);
+        let mut failures = Vec::new();
+        let mut last_error: Option<ProviderError> = None;
+        let mut circuit_retry_in: Option<u64> = None;
+
+        for current_model in &models {
+            for (provider_name, provider) in &self.providers {
+                if let Some(secs) = self.circuit.check(provider_name) {
+                    circuit_retry_in = Some(circuit_retry_in.map_or(secs, |s| s.min(secs)));
+                    continue;
+                }
+                let mut backoff_ms = self.base_backoff_ms;
+
+                for attempt in 0..=self.max_retries {
+                    match provider.chat(request, current_model, temperature).await {
+                        Ok(resp) => {
+                            if attempt > 0 || *current_model != model {
+                                tracing::info!(
+                                    provider = provider_name,
+                                    model = *current_model,
+                                    attempt,
+                                    original_model = model,
+                                    "Provider recovered (failover/retry)"
+                                );
+                            }
+                            self.circuit.record_success(provider_name);
+                            return Ok(resp);
+                        }
+                        Err(e) => {
+                            let classification = RetryClassification::of(&e);
+                            if let Some(provider_err) = ProviderError::find(&e) {
+                                last_error = Some(provider_err.clone());
+                            }
+                            let non_retryable = !classification.is_retryable();
+                            let rate_limited = is_rate_limited(&e);
+                            let failure_reason = failure_reason(rate_limited, non_retryable);
+                            let error_detail = compact_error_detail(&e);
+                            let circuit_opened = if non_retryable {
+                                self.circuit.record_success(provider_name);
+                                false
+                            } else {
+                                self.circuit.record_failure(provider_name)
+                            };
+
+                            push_failure(
+                                &mut failures,
+                                provider_name,
+                                current_model,
+                                attempt + 1,
+                                self.max_retries + 1,
+                                failure_reason,
+                                &error_detail,
+                            );
+
+                            if classification == RetryClassification::RateLimited {
+                                if let Some(new_key) = self.rotate_key() {
+                                    tracing::info!(
+                                        provider = provider_name,
+                                        error = %error_detail,
+                                        "Rate limited, rotated API key (key ending ...{})",
+                                        &new_key[new_key.len().saturating_sub(4)..]
+                                    );
+                                }
+                            }
+
+                            if non_retryable {
+                                tracing::warn!(
+                                    provider = provider_name,
+                                    model = *current_model,
+                                    error = %error_detail,
+                                    "Non-retryable error, moving on"
+                                );
+
+                                if is_context_window_exceeded(&e) {
+                                    anyhow::bail!(
+                                        "Request exceeds model context window; retries and fallbacks were skipped. Attempts:\n{}",
+                                        failures.join("\n")
+                                    );
+                                }
+
+                                break;
+                            }
+
+                            if circuit_opened {
+                                break;
+                            }
+
+                            if attempt < self.max_retries {
+                                let wait = self.compute_backoff(backoff_ms, &e);
+                                tracing::warn!(
+                                    provider = provider_name,
+                                    model = *current_model,
+                                    attempt = attempt + 1,
+                                    backoff_ms = wait,
+                                    reason = failure_reason,
+                                    error = %error_detail,
+                                    "Provider call failed, retrying"
+                                );
+                                tokio::time::sleep(Duration::from_millis(wait)).await;
+                                backoff_ms = (backoff_ms.saturating_mul(2)).min(10_000);
+                            }
+                        }
+                    }
+                }
+
+                tracing::warn!(
+                    provider = provider_name,
+                    model = *current_model,
+                    "Exhausted retries, trying next provider/model"
+                );
+            }
+        }
+
+        Err(exhausted_error(&failures, circuit_retry_in, last_error))
     }
 
     fn supports_streaming(&self) -> bool {
@@ -844,6 +1157,45 @@ mod tests {
         assert_eq!(fallback_calls.load(Ordering::SeqCst), 1);
     }
 
+    #[tokio::test]
+    async fn open_circuit_fails_fast_and_skips_to_fallback() {
+        let primary_calls = Arc::new(AtomicUsize::new(0));
+        let provider = ReliableProvider::new(
+            vec![(
+                "primary".into(),
+                Box::new(MockProvider {
+                    calls: Arc::clone(&primary_calls),
+                    fail_until_attempt: usize::MAX,
+                    response: "never",
+                    error: "503 Service Unavailable",
+                }) as Box<dyn Provider>,
+            )],
+            1,
+            1,
+        )
+        .with_circuit_breaker(2, Duration::from_secs(60), None);
+
+        let err = provider
+            .simple_chat("hello", "test", 0.0)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("All providers/models failed"));
+        assert_eq!(primary_calls.load(Ordering::SeqCst), 2);
+
+        let err = provider
+            .simple_chat("hello", "test", 0.0)
+            .await
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("Provider circuit open, retry in"));
+        assert_eq!(
+            primary_calls.load(Ordering::SeqCst),
+            2,
+            "no call while open"
+        );
+    }
+
     #[tokio::test]
     async fn returns_aggregated_error_when_all_providers_fail() {
         let provider = ReliableProvider::new(
@@ -916,6 +1268,27 @@ mod tests {
         )));
     }
 
+    #[test]
+    fn retry_classification_is_status_driven() {
+        use RetryClassification::*;
+        assert_eq!(RetryClassification::from_status(400), Terminal);
+        assert_eq!(RetryClassification::from_status(422), Terminal);
+        assert_eq!(RetryClassification::from_status(408), Retryable);
+        assert_eq!(RetryClassification::from_status(429), RateLimited);
+        for code in 500..=504 {
+            assert_eq!(RetryClassification::from_status(code), Retryable);
+        }
+
+        let of = |msg: &str| RetryClassification::of(&anyhow::anyhow!(msg.to_string()));
+        assert_eq!(of("API error (400 Bad Request): bad field"), Terminal);
+        assert_eq!(of("API error (503 Service Unavailable)"), Retryable);
+        assert_eq!(of("429 Too Many Requests: rate limit"), RateLimited);
+        assert_eq!(of("429 Too Many Requests: insufficient_quota"), Terminal);
+        assert_eq!(of("error sending request: connection reset"), Retryable);
+        assert!(!Terminal.is_retryable());
+        assert!(RateLimited.is_retryable());
+    }
+
     #[tokio::test]
     async fn context_window_error_aborts_retries_and_model_fallbacks() {
         let calls = Arc::new(AtomicUsize::new(0));
@@ -1291,6 +1664,20 @@ mod tests {
         assert_eq!(provider.compute_backoff(500, &err), 500);
     }
 
+    #[test]
+    fn transport_errors_are_detected() {
+        let reset = anyhow::Error::new(std::io::Error::new(
+            std::io::ErrorKind::ConnectionReset,
+            "peer went away",
+        ));
+        assert!(is_transport_error(&reset));
+        assert!(is_transport_error(&anyhow::anyhow!(
+            "error sending request for url (https://api.example.com)"
+        )));
+        assert!(!is_transport_error(&anyhow::anyhow!("401 Unauthorized")));
+        assert!(!is_transport_error(&anyhow::anyhow!("500 Server Error")));
+    }
+
     // ── §2.1 API auth error (401/403) tests ──────────────────
 
     #[test]
@@ -1470,4 +1857,149 @@ mod tests {
                 .await
         }
     }
+
+    /// Mock that fails its first structured `chat` call and records the
+    /// generation params it receives.
+    struct ParamsRecordingMock {
+        calls: Arc<AtomicUsize>,
+        max_tokens_seen: Arc<parking_lot::Mutex<Vec<Option<u32>>>>,
+    }
+
+    #[async_trait]
+    impl Provider for ParamsRecordingMock {
+        async fn chat_with_system(
+            &self,
+            _system_prompt: Option<&str>,
+            _message: &str,
+            _model: &str,
+            _temperature: f64,
+        ) -> anyhow::Result<String> {
+            Ok("unused".into())
+        }
+
+        async fn chat(
+            &self,
+            request: ChatRequest<'_>,
+            _model: &str,
+            _temperature: f64,
+        ) -> anyhow::Result<ChatResponse> {
+            self.max_tokens_seen
+                .lock()
+                .push(request.params.and_then(|p| p.max_tokens));
+            if self.calls.fetch_add(1, Ordering::SeqCst) == 0 {
+                anyhow::bail!("500 temporarily unavailable");
+            }
+            Ok(ChatResponse {
+                text: Some("ok".into()),
+                tool_calls: Vec::new(),
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn chat_retries_and_forwards_generation_params() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let max_tokens_seen = Arc::new(parking_lot::Mutex::new(Vec::new()));
+        let provider = ReliableProvider::new(
+            vec![(
+                "primary".into(),
+                Box::new(ParamsRecordingMock {
+                    calls: Arc::clone(&calls),
+                    max_tokens_seen: Arc::clone(&max_tokens_seen),
+                }),
+            )],
+            2,
+            1,
+        );
+
+        let params = super::super::traits::GenerationParams {
+            max_tokens: Some(128),
+            ..Default::default()
+        };
+        let messages = [ChatMessage::user("hello")];
+        let response = provider
+            .chat(
+                ChatRequest {
+                    messages: &messages,
+                    tools: None,
+                    params: Some(&params),
+                },
+                "test",
+                0.0,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.text.as_deref(), Some("ok"));
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+        assert_eq!(*max_tokens_seen.lock(), vec![Some(128), Some(128)]);
+    }
+
+    /// Fails every call with a typed [`ProviderError`].
+    struct TypedErrorProvider {
+        calls: Arc<AtomicUsize>,
+        error: ProviderError,
+    }
+
+    #[async_trait]
+    impl Provider for TypedErrorProvider {
+        async fn chat_with_system(
+            &self,
+            _system_prompt: Option<&str>,
+            _message: &str,
+            _model: &str,
+            _temperature: f64,
+        ) -> anyhow::Result<String> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            Err(self.error.clone().into())
+        }
+    }
+
+    #[tokio::test]
+    async fn typed_provider_errors_drive_classification_and_survive_exhaustion() {
+        let typed = |e: ProviderError| RetryClassification::of(&e.into());
+        assert_eq!(
+            typed(ProviderError::BadRequest("upstream said 503".into())),
+            RetryClassification::Terminal
+        );
+        assert_eq!(
+            typed(ProviderError::Network("dns failure".into())),
+            RetryClassification::Retryable
+        );
+        assert_eq!(
+            typed(ProviderError::from_status(
+                429,
+                "429 Too Many Requests".into()
+            )),
+            RetryClassification::RateLimited
+        );
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let provider = ReliableProvider::new(
+            vec![(
+                "primary".into(),
+                Box::new(TypedErrorProvider {
+                    calls: Arc::clone(&calls),
+                    error: ProviderError::from_status(401, "invalid credentials".into()),
+                }) as Box<dyn Provider>,
+            )],
+            3,
+            1,
+        );
+
+        let err = provider
+            .simple_chat("hello", "test", 0.0)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            calls.load(Ordering::SeqCst),
+            1,
+            "auth errors are not retried"
+        );
+        assert!(err.to_string().contains("All providers/models failed"));
+        assert!(matches!(
+            ProviderError::find(&err),
+            Some(ProviderError::Auth(_))
+        ));
+    }
 }
@@ -412,6 +412,7 @@ mod tests {
         let messages = vec![ChatMessage {
             role: "user".to_string(),
             content: "use tools".to_string(),
+            pinned: false,
         }];
         let tools = vec![serde_json::json!({
             "type": "function",
@@ -443,6 +444,7 @@ mod tests {
         let messages = vec![ChatMessage {
             role: "user".to_string(),
             content: "reason about this".to_string(),
+            pinned: false,
         }];
         let tools = vec![serde_json::json!({"type": "function", "function": {"name": "test"}})];
 
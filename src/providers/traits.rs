@@ -9,6 +9,10 @@ use std::fmt::Write;
 pub struct ChatMessage {
     pub role: String,
     pub content: String,
+    /// Pinned messages survive history trimming and compaction. Local-only:
+    /// never sent to providers.
+    #[serde(skip)]
+    pub pinned: bool,
 }
 
 impl ChatMessage {
@@ -16,6 +20,7 @@ impl ChatMessage {
         Self {
             role: "system".into(),
             content: content.into(),
+            pinned: false,
         }
     }
 
@@ -23,6 +28,7 @@ impl ChatMessage {
         Self {
             role: "user".into(),
             content: content.into(),
+            pinned: false,
         }
     }
 
@@ -30,6 +36,7 @@ impl ChatMessage {
         Self {
             role: "assistant".into(),
             content: content.into(),
+            pinned: false,
         }
     }
 
@@ -37,6 +44,7 @@ impl ChatMessage {
         Self {
             role: "tool".into(),
             content: content.into(),
+            pinned: false,
         }
     }
 }
@@ -70,11 +78,50 @@ impl ChatResponse {
     }
 }
 
+/// Optional generation controls passed alongside a chat request.
+///
+/// Temperature keeps its dedicated argument on every `Provider` method;
+/// `temperature` here overrides it for a single request. Providers map what
+/// their API supports and silently ignore the rest.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GenerationParams {
+    /// Sampling temperature; replaces the `temperature` argument when set.
+    pub temperature: Option<f64>,
+    /// Nucleus sampling cutoff (0.0 - 1.0).
+    pub top_p: Option<f64>,
+    /// Upper bound on generated tokens for a single response.
+    pub max_tokens: Option<u32>,
+    /// Stop sequences; generation halts when any is produced.
+    pub stop: Vec<String>,
+}
+
+impl GenerationParams {
+    /// True when no field is set, i.e. provider defaults apply.
+    pub fn is_empty(&self) -> bool {
+        self.temperature.is_none()
+            && self.top_p.is_none()
+            && self.max_tokens.is_none()
+            && self.stop.is_empty()
+    }
+
+    /// The temperature to send: the override if set, else `fallback`.
+    pub fn temperature_or(&self, fallback: f64) -> f64 {
+        self.temperature.unwrap_or(fallback)
+    }
+
+    /// Stop sequences as an `Option`, for `skip_serializing_if` request bodies.
+    pub fn stop_sequences(&self) -> Option<Vec<String>> {
+        (!self.stop.is_empty()).then(|| self.stop.clone())
+    }
+}
+
 /// Request payload for provider chat calls.
 #[derive(Debug, Clone, Copy)]
 pub struct ChatRequest<'a> {
     pub messages: &'a [ChatMessage],
     pub tools: Option<&'a [ToolSpec]>,
+    /// Generation controls; `None` leaves provider defaults in place.
+    pub params: Option<&'a GenerationParams>,
 }
 
 /// A tool result to feed back to the LLM.
@@ -192,6 +239,77 @@ pub enum StreamError {
     Io(#[from] std::io::Error),
 }
 
+/// Typed cause of a failed provider call.
+///
+/// Provider methods still return `anyhow::Result`; errors built by
+/// [`crate::providers::api_error`] carry a `ProviderError`, which callers
+/// recover with [`ProviderError::find`] instead of inspecting message text.
+/// The display string is the full provider message.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ProviderError {
+    /// 429 from the provider.
+    #[error("{0}")]
+    RateLimited(String),
+    /// 401/403: missing, invalid or unauthorized credentials.
+    #[error("{0}")]
+    Auth(String),
+    /// 408 from the provider, or the request timed out locally.
+    #[error("{0}")]
+    Timeout(String),
+    /// Any other 4xx: malformed request, unknown model, context overflow.
+    #[error("{0}")]
+    BadRequest(String),
+    /// 5xx (or any other unexpected status) from the provider.
+    #[error("{message}")]
+    Upstream { status: u16, message: String },
+    /// The request never got a response (DNS, connect, reset).
+    #[error("{0}")]
+    Network(String),
+}
+
+impl ProviderError {
+    /// Map an HTTP status to a variant carrying `message`.
+    pub fn from_status(status: u16, message: String) -> Self {
+        match status {
+            429 => Self::RateLimited(message),
+            401 | 403 => Self::Auth(message),
+            408 => Self::Timeout(message),
+            400..=499 => Self::BadRequest(message),
+            _ => Self::Upstream { status, message },
+        }
+    }
+
+    /// First `ProviderError` in `err`'s cause chain.
+    pub fn find(err: &anyhow::Error) -> Option<&Self> {
+        err.chain().find_map(|cause| cause.downcast_ref::<Self>())
+    }
+
+    /// Stable snake_case name for logs and API error bodies.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::RateLimited(_) => "rate_limited",
+            Self::Auth(_) => "auth",
+            Self::Timeout(_) => "timeout",
+            Self::BadRequest(_) => "bad_request",
+            Self::Upstream { .. } => "upstream",
+            Self::Network(_) => "network",
+        }
+    }
+}
+
+impl From<reqwest::Error> for ProviderError {
+    fn from(err: reqwest::Error) -> Self {
+        let message = err.to_string();
+        if err.is_timeout() {
+            Self::Timeout(message)
+        } else if let Some(status) = err.status() {
+            Self::from_status(status.as_u16(), message)
+        } else {
+            Self::Network(message)
+        }
+    }
+}
+
 /// Provider capabilities declaration.
 ///
 /// Describes what features a provider supports, enabling intelligent
@@ -312,20 +430,8 @@ pub trait Provider: Send + Sync {
                         )
                     }
                 };
-                let mut modified_messages = request.messages.to_vec();
-
-                // Inject tool instructions into an existing system message.
-                // If none exists, prepend one to the conversation.
-                if let Some(system_message) =
-                    modified_messages.iter_mut().find(|m| m.role == "system")
-                {
-                    if !system_message.content.is_empty() {
-                        system_message.content.push_str("\n\n");
-                    }
-                    system_message.content.push_str(&tool_instructions);
-                } else {
-                    modified_messages.insert(0, ChatMessage::system(tool_instructions));
-                }
+                let modified_messages =
+                    with_tool_instructions(request.messages, &tool_instructions);
 
                 let text = self
                     .chat_with_history(&modified_messages, model, temperature)
@@ -416,6 +522,21 @@ pub trait Provider: Send + Sync {
 
 /// Build tool instructions text for prompt-guided tool calling.
 ///
+/// Copy of `messages` with prompt-guided tool instructions appended to the
+/// first system message, or prepended as a new one if there is none.
+pub fn with_tool_instructions(messages: &[ChatMessage], instructions: &str) -> Vec<ChatMessage> {
+    let mut modified = messages.to_vec();
+    if let Some(system_message) = modified.iter_mut().find(|m| m.role == "system") {
+        if !system_message.content.is_empty() {
+            system_message.content.push_str("\n\n");
+        }
+        system_message.content.push_str(instructions);
+    } else {
+        modified.insert(0, ChatMessage::system(instructions));
+    }
+    modified
+}
+
 /// Generates a formatted text block describing available tools and how to
 /// invoke them using XML-style tags. This is used as a fallback when the
 /// provider doesn't support native tool calling.
@@ -509,6 +630,24 @@ mod tests {
         assert_eq!(with_tools.text_or_empty(), "Let me check");
     }
 
+    #[test]
+    fn generation_params_helpers() {
+        let empty = GenerationParams::default();
+        assert!(empty.is_empty());
+        assert!(empty.stop_sequences().is_none());
+        assert_eq!(empty.temperature_or(0.7), 0.7);
+
+        let params = GenerationParams {
+            temperature: Some(0.2),
+            top_p: Some(0.9),
+            max_tokens: Some(256),
+            stop: vec!["END".into()],
+        };
+        assert!(!params.is_empty());
+        assert_eq!(params.stop_sequences(), Some(vec!["END".to_string()]));
+        assert_eq!(params.temperature_or(0.7), 0.2);
+    }
+
     #[test]
     fn tool_call_serialization() {
         let tc = ToolCall {
@@ -704,6 +843,7 @@ mod tests {
         let request = ChatRequest {
             messages: &[ChatMessage::user("Hello")],
             tools: Some(&tools),
+            params: None,
         };
 
         let response = provider.chat(request, "model", 0.7).await.unwrap();
@@ -721,6 +861,7 @@ mod tests {
         let request = ChatRequest {
             messages: &[ChatMessage::user("Hello")],
             tools: None,
+            params: None,
         };
 
         let response = provider.chat(request, "model", 0.7).await.unwrap();
@@ -821,6 +962,7 @@ mod tests {
                 ChatMessage::system("BASE_SYSTEM_PROMPT"),
             ],
             tools: Some(&tools),
+            params: None,
         };
 
         let response = provider.chat(request, "model", 0.7).await.unwrap();
@@ -843,6 +985,7 @@ mod tests {
         let request = ChatRequest {
             messages: &[ChatMessage::system("BASE"), ChatMessage::user("Hello")],
             tools: Some(&tools),
+            params: None,
         };
 
         let response = provider.chat(request, "model", 0.7).await.unwrap();
@@ -865,6 +1008,7 @@ mod tests {
         let request = ChatRequest {
             messages: &[ChatMessage::user("Hello")],
             tools: Some(&tools),
+            params: None,
         };
 
         let err = provider.chat(request, "model", 0.7).await.unwrap_err();
@@ -872,4 +1016,39 @@ mod tests {
 
         assert!(message.contains("non-prompt-guided"));
     }
+
+    #[test]
+    fn provider_error_maps_status_and_is_found_through_context() {
+        let kind = |status| ProviderError::from_status(status, String::new()).kind();
+        assert_eq!(kind(429), "rate_limited");
+        assert_eq!(kind(401), "auth");
+        assert_eq!(kind(403), "auth");
+        assert_eq!(kind(408), "timeout");
+        assert_eq!(kind(404), "bad_request");
+        assert_eq!(kind(502), "upstream");
+
+        let err: anyhow::Error =
+            ProviderError::from_status(503, "openai API error (503): overloaded".into()).into();
+        let err = err.context("turn failed");
+        assert_eq!(err.to_string(), "turn failed");
+        assert_eq!(
+            ProviderError::find(&err),
+            Some(&ProviderError::Upstream {
+                status: 503,
+                message: "openai API error (503): overloaded".into()
+            })
+        );
+        assert!(ProviderError::find(&anyhow::anyhow!("plain")).is_none());
+    }
+
+    #[tokio::test]
+    async fn provider_error_maps_transport_failures_to_network() {
+        // Nothing listens on port 1, so the connection is refused.
+        let err = reqwest::Client::new()
+            .get("http://127.0.0.1:1/")
+            .send()
+            .await
+            .unwrap_err();
+        assert_eq!(ProviderError::from(err).kind(), "network");
+    }
 }
@@ -4,11 +4,30 @@
 //! - Markdown and text datasheets (always)
 //! - PDF ingestion (with `rag-pdf` feature)
 //! - Pin/alias tables (e.g. `red_led: 13`) for explicit lookup
+//! - Structured per-board pin tables (`<board>.pins.toml` / `<board>.pins.json`)
+//!   with aliases and capabilities (PWM, ADC, I2C, ...)
 //! - Keyword retrieval (default) or semantic search via embeddings (optional)
 
 use crate::memory::chunker;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::fmt::Write;
 use std::path::Path;
+use std::time::SystemTime;
+
+/// How datasheet markdown is split into RAG chunks.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ChunkStrategy {
+    /// Split at every heading, then into ~512-token windows (default).
+    #[default]
+    Fixed,
+    /// One chunk per `#`/`##` section, subsections included, while it fits.
+    Heading,
+    /// Like `heading`, but consecutive short sections share a chunk.
+    Semantic,
+}
 
 /// A chunk of datasheet content with board metadata.
 #[derive(Debug, Clone)]
@@ -19,11 +38,86 @@ pub struct DatasheetChunk {
     pub source: String,
     /// Chunk content.
     pub content: String,
+    /// Source file's modification time, for the recency boost.
+    pub modified: Option<SystemTime>,
 }
 
 /// Pin alias: human-readable name → pin number (e.g. "red_led" → 13).
 pub type PinAliases = HashMap<String, u32>;
 
+/// One pin in a board's structured pin table.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct BoardPin {
+    pub pin: u32,
+    /// Names the user may use for this pin (e.g. "red_led", "user_led").
+    #[serde(default)]
+    pub aliases: Vec<String>,
+    /// What the pin can do (e.g. "pwm", "adc", "i2c_sda").
+    #[serde(default)]
+    pub capabilities: Vec<String>,
+}
+
+/// Shape of a `<board>.pins.toml` / `<board>.pins.json` file.
+#[derive(Debug, Default, Deserialize)]
+struct PinTableFile {
+    #[serde(default, alias = "pins")]
+    pin: Vec<BoardPin>,
+}
+
+/// Parse a structured pin table; `is_json` selects the format.
+fn parse_pin_table(content: &str, is_json: bool) -> anyhow::Result<Vec<BoardPin>> {
+    let file: PinTableFile = if is_json {
+        serde_json::from_str(content)?
+    } else {
+        toml::from_str(content)?
+    };
+    Ok(file
+        .pin
+        .into_iter()
+        .map(|mut p| {
+            p.aliases = p.aliases.iter().map(|a| normalize_alias(a)).collect();
+            p.capabilities = p
+                .capabilities
+                .iter()
+                .map(|c| c.trim().to_lowercase())
+                .collect();
+            p
+        })
+        .collect())
+}
+
+fn normalize_alias(alias: &str) -> String {
+    alias.trim().to_lowercase().replace([' ', '-'], "_")
+}
+
+/// Board name for a pin table file: `nucleo-f401re.pins.toml` → "nucleo-f401re".
+fn pin_table_board(path: &Path) -> Option<(String, bool)> {
+    let name = path.file_name()?.to_str()?;
+    if let Some(board) = name.strip_suffix(".pins.toml") {
+        return Some((board.to_string(), false));
+    }
+    name.strip_suffix(".pins.json")
+        .map(|board| (board.to_string(), true))
+}
+
+/// Fold markdown `## Pin Aliases` entries into a board's pin table, adding
+/// aliases to existing pins rather than duplicating them.
+fn merge_aliases(pins: &mut Vec<BoardPin>, aliases: &PinAliases) {
+    for (alias, &pin) in aliases {
+        if let Some(entry) = pins.iter_mut().find(|p| p.pin == pin) {
+            if !entry.aliases.contains(alias) {
+                entry.aliases.push(alias.clone());
+            }
+        } else {
+            pins.push(BoardPin {
+                pin,
+                aliases: vec![alias.clone()],
+                capabilities: Vec::new(),
+            });
+        }
+    }
+}
+
 /// Parse pin aliases from markdown. Looks for:
 /// - `## Pin Aliases` section with `alias: pin` lines
 /// - Markdown table `| alias | pin |`
@@ -98,6 +192,20 @@ fn parse_pin_aliases(content: &str) -> PinAliases {
     aliases
 }
 
+fn collect_pin_table_paths(dir: &Path, out: &mut Vec<std::path::PathBuf>) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            collect_pin_table_paths(&path, out);
+        } else if path.is_file() && pin_table_board(&path).is_some() {
+            out.push(path);
+        }
+    }
+}
+
 fn collect_md_txt_paths(dir: &Path, out: &mut Vec<std::path::PathBuf>) {
     let Ok(entries) = std::fs::read_dir(dir) else {
         return;
@@ -141,30 +249,58 @@ fn extract_pdf_text(path: &Path) -> Option<String> {
 /// Hardware RAG index — loads and retrieves datasheet chunks.
 pub struct HardwareRag {
     chunks: Vec<DatasheetChunk>,
-    /// Per-board pin aliases (board -> alias -> pin).
-    pin_aliases: HashMap<String, PinAliases>,
+    /// Per-board pin tables: structured files plus markdown aliases.
+    pins: HashMap<String, Vec<BoardPin>>,
+    /// Score bonus for the most recently modified source file; 0 disables it.
+    recency_weight: f32,
 }
 
 impl HardwareRag {
     /// Load datasheets from a directory. Expects .md, .txt, and optionally .pdf (with rag-pdf).
     /// Filename (without extension) is used as board tag.
-    /// Supports `## Pin Aliases` section for explicit alias→pin mapping.
+    /// Supports `## Pin Aliases` section for explicit alias→pin mapping, and
+    /// `<board>.pins.toml` / `<board>.pins.json` tables with capabilities.
     pub fn load(workspace_dir: &Path, datasheet_dir: &str) -> anyhow::Result<Self> {
+        Self::load_with_strategy(workspace_dir, datasheet_dir, ChunkStrategy::Fixed)
+    }
+
+    /// Like [`Self::load`], splitting datasheets with the given strategy.
+    pub fn load_with_strategy(
+        workspace_dir: &Path,
+        datasheet_dir: &str,
+        strategy: ChunkStrategy,
+    ) -> anyhow::Result<Self> {
         let base = workspace_dir.join(datasheet_dir);
         if !base.exists() || !base.is_dir() {
             return Ok(Self {
                 chunks: Vec::new(),
-                pin_aliases: HashMap::new(),
+                pins: HashMap::new(),
+                recency_weight: 0.0,
             });
         }
 
+        let mut pins: HashMap<String, Vec<BoardPin>> = HashMap::new();
+        let mut table_paths = Vec::new();
+        collect_pin_table_paths(&base, &mut table_paths);
+        for path in table_paths {
+            let Some((board, is_json)) = pin_table_board(&path) else {
+                continue;
+            };
+            let content = std::fs::read_to_string(&path).unwrap_or_default();
+            match parse_pin_table(&content, is_json) {
+                Ok(table) => pins.entry(board).or_default().extend(table),
+                Err(e) => {
+                    tracing::warn!(path = %path.display(), "Skipping invalid pin table: {e}");
+                }
+            }
+        }
+
         let mut paths: Vec<std::path::PathBuf> = Vec::new();
         collect_md_txt_paths(&base, &mut paths);
         #[cfg(feature = "rag-pdf")]
         collect_pdf_paths(&base, &mut paths);
 
         let mut chunks = Vec::new();
-        let mut pin_aliases: HashMap<String, PinAliases> = HashMap::new();
         let max_tokens = 512;
 
         for path in paths {
@@ -186,6 +322,7 @@ impl HardwareRag {
             }
 
             let board = infer_board_from_path(&path, &base);
+            let modified = std::fs::metadata(&path).and_then(|m| m.modified()).ok();
             let source = path
                 .strip_prefix(workspace_dir)
                 .unwrap_or(&path)
@@ -196,28 +333,72 @@ impl HardwareRag {
             let aliases = parse_pin_aliases(&content);
             if let Some(ref b) = board {
                 if !aliases.is_empty() {
-                    pin_aliases.insert(b.clone(), aliases);
+                    merge_aliases(pins.entry(b.clone()).or_default(), &aliases);
                 }
             }
 
-            for chunk in chunker::chunk_markdown(&content, max_tokens) {
+            let file_chunks = match strategy {
+                ChunkStrategy::Fixed => chunker::chunk_markdown(&content, max_tokens),
+                ChunkStrategy::Heading => chunker::chunk_by_headings(&content, max_tokens),
+                ChunkStrategy::Semantic => chunker::chunk_semantic(&content, max_tokens),
+            };
+            for chunk in file_chunks {
                 chunks.push(DatasheetChunk {
                     board: board.clone(),
                     source: source.clone(),
                     content: chunk.content,
+                    modified,
                 });
             }
         }
 
         Ok(Self {
             chunks,
-            pin_aliases,
+            pins,
+            recency_weight: 0.0,
         })
     }
 
+    /// Prefer chunks from recently modified files: a matching chunk gains up
+    /// to `weight` (newest file) scaled linearly down to 0 (oldest file).
+    /// Keyword hits score 1.0 each, so small weights (e.g. 0.5) only break
+    /// ties between otherwise similar revisions.
+    pub fn with_recency_weight(mut self, weight: f64) -> Self {
+        #[allow(clippy::cast_possible_truncation)]
+        let weight = weight.max(0.0) as f32;
+        self.recency_weight = weight;
+        self
+    }
+
+    /// Recency bonus for `chunk`, relative to the oldest and newest sources.
+    fn recency_boost(&self, chunk: &DatasheetChunk, span: Option<(SystemTime, SystemTime)>) -> f32 {
+        let (Some(modified), Some((oldest, newest))) = (chunk.modified, span) else {
+            return 0.0;
+        };
+        let Ok(range) = newest.duration_since(oldest) else {
+            return 0.0;
+        };
+        if range.is_zero() {
+            return 0.0;
+        }
+        let age = modified.duration_since(oldest).unwrap_or_default();
+        #[allow(clippy::cast_possible_truncation)]
+        let freshness = (age.as_secs_f64() / range.as_secs_f64()) as f32;
+        self.recency_weight * freshness
+    }
+
+    /// Structured pin table for a board.
+    pub fn pins_for_board(&self, board: &str) -> Option<&[BoardPin]> {
+        self.pins.get(board).map(Vec::as_slice)
+    }
+
     /// Get pin aliases for a board (e.g. "red_led" -> 13).
-    pub fn pin_aliases_for_board(&self, board: &str) -> Option<&PinAliases> {
-        self.pin_aliases.get(board)
+    pub fn pin_aliases_for_board(&self, board: &str) -> Option<PinAliases> {
+        self.pins.get(board).map(|pins| {
+            pins.iter()
+                .flat_map(|p| p.aliases.iter().map(move |a| (a.clone(), p.pin)))
+                .collect()
+        })
     }
 
     /// Build pin-alias context for query. When user says "red led", inject "red_led: 13" for matching boards.
@@ -230,15 +411,48 @@ impl HardwareRag {
 
         let mut lines = Vec::new();
         for board in boards {
-            if let Some(aliases) = self.pin_aliases.get(board) {
-                for (alias, pin) in aliases {
-                    let alias_words: Vec<&str> = alias.split('_').collect();
-                    let matches = query_words.iter().any(|qw| alias_words.contains(qw))
-                        || query_lower.contains(&alias.replace('_', " "));
-                    if matches {
-                        lines.push(format!("{board}: {alias} = pin {pin}"));
-                    }
+            let Some(pins) = self.pins.get(board) else {
+                continue;
+            };
+            for pin in pins {
+                // Prefer an alias the query names outright ("user led") over
+                // one that merely shares a word with it ("red_led").
+                let matched = pin
+                    .aliases
+                    .iter()
+                    .find(|alias| query_lower.contains(&alias.replace('_', " ")))
+                    .or_else(|| {
+                        pin.aliases.iter().find(|alias| {
+                            let alias_words: Vec<&str> = alias.split('_').collect();
+                            query_words.iter().any(|qw| alias_words.contains(qw))
+                        })
+                    });
+                // Capability questions ("which pins do pwm") match too.
+                let cap_match = pin
+                    .capabilities
+                    .iter()
+                    .any(|c| query_words.contains(&c.as_str()));
+                if matched.is_none() && !cap_match {
+                    continue;
+                }
+
+                let mut line = match matched.or(pin.aliases.first()) {
+                    Some(alias) => format!("{board}: {alias} = pin {}", pin.pin),
+                    None => format!("{board}: pin {}", pin.pin),
+                };
+                let others: Vec<&str> = pin
+                    .aliases
+                    .iter()
+                    .filter(|a| Some(*a) != matched.or(pin.aliases.first()))
+                    .map(String::as_str)
+                    .collect();
+                if !others.is_empty() {
+                    let _ = write!(line, " (also: {})", others.join(", "));
                 }
+                if !pin.capabilities.is_empty() {
+                    let _ = write!(line, " [{}]", pin.capabilities.join(", "));
+                }
+                lines.push(line);
             }
         }
         if lines.is_empty() {
@@ -248,7 +462,8 @@ impl HardwareRag {
     }
 
     /// Retrieve chunks relevant to the query and boards.
-    /// Uses keyword matching and board filter. Pin-alias context is built separately via `pin_alias_context`.
+    /// Uses keyword matching, board filter and the optional recency boost.
+    /// Pin-alias context is built separately via `pin_alias_context`.
     pub fn retrieve(&self, query: &str, boards: &[String], limit: usize) -> Vec<&DatasheetChunk> {
         if self.chunks.is_empty() || limit == 0 {
             return Vec::new();
@@ -260,6 +475,13 @@ impl HardwareRag {
             .filter(|w| w.len() > 2)
             .collect();
 
+        let span = if self.recency_weight > 0.0 {
+            let times = self.chunks.iter().filter_map(|c| c.modified);
+            times.clone().min().zip(times.max())
+        } else {
+            None
+        };
+
         let mut scored: Vec<(&DatasheetChunk, f32)> = Vec::new();
         for chunk in &self.chunks {
             let content_lower = chunk.content.to_lowercase();
@@ -276,6 +498,7 @@ impl HardwareRag {
                 if board_match {
                     score += 2.0;
                 }
+                score += self.recency_boost(chunk, span);
                 scored.push((chunk, score));
             }
         }
@@ -384,6 +607,109 @@ Pin 13: LED
         assert!(ctx.contains("13"));
     }
 
+    #[test]
+    fn recency_weight_prefers_newer_revision() {
+        let tmp = tempfile::tempdir().unwrap();
+        let base = tmp.path().join("datasheets");
+        std::fs::create_dir_all(&base).unwrap();
+        let now = SystemTime::now();
+        for (name, content, age_secs) in [
+            ("board-rev-b.md", "LED is on pin 5 (rev B)", 0),
+            ("board-rev-a.md", "LED is on pin 13 (rev A)", 86_400),
+        ] {
+            let path = base.join(name);
+            std::fs::write(&path, content).unwrap();
+            std::fs::File::options()
+                .write(true)
+                .open(&path)
+                .unwrap()
+                .set_modified(now - std::time::Duration::from_secs(age_secs))
+                .unwrap();
+        }
+
+        let rag = HardwareRag::load(tmp.path(), "datasheets").unwrap();
+        let sources = |rag: &HardwareRag| -> Vec<String> {
+            rag.retrieve("which pin is the led", &[], 5)
+                .iter()
+                .map(|c| c.content.clone())
+                .collect()
+        };
+        // Without a weight both revisions score the same.
+        assert_eq!(sources(&rag).len(), 2);
+
+        let rag = rag.with_recency_weight(0.5);
+        assert_eq!(sources(&rag)[0], "LED is on pin 5 (rev B)");
+    }
+
+    #[test]
+    fn pin_table_toml_supports_multiple_aliases_and_capabilities() {
+        let tmp = tempfile::tempdir().unwrap();
+        let base = tmp.path().join("datasheets");
+        std::fs::create_dir_all(&base).unwrap();
+        std::fs::write(
+            base.join("test-board.pins.toml"),
+            r#"
+[[pin]]
+pin = 13
+aliases = ["red_led", "Builtin LED", "user-led"]
+capabilities = ["GPIO", "PWM"]
+
+[[pin]]
+pin = 14
+aliases = ["light_sensor"]
+capabilities = ["adc"]
+"#,
+        )
+        .unwrap();
+        // Markdown aliases merge into the same table without duplicating pins.
+        std::fs::write(
+            base.join("test-board.md"),
+            "# Test Board\n## Pin Aliases\nstatus_led: 13\nbuzzer: 9\n",
+        )
+        .unwrap();
+
+        let rag = HardwareRag::load(tmp.path(), "datasheets").unwrap();
+        let pins = rag.pins_for_board("test-board").unwrap();
+        let p13 = pins.iter().find(|p| p.pin == 13).unwrap();
+        assert_eq!(
+            p13.aliases,
+            vec!["red_led", "builtin_led", "user_led", "status_led"]
+        );
+        assert_eq!(p13.capabilities, vec!["gpio", "pwm"]);
+        assert_eq!(pins.iter().filter(|p| p.pin == 13).count(), 1);
+
+        let aliases = rag.pin_aliases_for_board("test-board").unwrap();
+        assert_eq!(aliases.get("user_led"), Some(&13));
+        assert_eq!(aliases.get("buzzer"), Some(&9));
+
+        let boards = vec!["test-board".to_string()];
+        let ctx = rag.pin_alias_context("turn on the user led", &boards);
+        assert!(ctx.contains("test-board: user_led = pin 13"));
+        assert!(ctx.contains("also: red_led, builtin_led, status_led"));
+        assert!(ctx.contains("[gpio, pwm]"));
+
+        let ctx = rag.pin_alias_context("which pin has adc", &boards);
+        assert!(ctx.contains("light_sensor = pin 14 [adc]"));
+    }
+
+    #[test]
+    fn pin_table_json_and_board_name() {
+        let table = parse_pin_table(
+            r#"{"pins": [{"pin": 2, "aliases": ["sda"], "capabilities": ["i2c_sda"]}]}"#,
+            true,
+        )
+        .unwrap();
+        assert_eq!(table[0].pin, 2);
+        assert_eq!(table[0].capabilities, vec!["i2c_sda"]);
+
+        let path = std::path::Path::new("/ds/nucleo-f401re.pins.json");
+        assert_eq!(
+            pin_table_board(path),
+            Some(("nucleo-f401re".to_string(), true))
+        );
+        assert_eq!(pin_table_board(std::path::Path::new("/ds/board.md")), None);
+    }
+
     #[test]
     fn hardware_rag_load_empty_dir() {
         let tmp = tempfile::tempdir().unwrap();
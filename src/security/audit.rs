@@ -5,11 +5,31 @@ use anyhow::Result;
 use chrono::{DateTime, Utc};
 use parking_lot::Mutex;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use std::fs::OpenOptions;
 use std::io::Write;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use uuid::Uuid;
 
+/// Argument keys whose values are never written to the audit log. A key
+/// matches when it equals an entry or ends in `_<entry>` (`-` counts as `_`),
+/// so `github_token` is redacted but `max_tokens` and `token_count` are not.
+const SENSITIVE_ARG_KEYS: &[&str] = &[
+    "api_key",
+    "apikey",
+    "access_key",
+    "secret_key",
+    "private_key",
+    "token",
+    "secret",
+    "password",
+    "passwd",
+    "authorization",
+    "cookie",
+];
+const REDACTED: &str = "[REDACTED]";
+
 /// Audit event types
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -21,6 +41,10 @@ pub enum AuditEventType {
     AuthFailure,
     PolicyViolation,
     SecurityEvent,
+    /// Written before a tool runs.
+    ToolCallStart,
+    /// Written after a tool returns, with the result hash.
+    ToolCall,
 }
 
 /// Actor information (who performed the action)
@@ -57,6 +81,25 @@ pub struct SecurityContext {
     pub sandbox_backend: Option<String>,
 }
 
+/// Tool call details (tool events only)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ToolInvocation {
+    pub name: String,
+    /// Arguments as the model wrote them: `{{secret:..}}` placeholders stay
+    /// unresolved and sensitive keys are redacted.
+    pub arguments: serde_json::Value,
+    /// SHA-256 (hex) of the result text handed back to the model.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub result_sha256: Option<String>,
+    /// `ToolResult::metadata` (HTTP status, exit code, ...), redacted like
+    /// the arguments.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<serde_json::Value>,
+    /// `ToolResult::content_type`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>,
+}
+
 /// Complete audit event
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AuditEvent {
@@ -67,6 +110,8 @@ pub struct AuditEvent {
     pub action: Option<Action>,
     pub result: Option<ExecutionResult>,
     pub security: SecurityContext,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tool: Option<ToolInvocation>,
 }
 
 impl AuditEvent {
@@ -84,6 +129,7 @@ impl AuditEvent {
                 rate_limit_remaining: None,
                 sandbox_backend: None,
             },
+            tool: None,
         }
     }
 
@@ -141,6 +187,72 @@ impl AuditEvent {
         self.security.sandbox_backend = sandbox_backend;
         self
     }
+
+    /// Set the tool call (arguments are redacted here)
+    pub fn with_tool(
+        mut self,
+        name: &str,
+        arguments: &serde_json::Value,
+        result_sha256: Option<String>,
+    ) -> Self {
+        self.tool = Some(ToolInvocation {
+            name: name.to_string(),
+            arguments: redact_arguments(arguments),
+            result_sha256,
+            metadata: None,
+            content_type: None,
+        });
+        self
+    }
+
+    /// Attach a tool result's metadata and content type to the tool call set
+    /// by [`Self::with_tool`].
+    pub fn with_tool_result_details(
+        mut self,
+        metadata: Option<&serde_json::Value>,
+        content_type: Option<&str>,
+    ) -> Self {
+        if let Some(tool) = self.tool.as_mut() {
+            tool.metadata = metadata.map(redact_arguments);
+            tool.content_type = content_type.map(String::from);
+        }
+        self
+    }
+}
+
+/// Replace values under sensitive-looking keys with `[REDACTED]` and scrub
+/// secret-looking tokens from the remaining strings.
+fn redact_arguments(value: &serde_json::Value) -> serde_json::Value {
+    match value {
+        serde_json::Value::Object(map) => serde_json::Value::Object(
+            map.iter()
+                .map(|(key, v)| {
+                    if is_sensitive_key(key) {
+                        (key.clone(), serde_json::Value::String(REDACTED.into()))
+                    } else {
+                        (key.clone(), redact_arguments(v))
+                    }
+                })
+                .collect(),
+        ),
+        serde_json::Value::Array(items) => {
+            serde_json::Value::Array(items.iter().map(redact_arguments).collect())
+        }
+        serde_json::Value::String(s) => {
+            serde_json::Value::String(crate::providers::scrub_secret_patterns(s))
+        }
+        other => other.clone(),
+    }
+}
+
+fn is_sensitive_key(key: &str) -> bool {
+    let key = key.to_ascii_lowercase().replace('-', "_");
+    SENSITIVE_ARG_KEYS.iter().any(|sensitive| {
+        key == *sensitive
+            || key
+                .strip_suffix(sensitive)
+                .is_some_and(|rest| rest.ends_with('_'))
+    })
 }
 
 /// Audit logger
@@ -162,6 +274,20 @@ pub struct CommandExecutionLog<'a> {
     pub duration_ms: u64,
 }
 
+/// Structured tool execution details for audit logging.
+#[derive(Debug, Clone)]
+pub struct ToolExecutionLog<'a> {
+    pub channel: &'a str,
+    pub tool: &'a str,
+    pub arguments: &'a serde_json::Value,
+    pub success: bool,
+    pub duration_ms: u64,
+    /// Result text as returned to the model; only its hash is logged.
+    pub result: &'a str,
+    pub metadata: Option<&'a serde_json::Value>,
+    pub content_type: Option<&'a str>,
+}
+
 impl AuditLogger {
     /// Create a new audit logger
     pub fn new(config: AuditConfig, zeroclaw_dir: PathBuf) -> Result<Self> {
@@ -173,6 +299,20 @@ impl AuditLogger {
         })
     }
 
+    /// The tool-call audit logger for a run: `None` when `[security.audit]`
+    /// is disabled. `config_path` is the loaded `config.toml`; relative log
+    /// paths resolve next to it.
+    pub fn for_run(config: &AuditConfig, config_path: &Path) -> Result<Option<Arc<Self>>> {
+        if !config.enabled {
+            return Ok(None);
+        }
+        let zeroclaw_dir = config_path.parent().unwrap_or_else(|| Path::new("."));
+        Ok(Some(Arc::new(Self::new(
+            config.clone(),
+            zeroclaw_dir.to_path_buf(),
+        )?)))
+    }
+
     /// Log an event
     pub fn log(&self, event: &AuditEvent) -> Result<()> {
         if !self.config.enabled {
@@ -200,7 +340,7 @@ impl AuditLogger {
         let event = AuditEvent::new(AuditEventType::CommandExecution)
             .with_actor(entry.channel.to_string(), None, None)
             .with_action(
-                entry.command.to_string(),
+                crate::providers::scrub_secret_patterns(entry.command),
                 entry.risk_level.to_string(),
                 entry.approved,
                 entry.allowed,
@@ -210,6 +350,31 @@ impl AuditLogger {
         self.log(&event)
     }
 
+    /// Log that a tool is about to run. Callers must not run the tool when
+    /// this fails, so nothing executes without a trail.
+    pub fn log_tool_start(
+        &self,
+        channel: &str,
+        tool: &str,
+        arguments: &serde_json::Value,
+    ) -> Result<()> {
+        let event = AuditEvent::new(AuditEventType::ToolCallStart)
+            .with_actor(channel.to_string(), None, None)
+            .with_tool(tool, arguments, None);
+        self.log(&event)
+    }
+
+    /// Log a finished tool call.
+    pub fn log_tool_event(&self, entry: ToolExecutionLog<'_>) -> Result<()> {
+        let digest = hex::encode(Sha256::digest(entry.result.as_bytes()));
+        let event = AuditEvent::new(AuditEventType::ToolCall)
+            .with_actor(entry.channel.to_string(), None, None)
+            .with_tool(entry.tool, entry.arguments, Some(digest))
+            .with_tool_result_details(entry.metadata, entry.content_type)
+            .with_result(entry.success, None, entry.duration_ms, None);
+        self.log(&event)
+    }
+
     /// Backward-compatible helper to log a command execution event.
     #[allow(clippy::too_many_arguments)]
     pub fn log_command(
@@ -333,6 +498,23 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn for_run_is_none_when_disabled_and_logs_next_to_config() -> Result<()> {
+        let tmp = TempDir::new()?;
+        let config_path = tmp.path().join("config.toml");
+        let disabled = AuditConfig {
+            enabled: false,
+            ..Default::default()
+        };
+        assert!(AuditLogger::for_run(&disabled, &config_path)?.is_none());
+
+        let logger = AuditLogger::for_run(&AuditConfig::default(), &config_path)?
+            .expect("auditing is enabled by default");
+        logger.log_tool_start("cli", "shell", &serde_json::json!({"command": "ls"}))?;
+        assert!(tmp.path().join("audit.log").exists());
+        Ok(())
+    }
+
     // ── §8.1 Log rotation tests ─────────────────────────────
 
     #[tokio::test]
@@ -396,6 +578,80 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn audit_tool_events_redact_arguments_and_hash_result() -> Result<()> {
+        let tmp = TempDir::new()?;
+        let logger = AuditLogger::new(AuditConfig::default(), tmp.path().to_path_buf())?;
+        let args = serde_json::json!({
+            "url": "https://api.example.com",
+            "headers": {"Authorization": "Bearer sk-live-123", "X-Trace": "{{secret:trace}}"},
+            "api_key": "sk-live-456",
+            "body": "token=ghp_abcdef123456 rest"
+        });
+
+        logger.log_tool_start("cli", "http_request", &args)?;
+        logger.log_tool_event(ToolExecutionLog {
+            channel: "cli",
+            tool: "http_request",
+            arguments: &args,
+            success: true,
+            duration_ms: 7,
+            result: "ok",
+            metadata: Some(&serde_json::json!({"status": 200, "token": "sk-live-789"})),
+            content_type: Some("application/json"),
+        })?;
+
+        let content = tokio::fs::read_to_string(tmp.path().join("audit.log")).await?;
+        assert!(!content.contains("sk-live"));
+        let events: Vec<AuditEvent> = content
+            .lines()
+            .map(serde_json::from_str)
+            .collect::<std::result::Result<_, _>>()?;
+        assert_eq!(events.len(), 2);
+        assert!(matches!(
+            events[0].event_type,
+            AuditEventType::ToolCallStart
+        ));
+
+        let tool = events[1].tool.as_ref().unwrap();
+        assert_eq!(tool.name, "http_request");
+        assert_eq!(tool.arguments["url"], "https://api.example.com");
+        assert_eq!(tool.arguments["headers"]["Authorization"], REDACTED);
+        assert_eq!(tool.arguments["headers"]["X-Trace"], "{{secret:trace}}");
+        assert!(!tool.arguments["body"]
+            .as_str()
+            .unwrap()
+            .contains("ghp_abcdef123456"));
+        assert_eq!(
+            tool.result_sha256.as_deref(),
+            Some("2689367b205c16ce32ed4200942b8b8b1e262dfc70d9bc9fbc77c49699a4f1df")
+        );
+        assert_eq!(tool.metadata.as_ref().unwrap()["status"], 200);
+        assert_eq!(tool.metadata.as_ref().unwrap()["token"], REDACTED);
+        assert_eq!(tool.content_type.as_deref(), Some("application/json"));
+        assert!(events[1].result.as_ref().unwrap().success);
+        Ok(())
+    }
+
+    #[test]
+    fn sensitive_keys_match_whole_keys_and_suffix_segments() {
+        for key in [
+            "token",
+            "api_key",
+            "apiKey",
+            "github_token",
+            "X-Api-Key",
+            "Authorization",
+            "client_secret",
+            "db_password",
+        ] {
+            assert!(is_sensitive_key(key), "{key} should be redacted");
+        }
+        for key in ["max_tokens", "token_count", "tokens", "secretary", "path"] {
+            assert!(!is_sensitive_key(key), "{key} should be kept");
+        }
+    }
+
     #[test]
     fn audit_rotation_creates_numbered_backup() -> Result<()> {
         let tmp = TempDir::new()?;
@@ -7,8 +7,10 @@ pub mod docker;
 pub mod firejail;
 #[cfg(feature = "sandbox-landlock")]
 pub mod landlock;
+pub mod moderation;
 pub mod pairing;
 pub mod policy;
+pub mod secret_refs;
 pub mod secrets;
 pub mod traits;
 
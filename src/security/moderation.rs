@@ -0,0 +1,275 @@
+//! Content moderation for user messages and agent responses.
+//!
+//! A [`Moderator`] classifies a piece of text; [`ModerationGate`] applies the
+//! `[moderation]` config on top of one (which direction to screen, what to
+//! reply when something is blocked). Moderator errors fail closed: the text is
+//! treated as flagged so an outage cannot silently disable screening.
+
+use crate::config::ModerationConfig;
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use regex::{Regex, RegexBuilder};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ModerationVerdict {
+    Allowed,
+    Flagged { reason: String },
+}
+
+/// Pluggable content classifier.
+#[async_trait]
+pub trait Moderator: Send + Sync {
+    fn name(&self) -> &str;
+
+    async fn check(&self, text: &str) -> Result<ModerationVerdict>;
+}
+
+/// Flags text matching any of a set of case-insensitive regexes.
+pub struct DenylistModerator {
+    patterns: Vec<Regex>,
+}
+
+impl DenylistModerator {
+    pub fn new(patterns: &[String]) -> Result<Self> {
+        let patterns = patterns
+            .iter()
+            .map(|p| {
+                RegexBuilder::new(p)
+                    .case_insensitive(true)
+                    .build()
+                    .with_context(|| format!("Invalid moderation.denied_patterns entry '{p}'"))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self { patterns })
+    }
+}
+
+#[async_trait]
+impl Moderator for DenylistModerator {
+    fn name(&self) -> &str {
+        "denylist"
+    }
+
+    async fn check(&self, text: &str) -> Result<ModerationVerdict> {
+        Ok(self.patterns.iter().find(|re| re.is_match(text)).map_or(
+            ModerationVerdict::Allowed,
+            |re| ModerationVerdict::Flagged {
+                reason: format!("matches denied pattern '{}'", re.as_str()),
+            },
+        ))
+    }
+}
+
+const OPENAI_MODERATION_URL: &str = "https://api.openai.com/v1/moderations";
+
+/// Uses the OpenAI moderation endpoint.
+pub struct OpenAiModerator {
+    api_key: String,
+    client: reqwest::Client,
+}
+
+impl OpenAiModerator {
+    pub fn new(api_key: Option<&str>) -> Result<Self> {
+        let api_key = api_key
+            .map(str::to_string)
+            .or_else(|| std::env::var("OPENAI_API_KEY").ok())
+            .filter(|k| !k.trim().is_empty())
+            .context(
+                "moderation.backend = \"openai\" requires moderation.api_key or OPENAI_API_KEY",
+            )?;
+        Ok(Self {
+            api_key,
+            client: crate::config::build_runtime_proxy_client_with_timeouts(
+                "moderation.openai",
+                15,
+                5,
+            ),
+        })
+    }
+}
+
+#[async_trait]
+impl Moderator for OpenAiModerator {
+    fn name(&self) -> &str {
+        "openai"
+    }
+
+    async fn check(&self, text: &str) -> Result<ModerationVerdict> {
+        let response = self
+            .client
+            .post(OPENAI_MODERATION_URL)
+            .bearer_auth(&self.api_key)
+            .json(&serde_json::json!({ "input": text }))
+            .send()
+            .await?
+            .error_for_status()?;
+        let body: serde_json::Value = response.json().await?;
+        let result = body
+            .get("results")
+            .and_then(|r| r.get(0))
+            .context("OpenAI moderation response has no results")?;
+        if !result
+            .get("flagged")
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false)
+        {
+            return Ok(ModerationVerdict::Allowed);
+        }
+        let categories: Vec<&str> = result
+            .get("categories")
+            .and_then(serde_json::Value::as_object)
+            .map(|c| {
+                c.iter()
+                    .filter(|(_, v)| v.as_bool() == Some(true))
+                    .map(|(k, _)| k.as_str())
+                    .collect()
+            })
+            .unwrap_or_default();
+        Ok(ModerationVerdict::Flagged {
+            reason: if categories.is_empty() {
+                "flagged by OpenAI moderation".into()
+            } else {
+                format!("flagged by OpenAI moderation: {}", categories.join(", "))
+            },
+        })
+    }
+}
+
+/// Build the moderator selected by `config.backend`.
+pub fn create_moderator(config: &ModerationConfig) -> Result<Box<dyn Moderator>> {
+    match config.backend.as_str() {
+        "denylist" => Ok(Box::new(DenylistModerator::new(&config.denied_patterns)?)),
+        "openai" => Ok(Box::new(OpenAiModerator::new(config.api_key.as_deref())?)),
+        other => anyhow::bail!(
+            "Unknown moderation.backend '{other}' (expected \"denylist\" or \"openai\")"
+        ),
+    }
+}
+
+/// Applies a [`Moderator`] to inbound messages and outbound responses.
+pub struct ModerationGate {
+    moderator: Box<dyn Moderator>,
+    check_input: bool,
+    check_output: bool,
+    blocked_message: String,
+}
+
+impl ModerationGate {
+    pub fn new(moderator: Box<dyn Moderator>, config: &ModerationConfig) -> Self {
+        Self {
+            moderator,
+            check_input: config.check_input,
+            check_output: config.check_output,
+            blocked_message: config.blocked_message.clone(),
+        }
+    }
+
+    /// `None` when moderation is disabled.
+    pub fn from_config(config: &ModerationConfig) -> Result<Option<Self>> {
+        if !config.enabled {
+            return Ok(None);
+        }
+        Ok(Some(Self::new(create_moderator(config)?, config)))
+    }
+
+    pub fn checks_output(&self) -> bool {
+        self.check_output
+    }
+
+    /// `Some(policy message)` if the user message must not reach the model.
+    pub async fn screen_input(&self, text: &str) -> Option<String> {
+        if !self.check_input {
+            return None;
+        }
+        self.screen("input", text).await
+    }
+
+    /// `Some(policy message)` if the response must not be delivered.
+    pub async fn screen_output(&self, text: &str) -> Option<String> {
+        if !self.check_output {
+            return None;
+        }
+        self.screen("output", text).await
+    }
+
+    async fn screen(&self, direction: &str, text: &str) -> Option<String> {
+        let reason = match self.moderator.check(text).await {
+            Ok(ModerationVerdict::Allowed) => return None,
+            Ok(ModerationVerdict::Flagged { reason }) => reason,
+            Err(e) => format!("moderator error: {e}"),
+        };
+        tracing::warn!(
+            moderator = self.moderator.name(),
+            direction,
+            reason,
+            "Moderation blocked message"
+        );
+        Some(self.blocked_message.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct FailingModerator;
+
+    #[async_trait]
+    impl Moderator for FailingModerator {
+        fn name(&self) -> &str {
+            "failing"
+        }
+
+        async fn check(&self, _text: &str) -> Result<ModerationVerdict> {
+            anyhow::bail!("endpoint unavailable")
+        }
+    }
+
+    #[tokio::test]
+    async fn denylist_gate_blocks_matching_input_only() {
+        let config = ModerationConfig {
+            enabled: true,
+            denied_patterns: vec![r"\bforbidden\b".into()],
+            ..ModerationConfig::default()
+        };
+        let gate = ModerationGate::from_config(&config).unwrap().unwrap();
+
+        assert_eq!(gate.screen_input("hello there").await, None);
+        assert_eq!(
+            gate.screen_input("tell me the FORBIDDEN thing").await,
+            Some(config.blocked_message.clone())
+        );
+        assert_eq!(
+            gate.screen_output("forbidden").await,
+            None,
+            "check_output is off by default"
+        );
+    }
+
+    #[tokio::test]
+    async fn gate_fails_closed_and_rejects_bad_config() {
+        let config = ModerationConfig {
+            check_output: true,
+            ..ModerationConfig::default()
+        };
+        let gate = ModerationGate::new(Box::new(FailingModerator), &config);
+        assert!(gate.screen_input("anything").await.is_some());
+        assert!(gate.screen_output("anything").await.is_some());
+
+        assert!(ModerationGate::from_config(&ModerationConfig::default())
+            .unwrap()
+            .is_none());
+        let bad = ModerationConfig {
+            enabled: true,
+            backend: "nope".into(),
+            ..ModerationConfig::default()
+        };
+        assert!(ModerationGate::from_config(&bad).is_err());
+        let bad_pattern = ModerationConfig {
+            enabled: true,
+            denied_patterns: vec!["(".into()],
+            ..ModerationConfig::default()
+        };
+        assert!(ModerationGate::from_config(&bad_pattern).is_err());
+    }
+}
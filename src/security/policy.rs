@@ -89,6 +89,7 @@ pub struct SecurityPolicy {
     pub max_cost_per_day_cents: u32,
     pub require_approval_for_medium_risk: bool,
     pub block_high_risk_commands: bool,
+    pub allow_clipboard: bool,
     pub tracker: ActionTracker,
 }
 
@@ -139,6 +140,7 @@ impl Default for SecurityPolicy {
             max_cost_per_day_cents: 500,
             require_approval_for_medium_risk: true,
             block_high_risk_commands: true,
+            allow_clipboard: false,
             tracker: ActionTracker::new(),
         }
     }
@@ -703,6 +705,12 @@ impl SecurityPolicy {
         self.autonomy != AutonomyLevel::ReadOnly
     }
 
+    /// Check if the system clipboard may be read or written. Opt-in only:
+    /// the clipboard routinely holds passwords and tokens.
+    pub fn can_access_clipboard(&self) -> bool {
+        self.allow_clipboard
+    }
+
     /// Enforce policy for a tool operation.
     ///
     /// Read operations are always allowed by autonomy/rate gates.
@@ -757,6 +765,7 @@ impl SecurityPolicy {
             max_cost_per_day_cents: autonomy_config.max_cost_per_day_cents,
             require_approval_for_medium_risk: autonomy_config.require_approval_for_medium_risk,
             block_high_risk_commands: autonomy_config.block_high_risk_commands,
+            allow_clipboard: autonomy_config.allow_clipboard,
             tracker: ActionTracker::new(),
         }
     }
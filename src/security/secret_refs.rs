@@ -0,0 +1,325 @@
+// Secret references for tool arguments.
+//
+// `[secrets.refs]` maps logical names to credentials: `env:VAR` reads an
+// environment variable at call time, `keyring:<service>/<entry>` reads the OS
+// keyring (builds with the `keyring` feature), anything else is the secret
+// itself (encrypted in config.toml like other keys). The model only ever writes a
+// placeholder such as `{{secret:github}}` inside tool arguments; the agent
+// loop swaps in the real value right before `Tool::execute` and scrubs it
+// from whatever the tool returns, so it never reaches history or logs.
+//
+// Placeholders are only honoured in the argument fields listed in
+// `SECRET_REF_FIELDS`. Anywhere else (a shell command, a file body, a URL)
+// the tool could echo or store the value, so the call is refused instead.
+
+use serde_json::Value;
+use std::collections::HashMap;
+
+const PLACEHOLDER_PREFIX: &str = "{{secret:";
+const PLACEHOLDER_SUFFIX: &str = "}}";
+const ENV_PREFIX: &str = "env:";
+const KEYRING_PREFIX: &str = "keyring:";
+const REDACTED: &str = "[REDACTED]";
+
+/// Top-level argument fields that may contain placeholders, per tool.
+const SECRET_REF_FIELDS: &[(&str, &str)] = &[("http_request", "headers")];
+
+/// Tool arguments with placeholders replaced, plus the values that were
+/// substituted so tool output can be scrubbed.
+#[derive(Debug, Clone)]
+pub struct ResolvedArgs {
+    pub arguments: Value,
+    secrets: Vec<String>,
+}
+
+impl ResolvedArgs {
+    /// Replace every substituted secret value in `text` with `[REDACTED]`.
+    pub fn redact(&self, text: &str) -> String {
+        self.secrets
+            .iter()
+            .filter(|s| !s.is_empty())
+            .fold(text.to_string(), |acc, secret| {
+                acc.replace(secret, REDACTED)
+            })
+    }
+}
+
+/// Whether a `[secrets.refs]` value points somewhere (`env:`, `keyring:`)
+/// rather than holding the secret, so it is stored as-is, not encrypted.
+pub fn is_secret_pointer(spec: &str) -> bool {
+    spec.starts_with(ENV_PREFIX) || spec.starts_with(KEYRING_PREFIX)
+}
+
+/// Whether `text` contains a `{{secret:name}}` placeholder.
+pub fn contains_secret_ref(text: &str) -> bool {
+    text.contains(PLACEHOLDER_PREFIX)
+}
+
+/// Resolve placeholders in a tool call's arguments against the decrypted
+/// `[secrets.refs]` table. Only the fields in `SECRET_REF_FIELDS` for `tool`
+/// are resolved; a placeholder anywhere else fails the call so the secret
+/// can't be routed into a command or file.
+pub fn resolve_tool_secret_refs(
+    tool: &str,
+    args: &Value,
+    refs: &HashMap<String, String>,
+) -> anyhow::Result<ResolvedArgs> {
+    let Value::Object(map) = args else {
+        if value_contains_ref(args) {
+            anyhow::bail!("Secret references are not allowed in {tool} arguments");
+        }
+        return Ok(ResolvedArgs {
+            arguments: args.clone(),
+            secrets: Vec::new(),
+        });
+    };
+
+    let mut secrets = Vec::new();
+    let mut arguments = serde_json::Map::with_capacity(map.len());
+    for (key, value) in map {
+        let allowed = SECRET_REF_FIELDS
+            .iter()
+            .any(|(name, field)| *name == tool && field == key);
+        let value = if allowed {
+            resolve_value(value, refs, &mut secrets)?
+        } else if value_contains_ref(value) {
+            anyhow::bail!("Secret references are not allowed in {tool} argument '{key}'");
+        } else {
+            value.clone()
+        };
+        arguments.insert(key.clone(), value);
+    }
+    Ok(ResolvedArgs {
+        arguments: Value::Object(arguments),
+        secrets,
+    })
+}
+
+fn value_contains_ref(value: &Value) -> bool {
+    match value {
+        Value::String(s) => contains_secret_ref(s),
+        Value::Array(items) => items.iter().any(value_contains_ref),
+        Value::Object(map) => map.values().any(value_contains_ref),
+        _ => false,
+    }
+}
+
+/// Resolve `{{secret:name}}` placeholders in any string inside `args`
+/// against the decrypted `[secrets.refs]` table.
+pub fn resolve_secret_refs(
+    args: &Value,
+    refs: &HashMap<String, String>,
+) -> anyhow::Result<ResolvedArgs> {
+    let mut secrets = Vec::new();
+    let arguments = resolve_value(args, refs, &mut secrets)?;
+    Ok(ResolvedArgs { arguments, secrets })
+}
+
+fn resolve_value(
+    value: &Value,
+    refs: &HashMap<String, String>,
+    secrets: &mut Vec<String>,
+) -> anyhow::Result<Value> {
+    Ok(match value {
+        Value::String(s) if s.contains(PLACEHOLDER_PREFIX) => {
+            Value::String(resolve_str(s, refs, secrets)?)
+        }
+        Value::Array(items) => Value::Array(
+            items
+                .iter()
+                .map(|item| resolve_value(item, refs, secrets))
+                .collect::<anyhow::Result<_>>()?,
+        ),
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .map(|(k, v)| Ok((k.clone(), resolve_value(v, refs, secrets)?)))
+                .collect::<anyhow::Result<_>>()?,
+        ),
+        other => other.clone(),
+    })
+}
+
+fn resolve_str(
+    input: &str,
+    refs: &HashMap<String, String>,
+    secrets: &mut Vec<String>,
+) -> anyhow::Result<String> {
+    let mut out = String::with_capacity(input.len());
+    let mut rest = input;
+    while let Some(start) = rest.find(PLACEHOLDER_PREFIX) {
+        let after = &rest[start + PLACEHOLDER_PREFIX.len()..];
+        let Some(end) = after.find(PLACEHOLDER_SUFFIX) else {
+            break;
+        };
+        let name = after[..end].trim();
+        let value = lookup_secret(name, refs)?;
+        out.push_str(&rest[..start]);
+        out.push_str(&value);
+        secrets.push(value);
+        rest = &after[end + PLACEHOLDER_SUFFIX.len()..];
+    }
+    out.push_str(rest);
+    Ok(out)
+}
+
+fn lookup_secret(name: &str, refs: &HashMap<String, String>) -> anyhow::Result<String> {
+    let spec = refs.get(name).ok_or_else(|| {
+        anyhow::anyhow!("Unknown secret reference '{name}' (not in [secrets.refs])")
+    })?;
+
+    if let Some(var) = spec.strip_prefix(ENV_PREFIX) {
+        return std::env::var(var.trim())
+            .ok()
+            .filter(|v| !v.is_empty())
+            .ok_or_else(|| {
+                anyhow::anyhow!("Secret '{name}' is unavailable: environment variable is not set")
+            });
+    }
+
+    if let Some(location) = spec.strip_prefix(KEYRING_PREFIX) {
+        let (service, entry) = location
+            .split_once('/')
+            .filter(|(service, entry)| !service.is_empty() && !entry.is_empty())
+            .ok_or_else(|| {
+                anyhow::anyhow!("Secret '{name}' must look like keyring:<service>/<entry>")
+            })?;
+        return read_keyring(name, service, entry);
+    }
+
+    if spec.is_empty() {
+        anyhow::bail!("Secret '{name}' is empty");
+    }
+    Ok(spec.clone())
+}
+
+#[cfg(feature = "keyring")]
+fn read_keyring(name: &str, service: &str, entry: &str) -> anyhow::Result<String> {
+    keyring::Entry::new(service, entry)
+        .and_then(|entry| entry.get_password())
+        .ok()
+        .filter(|v| !v.is_empty())
+        .ok_or_else(|| anyhow::anyhow!("Secret '{name}' is unavailable: keyring entry not found"))
+}
+
+#[cfg(not(feature = "keyring"))]
+fn read_keyring(name: &str, _service: &str, _entry: &str) -> anyhow::Result<String> {
+    anyhow::bail!(
+        "Secret '{name}' is stored in the OS keyring, but this build lacks the `keyring` feature"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn refs() -> HashMap<String, String> {
+        HashMap::from([
+            ("github".to_string(), "ghp_literal_token".to_string()),
+            (
+                "from_env".to_string(),
+                "env:ZEROCLAW_TEST_SECRET_REF".to_string(),
+            ),
+            (
+                "missing_env".to_string(),
+                "env:ZEROCLAW_TEST_SECRET_REF_UNSET".to_string(),
+            ),
+        ])
+    }
+
+    #[test]
+    fn resolves_nested_placeholders_and_redacts_output() {
+        std::env::set_var("ZEROCLAW_TEST_SECRET_REF", "env-value-123");
+        let args = json!({
+            "url": "https://api.github.com/user",
+            "headers": {
+                "Authorization": "Bearer {{secret:github}}",
+                "X-Other": "{{secret:from_env}}"
+            },
+            "count": 3
+        });
+
+        let resolved = resolve_secret_refs(&args, &refs()).unwrap();
+        assert_eq!(
+            resolved.arguments["headers"]["Authorization"],
+            "Bearer ghp_literal_token"
+        );
+        assert_eq!(resolved.arguments["headers"]["X-Other"], "env-value-123");
+        assert_eq!(resolved.arguments["count"], 3);
+
+        let echoed = "token=ghp_literal_token other=env-value-123";
+        assert_eq!(resolved.redact(echoed), "token=[REDACTED] other=[REDACTED]");
+        std::env::remove_var("ZEROCLAW_TEST_SECRET_REF");
+    }
+
+    #[test]
+    fn unknown_or_unset_secret_is_an_error_without_leaking() {
+        let err = resolve_secret_refs(&json!({"h": "{{secret:nope}}"}), &refs())
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("Unknown secret reference 'nope'"));
+
+        let err = resolve_secret_refs(&json!({"h": "{{secret:missing_env}}"}), &refs())
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("unavailable"));
+        assert!(!err.contains("ZEROCLAW_TEST_SECRET_REF_UNSET"));
+    }
+
+    #[test]
+    fn placeholders_only_resolve_in_allowed_tool_fields() {
+        let args = json!({
+            "url": "https://api.github.com/user",
+            "headers": {"Authorization": "Bearer {{secret:github}}"}
+        });
+        let resolved = resolve_tool_secret_refs("http_request", &args, &refs()).unwrap();
+        assert_eq!(
+            resolved.arguments["headers"]["Authorization"],
+            "Bearer ghp_literal_token"
+        );
+
+        let body = json!({"url": "https://x.test", "body": "{{secret:github}}"});
+        let err = resolve_tool_secret_refs("http_request", &body, &refs()).unwrap_err();
+        assert!(err.to_string().contains("argument 'body'"));
+
+        let shell = json!({"command": "curl -H 'Authorization: {{secret:github}}' x"});
+        assert!(resolve_tool_secret_refs("shell", &shell, &refs()).is_err());
+        let nested = json!({"path": "a.txt", "content": ["{{secret:github}}"]});
+        assert!(resolve_tool_secret_refs("file_write", &nested, &refs()).is_err());
+
+        let plain = json!({"command": "ls"});
+        let resolved = resolve_tool_secret_refs("shell", &plain, &refs()).unwrap();
+        assert_eq!(resolved.arguments, plain);
+    }
+
+    #[test]
+    fn keyring_refs_are_pointers_and_need_service_and_entry() {
+        assert!(is_secret_pointer("env:GITHUB_TOKEN"));
+        assert!(is_secret_pointer("keyring:zeroclaw/github"));
+        assert!(!is_secret_pointer("ghp_inline"));
+
+        let refs = HashMap::from([("bad".to_string(), "keyring:zeroclaw".to_string())]);
+        let err = resolve_secret_refs(&json!({"h": "{{secret:bad}}"}), &refs)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("keyring:<service>/<entry>"));
+    }
+
+    #[cfg(not(feature = "keyring"))]
+    #[test]
+    fn keyring_refs_fail_clearly_without_the_feature() {
+        let refs = HashMap::from([("github".to_string(), "keyring:zeroclaw/github".to_string())]);
+        let err = resolve_secret_refs(&json!({"h": "{{secret:github}}"}), &refs)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("`keyring` feature"));
+    }
+
+    #[test]
+    fn arguments_without_placeholders_pass_through() {
+        let args = json!({"command": "echo {{not a secret}}", "list": ["a", 1]});
+        let resolved = resolve_secret_refs(&args, &refs()).unwrap();
+        assert_eq!(resolved.arguments, args);
+        assert_eq!(resolved.redact("unchanged"), "unchanged");
+    }
+}
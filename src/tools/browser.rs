@@ -647,6 +647,8 @@ impl BrowserTool {
                 success: true,
                 output: serde_json::to_string_pretty(&output).unwrap_or_default(),
                 error: None,
+                metadata: None,
+                content_type: None,
             })
         }
 
@@ -794,6 +796,8 @@ impl BrowserTool {
                     success: true,
                     output,
                     error: None,
+                    metadata: None,
+                    content_type: None,
                 });
             }
 
@@ -811,6 +815,8 @@ impl BrowserTool {
                 success: false,
                 output: String::new(),
                 error,
+                metadata: None,
+                content_type: None,
             });
         }
 
@@ -819,6 +825,8 @@ impl BrowserTool {
                 success: true,
                 output: body,
                 error: None,
+                metadata: None,
+                content_type: None,
             });
         }
 
@@ -829,6 +837,8 @@ impl BrowserTool {
                 "computer-use sidecar request failed with status {status}: {}",
                 body.trim()
             )),
+            metadata: None,
+            content_type: None,
         })
     }
 
@@ -857,12 +867,16 @@ impl BrowserTool {
                 success: true,
                 output,
                 error: None,
+                metadata: None,
+                content_type: None,
             })
         } else {
             Ok(ToolResult {
                 success: false,
                 output: String::new(),
                 error: resp.error,
+                metadata: None,
+                content_type: None,
             })
         }
     }
@@ -883,6 +897,10 @@ impl Tool for BrowserTool {
         )
     }
 
+    fn category(&self) -> &str {
+        "web"
+    }
+
     fn parameters_schema(&self) -> Value {
         json!({
             "type": "object",
@@ -1004,6 +1022,8 @@ impl Tool for BrowserTool {
                 success: false,
                 output: String::new(),
                 error: Some("Action blocked: autonomy is read-only".into()),
+                metadata: None,
+                content_type: None,
             });
         }
 
@@ -1012,6 +1032,8 @@ impl Tool for BrowserTool {
                 success: false,
                 output: String::new(),
                 error: Some("Action blocked: rate limit exceeded".into()),
+                metadata: None,
+                content_type: None,
             });
         }
 
@@ -1022,6 +1044,8 @@ impl Tool for BrowserTool {
                     success: false,
                     output: String::new(),
                     error: Some(error.to_string()),
+                    metadata: None,
+                    content_type: None,
                 });
             }
         };
@@ -1037,6 +1061,8 @@ impl Tool for BrowserTool {
                 success: false,
                 output: String::new(),
                 error: Some(format!("Unknown action: {action_str}")),
+                metadata: None,
+                content_type: None,
             });
         }
 
@@ -1049,6 +1075,8 @@ impl Tool for BrowserTool {
                 success: false,
                 output: String::new(),
                 error: Some(unavailable_action_for_backend_error(action_str, backend)),
+                metadata: None,
+                content_type: None,
             });
         }
 
@@ -1059,6 +1087,8 @@ impl Tool for BrowserTool {
                     success: false,
                     output: String::new(),
                     error: Some(e.to_string()),
+                    metadata: None,
+                    content_type: None,
                 });
             }
         };
@@ -0,0 +1,311 @@
+use super::browser_open::{normalize_allowed_domains, validate_allowlisted_url};
+use super::traits::{Tool, ToolResult};
+use crate::security::SecurityPolicy;
+use async_trait::async_trait;
+use regex::Regex;
+use serde_json::json;
+use std::fmt::Write;
+use std::sync::{Arc, LazyLock};
+use std::time::Duration;
+
+/// Request timeout for page fetches.
+const FETCH_TIMEOUT_SECS: u64 = 30;
+/// Maximum raw HTML we will download before extracting text (2 MB).
+const MAX_FETCH_BYTES: usize = 2_097_152;
+
+static TITLE_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?is)<title[^>]*>(.*?)</title>").unwrap());
+static MAIN_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?is)<(article|main)\b[^>]*>(.*?)</(?:article|main)>").unwrap());
+static BODY_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?is)<body\b[^>]*>(.*?)</body>").unwrap());
+static NOISE_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?is)<!--.*?-->|<(script|style|noscript|svg|nav|header|footer|aside|form|iframe|template)\b[^>]*>.*?</(?:script|style|noscript|svg|nav|header|footer|aside|form|iframe|template)>",
+    )
+    .unwrap()
+});
+static BLOCK_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)</?(p|div|br|li|ul|ol|tr|table|section|blockquote|pre|h[1-6])\b[^>]*>")
+        .unwrap()
+});
+static TAG_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?s)<[^>]*>").unwrap());
+static SPACES_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"[ \t\r\f\v]+").unwrap());
+static BLANK_LINES_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\n\s*\n+").unwrap());
+
+/// Fetch an allowlisted HTTPS page and return its readable text.
+///
+/// Opt-in via `browser.fetch_enabled`, separate from `browser.enabled`, so the
+/// default `browser_open` stays no-scrape. Uses the same `allowed_domains`
+/// list and local/private host blocking as `browser_open`; redirects are not
+/// followed.
+pub struct BrowserFetchTool {
+    security: Arc<SecurityPolicy>,
+    allowed_domains: Vec<String>,
+    max_chars: usize,
+}
+
+impl BrowserFetchTool {
+    pub fn new(
+        security: Arc<SecurityPolicy>,
+        allowed_domains: Vec<String>,
+        max_chars: usize,
+    ) -> Self {
+        Self {
+            security,
+            allowed_domains: normalize_allowed_domains(allowed_domains),
+            max_chars,
+        }
+    }
+
+    async fn fetch(&self, url: &str) -> anyhow::Result<(reqwest::StatusCode, String, String)> {
+        let builder = reqwest::Client::builder()
+            .timeout(Duration::from_secs(FETCH_TIMEOUT_SECS))
+            .connect_timeout(Duration::from_secs(10))
+            .redirect(reqwest::redirect::Policy::none());
+        let builder = crate::config::apply_runtime_proxy_to_builder(builder, "tool.browser");
+        let client = builder.build()?;
+
+        let mut response = client
+            .get(url)
+            .header(reqwest::header::ACCEPT, "text/html,text/plain;q=0.9")
+            .send()
+            .await?;
+        let status = response.status();
+        let content_type = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or_default()
+            .to_lowercase();
+        let location = response
+            .headers()
+            .get(reqwest::header::LOCATION)
+            .and_then(|v| v.to_str().ok())
+            .map(String::from);
+
+        if status.is_redirection() {
+            anyhow::bail!(
+                "Redirected to {}; fetch that URL directly if it is allowlisted",
+                location.as_deref().unwrap_or("an unknown location")
+            );
+        }
+
+        let mut body = Vec::new();
+        while let Some(chunk) = response.chunk().await? {
+            body.extend_from_slice(&chunk);
+            if body.len() > MAX_FETCH_BYTES {
+                body.truncate(MAX_FETCH_BYTES);
+                break;
+            }
+        }
+
+        Ok((
+            status,
+            content_type,
+            String::from_utf8_lossy(&body).into_owned(),
+        ))
+    }
+
+    fn truncate(&self, text: &str) -> String {
+        if text.chars().count() > self.max_chars {
+            let truncated: String = text.chars().take(self.max_chars).collect();
+            format!(
+                "{truncated}\n\n... [page text truncated at {} chars]",
+                self.max_chars
+            )
+        } else {
+            text.to_string()
+        }
+    }
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&nbsp;", " ")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+fn normalize_whitespace(text: &str) -> String {
+    let text = SPACES_RE.replace_all(text, " ");
+    let lines: Vec<&str> = text.lines().map(str::trim).collect();
+    BLANK_LINES_RE
+        .replace_all(&lines.join("\n"), "\n\n")
+        .trim()
+        .to_string()
+}
+
+/// Reader-mode extraction: prefer `<article>`/`<main>`, drop scripts and page
+/// chrome (nav, header, footer, ...), then strip the remaining markup.
+fn extract_readable_text(html: &str) -> (Option<String>, String) {
+    let title = TITLE_RE
+        .captures(html)
+        .map(|c| normalize_whitespace(&decode_entities(&TAG_RE.replace_all(&c[1], ""))))
+        .filter(|t| !t.is_empty());
+
+    let cleaned = NOISE_RE.replace_all(html, " ");
+    let content = MAIN_RE
+        .captures_iter(&cleaned)
+        .map(|c| c[2].to_string())
+        .max_by_key(String::len)
+        .or_else(|| BODY_RE.captures(&cleaned).map(|c| c[1].to_string()))
+        .unwrap_or_else(|| cleaned.to_string());
+
+    let with_breaks = BLOCK_RE.replace_all(&content, "\n");
+    let text = TAG_RE.replace_all(&with_breaks, "");
+    (title, normalize_whitespace(&decode_entities(&text)))
+}
+
+#[async_trait]
+impl Tool for BrowserFetchTool {
+    fn name(&self) -> &str {
+        "browser_fetch"
+    }
+
+    fn description(&self) -> &str {
+        "Fetch an approved HTTPS page and return its readable text (reader mode). Security constraints: allowlist-only domains, no local/private hosts, no redirects."
+    }
+
+    fn category(&self) -> &str {
+        "web"
+    }
+
+    fn parameters_schema(&self) -> serde_json::Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "url": {
+                    "type": "string",
+                    "description": "HTTPS URL on an allowed domain"
+                }
+            },
+            "required": ["url"]
+        })
+    }
+
+    async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
+        let url = args
+            .get("url")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| anyhow::anyhow!("Missing 'url' parameter"))?;
+
+        if !self.security.can_act() {
+            return Ok(ToolResult::err("Action blocked: autonomy is read-only"));
+        }
+
+        if !self.security.record_action() {
+            return Ok(ToolResult::err("Action blocked: rate limit exceeded"));
+        }
+
+        let url = match validate_allowlisted_url(url, &self.allowed_domains) {
+            Ok(v) => v,
+            Err(e) => return Ok(ToolResult::err(e.to_string())),
+        };
+
+        let (status, content_type, body) = match self.fetch(&url).await {
+            Ok(v) => v,
+            Err(e) => return Ok(ToolResult::err(format!("Fetch failed: {e}"))),
+        };
+
+        if !status.is_success() {
+            return Ok(ToolResult::err(format!(
+                "HTTP {} from {url}",
+                status.as_u16()
+            )));
+        }
+
+        let (title, text) = if content_type.is_empty() || content_type.contains("html") {
+            extract_readable_text(&body)
+        } else if content_type.starts_with("text/") {
+            (None, normalize_whitespace(&body))
+        } else {
+            return Ok(ToolResult::err(format!(
+                "Unsupported content type '{content_type}' (only HTML and plain text are extracted)"
+            )));
+        };
+
+        let mut output = format!("URL: {url}\n");
+        if let Some(title) = title {
+            let _ = writeln!(output, "Title: {title}");
+        }
+        output.push('\n');
+        output.push_str(&self.truncate(&text));
+
+        Ok(ToolResult::ok(output))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::security::AutonomyLevel;
+
+    fn test_tool(autonomy: AutonomyLevel) -> BrowserFetchTool {
+        let security = Arc::new(SecurityPolicy {
+            autonomy,
+            ..SecurityPolicy::default()
+        });
+        BrowserFetchTool::new(security, vec!["example.com".into()], 100)
+    }
+
+    #[test]
+    fn extract_prefers_article_and_drops_chrome() {
+        let html = r#"<html><head><title>Docs &amp; Guides</title>
+<style>body { color: red }</style></head>
+<body><nav>Home | About</nav>
+<article><h1>Install</h1><p>Run <code>cargo install</code>&nbsp;now.</p>
+<script>track()</script><p>Second   paragraph.</p></article>
+<footer>Copyright</footer></body></html>"#;
+        let (title, text) = extract_readable_text(html);
+        assert_eq!(title.as_deref(), Some("Docs & Guides"));
+        assert_eq!(
+            text,
+            "Install\n\nRun cargo install now.\n\nSecond paragraph."
+        );
+    }
+
+    #[test]
+    fn extract_falls_back_to_body() {
+        let html = "<html><body><div>One</div><div>Two &lt;3</div></body></html>";
+        let (title, text) = extract_readable_text(html);
+        assert!(title.is_none());
+        assert_eq!(text, "One\n\nTwo <3");
+    }
+
+    #[test]
+    fn truncate_respects_max_chars() {
+        let tool = test_tool(AutonomyLevel::Supervised);
+        let out = tool.truncate(&"a".repeat(150));
+        assert!(out.starts_with(&"a".repeat(100)));
+        assert!(out.contains("truncated at 100 chars"));
+        assert_eq!(tool.truncate("short"), "short");
+    }
+
+    #[tokio::test]
+    async fn execute_rejects_urls_outside_allowlist() {
+        let tool = test_tool(AutonomyLevel::Supervised);
+        for url in [
+            "https://evil.com/page",
+            "http://example.com",
+            "https://127.0.0.1/admin",
+        ] {
+            let result = tool.execute(json!({"url": url})).await.unwrap();
+            assert!(!result.success, "{url} should be rejected");
+        }
+    }
+
+    #[tokio::test]
+    async fn execute_blocked_in_readonly_mode() {
+        let tool = test_tool(AutonomyLevel::ReadOnly);
+        let result = tool
+            .execute(json!({"url": "https://example.com"}))
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("read-only"));
+    }
+}
@@ -19,37 +19,7 @@ impl BrowserOpenTool {
     }
 
     fn validate_url(&self, raw_url: &str) -> anyhow::Result<String> {
-        let url = raw_url.trim();
-
-        if url.is_empty() {
-            anyhow::bail!("URL cannot be empty");
-        }
-
-        if url.chars().any(char::is_whitespace) {
-            anyhow::bail!("URL cannot contain whitespace");
-        }
-
-        if !url.starts_with("https://") {
-            anyhow::bail!("Only https:// URLs are allowed");
-        }
-
-        if self.allowed_domains.is_empty() {
-            anyhow::bail!(
-                "Browser tool is enabled but no allowed_domains are configured. Add [browser].allowed_domains in config.toml"
-            );
-        }
-
-        let host = extract_host(url)?;
-
-        if is_private_or_local_host(&host) {
-            anyhow::bail!("Blocked local/private host: {host}");
-        }
-
-        if !host_matches_allowlist(&host, &self.allowed_domains) {
-            anyhow::bail!("Host '{host}' is not in browser.allowed_domains");
-        }
-
-        Ok(url.to_string())
+        validate_allowlisted_url(raw_url, &self.allowed_domains)
     }
 }
 
@@ -63,6 +33,10 @@ impl Tool for BrowserOpenTool {
         "Open an approved HTTPS URL in Brave Browser. Security constraints: allowlist-only domains, no local/private hosts, no scraping."
     }
 
+    fn category(&self) -> &str {
+        "web"
+    }
+
     fn parameters_schema(&self) -> serde_json::Value {
         json!({
             "type": "object",
@@ -87,6 +61,8 @@ impl Tool for BrowserOpenTool {
                 success: false,
                 output: String::new(),
                 error: Some("Action blocked: autonomy is read-only".into()),
+                metadata: None,
+                content_type: None,
             });
         }
 
@@ -95,6 +71,8 @@ impl Tool for BrowserOpenTool {
                 success: false,
                 output: String::new(),
                 error: Some("Action blocked: rate limit exceeded".into()),
+                metadata: None,
+                content_type: None,
             });
         }
 
@@ -105,6 +83,8 @@ impl Tool for BrowserOpenTool {
                     success: false,
                     output: String::new(),
                     error: Some(e.to_string()),
+                    metadata: None,
+                    content_type: None,
                 })
             }
         };
@@ -114,11 +94,15 @@ impl Tool for BrowserOpenTool {
                 success: true,
                 output: format!("Opened in Brave: {url}"),
                 error: None,
+                metadata: None,
+                content_type: None,
             }),
             Err(e) => Ok(ToolResult {
                 success: false,
                 output: String::new(),
                 error: Some(format!("Failed to open Brave Browser: {e}")),
+                metadata: None,
+                content_type: None,
             }),
         }
     }
@@ -184,7 +168,46 @@ async fn open_in_brave(url: &str) -> anyhow::Result<()> {
     }
 }
 
-fn normalize_allowed_domains(domains: Vec<String>) -> Vec<String> {
+/// Validate an HTTPS URL against a normalized `browser.allowed_domains` list.
+/// Shared with `browser_fetch`, which must honour the same allowlist.
+pub(super) fn validate_allowlisted_url(
+    raw_url: &str,
+    allowed_domains: &[String],
+) -> anyhow::Result<String> {
+    let url = raw_url.trim();
+
+    if url.is_empty() {
+        anyhow::bail!("URL cannot be empty");
+    }
+
+    if url.chars().any(char::is_whitespace) {
+        anyhow::bail!("URL cannot contain whitespace");
+    }
+
+    if !url.starts_with("https://") {
+        anyhow::bail!("Only https:// URLs are allowed");
+    }
+
+    if allowed_domains.is_empty() {
+        anyhow::bail!(
+            "Browser tool is enabled but no allowed_domains are configured. Add [browser].allowed_domains in config.toml"
+        );
+    }
+
+    let host = extract_host(url)?;
+
+    if is_private_or_local_host(&host) {
+        anyhow::bail!("Blocked local/private host: {host}");
+    }
+
+    if !host_matches_allowlist(&host, allowed_domains) {
+        anyhow::bail!("Host '{host}' is not in browser.allowed_domains");
+    }
+
+    Ok(url.to_string())
+}
+
+pub(super) fn normalize_allowed_domains(domains: Vec<String>) -> Vec<String> {
     let mut normalized = domains
         .into_iter()
         .filter_map(|d| normalize_domain(&d))
@@ -0,0 +1,208 @@
+use super::traits::{Tool, ToolResult};
+use crate::security::policy::ToolOperation;
+use crate::security::SecurityPolicy;
+use async_trait::async_trait;
+use serde_json::json;
+use std::sync::Arc;
+
+/// Maximum clipboard text returned to the model (characters).
+const MAX_CLIPBOARD_CHARS: usize = 100_000;
+
+/// Read or write plain text on the system clipboard.
+///
+/// Requires the `clipboard` build feature (`arboard`) and
+/// `autonomy.allow_clipboard = true`; the clipboard routinely holds secrets,
+/// so access is denied by default.
+pub struct ClipboardTool {
+    security: Arc<SecurityPolicy>,
+}
+
+impl ClipboardTool {
+    pub fn new(security: Arc<SecurityPolicy>) -> Self {
+        Self { security }
+    }
+}
+
+#[cfg(feature = "clipboard")]
+fn read_clipboard() -> anyhow::Result<String> {
+    let mut clipboard = arboard::Clipboard::new()?;
+    Ok(clipboard.get_text()?)
+}
+
+#[cfg(feature = "clipboard")]
+fn write_clipboard(text: String) -> anyhow::Result<()> {
+    let mut clipboard = arboard::Clipboard::new()?;
+    clipboard.set_text(text)?;
+    Ok(())
+}
+
+#[cfg(not(feature = "clipboard"))]
+fn read_clipboard() -> anyhow::Result<String> {
+    anyhow::bail!("Clipboard support not compiled in; rebuild with --features clipboard")
+}
+
+#[cfg(not(feature = "clipboard"))]
+fn write_clipboard(_text: String) -> anyhow::Result<()> {
+    anyhow::bail!("Clipboard support not compiled in; rebuild with --features clipboard")
+}
+
+#[async_trait]
+impl Tool for ClipboardTool {
+    fn name(&self) -> &str {
+        "clipboard"
+    }
+
+    fn description(&self) -> &str {
+        "Read (action=get) or write (action=set) plain text on the system clipboard."
+    }
+
+    fn category(&self) -> &str {
+        "system"
+    }
+
+    fn parameters_schema(&self) -> serde_json::Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "action": {
+                    "type": "string",
+                    "enum": ["get", "set"],
+                    "description": "'get' returns the clipboard text, 'set' replaces it"
+                },
+                "text": {
+                    "type": "string",
+                    "description": "Text to place on the clipboard (required for 'set')"
+                }
+            },
+            "required": ["action"]
+        })
+    }
+
+    async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
+        let action = args
+            .get("action")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| anyhow::anyhow!("Missing 'action' parameter"))?;
+
+        if !self.security.can_access_clipboard() {
+            return Ok(ToolResult::err(
+                "Clipboard access is disabled. Set autonomy.allow_clipboard = true to enable it.",
+            ));
+        }
+
+        match action {
+            "get" => {
+                if self.security.is_rate_limited() {
+                    return Ok(ToolResult::err(
+                        "Rate limit exceeded: too many actions in the last hour",
+                    ));
+                }
+                if !self.security.record_action() {
+                    return Ok(ToolResult::err(
+                        "Rate limit exceeded: action budget exhausted",
+                    ));
+                }
+
+                match tokio::task::spawn_blocking(read_clipboard).await? {
+                    Ok(text) => {
+                        let output = if text.chars().count() > MAX_CLIPBOARD_CHARS {
+                            let truncated: String =
+                                text.chars().take(MAX_CLIPBOARD_CHARS).collect();
+                            format!("{truncated}\n... [clipboard truncated at {MAX_CLIPBOARD_CHARS} chars]")
+                        } else {
+                            text
+                        };
+                        Ok(ToolResult::ok(output))
+                    }
+                    Err(e) => Ok(ToolResult::err(format!("Failed to read clipboard: {e}"))),
+                }
+            }
+            "set" => {
+                let text = args
+                    .get("text")
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| anyhow::anyhow!("Missing 'text' parameter for 'set'"))?
+                    .to_string();
+
+                if let Err(error) = self
+                    .security
+                    .enforce_tool_operation(ToolOperation::Act, "clipboard.set")
+                {
+                    return Ok(ToolResult::err(error));
+                }
+
+                let len = text.chars().count();
+                match tokio::task::spawn_blocking(move || write_clipboard(text)).await? {
+                    Ok(()) => Ok(ToolResult::ok(format!(
+                        "Copied {len} characters to the clipboard"
+                    ))),
+                    Err(e) => Ok(ToolResult::err(format!("Failed to write clipboard: {e}"))),
+                }
+            }
+            other => Ok(ToolResult::err(format!(
+                "Unknown action '{other}'. Use 'get' or 'set'."
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::security::AutonomyLevel;
+
+    fn tool(allow_clipboard: bool, autonomy: AutonomyLevel) -> ClipboardTool {
+        ClipboardTool::new(Arc::new(SecurityPolicy {
+            allow_clipboard,
+            autonomy,
+            ..SecurityPolicy::default()
+        }))
+    }
+
+    #[test]
+    fn clipboard_tool_name_and_schema() {
+        let tool = tool(true, AutonomyLevel::Supervised);
+        assert_eq!(tool.name(), "clipboard");
+        let schema = tool.parameters_schema();
+        assert_eq!(schema["required"], json!(["action"]));
+        assert_eq!(
+            schema["properties"]["action"]["enum"],
+            json!(["get", "set"])
+        );
+    }
+
+    #[tokio::test]
+    async fn clipboard_denied_by_default() {
+        let tool = ClipboardTool::new(Arc::new(SecurityPolicy::default()));
+        for args in [
+            json!({"action": "get"}),
+            json!({"action": "set", "text": "x"}),
+        ] {
+            let result = tool.execute(args).await.unwrap();
+            assert!(!result.success);
+            assert!(result.error.unwrap().contains("allow_clipboard"));
+        }
+    }
+
+    #[tokio::test]
+    async fn clipboard_set_blocked_in_readonly_mode() {
+        let tool = tool(true, AutonomyLevel::ReadOnly);
+        let result = tool
+            .execute(json!({"action": "set", "text": "hello"}))
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("read-only"));
+    }
+
+    #[tokio::test]
+    async fn clipboard_rejects_bad_arguments() {
+        let tool = tool(true, AutonomyLevel::Supervised);
+        assert!(tool.execute(json!({})).await.is_err());
+        assert!(tool.execute(json!({"action": "set"})).await.is_err());
+
+        let result = tool.execute(json!({"action": "clear"})).await.unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("Unknown action"));
+    }
+}
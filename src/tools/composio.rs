@@ -404,6 +404,10 @@ impl Tool for ComposioTool {
          or action='connect' with app/auth_config_id to get OAuth URL."
     }
 
+    fn category(&self) -> &str {
+        "integrations"
+    }
+
     fn parameters_schema(&self) -> serde_json::Value {
         json!({
             "type": "object",
@@ -488,12 +492,16 @@ impl Tool for ComposioTool {
                             success: true,
                             output,
                             error: None,
+                            metadata: None,
+                            content_type: None,
                         })
                     }
                     Err(e) => Ok(ToolResult {
                         success: false,
                         output: String::new(),
                         error: Some(format!("Failed to list actions: {e}")),
+                        metadata: None,
+                        content_type: None,
                     }),
                 }
             }
@@ -507,6 +515,8 @@ impl Tool for ComposioTool {
                         success: false,
                         output: String::new(),
                         error: Some(error),
+                        metadata: None,
+                        content_type: None,
                     });
                 }
 
@@ -532,12 +542,16 @@ impl Tool for ComposioTool {
                             success: true,
                             output,
                             error: None,
+                            metadata: None,
+                            content_type: None,
                         })
                     }
                     Err(e) => Ok(ToolResult {
                         success: false,
                         output: String::new(),
                         error: Some(format!("Action execution failed: {e}")),
+                        metadata: None,
+                        content_type: None,
                     }),
                 }
             }
@@ -551,6 +565,8 @@ impl Tool for ComposioTool {
                         success: false,
                         output: String::new(),
                         error: Some(error),
+                        metadata: None,
+                        content_type: None,
                     });
                 }
 
@@ -572,12 +588,16 @@ impl Tool for ComposioTool {
                             success: true,
                             output: format!("Open this URL to connect {target}:\n{url}"),
                             error: None,
+                            metadata: None,
+                            content_type: None,
                         })
                     }
                     Err(e) => Ok(ToolResult {
                         success: false,
                         output: String::new(),
                         error: Some(format!("Failed to get connection URL: {e}")),
+                        metadata: None,
+                        content_type: None,
                     }),
                 }
             }
@@ -588,6 +608,8 @@ impl Tool for ComposioTool {
                 error: Some(format!(
                     "Unknown action '{action}'. Use 'list', 'execute', or 'connect'."
                 )),
+                metadata: None,
+                content_type: None,
             }),
         }
     }
@@ -27,6 +27,10 @@ impl Tool for CronAddTool {
         "Create a scheduled cron job (shell or agent) with cron/at/every schedules"
     }
 
+    fn category(&self) -> &str {
+        "scheduling"
+    }
+
     fn parameters_schema(&self) -> serde_json::Value {
         json!({
             "type": "object",
@@ -54,6 +58,8 @@ impl Tool for CronAddTool {
                 success: false,
                 output: String::new(),
                 error: Some("cron is disabled by config (cron.enabled=false)".to_string()),
+                metadata: None,
+                content_type: None,
             });
         }
 
@@ -65,6 +71,8 @@ impl Tool for CronAddTool {
                         success: false,
                         output: String::new(),
                         error: Some(format!("Invalid schedule: {e}")),
+                        metadata: None,
+                        content_type: None,
                     });
                 }
             },
@@ -73,6 +81,8 @@ impl Tool for CronAddTool {
                     success: false,
                     output: String::new(),
                     error: Some("Missing 'schedule' parameter".to_string()),
+                    metadata: None,
+                    content_type: None,
                 });
             }
         };
@@ -90,6 +100,8 @@ impl Tool for CronAddTool {
                     success: false,
                     output: String::new(),
                     error: Some(format!("Invalid job_type: {other}")),
+                    metadata: None,
+                    content_type: None,
                 });
             }
             None => {
@@ -116,6 +128,8 @@ impl Tool for CronAddTool {
                             success: false,
                             output: String::new(),
                             error: Some("Missing 'command' for shell job".to_string()),
+                            metadata: None,
+                            content_type: None,
                         });
                     }
                 };
@@ -125,6 +139,8 @@ impl Tool for CronAddTool {
                         success: false,
                         output: String::new(),
                         error: Some(format!("Command blocked by security policy: {command}")),
+                        metadata: None,
+                        content_type: None,
                     });
                 }
 
@@ -138,6 +154,8 @@ impl Tool for CronAddTool {
                             success: false,
                             output: String::new(),
                             error: Some("Missing 'prompt' for agent job".to_string()),
+                            metadata: None,
+                            content_type: None,
                         });
                     }
                 };
@@ -150,6 +168,8 @@ impl Tool for CronAddTool {
                                 success: false,
                                 output: String::new(),
                                 error: Some(format!("Invalid session_target: {e}")),
+                                metadata: None,
+                                content_type: None,
                             });
                         }
                     },
@@ -169,6 +189,8 @@ impl Tool for CronAddTool {
                                 success: false,
                                 output: String::new(),
                                 error: Some(format!("Invalid delivery config: {e}")),
+                                metadata: None,
+                                content_type: None,
                             });
                         }
                     },
@@ -200,11 +222,15 @@ impl Tool for CronAddTool {
                     "enabled": job.enabled
                 }))?,
                 error: None,
+                metadata: None,
+                content_type: None,
             }),
             Err(e) => Ok(ToolResult {
                 success: false,
                 output: String::new(),
                 error: Some(e.to_string()),
+                metadata: None,
+                content_type: None,
             }),
         }
     }
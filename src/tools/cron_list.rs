@@ -25,6 +25,10 @@ impl Tool for CronListTool {
         "List all scheduled cron jobs"
     }
 
+    fn category(&self) -> &str {
+        "scheduling"
+    }
+
     fn parameters_schema(&self) -> serde_json::Value {
         json!({
             "type": "object",
@@ -39,6 +43,8 @@ impl Tool for CronListTool {
                 success: false,
                 output: String::new(),
                 error: Some("cron is disabled by config (cron.enabled=false)".to_string()),
+                metadata: None,
+                content_type: None,
             });
         }
 
@@ -47,11 +53,15 @@ impl Tool for CronListTool {
                 success: true,
                 output: serde_json::to_string_pretty(&jobs)?,
                 error: None,
+                metadata: None,
+                content_type: None,
             }),
             Err(e) => Ok(ToolResult {
                 success: false,
                 output: String::new(),
                 error: Some(e.to_string()),
+                metadata: None,
+                content_type: None,
             }),
         }
     }
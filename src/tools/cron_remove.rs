@@ -25,6 +25,10 @@ impl Tool for CronRemoveTool {
         "Remove a cron job by id"
     }
 
+    fn category(&self) -> &str {
+        "scheduling"
+    }
+
     fn parameters_schema(&self) -> serde_json::Value {
         json!({
             "type": "object",
@@ -41,6 +45,8 @@ impl Tool for CronRemoveTool {
                 success: false,
                 output: String::new(),
                 error: Some("cron is disabled by config (cron.enabled=false)".to_string()),
+                metadata: None,
+                content_type: None,
             });
         }
 
@@ -51,6 +57,8 @@ impl Tool for CronRemoveTool {
                     success: false,
                     output: String::new(),
                     error: Some("Missing 'job_id' parameter".to_string()),
+                    metadata: None,
+                    content_type: None,
                 });
             }
         };
@@ -60,11 +68,15 @@ impl Tool for CronRemoveTool {
                 success: true,
                 output: format!("Removed cron job {job_id}"),
                 error: None,
+                metadata: None,
+                content_type: None,
             }),
             Err(e) => Ok(ToolResult {
                 success: false,
                 output: String::new(),
                 error: Some(e.to_string()),
+                metadata: None,
+                content_type: None,
             }),
         }
     }
@@ -26,6 +26,10 @@ impl Tool for CronRunTool {
         "Force-run a cron job immediately and record run history"
     }
 
+    fn category(&self) -> &str {
+        "scheduling"
+    }
+
     fn parameters_schema(&self) -> serde_json::Value {
         json!({
             "type": "object",
@@ -42,6 +46,8 @@ impl Tool for CronRunTool {
                 success: false,
                 output: String::new(),
                 error: Some("cron is disabled by config (cron.enabled=false)".to_string()),
+                metadata: None,
+                content_type: None,
             });
         }
 
@@ -52,6 +58,8 @@ impl Tool for CronRunTool {
                     success: false,
                     output: String::new(),
                     error: Some("Missing 'job_id' parameter".to_string()),
+                    metadata: None,
+                    content_type: None,
                 });
             }
         };
@@ -63,6 +71,8 @@ impl Tool for CronRunTool {
                     success: false,
                     output: String::new(),
                     error: Some(e.to_string()),
+                    metadata: None,
+                    content_type: None,
                 });
             }
         };
@@ -97,6 +107,8 @@ impl Tool for CronRunTool {
             } else {
                 Some("cron job execution failed".to_string())
             },
+            metadata: None,
+            content_type: None,
         })
     }
 }
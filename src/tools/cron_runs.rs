@@ -39,6 +39,10 @@ impl Tool for CronRunsTool {
         "List recent run history for a cron job"
     }
 
+    fn category(&self) -> &str {
+        "scheduling"
+    }
+
     fn parameters_schema(&self) -> serde_json::Value {
         json!({
             "type": "object",
@@ -56,6 +60,8 @@ impl Tool for CronRunsTool {
                 success: false,
                 output: String::new(),
                 error: Some("cron is disabled by config (cron.enabled=false)".to_string()),
+                metadata: None,
+                content_type: None,
             });
         }
 
@@ -66,6 +72,8 @@ impl Tool for CronRunsTool {
                     success: false,
                     output: String::new(),
                     error: Some("Missing 'job_id' parameter".to_string()),
+                    metadata: None,
+                    content_type: None,
                 });
             }
         };
@@ -94,12 +102,16 @@ impl Tool for CronRunsTool {
                     success: true,
                     output: serde_json::to_string_pretty(&runs)?,
                     error: None,
+                    metadata: None,
+                    content_type: None,
                 })
             }
             Err(e) => Ok(ToolResult {
                 success: false,
                 output: String::new(),
                 error: Some(e.to_string()),
+                metadata: None,
+                content_type: None,
             }),
         }
     }
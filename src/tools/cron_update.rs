@@ -27,6 +27,10 @@ impl Tool for CronUpdateTool {
         "Patch an existing cron job (schedule, command, prompt, enabled, delivery, model, etc.)"
     }
 
+    fn category(&self) -> &str {
+        "scheduling"
+    }
+
     fn parameters_schema(&self) -> serde_json::Value {
         json!({
             "type": "object",
@@ -44,6 +48,8 @@ impl Tool for CronUpdateTool {
                 success: false,
                 output: String::new(),
                 error: Some("cron is disabled by config (cron.enabled=false)".to_string()),
+                metadata: None,
+                content_type: None,
             });
         }
 
@@ -54,6 +60,8 @@ impl Tool for CronUpdateTool {
                     success: false,
                     output: String::new(),
                     error: Some("Missing 'job_id' parameter".to_string()),
+                    metadata: None,
+                    content_type: None,
                 });
             }
         };
@@ -65,6 +73,8 @@ impl Tool for CronUpdateTool {
                     success: false,
                     output: String::new(),
                     error: Some("Missing 'patch' parameter".to_string()),
+                    metadata: None,
+                    content_type: None,
                 });
             }
         };
@@ -76,6 +86,8 @@ impl Tool for CronUpdateTool {
                     success: false,
                     output: String::new(),
                     error: Some(format!("Invalid patch payload: {e}")),
+                    metadata: None,
+                    content_type: None,
                 });
             }
         };
@@ -86,6 +98,8 @@ impl Tool for CronUpdateTool {
                     success: false,
                     output: String::new(),
                     error: Some(format!("Command blocked by security policy: {command}")),
+                    metadata: None,
+                    content_type: None,
                 });
             }
         }
@@ -95,11 +109,15 @@ impl Tool for CronUpdateTool {
                 success: true,
                 output: serde_json::to_string_pretty(&job)?,
                 error: None,
+                metadata: None,
+                content_type: None,
             }),
             Err(e) => Ok(ToolResult {
                 success: false,
                 output: String::new(),
                 error: Some(e.to_string()),
+                metadata: None,
+                content_type: None,
             }),
         }
     }
@@ -1,6 +1,8 @@
+use super::scratchpad::{new_scratchpad, ScratchGetTool, ScratchSetTool, Scratchpad};
 use super::traits::{Tool, ToolResult};
 use crate::config::DelegateAgentConfig;
-use crate::providers::{self, Provider};
+use crate::observability::NoopObserver;
+use crate::providers::{self, ChatMessage, Provider};
 use crate::security::policy::ToolOperation;
 use crate::security::SecurityPolicy;
 use async_trait::async_trait;
@@ -12,6 +14,86 @@ use std::time::Duration;
 /// Default timeout for sub-agent provider calls.
 const DELEGATE_TIMEOUT_SECS: u64 = 120;
 
+/// Max tool iterations a sub-agent may spend on scratchpad reads/writes.
+const DELEGATE_MAX_TOOL_ITERATIONS: usize = 5;
+
+/// Temperature range accepted for a per-call override.
+const MIN_TEMPERATURE: f64 = 0.0;
+const MAX_TEMPERATURE: f64 = 2.0;
+
+/// Timeout for each step of [`check_agent`].
+const CHECK_TIMEOUT_SECS: u64 = 30;
+
+/// Create the provider a delegate agent runs on, using the agent's own
+/// `api_key` or else the global credential.
+pub fn create_agent_provider(
+    agent_config: &DelegateAgentConfig,
+    fallback_credential: Option<&str>,
+    options: &providers::ProviderRuntimeOptions,
+) -> anyhow::Result<Box<dyn Provider>> {
+    let credential = agent_config.api_key.as_deref().or(fallback_credential);
+    providers::create_provider_with_options(&agent_config.provider, credential, options)
+}
+
+/// Check that a delegate agent is usable without spending a live agent
+/// turn: the config is sane, a required credential is present, and its
+/// provider can be created and warmed up. Warmup only proves the endpoint
+/// is reachable; the credential and model are verified only with `live`,
+/// which sends the model a one-line prompt.
+pub async fn check_agent(
+    agent_config: &DelegateAgentConfig,
+    fallback_credential: Option<&str>,
+    options: &providers::ProviderRuntimeOptions,
+    live: bool,
+) -> anyhow::Result<()> {
+    if agent_config.model.trim().is_empty() {
+        anyhow::bail!("no model configured");
+    }
+    if let Some(temperature) = agent_config.temperature {
+        if !(MIN_TEMPERATURE..=MAX_TEMPERATURE).contains(&temperature) {
+            anyhow::bail!(
+                "temperature {temperature} is outside {MIN_TEMPERATURE}..={MAX_TEMPERATURE}"
+            );
+        }
+    }
+
+    let credential = agent_config.api_key.as_deref().or(fallback_credential);
+    if providers::is_missing_required_credential(&agent_config.provider, credential) {
+        anyhow::bail!(
+            "no API key for provider '{}': set api_key on the agent, the top-level api_key, or the provider's environment variable",
+            agent_config.provider
+        );
+    }
+
+    let provider =
+        create_agent_provider(agent_config, fallback_credential, options).map_err(|e| {
+            anyhow::anyhow!("failed to create provider '{}': {e}", agent_config.provider)
+        })?;
+
+    let timeout = Duration::from_secs(CHECK_TIMEOUT_SECS);
+    tokio::time::timeout(timeout, provider.warmup())
+        .await
+        .map_err(|_| anyhow::anyhow!("provider warmup timed out after {CHECK_TIMEOUT_SECS}s"))?
+        .map_err(|e| anyhow::anyhow!("provider warmup failed: {e}"))?;
+
+    if live {
+        tokio::time::timeout(
+            timeout,
+            provider.chat_with_system(
+                agent_config.system_prompt.as_deref(),
+                "Reply with OK.",
+                &agent_config.model,
+                agent_config.temperature.unwrap_or(0.7),
+            ),
+        )
+        .await
+        .map_err(|_| anyhow::anyhow!("model call timed out after {CHECK_TIMEOUT_SECS}s"))?
+        .map_err(|e| anyhow::anyhow!("model call failed: {e}"))?;
+    }
+
+    Ok(())
+}
+
 /// Tool that delegates a subtask to a named agent with a different
 /// provider/model configuration. Enables multi-agent workflows where
 /// a primary agent can hand off specialized work (research, coding,
@@ -23,6 +105,10 @@ pub struct DelegateTool {
     fallback_credential: Option<String>,
     /// Depth at which this tool instance lives in the delegation chain.
     depth: u32,
+    /// Key-value scratchpad shared with every sub-agent this tool spawns.
+    scratchpad: Scratchpad,
+    /// Runtime options for sub-agent providers (e.g. the Azure deployment map).
+    provider_options: providers::ProviderRuntimeOptions,
 }
 
 impl DelegateTool {
@@ -36,6 +122,8 @@ impl DelegateTool {
             security,
             fallback_credential,
             depth: 0,
+            scratchpad: new_scratchpad(),
+            provider_options: providers::ProviderRuntimeOptions::default(),
         }
     }
 
@@ -53,8 +141,28 @@ impl DelegateTool {
             security,
             fallback_credential,
             depth,
+            scratchpad: new_scratchpad(),
+            provider_options: providers::ProviderRuntimeOptions::default(),
         }
     }
+
+    /// Share an existing scratchpad (e.g. the one backing the parent's
+    /// `scratch_set`/`scratch_get` tools) with delegated sub-agents.
+    pub fn with_scratchpad(mut self, scratchpad: Scratchpad) -> Self {
+        self.scratchpad = scratchpad;
+        self
+    }
+
+    /// Create sub-agent providers with these runtime options.
+    pub fn with_provider_options(mut self, options: providers::ProviderRuntimeOptions) -> Self {
+        self.provider_options = options;
+        self
+    }
+
+    /// Handle to the shared scratchpad, for inspecting what sub-agents left behind.
+    pub fn scratchpad(&self) -> Scratchpad {
+        self.scratchpad.clone()
+    }
 }
 
 #[async_trait]
@@ -66,7 +174,12 @@ impl Tool for DelegateTool {
     fn description(&self) -> &str {
         "Delegate a subtask to a specialized agent. Use when: a task benefits from a different model \
          (e.g. fast summarization, deep reasoning, code generation). The sub-agent runs a single \
-         prompt and returns its response."
+         prompt and returns its response; it can also leave structured findings in the shared \
+         scratchpad (read them with scratch_get)."
+    }
+
+    fn category(&self) -> &str {
+        "agents"
     }
 
     fn parameters_schema(&self) -> serde_json::Value {
@@ -95,6 +208,17 @@ impl Tool for DelegateTool {
                 "context": {
                     "type": "string",
                     "description": "Optional context to prepend (e.g. relevant code, prior findings)"
+                },
+                "model": {
+                    "type": "string",
+                    "minLength": 1,
+                    "description": "Optional model for this call only (must be in the agent's allowed_models when set); the agent config is unchanged"
+                },
+                "temperature": {
+                    "type": "number",
+                    "minimum": MIN_TEMPERATURE,
+                    "maximum": MAX_TEMPERATURE,
+                    "description": "Optional temperature for this call only (e.g. 0 for deterministic output); the agent config is unchanged"
                 }
             },
             "required": ["agent", "prompt"]
@@ -113,6 +237,8 @@ impl Tool for DelegateTool {
                 success: false,
                 output: String::new(),
                 error: Some("'agent' parameter must not be empty".into()),
+                metadata: None,
+                content_type: None,
             });
         }
 
@@ -127,6 +253,8 @@ impl Tool for DelegateTool {
                 success: false,
                 output: String::new(),
                 error: Some("'prompt' parameter must not be empty".into()),
+                metadata: None,
+                content_type: None,
             });
         }
 
@@ -153,8 +281,60 @@ impl Tool for DelegateTool {
                             available.join(", ")
                         }
                     )),
+                    metadata: None,
+                    content_type: None,
+                });
+            }
+        };
+
+        // Per-call overrides; they never touch the stored agent config.
+        let model = match args.get("model").and_then(|v| v.as_str()).map(str::trim) {
+            None => agent_config.model.as_str(),
+            Some(m) if m == agent_config.model => m,
+            Some("") => {
+                return Ok(ToolResult {
+                    success: false,
+                    output: String::new(),
+                    error: Some("'model' override must not be empty".into()),
+                    metadata: None,
+                    content_type: None,
                 });
             }
+            Some(m) => {
+                if !agent_config.allowed_models.is_empty()
+                    && !agent_config.allowed_models.iter().any(|a| a == m)
+                {
+                    return Ok(ToolResult {
+                        success: false,
+                        output: String::new(),
+                        error: Some(format!(
+                            "Model '{m}' is not allowed for agent '{agent_name}'. Allowed: {}",
+                            agent_config.allowed_models.join(", ")
+                        )),
+                        metadata: None,
+                        content_type: None,
+                    });
+                }
+                m
+            }
+        };
+
+        let temperature = match args.get("temperature") {
+            None | Some(serde_json::Value::Null) => agent_config.temperature.unwrap_or(0.7),
+            Some(v) => match v.as_f64() {
+                Some(t) if (MIN_TEMPERATURE..=MAX_TEMPERATURE).contains(&t) => t,
+                _ => {
+                    return Ok(ToolResult {
+                        success: false,
+                        output: String::new(),
+                        error: Some(format!(
+                            "'temperature' must be a number between {MIN_TEMPERATURE} and {MAX_TEMPERATURE}"
+                        )),
+                        metadata: None,
+                        content_type: None,
+                    });
+                }
+            },
         };
 
         // Check recursion depth (immutable — set at construction, incremented for sub-agents)
@@ -168,6 +348,8 @@ impl Tool for DelegateTool {
                     depth = self.depth,
                     max = agent_config.max_depth
                 )),
+                metadata: None,
+                content_type: None,
             });
         }
 
@@ -179,31 +361,31 @@ impl Tool for DelegateTool {
                 success: false,
                 output: String::new(),
                 error: Some(error),
+                metadata: None,
+                content_type: None,
             });
         }
 
         // Create provider for this agent
-        let provider_credential_owned = agent_config
-            .api_key
-            .clone()
-            .or_else(|| self.fallback_credential.clone());
-        #[allow(clippy::option_as_ref_deref)]
-        let provider_credential = provider_credential_owned.as_ref().map(String::as_str);
-
-        let provider: Box<dyn Provider> =
-            match providers::create_provider(&agent_config.provider, provider_credential) {
-                Ok(p) => p,
-                Err(e) => {
-                    return Ok(ToolResult {
-                        success: false,
-                        output: String::new(),
-                        error: Some(format!(
-                            "Failed to create provider '{}' for agent '{agent_name}': {e}",
-                            agent_config.provider
-                        )),
-                    });
-                }
-            };
+        let provider: Box<dyn Provider> = match create_agent_provider(
+            agent_config,
+            self.fallback_credential.as_deref(),
+            &self.provider_options,
+        ) {
+            Ok(p) => p,
+            Err(e) => {
+                return Ok(ToolResult {
+                    success: false,
+                    output: String::new(),
+                    error: Some(format!(
+                        "Failed to create provider '{}' for agent '{agent_name}': {e}",
+                        agent_config.provider
+                    )),
+                    metadata: None,
+                    content_type: None,
+                });
+            }
+        };
 
         // Build the message
         let full_prompt = if context.is_empty() {
@@ -212,16 +394,34 @@ impl Tool for DelegateTool {
             format!("[Context]\n{context}\n\n[Task]\n{prompt}")
         };
 
-        let temperature = agent_config.temperature.unwrap_or(0.7);
+        // Sub-agents only get the shared scratchpad tools, never the parent's
+        // full registry.
+        let sub_tools: Vec<Box<dyn Tool>> = vec![
+            Box::new(ScratchSetTool::new(self.scratchpad.clone())),
+            Box::new(ScratchGetTool::new(self.scratchpad.clone())),
+        ];
+        let mut system_prompt = agent_config.system_prompt.clone().unwrap_or_default();
+        if !provider.supports_native_tools() {
+            system_prompt.push_str(&crate::agent::loop_::build_tool_instructions(&sub_tools));
+        }
+        let mut history = vec![
+            ChatMessage::system(system_prompt),
+            ChatMessage::user(full_prompt),
+        ];
 
-        // Wrap the provider call in a timeout to prevent indefinite blocking
+        // Wrap the sub-agent run in a timeout to prevent indefinite blocking
         let result = tokio::time::timeout(
             Duration::from_secs(DELEGATE_TIMEOUT_SECS),
-            provider.chat_with_system(
-                agent_config.system_prompt.as_deref(),
-                &full_prompt,
-                &agent_config.model,
+            crate::agent::loop_::agent_turn_outcome(
+                provider.as_ref(),
+                &mut history,
+                &sub_tools,
+                &NoopObserver,
+                &agent_config.provider,
+                model,
                 temperature,
+                true,
+                DELEGATE_MAX_TOOL_ITERATIONS,
             ),
         )
         .await;
@@ -235,13 +435,28 @@ impl Tool for DelegateTool {
                     error: Some(format!(
                         "Agent '{agent_name}' timed out after {DELEGATE_TIMEOUT_SECS}s"
                     )),
+                    metadata: None,
+                    content_type: None,
                 });
             }
         };
 
         match result {
-            Ok(response) => {
-                let mut rendered = response;
+            Ok(outcome) => {
+                let mut rendered = outcome.text;
+                if outcome.truncated {
+                    // The parent can't reply "continue" to a sub-agent, so
+                    // hand back what it has and say it's incomplete.
+                    let note = format!(
+                        "[Stopped after {} tool iterations without a final answer; the result may be incomplete]",
+                        outcome.max_iterations
+                    );
+                    rendered = if rendered.trim().is_empty() {
+                        note
+                    } else {
+                        format!("{rendered}\n\n{note}")
+                    };
+                }
                 if rendered.trim().is_empty() {
                     rendered = "[Empty response]".to_string();
                 }
@@ -251,15 +466,18 @@ impl Tool for DelegateTool {
                     output: format!(
                         "[Agent '{agent_name}' ({provider}/{model})]\n{rendered}",
                         provider = agent_config.provider,
-                        model = agent_config.model
                     ),
                     error: None,
+                    metadata: None,
+                    content_type: None,
                 })
             }
             Err(e) => Ok(ToolResult {
                 success: false,
                 output: String::new(),
                 error: Some(format!("Agent '{agent_name}' failed: {e}",)),
+                metadata: None,
+                content_type: None,
             }),
         }
     }
@@ -285,6 +503,7 @@ mod tests {
                 api_key: None,
                 temperature: Some(0.3),
                 max_depth: 3,
+                allowed_models: Vec::new(),
             },
         );
         agents.insert(
@@ -296,6 +515,7 @@ mod tests {
                 api_key: Some("delegate-test-credential".to_string()),
                 temperature: None,
                 max_depth: 2,
+                allowed_models: Vec::new(),
             },
         );
         agents
@@ -403,6 +623,7 @@ mod tests {
                 api_key: None,
                 temperature: None,
                 max_depth: 3,
+                allowed_models: Vec::new(),
             },
         );
         let tool = DelegateTool::new(agents, None, test_security());
@@ -414,6 +635,114 @@ mod tests {
         assert!(result.error.unwrap().contains("Failed to create provider"));
     }
 
+    fn mock_agent(allowed_models: Vec<String>) -> HashMap<String, DelegateAgentConfig> {
+        HashMap::from([(
+            "tester".to_string(),
+            DelegateAgentConfig {
+                provider: "mock".to_string(),
+                model: "base-model".to_string(),
+                system_prompt: None,
+                api_key: None,
+                temperature: Some(0.9),
+                max_depth: 3,
+                allowed_models,
+            },
+        )])
+    }
+
+    #[tokio::test]
+    async fn model_and_temperature_overrides_apply_to_one_call() {
+        let agents = mock_agent(vec!["fast-model".to_string()]);
+        let tool = DelegateTool::new(agents, None, test_security());
+
+        let result = tool
+            .execute(json!({
+                "agent": "tester",
+                "prompt": "ping",
+                "model": "fast-model",
+                "temperature": 0
+            }))
+            .await
+            .unwrap();
+        assert!(result.success, "{:?}", result.error);
+        assert!(result
+            .output
+            .starts_with("[Agent 'tester' (mock/fast-model)]"));
+
+        // The stored agent config is untouched by the override.
+        let result = tool
+            .execute(json!({"agent": "tester", "prompt": "ping"}))
+            .await
+            .unwrap();
+        assert!(result
+            .output
+            .starts_with("[Agent 'tester' (mock/base-model)]"));
+        assert_eq!(tool.agents["tester"].temperature, Some(0.9));
+    }
+
+    #[tokio::test]
+    async fn check_agent_reports_usable_and_broken_agents() {
+        let mut agent = mock_agent(vec![]).remove("tester").unwrap();
+        let options = providers::ProviderRuntimeOptions::default();
+        check_agent(&agent, None, &options, true).await.unwrap();
+
+        agent.temperature = Some(5.0);
+        let err = check_agent(&agent, None, &options, false)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("temperature"));
+
+        agent.temperature = None;
+        agent.provider = "totally-invalid-provider".to_string();
+        let err = check_agent(&agent, None, &options, false)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("failed to create provider"));
+
+        agent.provider = "mock".to_string();
+        agent.model = "  ".to_string();
+        let err = check_agent(&agent, None, &options, false)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("no model"));
+    }
+
+    #[tokio::test]
+    async fn model_override_outside_allowlist_rejected() {
+        let tool = DelegateTool::new(
+            mock_agent(vec!["fast-model".to_string()]),
+            None,
+            test_security(),
+        );
+        let result = tool
+            .execute(json!({"agent": "tester", "prompt": "ping", "model": "pricey-model"}))
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("not allowed"));
+
+        // Without an allowlist any model may be requested.
+        let tool = DelegateTool::new(mock_agent(Vec::new()), None, test_security());
+        let result = tool
+            .execute(json!({"agent": "tester", "prompt": "ping", "model": "pricey-model"}))
+            .await
+            .unwrap();
+        assert!(result.success, "{:?}", result.error);
+    }
+
+    #[tokio::test]
+    async fn invalid_temperature_override_rejected() {
+        let tool = DelegateTool::new(mock_agent(Vec::new()), None, test_security());
+        for temperature in [json!(-0.5), json!(3.0), json!("hot")] {
+            let result = tool
+                .execute(json!({"agent": "tester", "prompt": "ping", "temperature": temperature}))
+                .await
+                .unwrap();
+            assert!(!result.success);
+            assert!(result.error.unwrap().contains("'temperature' must be"));
+        }
+    }
+
     #[tokio::test]
     async fn blank_agent_rejected() {
         let tool = DelegateTool::new(sample_agents(), None, test_security());
@@ -506,6 +835,7 @@ mod tests {
                 api_key: None,
                 temperature: None,
                 max_depth: 3,
+                allowed_models: Vec::new(),
             },
         );
         let tool = DelegateTool::new(agents, None, test_security());
@@ -538,6 +868,7 @@ mod tests {
                 api_key: None,
                 temperature: None,
                 max_depth: 3,
+                allowed_models: Vec::new(),
             },
         );
         let tool = DelegateTool::new(agents, None, test_security());
@@ -558,6 +889,18 @@ mod tests {
             .contains("Failed to create provider"));
     }
 
+    #[test]
+    fn scratchpad_is_shared_with_parent_handle() {
+        let pad = new_scratchpad();
+        let tool =
+            DelegateTool::new(sample_agents(), None, test_security()).with_scratchpad(pad.clone());
+        pad.lock().insert("findings".into(), "use v2".into());
+        assert_eq!(
+            tool.scratchpad().lock().get("findings").map(String::as_str),
+            Some("use v2")
+        );
+    }
+
     #[test]
     fn delegate_depth_construction() {
         let tool = DelegateTool::with_depth(sample_agents(), None, test_security(), 5);
@@ -2,9 +2,14 @@ use super::traits::{Tool, ToolResult};
 use crate::security::SecurityPolicy;
 use async_trait::async_trait;
 use serde_json::json;
+use std::fmt::Write;
+use std::path::Path;
 use std::sync::Arc;
+use tokio::io::{AsyncBufReadExt, AsyncReadExt};
 
 const MAX_FILE_SIZE_BYTES: u64 = 10 * 1024 * 1024;
+/// Output cap for line-range reads that don't pass `max_bytes`.
+const DEFAULT_RANGE_MAX_BYTES: usize = 256 * 1024;
 
 /// Read file contents with path sandboxing
 pub struct FileReadTool {
@@ -15,6 +20,106 @@ impl FileReadTool {
     pub fn new(security: Arc<SecurityPolicy>) -> Self {
         Self { security }
     }
+
+    /// Optional positive integer argument.
+    fn positive_arg(args: &serde_json::Value, name: &str) -> Result<Option<u64>, String> {
+        match args.get(name) {
+            None | Some(serde_json::Value::Null) => Ok(None),
+            Some(value) => match value.as_u64() {
+                Some(n) if n > 0 => Ok(Some(n)),
+                _ => Err(format!("'{name}' must be a positive integer")),
+            },
+        }
+    }
+}
+
+/// Lines `start..=end` (1-based) of `path`, each prefixed with its line
+/// number. Streams the file, so it stops reading after `end` or once the
+/// output passes `max_output` bytes; a single line is never buffered beyond
+/// that either, so files without newlines stay bounded. Invalid UTF-8 is
+/// replaced. Also returns the number of lines read, which is the file's line
+/// count when `start` was past the end.
+async fn read_line_range(
+    path: &Path,
+    start: u64,
+    end: u64,
+    max_output: usize,
+) -> std::io::Result<(String, u64)> {
+    let file = tokio::fs::File::open(path).await?;
+    let mut reader = tokio::io::BufReader::new(file);
+    let mut output = String::new();
+    let mut line = Vec::new();
+    let mut line_no = 0u64;
+    while line_no < end && output.len() <= max_output {
+        line.clear();
+        let keep = line_no + 1 >= start;
+        let mut read_any = false;
+        loop {
+            let buf = reader.fill_buf().await?;
+            if buf.is_empty() {
+                break;
+            }
+            read_any = true;
+            let newline = buf.iter().position(|&b| b == b'\n');
+            let chunk = &buf[..newline.unwrap_or(buf.len())];
+            if keep {
+                let room = (max_output + 1).saturating_sub(line.len());
+                line.extend_from_slice(&chunk[..chunk.len().min(room)]);
+            }
+            let used = chunk.len() + usize::from(newline.is_some());
+            reader.consume(used);
+            if newline.is_some() {
+                break;
+            }
+        }
+        if !read_any {
+            break;
+        }
+        line_no += 1;
+        if keep {
+            if line.last() == Some(&b'\r') {
+                line.pop();
+            }
+            let _ = writeln!(output, "{line_no:>6}\t{}", String::from_utf8_lossy(&line));
+        }
+    }
+    Ok((output, line_no))
+}
+
+/// Up to `max_bytes` from the start of `path`, cut back to a UTF-8 boundary.
+async fn read_prefix(path: &Path, max_bytes: u64) -> std::io::Result<String> {
+    let file = tokio::fs::File::open(path).await?;
+    let mut buf = Vec::new();
+    file.take(max_bytes).read_to_end(&mut buf).await?;
+    match String::from_utf8(buf) {
+        Ok(text) => Ok(text),
+        Err(e) => {
+            let valid = e.utf8_error().valid_up_to();
+            // Only a character split by the cut is tolerated.
+            if e.utf8_error().error_len().is_some() {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    "stream did not contain valid UTF-8",
+                ));
+            }
+            let mut bytes = e.into_bytes();
+            bytes.truncate(valid);
+            Ok(String::from_utf8(bytes).unwrap_or_default())
+        }
+    }
+}
+
+/// Cut `text` to at most `max_bytes` on a char boundary; true if anything was cut.
+fn truncate_to_bytes(text: &mut String, max_bytes: usize) -> bool {
+    if text.len() <= max_bytes {
+        return false;
+    }
+    let mut end = max_bytes;
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    text.truncate(end);
+    true
 }
 
 #[async_trait]
@@ -24,7 +129,11 @@ impl Tool for FileReadTool {
     }
 
     fn description(&self) -> &str {
-        "Read the contents of a file in the workspace"
+        "Read the contents of a file in the workspace. For large files, pass start_line/end_line to read only that range (returned with line numbers) or max_bytes to cap the output."
+    }
+
+    fn category(&self) -> &str {
+        "filesystem"
     }
 
     fn parameters_schema(&self) -> serde_json::Value {
@@ -34,6 +143,21 @@ impl Tool for FileReadTool {
                 "path": {
                     "type": "string",
                     "description": "Relative path to the file within the workspace"
+                },
+                "start_line": {
+                    "type": "integer",
+                    "minimum": 1,
+                    "description": "First line to return (1-based). Output lines are prefixed with their numbers."
+                },
+                "end_line": {
+                    "type": "integer",
+                    "minimum": 1,
+                    "description": "Last line to return, inclusive (default: end of file)"
+                },
+                "max_bytes": {
+                    "type": "integer",
+                    "minimum": 1,
+                    "description": "Return at most this many bytes of output (line ranges default to 262144)"
                 }
             },
             "required": ["path"]
@@ -46,32 +170,45 @@ impl Tool for FileReadTool {
             .and_then(|v| v.as_str())
             .ok_or_else(|| anyhow::anyhow!("Missing 'path' parameter"))?;
 
+        let (start_line, end_line, max_bytes) = match (
+            Self::positive_arg(&args, "start_line"),
+            Self::positive_arg(&args, "end_line"),
+            Self::positive_arg(&args, "max_bytes"),
+        ) {
+            (Ok(start), Ok(end), Ok(max)) => (start, end, max),
+            (Err(e), _, _) | (_, Err(e), _) | (_, _, Err(e)) => return Ok(ToolResult::err(e)),
+        };
+        if let (Some(start), Some(end)) = (start_line, end_line) {
+            if end < start {
+                return Ok(ToolResult::err(format!(
+                    "end_line ({end}) is before start_line ({start})"
+                )));
+            }
+        }
+        let line_range = (start_line.is_some() || end_line.is_some())
+            .then(|| (start_line.unwrap_or(1), end_line.unwrap_or(u64::MAX)));
+        let max_bytes = max_bytes.map(|max| max.min(MAX_FILE_SIZE_BYTES));
+
         if self.security.is_rate_limited() {
-            return Ok(ToolResult {
-                success: false,
-                output: String::new(),
-                error: Some("Rate limit exceeded: too many actions in the last hour".into()),
-            });
+            return Ok(ToolResult::err(
+                "Rate limit exceeded: too many actions in the last hour",
+            ));
         }
 
         // Security check: validate path is within workspace
         if !self.security.is_path_allowed(path) {
-            return Ok(ToolResult {
-                success: false,
-                output: String::new(),
-                error: Some(format!("Path not allowed by security policy: {path}")),
-            });
+            return Ok(ToolResult::err(format!(
+                "Path not allowed by security policy: {path}"
+            )));
         }
 
         // Record action BEFORE canonicalization so that every non-trivially-rejected
         // request consumes rate limit budget. This prevents attackers from probing
         // path existence (via canonicalize errors) without rate limit cost.
         if !self.security.record_action() {
-            return Ok(ToolResult {
-                success: false,
-                output: String::new(),
-                error: Some("Rate limit exceeded: action budget exhausted".into()),
-            });
+            return Ok(ToolResult::err(
+                "Rate limit exceeded: action budget exhausted",
+            ));
         }
 
         let full_path = self.security.workspace_dir.join(path);
@@ -80,22 +217,59 @@ impl Tool for FileReadTool {
         let resolved_path = match tokio::fs::canonicalize(&full_path).await {
             Ok(p) => p,
             Err(e) => {
-                return Ok(ToolResult {
-                    success: false,
-                    output: String::new(),
-                    error: Some(format!("Failed to resolve file path: {e}")),
-                });
+                return Ok(ToolResult::err(format!("Failed to resolve file path: {e}")));
             }
         };
 
         if !self.security.is_resolved_path_allowed(&resolved_path) {
-            return Ok(ToolResult {
-                success: false,
-                output: String::new(),
-                error: Some(format!(
-                    "Resolved path escapes workspace: {}",
-                    resolved_path.display()
-                )),
+            return Ok(ToolResult::err(format!(
+                "Resolved path escapes workspace: {}",
+                resolved_path.display()
+            )));
+        }
+
+        // Partial reads stream only what they return, so the whole-file size
+        // limit doesn't apply to them; their output is capped instead.
+        if let Some((start, end)) = line_range {
+            let max = max_bytes.map_or(DEFAULT_RANGE_MAX_BYTES, |m| {
+                usize::try_from(m).unwrap_or(DEFAULT_RANGE_MAX_BYTES)
+            });
+            return Ok(
+                match read_line_range(&resolved_path, start, end, max).await {
+                    Ok((output, line_count)) if output.is_empty() && line_count < start => {
+                        ToolResult::err(format!(
+                            "start_line {start} is past the end of the file ({line_count} lines)"
+                        ))
+                    }
+                    Ok((mut output, _)) => {
+                        if truncate_to_bytes(&mut output, max) {
+                            if max_bytes.is_some() {
+                                output.push_str("\n[... truncated at max_bytes]");
+                            } else {
+                                let _ = write!(
+                                output,
+                                "\n[... truncated at {DEFAULT_RANGE_MAX_BYTES} bytes; narrow the line range or pass max_bytes]"
+                            );
+                            }
+                        }
+                        ToolResult::ok(output)
+                    }
+                    Err(e) => ToolResult::err(format!("Failed to read file: {e}")),
+                },
+            );
+        }
+        if let Some(max) = max_bytes {
+            return Ok(match read_prefix(&resolved_path, max).await {
+                Ok(mut output) => {
+                    let file_len = tokio::fs::metadata(&resolved_path)
+                        .await
+                        .map_or(0, |meta| meta.len());
+                    if file_len > max {
+                        output.push_str("\n[... truncated at max_bytes]");
+                    }
+                    ToolResult::ok(output)
+                }
+                Err(e) => ToolResult::err(format!("Failed to read file: {e}")),
             });
         }
 
@@ -103,36 +277,22 @@ impl Tool for FileReadTool {
         match tokio::fs::metadata(&resolved_path).await {
             Ok(meta) => {
                 if meta.len() > MAX_FILE_SIZE_BYTES {
-                    return Ok(ToolResult {
-                        success: false,
-                        output: String::new(),
-                        error: Some(format!(
-                            "File too large: {} bytes (limit: {MAX_FILE_SIZE_BYTES} bytes)",
+                    return Ok(ToolResult::err(format!(
+                            "File too large: {} bytes (limit: {MAX_FILE_SIZE_BYTES} bytes); use start_line/end_line or max_bytes to read part of it",
                             meta.len()
-                        )),
-                    });
+                        )));
                 }
             }
             Err(e) => {
-                return Ok(ToolResult {
-                    success: false,
-                    output: String::new(),
-                    error: Some(format!("Failed to read file metadata: {e}")),
-                });
+                return Ok(ToolResult::err(format!(
+                    "Failed to read file metadata: {e}"
+                )));
             }
         }
 
         match tokio::fs::read_to_string(&resolved_path).await {
-            Ok(contents) => Ok(ToolResult {
-                success: true,
-                output: contents,
-                error: None,
-            }),
-            Err(e) => Ok(ToolResult {
-                success: false,
-                output: String::new(),
-                error: Some(format!("Failed to read file: {e}")),
-            }),
+            Ok(contents) => Ok(ToolResult::ok(contents)),
+            Err(e) => Ok(ToolResult::err(format!("Failed to read file: {e}"))),
         }
     }
 }
@@ -408,4 +568,89 @@ mod tests {
 
         let _ = tokio::fs::remove_dir_all(&dir).await;
     }
+
+    #[tokio::test]
+    async fn file_read_returns_numbered_line_range_and_caps_bytes() {
+        let tmp = tempfile::tempdir().unwrap();
+        let body: String = (1..=20).map(|n| format!("line {n}\n")).collect();
+        tokio::fs::write(tmp.path().join("log.txt"), &body)
+            .await
+            .unwrap();
+        let tool = FileReadTool::new(test_security(tmp.path().to_path_buf()));
+
+        let result = tool
+            .execute(json!({"path": "log.txt", "start_line": 9, "end_line": 11}))
+            .await
+            .unwrap();
+        assert!(result.success);
+        assert_eq!(
+            result.output,
+            "     9\tline 9\n    10\tline 10\n    11\tline 11\n"
+        );
+
+        let result = tool
+            .execute(json!({"path": "log.txt", "start_line": 19}))
+            .await
+            .unwrap();
+        assert_eq!(result.output, "    19\tline 19\n    20\tline 20\n");
+
+        let result = tool
+            .execute(json!({"path": "log.txt", "max_bytes": 14}))
+            .await
+            .unwrap();
+        assert_eq!(
+            result.output,
+            "line 1\nline 2\n\n[... truncated at max_bytes]"
+        );
+
+        let result = tool
+            .execute(json!({"path": "log.txt", "start_line": 2, "max_bytes": 10}))
+            .await
+            .unwrap();
+        assert_eq!(result.output, "     2\tlin\n[... truncated at max_bytes]");
+
+        let result = tool
+            .execute(json!({"path": "log.txt", "start_line": 30}))
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result
+            .error
+            .unwrap()
+            .contains("past the end of the file (20 lines)"));
+
+        let result = tool
+            .execute(json!({"path": "log.txt", "start_line": 5, "end_line": 2}))
+            .await
+            .unwrap();
+        assert!(!result.success);
+
+        let result = tool
+            .execute(json!({"path": "log.txt", "start_line": 0}))
+            .await
+            .unwrap();
+        assert!(!result.success);
+    }
+
+    #[tokio::test]
+    async fn file_read_line_range_caps_output_without_newlines() {
+        let tmp = tempfile::tempdir().unwrap();
+        let mut blob = vec![b'a'; 3 * DEFAULT_RANGE_MAX_BYTES];
+        blob[10] = 0xff;
+        tokio::fs::write(tmp.path().join("blob.bin"), &blob)
+            .await
+            .unwrap();
+        let tool = FileReadTool::new(test_security(tmp.path().to_path_buf()));
+
+        let result = tool
+            .execute(json!({"path": "blob.bin", "start_line": 1}))
+            .await
+            .unwrap();
+        assert!(result.success);
+        assert!(result.output.starts_with("     1\taaaaaaaaaa\u{fffd}a"));
+        assert!(result.output.len() < DEFAULT_RANGE_MAX_BYTES + 100);
+        assert!(result
+            .output
+            .ends_with("bytes; narrow the line range or pass max_bytes]"));
+    }
 }
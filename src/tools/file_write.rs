@@ -25,6 +25,10 @@ impl Tool for FileWriteTool {
         "Write contents to a file in the workspace"
     }
 
+    fn category(&self) -> &str {
+        "filesystem"
+    }
+
     fn parameters_schema(&self) -> serde_json::Value {
         json!({
             "type": "object",
@@ -58,6 +62,8 @@ impl Tool for FileWriteTool {
                 success: false,
                 output: String::new(),
                 error: Some("Action blocked: autonomy is read-only".into()),
+                metadata: None,
+                content_type: None,
             });
         }
 
@@ -66,6 +72,8 @@ impl Tool for FileWriteTool {
                 success: false,
                 output: String::new(),
                 error: Some("Rate limit exceeded: too many actions in the last hour".into()),
+                metadata: None,
+                content_type: None,
             });
         }
 
@@ -75,6 +83,8 @@ impl Tool for FileWriteTool {
                 success: false,
                 output: String::new(),
                 error: Some(format!("Path not allowed by security policy: {path}")),
+                metadata: None,
+                content_type: None,
             });
         }
 
@@ -85,6 +95,8 @@ impl Tool for FileWriteTool {
                 success: false,
                 output: String::new(),
                 error: Some("Invalid path: missing parent directory".into()),
+                metadata: None,
+                content_type: None,
             });
         };
 
@@ -99,6 +111,8 @@ impl Tool for FileWriteTool {
                     success: false,
                     output: String::new(),
                     error: Some(format!("Failed to resolve file path: {e}")),
+                    metadata: None,
+                    content_type: None,
                 });
             }
         };
@@ -111,6 +125,8 @@ impl Tool for FileWriteTool {
                     "Resolved path escapes workspace: {}",
                     resolved_parent.display()
                 )),
+                metadata: None,
+                content_type: None,
             });
         }
 
@@ -119,6 +135,8 @@ impl Tool for FileWriteTool {
                 success: false,
                 output: String::new(),
                 error: Some("Invalid path: missing file name".into()),
+                metadata: None,
+                content_type: None,
             });
         };
 
@@ -134,6 +152,8 @@ impl Tool for FileWriteTool {
                         "Refusing to write through symlink: {}",
                         resolved_target.display()
                     )),
+                    metadata: None,
+                    content_type: None,
                 });
             }
         }
@@ -143,6 +163,8 @@ impl Tool for FileWriteTool {
                 success: false,
                 output: String::new(),
                 error: Some("Rate limit exceeded: action budget exhausted".into()),
+                metadata: None,
+                content_type: None,
             });
         }
 
@@ -151,11 +173,15 @@ impl Tool for FileWriteTool {
                 success: true,
                 output: format!("Written {} bytes to {path}", content.len()),
                 error: None,
+                metadata: Some(json!({ "bytes_written": content.len() })),
+                content_type: None,
             }),
             Err(e) => Ok(ToolResult {
                 success: false,
                 output: String::new(),
                 error: Some(format!("Failed to write file: {e}")),
+                metadata: None,
+                content_type: None,
             }),
         }
     }
@@ -217,6 +243,7 @@ mod tests {
             .unwrap();
         assert!(result.success);
         assert!(result.output.contains("8 bytes"));
+        assert_eq!(result.metadata.unwrap()["bytes_written"], 8);
 
         let content = tokio::fs::read_to_string(dir.join("out.txt"))
             .await
@@ -53,10 +53,57 @@ impl GitOperationsTool {
     fn requires_write_access(&self, operation: &str) -> bool {
         matches!(
             operation,
-            "commit" | "add" | "checkout" | "stash" | "reset" | "revert"
+            "commit" | "add" | "checkout" | "stash" | "reset" | "revert" | "push" | "clean"
         )
     }
 
+    /// Operations that publish or discard work; in supervised mode they need
+    /// `approved=true`, like medium/high-risk shell commands.
+    fn is_destructive(&self, operation: &str) -> bool {
+        matches!(operation, "push" | "reset" | "clean")
+    }
+
+    /// Validate a single remote/branch/revision argument: one token, no
+    /// leading dash (so it can't be read as an option).
+    fn validate_ref_arg(&self, value: &str, what: &str) -> anyhow::Result<String> {
+        let sanitized = self.sanitize_git_args(value)?;
+        match sanitized.as_slice() {
+            [single] if !single.starts_with('-') => Ok(single.clone()),
+            _ => anyhow::bail!("Invalid {what}: {value}"),
+        }
+    }
+
+    /// Validate a push remote: it must be a name listed by `git remote`, so a
+    /// URL can't send the repository to an arbitrary host.
+    async fn validate_push_remote(&self, value: &str) -> anyhow::Result<String> {
+        let remote = self.validate_ref_arg(value, "remote")?;
+        let remotes = self.run_git_command(&["remote"]).await?;
+        if !remotes.lines().any(|name| name.trim() == remote) {
+            anyhow::bail!(
+                "Unknown remote '{remote}': push only goes to remotes listed by `git remote`"
+            );
+        }
+        Ok(remote)
+    }
+
+    /// Validate a push branch and return it as an explicit
+    /// `refs/heads/<name>:refs/heads/<name>` refspec. A leading `+` (force)
+    /// or a `:` (delete / rename) in the name is rejected.
+    async fn push_refspec(&self, value: &str) -> anyhow::Result<(String, String)> {
+        let branch = self.validate_ref_arg(value, "branch")?;
+        if branch.contains('+') || branch.contains(':') {
+            anyhow::bail!("Invalid branch: {branch} ('+' and ':' are not allowed)");
+        }
+        let name = self
+            .run_git_command(&["check-ref-format", "--branch", &branch])
+            .await
+            .map_err(|_| anyhow::anyhow!("Invalid branch: {branch}"))?
+            .trim()
+            .to_string();
+        let refspec = format!("refs/heads/{name}:refs/heads/{name}");
+        Ok((name, refspec))
+    }
+
     /// Check if an operation is read-only
     fn is_read_only(&self, operation: &str) -> bool {
         matches!(
@@ -69,6 +116,8 @@ impl GitOperationsTool {
         let output = tokio::process::Command::new("git")
             .args(args)
             .current_dir(&self.workspace_dir)
+            // Never block on a credential prompt (e.g. `push` without auth).
+            .env("GIT_TERMINAL_PROMPT", "0")
             .output()
             .await?;
 
@@ -128,6 +177,8 @@ impl GitOperationsTool {
             success: true,
             output: serde_json::to_string_pretty(&result).unwrap_or_default(),
             error: None,
+            metadata: None,
+            content_type: None,
         })
     }
 
@@ -207,6 +258,8 @@ impl GitOperationsTool {
             success: true,
             output: serde_json::to_string_pretty(&result).unwrap_or_default(),
             error: None,
+            metadata: None,
+            content_type: None,
         })
     }
 
@@ -244,6 +297,8 @@ impl GitOperationsTool {
             output: serde_json::to_string_pretty(&json!({ "commits": commits }))
                 .unwrap_or_default(),
             error: None,
+            metadata: None,
+            content_type: None,
         })
     }
 
@@ -276,6 +331,8 @@ impl GitOperationsTool {
             }))
             .unwrap_or_default(),
             error: None,
+            metadata: None,
+            content_type: None,
         })
     }
 
@@ -315,11 +372,15 @@ impl GitOperationsTool {
                 success: true,
                 output: format!("Committed: {message}"),
                 error: None,
+                metadata: None,
+                content_type: None,
             }),
             Err(e) => Ok(ToolResult {
                 success: false,
                 output: String::new(),
                 error: Some(format!("Commit failed: {e}")),
+                metadata: None,
+                content_type: None,
             }),
         }
     }
@@ -340,11 +401,15 @@ impl GitOperationsTool {
                 success: true,
                 output: format!("Staged: {paths}"),
                 error: None,
+                metadata: None,
+                content_type: None,
             }),
             Err(e) => Ok(ToolResult {
                 success: false,
                 output: String::new(),
                 error: Some(format!("Add failed: {e}")),
+                metadata: None,
+                content_type: None,
             }),
         }
     }
@@ -376,11 +441,15 @@ impl GitOperationsTool {
                 success: true,
                 output: format!("Switched to branch: {branch_name}"),
                 error: None,
+                metadata: None,
+                content_type: None,
             }),
             Err(e) => Ok(ToolResult {
                 success: false,
                 output: String::new(),
                 error: Some(format!("Checkout failed: {e}")),
+                metadata: None,
+                content_type: None,
             }),
         }
     }
@@ -413,11 +482,154 @@ impl GitOperationsTool {
                 success: true,
                 output: out,
                 error: None,
+                metadata: None,
+                content_type: None,
             }),
             Err(e) => Ok(ToolResult {
                 success: false,
                 output: String::new(),
                 error: Some(format!("Stash {action} failed: {e}")),
+                metadata: None,
+                content_type: None,
+            }),
+        }
+    }
+
+    async fn git_push(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
+        let remote = self
+            .validate_push_remote(
+                args.get("remote")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("origin"),
+            )
+            .await?;
+        let branch = match args.get("branch").and_then(|v| v.as_str()) {
+            Some(b) => b.to_string(),
+            None => self
+                .run_git_command(&["rev-parse", "--abbrev-ref", "HEAD"])
+                .await?
+                .trim()
+                .to_string(),
+        };
+        let (branch, refspec) = self.push_refspec(&branch).await?;
+        let set_upstream = args
+            .get("set_upstream")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+
+        // Force pushes and remote deletes are deliberately not offered: the
+        // refspec is always `refs/heads/<name>:refs/heads/<name>`.
+        let mut git_args = vec!["push"];
+        if set_upstream {
+            git_args.push("--set-upstream");
+        }
+        git_args.push(&remote);
+        git_args.push(&refspec);
+
+        match self.run_git_command(&git_args).await {
+            Ok(_) => Ok(ToolResult {
+                success: true,
+                output: serde_json::to_string_pretty(&json!({
+                    "remote": remote,
+                    "branch": branch,
+                    "pushed": true
+                }))
+                .unwrap_or_default(),
+                error: None,
+                metadata: None,
+                content_type: None,
+            }),
+            Err(e) => Ok(ToolResult {
+                success: false,
+                output: String::new(),
+                error: Some(format!("Push failed: {e}")),
+                metadata: None,
+                content_type: None,
+            }),
+        }
+    }
+
+    async fn git_reset(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
+        let mode = args.get("mode").and_then(|v| v.as_str()).unwrap_or("mixed");
+        if !matches!(mode, "soft" | "mixed" | "hard") {
+            anyhow::bail!("Unknown reset mode: {mode}. Use: soft, mixed, hard");
+        }
+        let target = self.validate_ref_arg(
+            args.get("target")
+                .and_then(|v| v.as_str())
+                .unwrap_or("HEAD"),
+            "reset target",
+        )?;
+
+        let mode_flag = format!("--{mode}");
+        match self
+            .run_git_command(&["reset", &mode_flag, &target, "--"])
+            .await
+        {
+            Ok(_) => {
+                let head = self
+                    .run_git_command(&["rev-parse", "HEAD"])
+                    .await
+                    .map(|h| h.trim().to_string())
+                    .unwrap_or_default();
+                Ok(ToolResult {
+                    success: true,
+                    output: serde_json::to_string_pretty(&json!({
+                        "mode": mode,
+                        "target": target,
+                        "head": head
+                    }))
+                    .unwrap_or_default(),
+                    error: None,
+                    metadata: None,
+                    content_type: None,
+                })
+            }
+            Err(e) => Ok(ToolResult {
+                success: false,
+                output: String::new(),
+                error: Some(format!("Reset failed: {e}")),
+                metadata: None,
+                content_type: None,
+            }),
+        }
+    }
+
+    async fn git_clean(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
+        let dry_run = args
+            .get("dry_run")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+
+        // Untracked files and directories only; ignored files are kept.
+        let flag = if dry_run { "-n" } else { "-f" };
+        match self.run_git_command(&["clean", flag, "-d"]).await {
+            Ok(out) => {
+                let paths: Vec<&str> = out
+                    .lines()
+                    .filter_map(|l| {
+                        l.strip_prefix("Would remove ")
+                            .or_else(|| l.strip_prefix("Removing "))
+                    })
+                    .collect();
+                Ok(ToolResult {
+                    success: true,
+                    output: serde_json::to_string_pretty(&json!({
+                        "dry_run": dry_run,
+                        "removed": paths
+                    }))
+                    .unwrap_or_default(),
+                    error: None,
+                    metadata: None,
+                    content_type: None,
+                })
+            }
+            Err(e) => Ok(ToolResult {
+                success: false,
+                output: String::new(),
+                error: Some(format!("Clean failed: {e}")),
+                metadata: None,
+                content_type: None,
             }),
         }
     }
@@ -430,7 +642,11 @@ impl Tool for GitOperationsTool {
     }
 
     fn description(&self) -> &str {
-        "Perform structured Git operations (status, diff, log, branch, commit, add, checkout, stash). Provides parsed JSON output and integrates with security policy for autonomy controls."
+        "Perform structured Git operations (status, diff, log, branch, commit, add, checkout, stash, push, reset, clean). Provides parsed JSON output and integrates with security policy for autonomy controls; push, reset and clean need approved=true in supervised mode."
+    }
+
+    fn category(&self) -> &str {
+        "filesystem"
     }
 
     fn parameters_schema(&self) -> serde_json::Value {
@@ -439,7 +655,7 @@ impl Tool for GitOperationsTool {
             "properties": {
                 "operation": {
                     "type": "string",
-                    "enum": ["status", "diff", "log", "branch", "commit", "add", "checkout", "stash"],
+                    "enum": ["status", "diff", "log", "branch", "commit", "add", "checkout", "stash", "push", "reset", "clean"],
                     "description": "Git operation to perform"
                 },
                 "message": {
@@ -452,7 +668,28 @@ impl Tool for GitOperationsTool {
                 },
                 "branch": {
                     "type": "string",
-                    "description": "Branch name (for 'checkout' operation)"
+                    "description": "Branch name (for 'checkout'; for 'push', default: current branch)"
+                },
+                "remote": {
+                    "type": "string",
+                    "description": "Remote name as listed by `git remote` (for 'push' operation, default: 'origin')"
+                },
+                "set_upstream": {
+                    "type": "boolean",
+                    "description": "Set the pushed branch's upstream (for 'push' operation)"
+                },
+                "mode": {
+                    "type": "string",
+                    "enum": ["soft", "mixed", "hard"],
+                    "description": "Reset mode (for 'reset' operation, default: 'mixed')"
+                },
+                "target": {
+                    "type": "string",
+                    "description": "Commit to reset to (for 'reset' operation, default: 'HEAD')"
+                },
+                "dry_run": {
+                    "type": "boolean",
+                    "description": "Only list what would be removed (for 'clean' operation)"
                 },
                 "files": {
                     "type": "string",
@@ -474,6 +711,11 @@ impl Tool for GitOperationsTool {
                 "index": {
                     "type": "integer",
                     "description": "Stash index (for 'stash' with 'drop' action)"
+                },
+                "approved": {
+                    "type": "boolean",
+                    "description": "Set true to explicitly approve push, reset or clean in supervised mode",
+                    "default": false
                 }
             },
             "required": ["operation"]
@@ -488,6 +730,8 @@ impl Tool for GitOperationsTool {
                     success: false,
                     output: String::new(),
                     error: Some("Missing 'operation' parameter".into()),
+                    metadata: None,
+                    content_type: None,
                 });
             }
         };
@@ -510,6 +754,8 @@ impl Tool for GitOperationsTool {
                     success: false,
                     output: String::new(),
                     error: Some("Not in a git repository".into()),
+                    metadata: None,
+                    content_type: None,
                 });
             }
         }
@@ -523,6 +769,8 @@ impl Tool for GitOperationsTool {
                     error: Some(
                         "Action blocked: git write operations require higher autonomy level".into(),
                     ),
+                    metadata: None,
+                    content_type: None,
                 });
             }
 
@@ -532,18 +780,46 @@ impl Tool for GitOperationsTool {
                         success: false,
                         output: String::new(),
                         error: Some("Action blocked: read-only mode".into()),
+                        metadata: None,
+                        content_type: None,
                     });
                 }
                 AutonomyLevel::Supervised | AutonomyLevel::Full => {}
             }
         }
 
+        let approved = args
+            .get("approved")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+        let dry_run = args
+            .get("dry_run")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+        if self.is_destructive(operation)
+            && !(operation == "clean" && dry_run)
+            && self.security.autonomy == AutonomyLevel::Supervised
+            && !approved
+        {
+            return Ok(ToolResult {
+                success: false,
+                output: String::new(),
+                error: Some(format!(
+                    "git {operation} requires explicit approval (approved=true)"
+                )),
+                metadata: None,
+                content_type: None,
+            });
+        }
+
         // Record action for rate limiting
         if !self.security.record_action() {
             return Ok(ToolResult {
                 success: false,
                 output: String::new(),
                 error: Some("Action blocked: rate limit exceeded".into()),
+                metadata: None,
+                content_type: None,
             });
         }
 
@@ -557,10 +833,15 @@ impl Tool for GitOperationsTool {
             "add" => self.git_add(args).await,
             "checkout" => self.git_checkout(args).await,
             "stash" => self.git_stash(args).await,
+            "push" => self.git_push(args).await,
+            "reset" => self.git_reset(args).await,
+            "clean" => self.git_clean(args).await,
             _ => Ok(ToolResult {
                 success: false,
                 output: String::new(),
                 error: Some(format!("Unknown operation: {operation}")),
+                metadata: None,
+                content_type: None,
             }),
         }
     }
@@ -794,7 +1075,7 @@ mod tests {
 
         let tool = test_tool(tmp.path());
 
-        let result = tool.execute(json!({"operation": "push"})).await.unwrap();
+        let result = tool.execute(json!({"operation": "rebase"})).await.unwrap();
         assert!(!result.success);
         assert!(result
             .error
@@ -803,6 +1084,111 @@ mod tests {
             .contains("Unknown operation"));
     }
 
+    #[test]
+    fn destructive_ops_are_write_gated() {
+        let tmp = TempDir::new().unwrap();
+        let tool = test_tool(tmp.path());
+
+        for op in ["push", "reset", "clean"] {
+            assert!(tool.is_destructive(op));
+            assert!(tool.requires_write_access(op));
+        }
+        assert!(!tool.is_destructive("commit"));
+        assert!(!tool.is_destructive("status"));
+    }
+
+    #[test]
+    fn validate_ref_arg_rejects_options_and_multiple_tokens() {
+        let tmp = TempDir::new().unwrap();
+        let tool = test_tool(tmp.path());
+
+        assert_eq!(tool.validate_ref_arg("origin", "remote").unwrap(), "origin");
+        assert!(tool.validate_ref_arg("--force", "remote").is_err());
+        assert!(tool.validate_ref_arg("origin main", "remote").is_err());
+        assert!(tool.validate_ref_arg("", "remote").is_err());
+    }
+
+    #[tokio::test]
+    async fn destructive_ops_require_approval_in_supervised_mode() {
+        let tmp = TempDir::new().unwrap();
+        std::process::Command::new("git")
+            .args(["init"])
+            .current_dir(tmp.path())
+            .output()
+            .unwrap();
+        std::fs::write(tmp.path().join("scratch.txt"), "temp").unwrap();
+
+        let tool = test_tool(tmp.path());
+        for op in ["push", "reset", "clean"] {
+            let result = tool.execute(json!({"operation": op})).await.unwrap();
+            assert!(!result.success);
+            assert!(result.error.unwrap_or_default().contains("approved=true"));
+        }
+
+        // Dry-run clean only lists files, so it needs no approval.
+        let result = tool
+            .execute(json!({"operation": "clean", "dry_run": true}))
+            .await
+            .unwrap();
+        assert!(result.success);
+        assert!(result.output.contains("scratch.txt"));
+        assert!(tmp.path().join("scratch.txt").exists());
+
+        let result = tool
+            .execute(json!({"operation": "clean", "approved": true}))
+            .await
+            .unwrap();
+        assert!(result.success, "{:?}", result.error);
+        assert!(!tmp.path().join("scratch.txt").exists());
+    }
+
+    #[tokio::test]
+    async fn push_rejects_force_delete_and_url_targets() {
+        let tmp = TempDir::new().unwrap();
+        std::process::Command::new("git")
+            .args(["init"])
+            .current_dir(tmp.path())
+            .output()
+            .unwrap();
+        std::process::Command::new("git")
+            .args(["remote", "add", "origin", "/nonexistent/remote.git"])
+            .current_dir(tmp.path())
+            .output()
+            .unwrap();
+        let tool = test_tool(tmp.path());
+
+        for branch in ["+main", ":main", "main:other"] {
+            let err = tool
+                .execute(json!({"operation": "push", "branch": branch, "approved": true}))
+                .await
+                .unwrap_err();
+            assert!(
+                err.to_string().contains("Invalid branch"),
+                "{branch}: {err}"
+            );
+        }
+
+        let err = tool
+            .execute(json!({
+                "operation": "push",
+                "remote": "https://attacker.example/repo.git",
+                "branch": "main",
+                "approved": true
+            }))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("Unknown remote"), "{err}");
+    }
+
+    #[tokio::test]
+    async fn push_refspec_names_the_branch_on_both_sides() {
+        let tmp = TempDir::new().unwrap();
+        let tool = test_tool(tmp.path());
+        let (name, refspec) = tool.push_refspec("feature/x").await.unwrap();
+        assert_eq!(name, "feature/x");
+        assert_eq!(refspec, "refs/heads/feature/x:refs/heads/feature/x");
+    }
+
     #[test]
     fn truncates_multibyte_commit_message_without_panicking() {
         let long = "🦀".repeat(2500);
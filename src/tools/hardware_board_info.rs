@@ -74,6 +74,10 @@ impl Tool for HardwareBoardInfoTool {
         "Return full board info (chip, architecture, memory map) for connected hardware. Use when: user asks for 'board info', 'what board do I have', 'connected hardware', 'chip info', 'what hardware', or 'memory map'."
     }
 
+    fn category(&self) -> &str {
+        "hardware"
+    }
+
     fn parameters_schema(&self) -> serde_json::Value {
         json!({
             "type": "object",
@@ -103,6 +107,8 @@ impl Tool for HardwareBoardInfoTool {
                     "No peripherals configured. Add boards to config.toml [peripherals.boards]."
                         .into(),
                 ),
+                metadata: None,
+                content_type: None,
             });
         }
 
@@ -121,6 +127,8 @@ impl Tool for HardwareBoardInfoTool {
                         success: true,
                         output: info,
                         error: None,
+                        metadata: None,
+                        content_type: None,
                     });
                 }
                 Err(e) => {
@@ -151,6 +159,8 @@ impl Tool for HardwareBoardInfoTool {
             success: true,
             output,
             error: None,
+            metadata: None,
+            content_type: None,
         })
     }
 }
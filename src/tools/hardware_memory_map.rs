@@ -60,6 +60,10 @@ impl Tool for HardwareMemoryMapTool {
         "Return the memory map (flash and RAM address ranges) for connected hardware. Use when: user asks for 'upper and lower memory addresses', 'memory map', 'address space', or 'readable addresses'. Returns flash/RAM ranges from datasheets."
     }
 
+    fn category(&self) -> &str {
+        "hardware"
+    }
+
     fn parameters_schema(&self) -> serde_json::Value {
         json!({
             "type": "object",
@@ -89,6 +93,8 @@ impl Tool for HardwareMemoryMapTool {
                     "No peripherals configured. Add boards to config.toml [peripherals.boards]."
                         .into(),
                 ),
+                metadata: None,
+                content_type: None,
             });
         }
 
@@ -139,6 +145,8 @@ impl Tool for HardwareMemoryMapTool {
             success: true,
             output,
             error: None,
+            metadata: None,
+            content_type: None,
         })
     }
 }
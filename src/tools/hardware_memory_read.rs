@@ -39,6 +39,10 @@ impl Tool for HardwareMemoryReadTool {
         "Read actual memory/register values from Nucleo via USB. Use when: user asks to 'read register values', 'read memory at address', 'dump memory', 'lower memory 0-126', or 'give address and value'. Returns hex dump. Requires Nucleo connected via USB and probe feature. Params: address (hex, e.g. 0x20000000 for RAM start), length (bytes, default 128)."
     }
 
+    fn category(&self) -> &str {
+        "hardware"
+    }
+
     fn parameters_schema(&self) -> serde_json::Value {
         json!({
             "type": "object",
@@ -67,7 +71,7 @@ impl Tool for HardwareMemoryReadTool {
                 error: Some(
                     "No peripherals configured. Add nucleo-f401re to config.toml [peripherals.boards]."
                         .into(),
-                ),
+                ), metadata: None, content_type: None,
             });
         }
 
@@ -87,6 +91,8 @@ impl Tool for HardwareMemoryReadTool {
                     "Memory read only supports nucleo-f401re, nucleo-f411re. Got: {}",
                     board
                 )),
+                metadata: None,
+                content_type: None,
             });
         }
 
@@ -109,6 +115,8 @@ impl Tool for HardwareMemoryReadTool {
                         success: true,
                         output,
                         error: None,
+                        metadata: None,
+                        content_type: None,
                     });
                 }
                 Err(e) => {
@@ -118,7 +126,7 @@ impl Tool for HardwareMemoryReadTool {
                         error: Some(format!(
                             "probe-rs read failed: {}. Ensure Nucleo is connected via USB and built with --features probe.",
                             e
-                        )),
+                        )), metadata: None, content_type: None,
                     });
                 }
             }
@@ -132,7 +140,7 @@ impl Tool for HardwareMemoryReadTool {
                 error: Some(
                     "Memory read requires probe feature. Build with: cargo build --features hardware,probe"
                         .into(),
-                ),
+                ), metadata: None, content_type: None,
             })
         }
     }
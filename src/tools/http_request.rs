@@ -1,10 +1,16 @@
 use super::traits::{Tool, ToolResult};
+use crate::security::secret_refs::{contains_secret_ref, resolve_secret_refs, ResolvedArgs};
 use crate::security::SecurityPolicy;
 use async_trait::async_trait;
 use serde_json::json;
+use std::collections::HashMap;
+use std::path::Path;
 use std::sync::Arc;
 use std::time::Duration;
 
+/// Largest file `body_file` will send (10 MB).
+const MAX_BODY_FILE_BYTES: u64 = 10_485_760;
+
 /// HTTP request tool for API interactions.
 /// Supports GET, POST, PUT, DELETE methods with configurable security.
 pub struct HttpRequestTool {
@@ -12,6 +18,17 @@ pub struct HttpRequestTool {
     allowed_domains: Vec<String>,
     max_response_size: usize,
     timeout_secs: u64,
+    allow_private_ips: bool,
+    user_agent: String,
+    /// Sent with every request; values may hold `{{secret:name}}` placeholders.
+    default_headers: HashMap<String, String>,
+    /// Decrypted `[secrets.refs]` used to fill those placeholders.
+    secret_refs: HashMap<String, String>,
+}
+
+/// `User-Agent` sent when `http_request.user_agent` is unset.
+pub fn default_user_agent() -> String {
+    format!("zeroclaw/{}", env!("CARGO_PKG_VERSION"))
 }
 
 impl HttpRequestTool {
@@ -20,13 +37,71 @@ impl HttpRequestTool {
         allowed_domains: Vec<String>,
         max_response_size: usize,
         timeout_secs: u64,
+        allow_private_ips: bool,
     ) -> Self {
         Self {
             security,
             allowed_domains: normalize_allowed_domains(allowed_domains),
             max_response_size,
             timeout_secs,
+            allow_private_ips,
+            user_agent: default_user_agent(),
+            default_headers: HashMap::new(),
+            secret_refs: HashMap::new(),
+        }
+    }
+
+    /// Override the `User-Agent` header; empty keeps the default.
+    pub fn with_user_agent(mut self, user_agent: Option<&str>) -> Self {
+        if let Some(ua) = user_agent.map(str::trim).filter(|ua| !ua.is_empty()) {
+            self.user_agent = ua.to_string();
+        }
+        self
+    }
+
+    /// Headers added to every request. The model's headers replace these by
+    /// name, except ones whose value injects a `{{secret:name}}`.
+    pub fn with_default_headers(mut self, headers: HashMap<String, String>) -> Self {
+        self.default_headers = headers;
+        self
+    }
+
+    /// The `[secrets.refs]` table that default header placeholders resolve against.
+    pub fn with_secret_refs(mut self, refs: HashMap<String, String>) -> Self {
+        self.secret_refs = refs;
+        self
+    }
+
+    /// Final request headers: `User-Agent`, then the configured defaults
+    /// (secrets resolved), then the model's. The returned [`ResolvedArgs`]
+    /// scrubs injected secrets from the output.
+    fn merge_headers(
+        &self,
+        model_headers: Vec<(String, String)>,
+    ) -> anyhow::Result<(Vec<(String, String)>, ResolvedArgs)> {
+        let defaults = resolve_secret_refs(&json!(self.default_headers), &self.secret_refs)?;
+        let mut headers = vec![("User-Agent".to_string(), self.user_agent.clone())];
+        if let Some(obj) = defaults.arguments.as_object() {
+            for (key, value) in obj {
+                if let Some(value) = value.as_str() {
+                    headers.retain(|(name, _)| !name.eq_ignore_ascii_case(key));
+                    headers.push((key.clone(), value.to_string()));
+                }
+            }
         }
+
+        for (key, value) in model_headers {
+            let pinned = self
+                .default_headers
+                .iter()
+                .any(|(name, raw)| name.eq_ignore_ascii_case(&key) && contains_secret_ref(raw));
+            if pinned {
+                continue;
+            }
+            headers.retain(|(name, _)| !name.eq_ignore_ascii_case(&key));
+            headers.push((key, value));
+        }
+        Ok((headers, defaults))
     }
 
     fn validate_url(&self, raw_url: &str) -> anyhow::Result<String> {
@@ -52,8 +127,10 @@ impl HttpRequestTool {
 
         let host = extract_host(url)?;
 
-        if is_private_or_local_host(&host) {
-            anyhow::bail!("Blocked local/private host: {host}");
+        if !self.allow_private_ips && is_private_or_local_host(&host) {
+            anyhow::bail!(
+                "Blocked local/private host: {host} (set http_request.allow_private_ips = true to permit)"
+            );
         }
 
         if !host_matches_allowlist(&host, &self.allowed_domains) {
@@ -63,6 +140,34 @@ impl HttpRequestTool {
         Ok(url.to_string())
     }
 
+    /// Resolve the URL's host and refuse it if any address is loopback,
+    /// link-local, private or otherwise non-global. Catches allowlisted names
+    /// that point inward (e.g. at `169.254.169.254`). The checked addresses
+    /// are returned so the request can be pinned to them, which stops a DNS
+    /// answer from changing between this check and the connect.
+    ///
+    /// Through a proxy the proxy resolves the name, so a local lookup may
+    /// fail outright and pinning would be ignored anyway. `proxied` skips
+    /// the lookup; only the literal-IP and local-name checks in
+    /// `validate_url` apply then.
+    async fn resolve_public_addrs(
+        &self,
+        url: &str,
+        proxied: bool,
+    ) -> anyhow::Result<Option<(String, Vec<std::net::SocketAddr>)>> {
+        if self.allow_private_ips || proxied {
+            return Ok(None);
+        }
+
+        let host = extract_host(url)?;
+        let addrs: Vec<std::net::SocketAddr> = tokio::net::lookup_host((host.as_str(), 0))
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to resolve host '{host}': {e}"))?
+            .collect();
+        check_resolved_addrs(&host, &addrs)?;
+        Ok(Some((host, addrs)))
+    }
+
     fn validate_method(&self, method: &str) -> anyhow::Result<reqwest::Method> {
         match method.to_uppercase().as_str() {
             "GET" => Ok(reqwest::Method::GET),
@@ -112,28 +217,94 @@ impl HttpRequestTool {
         url: &str,
         method: reqwest::Method,
         headers: Vec<(String, String)>,
-        body: Option<&str>,
+        body: Option<Vec<u8>>,
+        pinned: Option<&(String, Vec<std::net::SocketAddr>)>,
     ) -> anyhow::Result<reqwest::Response> {
-        let builder = reqwest::Client::builder()
+        // reqwest advertises `Accept-Encoding: gzip, deflate` and decodes the
+        // body before we read it, so truncation applies to the decoded text.
+        let mut builder = reqwest::Client::builder()
             .timeout(Duration::from_secs(self.timeout_secs))
             .connect_timeout(Duration::from_secs(10))
+            .gzip(true)
+            .deflate(true)
             .redirect(reqwest::redirect::Policy::none());
+        if let Some((host, addrs)) = pinned {
+            builder = builder.resolve_to_addrs(host, addrs);
+        }
         let builder = crate::config::apply_runtime_proxy_to_builder(builder, "tool.http_request");
         let client = builder.build()?;
 
         let mut request = client.request(method, url);
 
         for (key, value) in headers {
+            // A caller-supplied Accept-Encoding could invite an encoding
+            // (e.g. br) that we cannot decode; keep reqwest's own.
+            if key.eq_ignore_ascii_case("accept-encoding") {
+                continue;
+            }
             request = request.header(&key, &value);
         }
 
-        if let Some(body_str) = body {
-            request = request.body(body_str.to_string());
+        if let Some(body) = body {
+            request = request.body(body);
         }
 
         Ok(request.send().await?)
     }
 
+    /// Read a workspace file to send as the request body, applying the same
+    /// path checks as the file tools.
+    async fn read_body_file(&self, path: &str) -> Result<Vec<u8>, String> {
+        if !self.security.is_path_allowed(path) {
+            return Err(format!("Path not allowed by security policy: {path}"));
+        }
+        // Resolve before reading to block symlink escapes.
+        let resolved = tokio::fs::canonicalize(self.security.workspace_dir.join(path))
+            .await
+            .map_err(|e| format!("Failed to resolve body_file: {e}"))?;
+        if !self.security.is_resolved_path_allowed(&resolved) {
+            return Err(format!(
+                "Resolved path escapes workspace: {}",
+                resolved.display()
+            ));
+        }
+        let meta = tokio::fs::metadata(&resolved)
+            .await
+            .map_err(|e| format!("Failed to read file metadata: {e}"))?;
+        if meta.len() > MAX_BODY_FILE_BYTES {
+            return Err(format!(
+                "body_file too large: {} bytes (max {MAX_BODY_FILE_BYTES} bytes)",
+                meta.len()
+            ));
+        }
+        tokio::fs::read(&resolved)
+            .await
+            .map_err(|e| format!("Failed to read body_file: {e}"))
+    }
+
+    /// Content-Type for a `body_file`, guessed from its extension.
+    fn content_type_for_file(path: &str) -> &'static str {
+        match Path::new(path)
+            .extension()
+            .and_then(|e| e.to_str())
+            .map(str::to_ascii_lowercase)
+            .as_deref()
+        {
+            Some("json") => "application/json",
+            Some("xml") => "application/xml",
+            Some("yaml" | "yml") => "application/yaml",
+            Some("txt" | "md") => "text/plain; charset=utf-8",
+            Some("csv") => "text/csv",
+            Some("html" | "htm") => "text/html",
+            Some("form") => "application/x-www-form-urlencoded",
+            Some("png") => "image/png",
+            Some("jpg" | "jpeg") => "image/jpeg",
+            Some("pdf") => "application/pdf",
+            Some("zip") => "application/zip",
+            _ => "application/octet-stream",
+        }
+    }
+
     fn truncate_response(&self, text: &str) -> String {
         if text.len() > self.max_response_size {
             let mut truncated = text
@@ -159,6 +330,10 @@ impl Tool for HttpRequestTool {
         Security constraints: allowlist-only domains, no local/private hosts, configurable timeout and response size limits."
     }
 
+    fn category(&self) -> &str {
+        "web"
+    }
+
     fn parameters_schema(&self) -> serde_json::Value {
         json!({
             "type": "object",
@@ -180,6 +355,14 @@ impl Tool for HttpRequestTool {
                 "body": {
                     "type": "string",
                     "description": "Optional request body (for POST, PUT, PATCH requests)"
+                },
+                "body_file": {
+                    "type": "string",
+                    "description": "Send this workspace file (relative path) as the request body instead of 'body'"
+                },
+                "content_type": {
+                    "type": "string",
+                    "description": "Content-Type for the body; for body_file it is inferred from the extension when omitted. A Content-Type in 'headers' takes precedence"
                 }
             },
             "required": ["url"]
@@ -195,12 +378,34 @@ impl Tool for HttpRequestTool {
         let method_str = args.get("method").and_then(|v| v.as_str()).unwrap_or("GET");
         let headers_val = args.get("headers").cloned().unwrap_or(json!({}));
         let body = args.get("body").and_then(|v| v.as_str());
+        let body_file = args
+            .get("body_file")
+            .and_then(|v| v.as_str())
+            .map(str::trim)
+            .filter(|p| !p.is_empty());
+        let content_type_arg = args
+            .get("content_type")
+            .and_then(|v| v.as_str())
+            .map(str::trim)
+            .filter(|ct| !ct.is_empty());
+
+        if body.is_some() && body_file.is_some() {
+            return Ok(ToolResult {
+                success: false,
+                output: String::new(),
+                error: Some("Provide either 'body' or 'body_file', not both".into()),
+                metadata: None,
+                content_type: None,
+            });
+        }
 
         if !self.security.can_act() {
             return Ok(ToolResult {
                 success: false,
                 output: String::new(),
                 error: Some("Action blocked: autonomy is read-only".into()),
+                metadata: None,
+                content_type: None,
             });
         }
 
@@ -209,6 +414,8 @@ impl Tool for HttpRequestTool {
                 success: false,
                 output: String::new(),
                 error: Some("Action blocked: rate limit exceeded".into()),
+                metadata: None,
+                content_type: None,
             });
         }
 
@@ -219,6 +426,8 @@ impl Tool for HttpRequestTool {
                     success: false,
                     output: String::new(),
                     error: Some(e.to_string()),
+                    metadata: None,
+                    content_type: None,
                 })
             }
         };
@@ -230,33 +439,81 @@ impl Tool for HttpRequestTool {
                     success: false,
                     output: String::new(),
                     error: Some(e.to_string()),
+                    metadata: None,
+                    content_type: None,
+                })
+            }
+        };
+
+        let proxied = proxy_routes_http_request(&crate::config::runtime_proxy_config());
+        let pinned = match self.resolve_public_addrs(&url, proxied).await {
+            Ok(v) => v,
+            Err(e) => {
+                return Ok(ToolResult {
+                    success: false,
+                    output: String::new(),
+                    error: Some(e.to_string()),
+                    metadata: None,
+                    content_type: None,
                 })
             }
         };
 
-        let request_headers = self.parse_headers(&headers_val);
+        let body = match body_file {
+            Some(path) => match self.read_body_file(path).await {
+                Ok(bytes) => Some(bytes),
+                Err(e) => {
+                    return Ok(ToolResult {
+                        success: false,
+                        output: String::new(),
+                        error: Some(e),
+                        metadata: None,
+                        content_type: None,
+                    })
+                }
+            },
+            None => body.map(|b| b.as_bytes().to_vec()),
+        };
+
+        let mut model_headers = self.parse_headers(&headers_val);
+        let body_content_type =
+            content_type_arg.or_else(|| body_file.map(Self::content_type_for_file));
+        if let Some(content_type) = body_content_type {
+            if !model_headers
+                .iter()
+                .any(|(k, _)| k.eq_ignore_ascii_case("content-type"))
+            {
+                model_headers.push(("Content-Type".into(), content_type.to_string()));
+            }
+        }
+
+        let (request_headers, injected) = match self.merge_headers(model_headers) {
+            Ok(v) => v,
+            Err(e) => {
+                return Ok(ToolResult {
+                    success: false,
+                    output: String::new(),
+                    error: Some(e.to_string()),
+                    metadata: None,
+                    content_type: None,
+                })
+            }
+        };
 
         match self
-            .execute_request(&url, method, request_headers, body)
+            .execute_request(&url, method, request_headers, body, pinned.as_ref())
             .await
         {
             Ok(response) => {
                 let status = response.status();
                 let status_code = status.as_u16();
+                let content_type = response
+                    .headers()
+                    .get(reqwest::header::CONTENT_TYPE)
+                    .and_then(|v| v.to_str().ok())
+                    .map(String::from);
 
-                // Get response headers (redact sensitive ones)
-                let response_headers = response.headers().iter();
-                let headers_text = response_headers
-                    .map(|(k, _)| {
-                        let is_sensitive = k.as_str().to_lowercase().contains("set-cookie");
-                        if is_sensitive {
-                            format!("{}: ***REDACTED***", k.as_str())
-                        } else {
-                            format!("{}: {:?}", k.as_str(), k.as_str())
-                        }
-                    })
-                    .collect::<Vec<_>>()
-                    .join(", ");
+                let headers_text = format_response_headers(response.headers());
 
                 // Get response body with size limit
                 let response_text = match response.text().await {
@@ -264,13 +521,13 @@ impl Tool for HttpRequestTool {
                     Err(e) => format!("[Failed to read response body: {e}]"),
                 };
 
-                let output = format!(
-                    "Status: {} {}\nResponse Headers: {}\n\nResponse Body:\n{}",
+                let output = injected.redact(&format!(
+                    "Status: {} {}\nResponse Headers:\n{}\n\nResponse Body:\n{}",
                     status_code,
                     status.canonical_reason().unwrap_or("Unknown"),
                     headers_text,
                     response_text
-                );
+                ));
 
                 Ok(ToolResult {
                     success: status.is_success(),
@@ -280,17 +537,52 @@ impl Tool for HttpRequestTool {
                     } else {
                         None
                     },
+                    metadata: Some(json!({ "status": status_code })),
+                    content_type,
                 })
             }
             Err(e) => Ok(ToolResult {
                 success: false,
                 output: String::new(),
-                error: Some(format!("HTTP request failed: {e}")),
+                error: Some(injected.redact(&format!("HTTP request failed: {e}"))),
+                metadata: None,
+                content_type: None,
             }),
         }
     }
 }
 
+/// Render response headers one per line (`name: value`) so the model can
+/// read `Location`, `Link`, rate-limit and similar headers. Cookies and
+/// credential-looking headers are redacted.
+fn format_response_headers(headers: &reqwest::header::HeaderMap) -> String {
+    if headers.is_empty() {
+        return "(none)".into();
+    }
+
+    headers
+        .iter()
+        .map(|(name, value)| {
+            let name = name.as_str();
+            let is_sensitive = name.contains("cookie")
+                || name.contains("authorization")
+                || name.contains("api-key")
+                || name.contains("apikey")
+                || name.contains("token")
+                || name.contains("secret");
+            if is_sensitive {
+                format!("{name}: ***REDACTED***")
+            } else {
+                match value.to_str() {
+                    Ok(v) => format!("{name}: {v}"),
+                    Err(_) => format!("{name}: <non-UTF-8 value>"),
+                }
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 // Helper functions similar to browser_open.rs
 
 fn normalize_allowed_domains(domains: Vec<String>) -> Vec<String> {
@@ -370,6 +662,31 @@ fn extract_host(url: &str) -> anyhow::Result<String> {
     Ok(host)
 }
 
+fn check_resolved_addrs(host: &str, addrs: &[std::net::SocketAddr]) -> anyhow::Result<()> {
+    if addrs.is_empty() {
+        anyhow::bail!("Host '{host}' did not resolve to any address");
+    }
+
+    // Every address must be public: one internal entry is enough to reach it.
+    if let Some(addr) = addrs.iter().find(|addr| match addr.ip() {
+        std::net::IpAddr::V4(v4) => is_non_global_v4(v4),
+        std::net::IpAddr::V6(v6) => is_non_global_v6(v6),
+    }) {
+        anyhow::bail!(
+            "Blocked host '{host}': resolves to non-public address {} (set http_request.allow_private_ips = true to permit)",
+            addr.ip()
+        );
+    }
+
+    Ok(())
+}
+
+/// Whether `apply_runtime_proxy_to_builder` sends this tool's requests
+/// through a proxy.
+fn proxy_routes_http_request(proxy: &crate::config::ProxyConfig) -> bool {
+    proxy.should_apply_to_service("tool.http_request") && proxy.has_any_proxy_url()
+}
+
 fn host_matches_allowlist(host: &str, allowed_domains: &[String]) -> bool {
     allowed_domains.iter().any(|domain| {
         host == domain
@@ -389,7 +706,7 @@ fn is_private_or_local_host(host: &str) -> bool {
     let has_local_tld = bare
         .rsplit('.')
         .next()
-        .is_some_and(|label| label == "local");
+        .is_some_and(|label| label == "local" || label == "internal");
 
     if bare == "localhost" || bare.ends_with(".localhost") || has_local_tld {
         return true;
@@ -449,9 +766,153 @@ mod tests {
             allowed_domains.into_iter().map(String::from).collect(),
             1_000_000,
             30,
+            false,
         )
     }
 
+    #[test]
+    fn default_headers_apply_and_model_headers_override_by_name() {
+        let tool = test_tool(vec!["example.com"])
+            .with_user_agent(Some("my-bot/1.0"))
+            .with_default_headers(HashMap::from([
+                ("Accept".to_string(), "application/json".to_string()),
+                ("X-Team".to_string(), "infra".to_string()),
+            ]));
+        let (headers, _) = tool
+            .merge_headers(vec![("accept".into(), "text/plain".into())])
+            .unwrap();
+
+        let get = |name: &str| {
+            headers
+                .iter()
+                .filter(|(k, _)| k.eq_ignore_ascii_case(name))
+                .map(|(_, v)| v.as_str())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(get("user-agent"), ["my-bot/1.0"]);
+        assert_eq!(get("accept"), ["text/plain"]);
+        assert_eq!(get("x-team"), ["infra"]);
+
+        let (headers, _) = test_tool(vec!["example.com"])
+            .with_user_agent(Some("  "))
+            .merge_headers(Vec::new())
+            .unwrap();
+        assert_eq!(headers, [("User-Agent".to_string(), default_user_agent())]);
+    }
+
+    #[test]
+    fn default_header_secrets_resolve_against_configured_refs() {
+        let tool = test_tool(vec!["example.com"]).with_default_headers(HashMap::from([(
+            "Authorization".to_string(),
+            "Bearer {{secret:api}}".to_string(),
+        )]));
+        assert!(tool.merge_headers(Vec::new()).is_err());
+
+        let tool = tool.with_secret_refs(HashMap::from([(
+            "api".to_string(),
+            "tok_live_123".to_string(),
+        )]));
+        let (headers, resolved) = tool
+            .merge_headers(vec![("Authorization".into(), "Bearer model".into())])
+            .unwrap();
+        assert!(headers.contains(&(
+            "Authorization".to_string(),
+            "Bearer tok_live_123".to_string()
+        )));
+        assert_eq!(resolved.redact("echo tok_live_123"), "echo [REDACTED]");
+    }
+
+    #[tokio::test]
+    async fn gzip_response_is_decoded_before_reading() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        // gzip of `{"status":"ok"}`
+        const GZIP_BODY: [u8; 35] = [
+            0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x03, 0xab, 0x56, 0x2a, 0x2e,
+            0x49, 0x2c, 0x29, 0x2d, 0x56, 0xb2, 0x52, 0xca, 0xcf, 0x56, 0xaa, 0x05, 0x00, 0xdd,
+            0xed, 0x13, 0x1d, 0x0f, 0x00, 0x00, 0x00,
+        ];
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buf = vec![0u8; 4096];
+            let n = socket.read(&mut buf).await.unwrap();
+            let request = String::from_utf8_lossy(&buf[..n]).to_ascii_lowercase();
+            let head = format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Encoding: gzip\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                GZIP_BODY.len()
+            );
+            socket.write_all(head.as_bytes()).await.unwrap();
+            socket.write_all(&GZIP_BODY).await.unwrap();
+            request
+        });
+
+        let tool = test_tool(vec!["example.com"]);
+        let response = tool
+            .execute_request(
+                &format!("http://{addr}/data"),
+                reqwest::Method::GET,
+                vec![("Accept-Encoding".into(), "br".into())],
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.text().await.unwrap(), r#"{"status":"ok"}"#);
+
+        let request = server.await.unwrap();
+        assert!(request.contains("accept-encoding: gzip"));
+        assert!(!request.contains("accept-encoding: br"));
+    }
+
+    #[tokio::test]
+    async fn body_file_is_read_from_workspace_and_exclusive_with_body() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("payload.json"), br#"{"a":1}"#).unwrap();
+        let security = Arc::new(SecurityPolicy {
+            autonomy: AutonomyLevel::Supervised,
+            workspace_dir: dir.path().to_path_buf(),
+            ..SecurityPolicy::default()
+        });
+        let tool = HttpRequestTool::new(security, vec!["example.com".into()], 1_000_000, 30, false);
+
+        assert_eq!(
+            tool.read_body_file("payload.json").await.unwrap(),
+            br#"{"a":1}"#
+        );
+        assert!(tool
+            .read_body_file("/etc/passwd")
+            .await
+            .unwrap_err()
+            .contains("not allowed"));
+        assert!(tool
+            .read_body_file("missing.json")
+            .await
+            .unwrap_err()
+            .contains("Failed to resolve"));
+
+        let result = tool
+            .execute(json!({
+                "url": "https://example.com",
+                "method": "POST",
+                "body": "inline",
+                "body_file": "payload.json"
+            }))
+            .await
+            .unwrap();
+        assert!(result.error.unwrap().contains("not both"));
+
+        assert_eq!(
+            HttpRequestTool::content_type_for_file("data/Report.JSON"),
+            "application/json"
+        );
+        assert_eq!(
+            HttpRequestTool::content_type_for_file("blob.bin"),
+            "application/octet-stream"
+        );
+    }
+
     #[test]
     fn normalize_domain_strips_scheme_path_and_case() {
         let got = normalize_domain("  HTTPS://Docs.Example.com/path ").unwrap();
@@ -540,7 +1001,7 @@ mod tests {
     #[test]
     fn validate_requires_allowlist() {
         let security = Arc::new(SecurityPolicy::default());
-        let tool = HttpRequestTool::new(security, vec![], 1_000_000, 30);
+        let tool = HttpRequestTool::new(security, vec![], 1_000_000, 30, false);
         let err = tool
             .validate_url("https://example.com")
             .unwrap_err()
@@ -656,7 +1117,7 @@ mod tests {
             autonomy: AutonomyLevel::ReadOnly,
             ..SecurityPolicy::default()
         });
-        let tool = HttpRequestTool::new(security, vec!["example.com".into()], 1_000_000, 30);
+        let tool = HttpRequestTool::new(security, vec!["example.com".into()], 1_000_000, 30, false);
         let result = tool
             .execute(json!({"url": "https://example.com"}))
             .await
@@ -671,7 +1132,7 @@ mod tests {
             max_actions_per_hour: 0,
             ..SecurityPolicy::default()
         });
-        let tool = HttpRequestTool::new(security, vec!["example.com".into()], 1_000_000, 30);
+        let tool = HttpRequestTool::new(security, vec!["example.com".into()], 1_000_000, 30, false);
         let result = tool
             .execute(json!({"url": "https://example.com"}))
             .await
@@ -687,6 +1148,82 @@ mod tests {
         assert_eq!(tool.truncate_response(text), "hello world");
     }
 
+    #[test]
+    fn check_resolved_addrs_blocks_any_internal_address() {
+        let public: std::net::SocketAddr = "93.184.216.34:0".parse().unwrap();
+        let metadata: std::net::SocketAddr = "169.254.169.254:0".parse().unwrap();
+        let loopback_v6: std::net::SocketAddr = "[::1]:0".parse().unwrap();
+
+        assert!(check_resolved_addrs("example.com", &[public]).is_ok());
+        let err = check_resolved_addrs("example.com", &[public, metadata])
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("169.254.169.254"));
+        assert!(err.contains("allow_private_ips"));
+        assert!(check_resolved_addrs("example.com", &[loopback_v6]).is_err());
+        assert!(check_resolved_addrs("example.com", &[]).is_err());
+    }
+
+    #[tokio::test]
+    async fn resolve_public_addrs_blocks_names_pointing_inward() {
+        let tool = test_tool(vec!["localhost"]);
+        let err = tool
+            .resolve_public_addrs("http://localhost:8080/admin", false)
+            .await
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("Blocked host 'localhost'"));
+    }
+
+    #[tokio::test]
+    async fn proxied_requests_skip_local_dns_but_keep_literal_checks() {
+        let proxy = crate::config::ProxyConfig {
+            enabled: true,
+            all_proxy: Some("http://127.0.0.1:3128".into()),
+            ..crate::config::ProxyConfig::default()
+        };
+        assert!(proxy_routes_http_request(&proxy));
+        assert!(!proxy_routes_http_request(
+            &crate::config::ProxyConfig::default()
+        ));
+
+        let tool = test_tool(vec![
+            "unresolvable.invalid",
+            "metadata.google.internal",
+            "localhost",
+            "10.0.0.1",
+        ]);
+        // Only the proxy can resolve this name; a local lookup would fail.
+        assert!(tool
+            .resolve_public_addrs("https://unresolvable.invalid/", true)
+            .await
+            .unwrap()
+            .is_none());
+        assert!(tool
+            .validate_url("http://metadata.google.internal/computeMetadata/v1/")
+            .is_err());
+        assert!(tool.validate_url("http://localhost:8080/").is_err());
+        assert!(tool.validate_url("http://10.0.0.1/").is_err());
+    }
+
+    #[tokio::test]
+    async fn allow_private_ips_permits_local_hosts() {
+        let tool = HttpRequestTool::new(
+            Arc::new(SecurityPolicy::default()),
+            vec!["localhost".into(), "127.0.0.1".into()],
+            1_000_000,
+            30,
+            true,
+        );
+        assert!(tool.validate_url("http://localhost:8080/health").is_ok());
+        assert!(tool.validate_url("http://127.0.0.1/").is_ok());
+        assert!(tool
+            .resolve_public_addrs("http://localhost:8080/health", false)
+            .await
+            .unwrap()
+            .is_none());
+    }
+
     #[test]
     fn truncate_response_over_limit() {
         let tool = HttpRequestTool::new(
@@ -694,6 +1231,7 @@ mod tests {
             vec!["example.com".into()],
             10,
             30,
+            false,
         );
         let text = "hello world this is long";
         let truncated = tool.truncate_response(text);
@@ -722,6 +1260,30 @@ mod tests {
             .any(|(k, v)| k == "Content-Type" && v == "application/json"));
     }
 
+    #[test]
+    fn format_response_headers_lists_values_and_redacts_cookies() {
+        use reqwest::header::{HeaderMap, HeaderValue};
+
+        let mut headers = HeaderMap::new();
+        headers.insert("content-type", HeaderValue::from_static("application/json"));
+        headers.insert(
+            "link",
+            HeaderValue::from_static("<https://api.example.com/items?page=2>; rel=\"next\""),
+        );
+        headers.insert("x-ratelimit-remaining", HeaderValue::from_static("42"));
+        headers.insert("set-cookie", HeaderValue::from_static("session=abc123"));
+
+        let text = format_response_headers(&headers);
+        assert!(text.contains("content-type: application/json"));
+        assert!(text.contains("link: <https://api.example.com/items?page=2>; rel=\"next\""));
+        assert!(text.contains("x-ratelimit-remaining: 42"));
+        assert!(text.contains("set-cookie: ***REDACTED***"));
+        assert!(!text.contains("abc123"));
+        assert_eq!(text.lines().count(), 4);
+
+        assert_eq!(format_response_headers(&HeaderMap::new()), "(none)");
+    }
+
     #[test]
     fn redact_headers_for_display_redacts_sensitive() {
         let headers = vec![
@@ -128,6 +128,10 @@ impl Tool for ImageInfoTool {
         "Read image file metadata (format, dimensions, size) and optionally return base64-encoded data."
     }
 
+    fn category(&self) -> &str {
+        "media"
+    }
+
     fn parameters_schema(&self) -> serde_json::Value {
         json!({
             "type": "object",
@@ -166,6 +170,8 @@ impl Tool for ImageInfoTool {
                 error: Some(format!(
                     "Path not allowed: {path_str} (must be within workspace)"
                 )),
+                metadata: None,
+                content_type: None,
             });
         }
 
@@ -174,6 +180,8 @@ impl Tool for ImageInfoTool {
                 success: false,
                 output: String::new(),
                 error: Some(format!("File not found: {path_str}")),
+                metadata: None,
+                content_type: None,
             });
         }
 
@@ -190,6 +198,8 @@ impl Tool for ImageInfoTool {
                 error: Some(format!(
                     "Image too large: {file_size} bytes (max {MAX_IMAGE_BYTES} bytes)"
                 )),
+                metadata: None,
+                content_type: None,
             });
         }
 
@@ -224,6 +234,8 @@ impl Tool for ImageInfoTool {
             success: true,
             output,
             error: None,
+            metadata: None,
+            content_type: None,
         })
     }
 }
@@ -0,0 +1,237 @@
+use super::traits::{Tool, ToolResult};
+use crate::security::SecurityPolicy;
+use async_trait::async_trait;
+use serde_json::json;
+use std::path::Path;
+use std::sync::Arc;
+
+/// Maximum image size we will hand to the OCR engine (10 MB).
+const MAX_OCR_IMAGE_BYTES: u64 = 10_485_760;
+/// Maximum extracted text returned to the model (characters).
+const MAX_OCR_OUTPUT_CHARS: usize = 50_000;
+/// Tesseract language used when none is given.
+const DEFAULT_OCR_LANG: &str = "eng";
+
+/// Extract text from an image file with Tesseract OCR.
+///
+/// Lets text-only models read screenshots (pairs with `screenshot`).
+/// Requires the `ocr` build feature and a system `libtesseract` with the
+/// requested language data installed.
+pub struct ImageOcrTool {
+    security: Arc<SecurityPolicy>,
+}
+
+impl ImageOcrTool {
+    pub fn new(security: Arc<SecurityPolicy>) -> Self {
+        Self { security }
+    }
+
+    /// Tesseract language specs look like `eng` or `eng+deu`.
+    fn is_valid_lang(lang: &str) -> bool {
+        !lang.is_empty()
+            && lang.len() <= 64
+            && lang
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '+')
+    }
+}
+
+#[cfg(feature = "ocr")]
+fn run_ocr(path: &Path, lang: &str) -> anyhow::Result<String> {
+    let path = path
+        .to_str()
+        .ok_or_else(|| anyhow::anyhow!("Image path is not valid UTF-8"))?;
+    Ok(tesseract::ocr(path, lang)?)
+}
+
+#[cfg(not(feature = "ocr"))]
+fn run_ocr(_path: &Path, _lang: &str) -> anyhow::Result<String> {
+    anyhow::bail!("OCR support not compiled in; rebuild with --features ocr")
+}
+
+#[async_trait]
+impl Tool for ImageOcrTool {
+    fn name(&self) -> &str {
+        "image_ocr"
+    }
+
+    fn description(&self) -> &str {
+        "Extract text from an image file (e.g. a screenshot) using OCR."
+    }
+
+    fn category(&self) -> &str {
+        "media"
+    }
+
+    fn parameters_schema(&self) -> serde_json::Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "path": {
+                    "type": "string",
+                    "description": "Path to the image file (relative to workspace)"
+                },
+                "lang": {
+                    "type": "string",
+                    "description": "Tesseract language code(s), e.g. 'eng' or 'eng+deu' (default: eng)"
+                }
+            },
+            "required": ["path"]
+        })
+    }
+
+    async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
+        let path = args
+            .get("path")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| anyhow::anyhow!("Missing 'path' parameter"))?;
+        let lang = args
+            .get("lang")
+            .and_then(|v| v.as_str())
+            .map(str::trim)
+            .filter(|l| !l.is_empty())
+            .unwrap_or(DEFAULT_OCR_LANG)
+            .to_string();
+
+        if !Self::is_valid_lang(&lang) {
+            return Ok(ToolResult::err(format!("Invalid OCR language: {lang}")));
+        }
+
+        if self.security.is_rate_limited() {
+            return Ok(ToolResult::err(
+                "Rate limit exceeded: too many actions in the last hour",
+            ));
+        }
+
+        if !self.security.is_path_allowed(path) {
+            return Ok(ToolResult::err(format!(
+                "Path not allowed by security policy: {path}"
+            )));
+        }
+
+        if !self.security.record_action() {
+            return Ok(ToolResult::err(
+                "Rate limit exceeded: action budget exhausted",
+            ));
+        }
+
+        let full_path = self.security.workspace_dir.join(path);
+
+        // Resolve before reading to block symlink escapes.
+        let resolved_path = match tokio::fs::canonicalize(&full_path).await {
+            Ok(p) => p,
+            Err(e) => {
+                return Ok(ToolResult::err(format!(
+                    "Failed to resolve image path: {e}"
+                )));
+            }
+        };
+
+        if !self.security.is_resolved_path_allowed(&resolved_path) {
+            return Ok(ToolResult::err(format!(
+                "Resolved path escapes workspace: {}",
+                resolved_path.display()
+            )));
+        }
+
+        match tokio::fs::metadata(&resolved_path).await {
+            Ok(meta) if meta.len() > MAX_OCR_IMAGE_BYTES => {
+                return Ok(ToolResult::err(format!(
+                    "Image too large: {} bytes (max {MAX_OCR_IMAGE_BYTES} bytes)",
+                    meta.len()
+                )));
+            }
+            Ok(_) => {}
+            Err(e) => {
+                return Ok(ToolResult::err(format!(
+                    "Failed to read file metadata: {e}"
+                )));
+            }
+        }
+
+        let text = match tokio::task::spawn_blocking(move || run_ocr(&resolved_path, &lang)).await?
+        {
+            Ok(text) => text,
+            Err(e) => return Ok(ToolResult::err(format!("OCR failed: {e}"))),
+        };
+
+        let text = text.trim();
+        let output = if text.is_empty() {
+            "No text detected in image".to_string()
+        } else if text.chars().count() > MAX_OCR_OUTPUT_CHARS {
+            let truncated: String = text.chars().take(MAX_OCR_OUTPUT_CHARS).collect();
+            format!("{truncated}\n... [OCR output truncated at {MAX_OCR_OUTPUT_CHARS} chars]")
+        } else {
+            text.to_string()
+        };
+
+        Ok(ToolResult::ok(output))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::security::AutonomyLevel;
+
+    fn test_security(workspace: std::path::PathBuf) -> Arc<SecurityPolicy> {
+        Arc::new(SecurityPolicy {
+            autonomy: AutonomyLevel::Supervised,
+            workspace_dir: workspace,
+            ..SecurityPolicy::default()
+        })
+    }
+
+    #[test]
+    fn image_ocr_name_and_schema() {
+        let tool = ImageOcrTool::new(test_security(std::env::temp_dir()));
+        assert_eq!(tool.name(), "image_ocr");
+        let schema = tool.parameters_schema();
+        assert_eq!(schema["required"], json!(["path"]));
+        assert!(schema["properties"]["lang"].is_object());
+    }
+
+    #[test]
+    fn lang_validation() {
+        assert!(ImageOcrTool::is_valid_lang("eng"));
+        assert!(ImageOcrTool::is_valid_lang("eng+deu"));
+        assert!(ImageOcrTool::is_valid_lang("chi_sim"));
+        assert!(!ImageOcrTool::is_valid_lang(""));
+        assert!(!ImageOcrTool::is_valid_lang("../eng"));
+        assert!(!ImageOcrTool::is_valid_lang("eng; rm"));
+    }
+
+    #[tokio::test]
+    async fn image_ocr_rejects_path_outside_workspace() {
+        let dir = tempfile::tempdir().unwrap();
+        let tool = ImageOcrTool::new(test_security(dir.path().to_path_buf()));
+        let result = tool.execute(json!({"path": "/etc/passwd"})).await.unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("not allowed"));
+
+        let result = tool
+            .execute(json!({"path": "../outside.png"}))
+            .await
+            .unwrap();
+        assert!(!result.success);
+    }
+
+    #[tokio::test]
+    async fn image_ocr_rejects_invalid_lang_and_missing_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let tool = ImageOcrTool::new(test_security(dir.path().to_path_buf()));
+
+        let result = tool
+            .execute(json!({"path": "shot.png", "lang": "eng/../x"}))
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("Invalid OCR language"));
+
+        let result = tool.execute(json!({"path": "missing.png"})).await.unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("Failed to resolve"));
+
+        assert!(tool.execute(json!({})).await.is_err());
+    }
+}
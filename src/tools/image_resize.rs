@@ -0,0 +1,384 @@
+use super::traits::{Tool, ToolResult};
+use crate::security::SecurityPolicy;
+use async_trait::async_trait;
+use serde_json::json;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Maximum source image size we will decode (20 MB).
+const MAX_RESIZE_IMAGE_BYTES: u64 = 20_971_520;
+/// Largest edge (pixels) a caller may ask for.
+const MAX_TARGET_EDGE: u64 = 16_384;
+
+/// Write a downscaled copy of a workspace image.
+///
+/// Screenshots are often larger than a vision model's input limit; this
+/// shrinks them to fit (never upscaling) and can return the result as a
+/// base64 data URI. Requires the `image-resize` build feature.
+pub struct ImageResizeTool {
+    security: Arc<SecurityPolicy>,
+}
+
+impl ImageResizeTool {
+    pub fn new(security: Arc<SecurityPolicy>) -> Self {
+        Self { security }
+    }
+
+    /// Largest size within `max_width` x `max_height` that keeps the aspect
+    /// ratio. Images already inside the bounds keep their size.
+    fn fit_within(width: u32, height: u32, max_width: u32, max_height: u32) -> (u32, u32) {
+        if width <= max_width && height <= max_height {
+            return (width, height);
+        }
+        let scale = f64::min(
+            f64::from(max_width) / f64::from(width),
+            f64::from(max_height) / f64::from(height),
+        );
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let scaled = |edge: u32| ((f64::from(edge) * scale).round() as u32).max(1);
+        (scaled(width), scaled(height))
+    }
+
+    /// `shots/screen.png` -> `shots/screen.resized.png`
+    fn default_output_path(path: &str) -> String {
+        let path = Path::new(path);
+        let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("image");
+        let name = match path.extension().and_then(|e| e.to_str()) {
+            Some(ext) => format!("{stem}.resized.{ext}"),
+            None => format!("{stem}.resized.png"),
+        };
+        path.with_file_name(name).to_string_lossy().into_owned()
+    }
+
+    fn mime_for(path: &Path) -> &'static str {
+        match path
+            .extension()
+            .and_then(|e| e.to_str())
+            .map(str::to_ascii_lowercase)
+            .as_deref()
+        {
+            Some("png") => "image/png",
+            Some("jpg" | "jpeg") => "image/jpeg",
+            Some("gif") => "image/gif",
+            Some("webp") => "image/webp",
+            Some("bmp") => "image/bmp",
+            _ => "application/octet-stream",
+        }
+    }
+
+    fn edge_arg(args: &serde_json::Value, key: &str) -> Result<Option<u32>, String> {
+        match args.get(key) {
+            None | Some(serde_json::Value::Null) => Ok(None),
+            Some(value) => match value.as_u64() {
+                Some(edge) if (1..=MAX_TARGET_EDGE).contains(&edge) => Ok(u32::try_from(edge).ok()),
+                _ => Err(format!(
+                    "'{key}' must be an integer between 1 and {MAX_TARGET_EDGE}"
+                )),
+            },
+        }
+    }
+}
+
+#[cfg(feature = "image-resize")]
+fn resize_image(
+    src: &Path,
+    dest: &Path,
+    max_width: u32,
+    max_height: u32,
+) -> anyhow::Result<((u32, u32), (u32, u32))> {
+    let img = image::open(src)?;
+    let original = (img.width(), img.height());
+    let target = ImageResizeTool::fit_within(original.0, original.1, max_width, max_height);
+    let resized = if target == original {
+        img
+    } else {
+        img.resize_exact(target.0, target.1, image::imageops::FilterType::Lanczos3)
+    };
+    resized.save(dest)?;
+    Ok((original, target))
+}
+
+#[cfg(not(feature = "image-resize"))]
+fn resize_image(
+    _src: &Path,
+    _dest: &Path,
+    _max_width: u32,
+    _max_height: u32,
+) -> anyhow::Result<((u32, u32), (u32, u32))> {
+    anyhow::bail!("Image resizing not compiled in; rebuild with --features image-resize")
+}
+
+#[async_trait]
+impl Tool for ImageResizeTool {
+    fn name(&self) -> &str {
+        "image_resize"
+    }
+
+    fn description(&self) -> &str {
+        "Write a downscaled copy of an image (e.g. a screenshot) so it fits a vision model's size limit; optionally return it as base64."
+    }
+
+    fn category(&self) -> &str {
+        "media"
+    }
+
+    fn parameters_schema(&self) -> serde_json::Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "path": {
+                    "type": "string",
+                    "description": "Path to the source image (relative to workspace)"
+                },
+                "max_edge": {
+                    "type": "integer",
+                    "description": "Maximum width and height in pixels; aspect ratio is kept"
+                },
+                "width": {
+                    "type": "integer",
+                    "description": "Maximum width in pixels (overrides max_edge for width)"
+                },
+                "height": {
+                    "type": "integer",
+                    "description": "Maximum height in pixels (overrides max_edge for height)"
+                },
+                "output_path": {
+                    "type": "string",
+                    "description": "Where to write the copy (default: <name>.resized.<ext> next to the source); the extension picks the format"
+                },
+                "include_base64": {
+                    "type": "boolean",
+                    "description": "Include the resized image as a base64 data URI (default: false)"
+                }
+            },
+            "required": ["path"]
+        })
+    }
+
+    async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
+        let path = args
+            .get("path")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| anyhow::anyhow!("Missing 'path' parameter"))?;
+        let include_base64 = args
+            .get("include_base64")
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false);
+
+        let (max_edge, width, height) = match (
+            Self::edge_arg(&args, "max_edge"),
+            Self::edge_arg(&args, "width"),
+            Self::edge_arg(&args, "height"),
+        ) {
+            (Ok(max_edge), Ok(width), Ok(height)) => (max_edge, width, height),
+            (Err(e), _, _) | (_, Err(e), _) | (_, _, Err(e)) => return Ok(ToolResult::err(e)),
+        };
+        if max_edge.is_none() && width.is_none() && height.is_none() {
+            return Ok(ToolResult::err(
+                "Provide 'max_edge', 'width' or 'height' to size the copy",
+            ));
+        }
+        let max_width = width.or(max_edge).unwrap_or(u32::MAX);
+        let max_height = height.or(max_edge).unwrap_or(u32::MAX);
+
+        let output_path = args
+            .get("output_path")
+            .and_then(|v| v.as_str())
+            .map(str::trim)
+            .filter(|p| !p.is_empty())
+            .map_or_else(|| Self::default_output_path(path), str::to_string);
+
+        if !self.security.can_act() {
+            return Ok(ToolResult::err("Action blocked: autonomy is read-only"));
+        }
+
+        if self.security.is_rate_limited() {
+            return Ok(ToolResult::err(
+                "Rate limit exceeded: too many actions in the last hour",
+            ));
+        }
+
+        for candidate in [path, output_path.as_str()] {
+            if !self.security.is_path_allowed(candidate) {
+                return Ok(ToolResult::err(format!(
+                    "Path not allowed by security policy: {candidate}"
+                )));
+            }
+        }
+
+        if !self.security.record_action() {
+            return Ok(ToolResult::err(
+                "Rate limit exceeded: action budget exhausted",
+            ));
+        }
+
+        // Resolve before reading/writing to block symlink escapes.
+        let source = match tokio::fs::canonicalize(self.security.workspace_dir.join(path)).await {
+            Ok(p) => p,
+            Err(e) => {
+                return Ok(ToolResult::err(format!(
+                    "Failed to resolve image path: {e}"
+                )));
+            }
+        };
+        let dest = self.security.workspace_dir.join(&output_path);
+        let dest_parent = match dest.parent() {
+            Some(parent) => tokio::fs::canonicalize(parent).await,
+            None => Err(std::io::Error::other("missing parent directory")),
+        };
+        let dest: PathBuf = match (dest_parent, dest.file_name()) {
+            (Ok(parent), Some(name)) => parent.join(name),
+            (Err(e), _) => {
+                return Ok(ToolResult::err(format!(
+                    "Failed to resolve output path: {e}"
+                )));
+            }
+            (_, None) => return Ok(ToolResult::err("Invalid output path: missing file name")),
+        };
+
+        for resolved in [&source, &dest] {
+            if !self.security.is_resolved_path_allowed(resolved) {
+                return Ok(ToolResult::err(format!(
+                    "Resolved path escapes workspace: {}",
+                    resolved.display()
+                )));
+            }
+        }
+
+        // Refuse to overwrite through an existing symlink at the output path.
+        if let Ok(meta) = tokio::fs::symlink_metadata(&dest).await {
+            if meta.file_type().is_symlink() {
+                return Ok(ToolResult::err(format!(
+                    "Refusing to write through symlink: {}",
+                    dest.display()
+                )));
+            }
+        }
+
+        match tokio::fs::metadata(&source).await {
+            Ok(meta) if meta.len() > MAX_RESIZE_IMAGE_BYTES => {
+                return Ok(ToolResult::err(format!(
+                    "Image too large: {} bytes (max {MAX_RESIZE_IMAGE_BYTES} bytes)",
+                    meta.len()
+                )));
+            }
+            Ok(_) => {}
+            Err(e) => {
+                return Ok(ToolResult::err(format!(
+                    "Failed to read file metadata: {e}"
+                )));
+            }
+        }
+
+        let write_to = dest.clone();
+        let ((orig_w, orig_h), (new_w, new_h)) = match tokio::task::spawn_blocking(move || {
+            resize_image(&source, &write_to, max_width, max_height)
+        })
+        .await?
+        {
+            Ok(sizes) => sizes,
+            Err(e) => return Ok(ToolResult::err(format!("Resize failed: {e}"))),
+        };
+
+        let written = tokio::fs::read(&dest).await?;
+        let mut output = format!(
+            "Wrote {output_path} ({new_w}x{new_h}, {} bytes; source {orig_w}x{orig_h})",
+            written.len()
+        );
+        if include_base64 {
+            use base64::Engine;
+            let encoded = base64::engine::general_purpose::STANDARD.encode(&written);
+            let _ = write!(output, "\ndata:{};base64,{encoded}", Self::mime_for(&dest));
+        }
+
+        Ok(ToolResult::ok(output))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::security::AutonomyLevel;
+
+    fn test_security(workspace: std::path::PathBuf) -> Arc<SecurityPolicy> {
+        Arc::new(SecurityPolicy {
+            autonomy: AutonomyLevel::Supervised,
+            workspace_dir: workspace,
+            ..SecurityPolicy::default()
+        })
+    }
+
+    #[test]
+    fn fit_within_keeps_aspect_ratio_and_never_upscales() {
+        assert_eq!(
+            ImageResizeTool::fit_within(3840, 2160, 1568, 1568),
+            (1568, 882)
+        );
+        assert_eq!(
+            ImageResizeTool::fit_within(1000, 3000, 1000, 1500),
+            (500, 1500)
+        );
+        assert_eq!(
+            ImageResizeTool::fit_within(800, 600, 1568, 1568),
+            (800, 600)
+        );
+        assert_eq!(ImageResizeTool::fit_within(4000, 2, 100, 100), (100, 1));
+        assert_eq!(
+            ImageResizeTool::default_output_path("shots/screen.png"),
+            "shots/screen.resized.png"
+        );
+    }
+
+    #[tokio::test]
+    async fn image_resize_validates_args_and_paths() {
+        let dir = tempfile::tempdir().unwrap();
+        let tool = ImageResizeTool::new(test_security(dir.path().to_path_buf()));
+
+        let result = tool.execute(json!({"path": "shot.png"})).await.unwrap();
+        assert!(result.error.unwrap().contains("max_edge"));
+
+        let result = tool
+            .execute(json!({"path": "shot.png", "max_edge": 0}))
+            .await
+            .unwrap();
+        assert!(result.error.unwrap().contains("between 1 and"));
+
+        let result = tool
+            .execute(json!({"path": "shot.png", "max_edge": 512, "output_path": "/etc/x.png"}))
+            .await
+            .unwrap();
+        assert!(result.error.unwrap().contains("not allowed"));
+
+        let result = tool
+            .execute(json!({"path": "missing.png", "max_edge": 512}))
+            .await
+            .unwrap();
+        assert!(result.error.unwrap().contains("Failed to resolve"));
+
+        assert!(tool.execute(json!({})).await.is_err());
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn image_resize_refuses_symlinked_output_path() {
+        let dir = tempfile::tempdir().unwrap();
+        let outside = tempfile::tempdir().unwrap();
+        tokio::fs::write(dir.path().join("shot.png"), b"png")
+            .await
+            .unwrap();
+        std::os::unix::fs::symlink(
+            outside.path().join("target.png"),
+            dir.path().join("out.png"),
+        )
+        .unwrap();
+        let tool = ImageResizeTool::new(test_security(dir.path().to_path_buf()));
+
+        let result = tool
+            .execute(json!({"path": "shot.png", "max_edge": 512, "output_path": "out.png"}))
+            .await
+            .unwrap();
+        assert!(result.error.unwrap().contains("symlink"));
+        assert!(!outside.path().join("target.png").exists());
+    }
+}
@@ -28,6 +28,10 @@ impl Tool for MemoryForgetTool {
         "Remove a memory by key. Use to delete outdated facts or sensitive data. Returns whether the memory was found and removed."
     }
 
+    fn category(&self) -> &str {
+        "memory"
+    }
+
     fn parameters_schema(&self) -> serde_json::Value {
         json!({
             "type": "object",
@@ -55,6 +59,8 @@ impl Tool for MemoryForgetTool {
                 success: false,
                 output: String::new(),
                 error: Some(error),
+                metadata: None,
+                content_type: None,
             });
         }
 
@@ -63,16 +69,22 @@ impl Tool for MemoryForgetTool {
                 success: true,
                 output: format!("Forgot memory: {key}"),
                 error: None,
+                metadata: None,
+                content_type: None,
             }),
             Ok(false) => Ok(ToolResult {
                 success: true,
                 output: format!("No memory found with key: {key}"),
                 error: None,
+                metadata: None,
+                content_type: None,
             }),
             Err(e) => Ok(ToolResult {
                 success: false,
                 output: String::new(),
                 error: Some(format!("Failed to forget memory: {e}")),
+                metadata: None,
+                content_type: None,
             }),
         }
     }
@@ -26,6 +26,10 @@ impl Tool for MemoryRecallTool {
         "Search long-term memory for relevant facts, preferences, or context. Returns scored results ranked by relevance."
     }
 
+    fn category(&self) -> &str {
+        "memory"
+    }
+
     fn parameters_schema(&self) -> serde_json::Value {
         json!({
             "type": "object",
@@ -60,6 +64,8 @@ impl Tool for MemoryRecallTool {
                 success: true,
                 output: "No memories found matching that query.".into(),
                 error: None,
+                metadata: None,
+                content_type: None,
             }),
             Ok(entries) => {
                 let mut output = format!("Found {} memories:\n", entries.len());
@@ -77,12 +83,16 @@ impl Tool for MemoryRecallTool {
                     success: true,
                     output,
                     error: None,
+                    metadata: None,
+                    content_type: None,
                 })
             }
             Err(e) => Ok(ToolResult {
                 success: false,
                 output: String::new(),
                 error: Some(format!("Memory recall failed: {e}")),
+                metadata: None,
+                content_type: None,
             }),
         }
     }
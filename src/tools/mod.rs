@@ -1,5 +1,7 @@
 pub mod browser;
+pub mod browser_fetch;
 pub mod browser_open;
+pub mod clipboard;
 pub mod composio;
 pub mod cron_add;
 pub mod cron_list;
@@ -16,6 +18,8 @@ pub mod hardware_memory_map;
 pub mod hardware_memory_read;
 pub mod http_request;
 pub mod image_info;
+pub mod image_ocr;
+pub mod image_resize;
 pub mod memory_forget;
 pub mod memory_recall;
 pub mod memory_store;
@@ -23,13 +27,19 @@ pub mod proxy_config;
 pub mod pushover;
 pub mod schedule;
 pub mod schema;
+pub mod scratchpad;
 pub mod screenshot;
+pub mod self_info;
+pub mod set_preference;
 pub mod shell;
 pub mod traits;
+pub mod wait;
 pub mod web_search_tool;
 
 pub use browser::{BrowserTool, ComputerUseConfig};
+pub use browser_fetch::BrowserFetchTool;
 pub use browser_open::BrowserOpenTool;
+pub use clipboard::ClipboardTool;
 pub use composio::ComposioTool;
 pub use cron_add::CronAddTool;
 pub use cron_list::CronListTool;
@@ -46,6 +56,8 @@ pub use hardware_memory_map::HardwareMemoryMapTool;
 pub use hardware_memory_read::HardwareMemoryReadTool;
 pub use http_request::HttpRequestTool;
 pub use image_info::ImageInfoTool;
+pub use image_ocr::ImageOcrTool;
+pub use image_resize::ImageResizeTool;
 pub use memory_forget::MemoryForgetTool;
 pub use memory_recall::MemoryRecallTool;
 pub use memory_store::MemoryStoreTool;
@@ -54,11 +66,15 @@ pub use pushover::PushoverTool;
 pub use schedule::ScheduleTool;
 #[allow(unused_imports)]
 pub use schema::{CleaningStrategy, SchemaCleanr};
+pub use scratchpad::{new_scratchpad, ScratchGetTool, ScratchSetTool, Scratchpad};
 pub use screenshot::ScreenshotTool;
+pub use self_info::SelfInfoTool;
+pub use set_preference::SetPreferenceTool;
 pub use shell::ShellTool;
 pub use traits::Tool;
 #[allow(unused_imports)]
-pub use traits::{ToolResult, ToolSpec};
+pub use traits::{category_heading, group_by_category, ToolOutputCallback, ToolResult, ToolSpec};
+pub use wait::WaitTool;
 pub use web_search_tool::WebSearchTool;
 
 use crate::config::{Config, DelegateAgentConfig};
@@ -79,7 +95,10 @@ pub fn default_tools_with_runtime(
     runtime: Arc<dyn RuntimeAdapter>,
 ) -> Vec<Box<dyn Tool>> {
     vec![
-        Box::new(ShellTool::new(security.clone(), runtime)),
+        Box::new(
+            ShellTool::new(security.clone(), runtime)
+                .with_denied_patterns(&crate::config::ShellConfig::default().denied_patterns),
+        ),
         Box::new(FileReadTool::new(security.clone())),
         Box::new(FileWriteTool::new(security)),
     ]
@@ -133,7 +152,10 @@ pub fn all_tools_with_runtime(
     root_config: &crate::config::Config,
 ) -> Vec<Box<dyn Tool>> {
     let mut tools: Vec<Box<dyn Tool>> = vec![
-        Box::new(ShellTool::new(security.clone(), runtime)),
+        Box::new(
+            ShellTool::new(security.clone(), runtime)
+                .with_denied_patterns(&root_config.shell.denied_patterns),
+        ),
         Box::new(FileReadTool::new(security.clone())),
         Box::new(FileWriteTool::new(security.clone())),
         Box::new(CronAddTool::new(config.clone(), security.clone())),
@@ -155,8 +177,13 @@ pub fn all_tools_with_runtime(
             security.clone(),
             workspace_dir.to_path_buf(),
         )),
+        Box::new(WaitTool::new(root_config.agent.max_wait_secs)),
     ];
 
+    if !root_config.agent.ephemeral {
+        tools.push(Box::new(SetPreferenceTool::new(security.clone())));
+    }
+
     if browser_config.enabled {
         // Add legacy browser_open tool for simple URL opening
         tools.push(Box::new(BrowserOpenTool::new(
@@ -184,15 +211,29 @@ pub fn all_tools_with_runtime(
         )));
     }
 
-    if http_config.enabled {
-        tools.push(Box::new(HttpRequestTool::new(
+    if browser_config.fetch_enabled {
+        tools.push(Box::new(BrowserFetchTool::new(
             security.clone(),
-            http_config.allowed_domains.clone(),
-            http_config.max_response_size,
-            http_config.timeout_secs,
+            browser_config.allowed_domains.clone(),
+            browser_config.fetch_max_chars,
         )));
     }
 
+    if http_config.enabled {
+        tools.push(Box::new(
+            HttpRequestTool::new(
+                security.clone(),
+                http_config.allowed_domains.clone(),
+                http_config.max_response_size,
+                http_config.timeout_secs,
+                http_config.allow_private_ips,
+            )
+            .with_user_agent(http_config.user_agent.as_deref())
+            .with_default_headers(http_config.default_headers.clone())
+            .with_secret_refs(root_config.secrets.refs.clone()),
+        ));
+    }
+
     // Web search tool (enabled by default for GLM and other models)
     if root_config.web_search.enabled {
         tools.push(Box::new(WebSearchTool::new(
@@ -206,6 +247,17 @@ pub fn all_tools_with_runtime(
     // Vision tools are always available
     tools.push(Box::new(ScreenshotTool::new(security.clone())));
     tools.push(Box::new(ImageInfoTool::new(security.clone())));
+    if cfg!(feature = "ocr") {
+        tools.push(Box::new(ImageOcrTool::new(security.clone())));
+    }
+    if cfg!(feature = "image-resize") {
+        tools.push(Box::new(ImageResizeTool::new(security.clone())));
+    }
+
+    // Clipboard is opt-in at build time and in policy (it often holds secrets)
+    if cfg!(feature = "clipboard") && security.can_access_clipboard() {
+        tools.push(Box::new(ClipboardTool::new(security.clone())));
+    }
 
     if let Some(key) = composio_key {
         if !key.is_empty() {
@@ -227,16 +279,36 @@ pub fn all_tools_with_runtime(
             let trimmed_value = value.trim();
             (!trimmed_value.is_empty()).then(|| trimmed_value.to_owned())
         });
-        tools.push(Box::new(DelegateTool::new(
-            delegate_agents,
-            delegate_fallback_credential,
-            security.clone(),
-        )));
+        // One scratchpad shared by the parent and every delegated sub-agent.
+        let scratchpad = new_scratchpad();
+        tools.push(Box::new(
+            DelegateTool::new(
+                delegate_agents,
+                delegate_fallback_credential,
+                security.clone(),
+            )
+            .with_scratchpad(scratchpad.clone())
+            .with_provider_options(crate::providers::ProviderRuntimeOptions {
+                azure_openai: root_config.azure_openai.clone(),
+                ..crate::providers::ProviderRuntimeOptions::default()
+            }),
+        ));
+        tools.push(Box::new(ScratchSetTool::new(scratchpad.clone())));
+        tools.push(Box::new(ScratchGetTool::new(scratchpad)));
     }
 
     tools
 }
 
+/// Append the `self_info` tool, listing `tools` plus itself. Call it once the
+/// registry is final: after peripheral tools are added and any profile
+/// filter has run.
+pub fn register_self_info(tools: &mut Vec<Box<dyn Tool>>, config: &Config) {
+    let mut tool_names: Vec<String> = tools.iter().map(|t| t.name().to_string()).collect();
+    tool_names.push("self_info".to_string());
+    tools.push(Box::new(SelfInfoTool::new(config, tool_names)));
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -258,6 +330,24 @@ mod tests {
         assert_eq!(tools.len(), 3);
     }
 
+    #[tokio::test]
+    async fn self_info_lists_the_final_registry() {
+        let mut tools = default_tools(Arc::new(SecurityPolicy::default()));
+        tools.retain(|tool| tool.name() != "file_write");
+        register_self_info(&mut tools, &Config::default());
+
+        let self_info = tools.last().unwrap();
+        assert_eq!(self_info.name(), "self_info");
+        let output = self_info
+            .execute(serde_json::json!({}))
+            .await
+            .unwrap()
+            .output;
+        assert!(output.contains("\"file_read\""));
+        assert!(output.contains("\"self_info\""));
+        assert!(!output.contains("\"file_write\""));
+    }
+
     #[test]
     fn all_tools_excludes_browser_when_disabled() {
         let tmp = TempDir::new().unwrap();
@@ -296,6 +386,7 @@ mod tests {
         assert!(names.contains(&"schedule"));
         assert!(names.contains(&"pushover"));
         assert!(names.contains(&"proxy_config"));
+        assert!(names.contains(&"wait"));
     }
 
     #[test]
@@ -337,6 +428,44 @@ mod tests {
         assert!(names.contains(&"proxy_config"));
     }
 
+    #[test]
+    fn all_tools_includes_browser_fetch_independently_of_browser_open() {
+        let tmp = TempDir::new().unwrap();
+        let security = Arc::new(SecurityPolicy::default());
+        let mem_cfg = MemoryConfig {
+            backend: "markdown".into(),
+            ..MemoryConfig::default()
+        };
+        let mem: Arc<dyn Memory> =
+            Arc::from(crate::memory::create_memory(&mem_cfg, tmp.path(), None).unwrap());
+
+        let browser = BrowserConfig {
+            enabled: false,
+            fetch_enabled: true,
+            allowed_domains: vec!["example.com".into()],
+            ..BrowserConfig::default()
+        };
+        let http = crate::config::HttpRequestConfig::default();
+        let cfg = test_config(&tmp);
+
+        let tools = all_tools(
+            Arc::new(Config::default()),
+            &security,
+            mem,
+            None,
+            None,
+            &browser,
+            &http,
+            tmp.path(),
+            &HashMap::new(),
+            None,
+            &cfg,
+        );
+        let names: Vec<&str> = tools.iter().map(|t| t.name()).collect();
+        assert!(names.contains(&"browser_fetch"));
+        assert!(!names.contains(&"browser_open"));
+    }
+
     #[test]
     fn default_tools_names() {
         let security = Arc::new(SecurityPolicy::default());
@@ -397,6 +526,8 @@ mod tests {
             success: true,
             output: "hello".into(),
             error: None,
+            metadata: None,
+            content_type: None,
         };
         let json = serde_json::to_string(&result).unwrap();
         let parsed: ToolResult = serde_json::from_str(&json).unwrap();
@@ -411,6 +542,8 @@ mod tests {
             success: false,
             output: String::new(),
             error: Some("boom".into()),
+            metadata: None,
+            content_type: None,
         };
         let json = serde_json::to_string(&result).unwrap();
         let parsed: ToolResult = serde_json::from_str(&json).unwrap();
@@ -456,6 +589,7 @@ mod tests {
                 api_key: None,
                 temperature: None,
                 max_depth: 3,
+                allowed_models: Vec::new(),
             },
         );
 
@@ -474,6 +608,8 @@ mod tests {
         );
         let names: Vec<&str> = tools.iter().map(|t| t.name()).collect();
         assert!(names.contains(&"delegate"));
+        assert!(names.contains(&"scratch_set"));
+        assert!(names.contains(&"scratch_get"));
     }
 
     #[test]
@@ -506,5 +642,6 @@ mod tests {
         );
         let names: Vec<&str> = tools.iter().map(|t| t.name()).collect();
         assert!(!names.contains(&"delegate"));
+        assert!(!names.contains(&"scratch_set"));
     }
 }
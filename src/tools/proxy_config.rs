@@ -44,6 +44,8 @@ impl ProxyConfigTool {
                 success: false,
                 output: String::new(),
                 error: Some("Action blocked: autonomy is read-only".into()),
+                metadata: None,
+                content_type: None,
             });
         }
 
@@ -52,6 +54,8 @@ impl ProxyConfigTool {
                 success: false,
                 output: String::new(),
                 error: Some("Action blocked: rate limit exceeded".into()),
+                metadata: None,
+                content_type: None,
             });
         }
 
@@ -149,6 +153,8 @@ impl ProxyConfigTool {
                 "environment": Self::env_snapshot(),
             }))?,
             error: None,
+            metadata: None,
+            content_type: None,
         })
     }
 
@@ -165,6 +171,8 @@ impl ProxyConfigTool {
                 }
             }))?,
             error: None,
+            metadata: None,
+            content_type: None,
         })
     }
 
@@ -260,6 +268,8 @@ impl ProxyConfigTool {
                 "environment": Self::env_snapshot(),
             }))?,
             error: None,
+            metadata: None,
+            content_type: None,
         })
     }
 
@@ -287,6 +297,8 @@ impl ProxyConfigTool {
                 "environment": Self::env_snapshot(),
             }))?,
             error: None,
+            metadata: None,
+            content_type: None,
         })
     }
 
@@ -317,6 +329,8 @@ impl ProxyConfigTool {
                 "environment": Self::env_snapshot(),
             }))?,
             error: None,
+            metadata: None,
+            content_type: None,
         })
     }
 
@@ -329,6 +343,8 @@ impl ProxyConfigTool {
                 "environment": Self::env_snapshot(),
             }))?,
             error: None,
+            metadata: None,
+            content_type: None,
         })
     }
 }
@@ -343,6 +359,10 @@ impl Tool for ProxyConfigTool {
         "Manage ZeroClaw proxy settings (scope: environment | zeroclaw | services), including runtime and process env application"
     }
 
+    fn category(&self) -> &str {
+        "system"
+    }
+
     fn parameters_schema(&self) -> Value {
         json!({
             "type": "object",
@@ -428,6 +448,8 @@ impl Tool for ProxyConfigTool {
                 success: false,
                 output: String::new(),
                 error: Some(error.to_string()),
+                metadata: None,
+                content_type: None,
             }),
         }
     }
@@ -86,6 +86,10 @@ impl Tool for PushoverTool {
         "Send a Pushover notification to your device. Requires PUSHOVER_TOKEN and PUSHOVER_USER_KEY in .env file."
     }
 
+    fn category(&self) -> &str {
+        "integrations"
+    }
+
     fn parameters_schema(&self) -> serde_json::Value {
         json!({
             "type": "object",
@@ -117,6 +121,8 @@ impl Tool for PushoverTool {
                 success: false,
                 output: String::new(),
                 error: Some("Action blocked: autonomy is read-only".into()),
+                metadata: None,
+                content_type: None,
             });
         }
 
@@ -125,6 +131,8 @@ impl Tool for PushoverTool {
                 success: false,
                 output: String::new(),
                 error: Some("Action blocked: rate limit exceeded".into()),
+                metadata: None,
+                content_type: None,
             });
         }
 
@@ -147,6 +155,8 @@ impl Tool for PushoverTool {
                     error: Some(format!(
                         "Invalid 'priority': {value}. Expected integer in range -2..=2"
                     )),
+                    metadata: None,
+                    content_type: None,
                 })
             }
             None => None,
@@ -188,6 +198,8 @@ impl Tool for PushoverTool {
                 success: false,
                 output: body,
                 error: Some(format!("Pushover API returned status {}", status)),
+                metadata: None,
+                content_type: None,
             });
         }
 
@@ -203,12 +215,16 @@ impl Tool for PushoverTool {
                     body
                 ),
                 error: None,
+                metadata: None,
+                content_type: None,
             })
         } else {
             Ok(ToolResult {
                 success: false,
                 output: body,
                 error: Some("Pushover API returned an application-level error".into()),
+                metadata: None,
+                content_type: None,
             })
         }
     }
@@ -27,7 +27,11 @@ impl Tool for ScheduleTool {
     }
 
     fn description(&self) -> &str {
-        "Manage scheduled tasks. Actions: create/add/once/list/get/cancel/remove/pause/resume"
+        "Manage scheduled tasks: shell commands, or agent prompts (via 'prompt') whose reply can be delivered to a channel. Actions: create/add/once/list/get/history/cancel/remove/pause/resume"
+    }
+
+    fn category(&self) -> &str {
+        "scheduling"
     }
 
     fn parameters_schema(&self) -> serde_json::Value {
@@ -36,7 +40,7 @@ impl Tool for ScheduleTool {
             "properties": {
                 "action": {
                     "type": "string",
-                    "enum": ["create", "add", "once", "list", "get", "cancel", "remove", "pause", "resume"],
+                    "enum": ["create", "add", "once", "list", "get", "history", "cancel", "remove", "pause", "resume"],
                     "description": "Action to perform"
                 },
                 "expression": {
@@ -53,11 +57,27 @@ impl Tool for ScheduleTool {
                 },
                 "command": {
                     "type": "string",
-                    "description": "Shell command to execute. Required for create/add/once."
+                    "description": "Shell command to execute. Required for create/add/once unless 'prompt' is given."
+                },
+                "prompt": {
+                    "type": "string",
+                    "description": "Message to run through the agent at fire time instead of a shell command (e.g. a reminder or recurring check-in)."
+                },
+                "channel": {
+                    "type": "string",
+                    "description": "Channel to deliver the agent's reply to (telegram, discord, slack, mattermost). Only with 'prompt'."
+                },
+                "to": {
+                    "type": "string",
+                    "description": "Recipient on 'channel' (chat/channel ID). Required when 'channel' is set."
                 },
                 "id": {
                     "type": "string",
-                    "description": "Task ID. Required for get/cancel/remove/pause/resume."
+                    "description": "Task ID. Required for get/history/cancel/remove/pause/resume."
+                },
+                "limit": {
+                    "type": "integer",
+                    "description": "Most recent runs to return for history (default: 10)."
                 }
             },
             "required": ["action"]
@@ -79,6 +99,17 @@ impl Tool for ScheduleTool {
                     .ok_or_else(|| anyhow::anyhow!("Missing 'id' parameter for get action"))?;
                 self.handle_get(id)
             }
+            "history" => {
+                let id = args
+                    .get("id")
+                    .and_then(|value| value.as_str())
+                    .ok_or_else(|| anyhow::anyhow!("Missing 'id' parameter for history action"))?;
+                let limit = args
+                    .get("limit")
+                    .and_then(serde_json::Value::as_u64)
+                    .map_or(10, |v| usize::try_from(v).unwrap_or(10));
+                Ok(self.handle_history(id, limit))
+            }
             "create" | "add" | "once" => {
                 if let Some(blocked) = self.enforce_mutation_allowed(action) {
                     return Ok(blocked);
@@ -119,8 +150,8 @@ impl Tool for ScheduleTool {
                 success: false,
                 output: String::new(),
                 error: Some(format!(
-                    "Unknown action '{other}'. Use create/add/once/list/get/cancel/remove/pause/resume."
-                )),
+                    "Unknown action '{other}'. Use create/add/once/list/get/history/cancel/remove/pause/resume."
+                )), metadata: None, content_type: None,
             }),
         }
     }
@@ -135,6 +166,8 @@ impl ScheduleTool {
                 error: Some(format!(
                     "Security policy: read-only mode, cannot perform '{action}'"
                 )),
+                metadata: None,
+                content_type: None,
             });
         }
 
@@ -143,6 +176,8 @@ impl ScheduleTool {
                 success: false,
                 output: String::new(),
                 error: Some("Rate limit exceeded: action budget exhausted".to_string()),
+                metadata: None,
+                content_type: None,
             });
         }
 
@@ -156,6 +191,8 @@ impl ScheduleTool {
                 success: true,
                 output: "No scheduled jobs.".to_string(),
                 error: None,
+                metadata: None,
+                content_type: None,
             });
         }
 
@@ -173,15 +210,21 @@ impl ScheduleTool {
                 .last_run
                 .map_or_else(|| "never".to_string(), |value| value.to_rfc3339());
             let last_status = job.last_status.unwrap_or_else(|| "n/a".to_string());
+            let task = match job.job_type {
+                cron::JobType::Agent => {
+                    format!("prompt: {}", job.prompt.as_deref().unwrap_or_default())
+                }
+                cron::JobType::Shell => format!("cmd: {}", job.command),
+            };
             lines.push(format!(
-                "- {} | {} | next={} | last={} ({}){} | cmd: {}",
+                "- {} | {} | next={} | last={} ({}){} | {}",
                 job.id,
                 job.expression,
                 job.next_run.to_rfc3339(),
                 last_run,
                 last_status,
                 flags,
-                job.command
+                task
             ));
         }
 
@@ -189,6 +232,8 @@ impl ScheduleTool {
             success: true,
             output: format!("Scheduled jobs ({}):\n{}", lines.len(), lines.join("\n")),
             error: None,
+            metadata: None,
+            content_type: None,
         })
     }
 
@@ -199,6 +244,8 @@ impl ScheduleTool {
                     "id": job.id,
                     "expression": job.expression,
                     "command": job.command,
+                    "prompt": job.prompt,
+                    "delivery": job.delivery,
                     "next_run": job.next_run.to_rfc3339(),
                     "last_run": job.last_run.map(|value| value.to_rfc3339()),
                     "last_status": job.last_status,
@@ -209,22 +256,87 @@ impl ScheduleTool {
                     success: true,
                     output: serde_json::to_string_pretty(&detail)?,
                     error: None,
+                    metadata: None,
+                    content_type: None,
                 })
             }
             Err(_) => Ok(ToolResult {
                 success: false,
                 output: String::new(),
                 error: Some(format!("Job '{id}' not found")),
+                metadata: None,
+                content_type: None,
             }),
         }
     }
 
+    fn handle_history(&self, id: &str, limit: usize) -> ToolResult {
+        if cron::get_job(&self.config, id).is_err() {
+            return ToolResult {
+                success: false,
+                output: String::new(),
+                error: Some(format!("Job '{id}' not found")),
+                metadata: None,
+                content_type: None,
+            };
+        }
+        match cron::list_runs(&self.config, id, limit) {
+            Ok(runs) if runs.is_empty() => ToolResult {
+                success: true,
+                output: format!("Job {id} has not run yet."),
+                error: None,
+                metadata: None,
+                content_type: None,
+            },
+            Ok(runs) => {
+                let mut lines = Vec::with_capacity(runs.len() + 1);
+                lines.push(format!(
+                    "Last {} run(s) of job {id}, newest first:",
+                    runs.len()
+                ));
+                for run in runs {
+                    lines.push(run.summary_line());
+                }
+                ToolResult {
+                    success: true,
+                    output: lines.join("\n"),
+                    error: None,
+                    metadata: None,
+                    content_type: None,
+                }
+            }
+            Err(e) => ToolResult {
+                success: false,
+                output: String::new(),
+                error: Some(e.to_string()),
+                metadata: None,
+                content_type: None,
+            },
+        }
+    }
+
     fn handle_create_like(&self, action: &str, args: &serde_json::Value) -> Result<ToolResult> {
         let command = args
             .get("command")
             .and_then(|value| value.as_str())
-            .filter(|value| !value.trim().is_empty())
-            .ok_or_else(|| anyhow::anyhow!("Missing or empty 'command' parameter"))?;
+            .filter(|value| !value.trim().is_empty());
+        let prompt = args
+            .get("prompt")
+            .and_then(|value| value.as_str())
+            .filter(|value| !value.trim().is_empty());
+        let command = match (command, prompt) {
+            (Some(_), Some(_)) => {
+                return Ok(ToolResult {
+                    success: false,
+                    output: String::new(),
+                    error: Some("Provide either 'command' or 'prompt', not both".into()),
+                    metadata: None,
+                    content_type: None,
+                });
+            }
+            (None, None) => anyhow::bail!("Missing or empty 'command' or 'prompt' parameter"),
+            (command, _) => command.unwrap_or_default(),
+        };
 
         let expression = args.get("expression").and_then(|value| value.as_str());
         let delay = args.get("delay").and_then(|value| value.as_str());
@@ -237,6 +349,8 @@ impl ScheduleTool {
                         success: false,
                         output: String::new(),
                         error: Some("'add' requires 'expression' and forbids delay/run_at".into()),
+                        metadata: None,
+                        content_type: None,
                     });
                 }
             }
@@ -246,6 +360,8 @@ impl ScheduleTool {
                         success: false,
                         output: String::new(),
                         error: Some("'once' requires exactly one of 'delay' or 'run_at'".into()),
+                        metadata: None,
+                        content_type: None,
                     });
                 }
                 if delay.is_some() && run_at.is_some() {
@@ -253,6 +369,8 @@ impl ScheduleTool {
                         success: false,
                         output: String::new(),
                         error: Some("'once' supports either delay or run_at, not both".into()),
+                        metadata: None,
+                        content_type: None,
                     });
                 }
             }
@@ -269,55 +387,143 @@ impl ScheduleTool {
                             "Exactly one of 'expression', 'delay', or 'run_at' must be provided"
                                 .into(),
                         ),
+                        metadata: None,
+                        content_type: None,
                     });
                 }
             }
         }
 
-        if let Some(value) = expression {
-            let job = cron::add_job(&self.config, value, command)?;
-            return Ok(ToolResult {
-                success: true,
-                output: format!(
-                    "Created recurring job {} (expr: {}, next: {}, cmd: {})",
-                    job.id,
-                    job.expression,
-                    job.next_run.to_rfc3339(),
-                    job.command
-                ),
-                error: None,
-            });
+        let schedule = Self::parse_schedule(expression, delay, run_at)?;
+        if let Some(prompt) = prompt {
+            return self.create_agent_job(prompt, schedule, args);
         }
 
-        if let Some(value) = delay {
-            let job = cron::add_once(&self.config, value, command)?;
-            return Ok(ToolResult {
-                success: true,
-                output: format!(
-                    "Created one-shot job {} (runs at: {}, cmd: {})",
-                    job.id,
-                    job.next_run.to_rfc3339(),
-                    job.command
-                ),
-                error: None,
+        let job = cron::add_shell_job(&self.config, None, schedule, command)?;
+        let output = if matches!(job.schedule, cron::Schedule::At { .. }) {
+            format!(
+                "Created one-shot job {} (runs at: {}, cmd: {})",
+                job.id,
+                job.next_run.to_rfc3339(),
+                job.command
+            )
+        } else {
+            format!(
+                "Created recurring job {} (expr: {}, next: {}, cmd: {})",
+                job.id,
+                job.expression,
+                job.next_run.to_rfc3339(),
+                job.command
+            )
+        };
+        Ok(ToolResult {
+            success: true,
+            output,
+            error: None,
+            metadata: None,
+            content_type: None,
+        })
+    }
+
+    /// The schedule for a shell or agent job: a cron `expression`, a `delay`
+    /// from now, or an RFC 3339 `run_at`, checked by `handle_create_like`.
+    fn parse_schedule(
+        expression: Option<&str>,
+        delay: Option<&str>,
+        run_at: Option<&str>,
+    ) -> Result<cron::Schedule> {
+        if let Some(expr) = expression {
+            return Ok(cron::Schedule::Cron {
+                expr: expr.to_string(),
+                tz: None,
+            });
+        }
+        if let Some(delay) = delay {
+            return Ok(cron::Schedule::At {
+                at: Utc::now() + cron::parse_delay(delay)?,
             });
         }
+        let raw = run_at.ok_or_else(|| anyhow::anyhow!("Missing scheduling parameters"))?;
+        Ok(cron::Schedule::At {
+            at: DateTime::parse_from_rfc3339(raw)
+                .map_err(|error| anyhow::anyhow!("Invalid run_at timestamp: {error}"))?
+                .with_timezone(&Utc),
+        })
+    }
 
-        let run_at_raw = run_at.ok_or_else(|| anyhow::anyhow!("Missing scheduling parameters"))?;
-        let run_at_parsed: DateTime<Utc> = DateTime::parse_from_rfc3339(run_at_raw)
-            .map_err(|error| anyhow::anyhow!("Invalid run_at timestamp: {error}"))?
-            .with_timezone(&Utc);
+    /// Schedule a message for the agent itself; the cron scheduler runs it as
+    /// an agent job and, with `channel`/`to`, announces the reply there.
+    fn create_agent_job(
+        &self,
+        prompt: &str,
+        schedule: cron::Schedule,
+        args: &serde_json::Value,
+    ) -> Result<ToolResult> {
+        let channel = args.get("channel").and_then(|value| value.as_str());
+        let to = args.get("to").and_then(|value| value.as_str());
+        if let Some(channel) = channel {
+            if !cron::scheduler::DELIVERY_CHANNELS
+                .iter()
+                .any(|supported| supported.eq_ignore_ascii_case(channel))
+            {
+                return Ok(ToolResult {
+                    success: false,
+                    output: String::new(),
+                    error: Some(format!(
+                        "Unsupported delivery channel '{channel}'; use one of: {}",
+                        cron::scheduler::DELIVERY_CHANNELS.join(", ")
+                    )),
+                    metadata: None,
+                    content_type: None,
+                });
+            }
+        }
+        let delivery = match (channel, to) {
+            (Some(channel), Some(to)) => Some(cron::DeliveryConfig {
+                mode: "announce".into(),
+                channel: Some(channel.to_string()),
+                to: Some(to.to_string()),
+                best_effort: true,
+            }),
+            (None, None) => None,
+            _ => {
+                return Ok(ToolResult {
+                    success: false,
+                    output: String::new(),
+                    error: Some("'channel' and 'to' must be provided together".into()),
+                    metadata: None,
+                    content_type: None,
+                });
+            }
+        };
 
-        let job = cron::add_once_at(&self.config, run_at_parsed, command)?;
+        let one_shot = matches!(schedule, cron::Schedule::At { .. });
+        let job = cron::add_agent_job(
+            &self.config,
+            None,
+            schedule,
+            prompt,
+            cron::SessionTarget::Isolated,
+            None,
+            delivery,
+            one_shot,
+        )?;
+        let target = match (&job.delivery.channel, &job.delivery.to) {
+            (Some(channel), Some(to)) => format!(", deliver to: {channel}:{to}"),
+            _ => String::new(),
+        };
         Ok(ToolResult {
             success: true,
             output: format!(
-                "Created one-shot job {} (runs at: {}, cmd: {})",
+                "Created {} agent job {} (next: {}{target}, prompt: {})",
+                if one_shot { "one-shot" } else { "recurring" },
                 job.id,
                 job.next_run.to_rfc3339(),
-                job.command
+                prompt
             ),
             error: None,
+            metadata: None,
+            content_type: None,
         })
     }
 
@@ -327,11 +533,15 @@ impl ScheduleTool {
                 success: true,
                 output: format!("Cancelled job {id}"),
                 error: None,
+                metadata: None,
+                content_type: None,
             },
             Err(error) => ToolResult {
                 success: false,
                 output: String::new(),
                 error: Some(error.to_string()),
+                metadata: None,
+                content_type: None,
             },
         }
     }
@@ -352,11 +562,15 @@ impl ScheduleTool {
                     format!("Resumed job {id}")
                 },
                 error: None,
+                metadata: None,
+                content_type: None,
             },
             Err(error) => ToolResult {
                 success: false,
                 output: String::new(),
                 error: Some(error.to_string()),
+                metadata: None,
+                content_type: None,
             },
         }
     }
@@ -479,6 +693,120 @@ mod tests {
         assert!(resume.success);
     }
 
+    #[tokio::test]
+    async fn history_reports_recent_runs_newest_first() {
+        let (_tmp, config, security) = test_setup().await;
+        let tool = ScheduleTool::new(security, config.clone());
+
+        let add = tool
+            .execute(json!({
+                "action": "add",
+                "expression": "0 3 * * *",
+                "command": "echo backup"
+            }))
+            .await
+            .unwrap();
+        let id = add.output.split_whitespace().nth(3).unwrap();
+
+        let empty = tool
+            .execute(json!({"action": "history", "id": id}))
+            .await
+            .unwrap();
+        assert!(empty.output.contains("has not run yet"));
+
+        let start = Utc::now();
+        cron::record_run(&config, id, start, start, "ok", Some("backup done\n"), 40).unwrap();
+        let later = start + chrono::Duration::days(1);
+        cron::record_run(&config, id, later, later, "error", Some("disk full"), 12).unwrap();
+
+        let history = tool
+            .execute(json!({"action": "history", "id": id, "limit": 5}))
+            .await
+            .unwrap();
+        assert!(history.success);
+        let lines: Vec<&str> = history.output.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[1].contains("error (12 ms): disk full"));
+        assert!(lines[2].contains("ok (40 ms): backup done"));
+
+        let missing = tool
+            .execute(json!({"action": "history", "id": "nope"}))
+            .await
+            .unwrap();
+        assert!(!missing.success);
+    }
+
+    #[tokio::test]
+    async fn prompt_creates_agent_job_with_channel_delivery() {
+        let (_tmp, config, security) = test_setup().await;
+        let tool = ScheduleTool::new(security, config.clone());
+
+        let once = tool
+            .execute(json!({
+                "action": "once",
+                "delay": "1d",
+                "prompt": "Remind me to water the plants",
+                "channel": "telegram",
+                "to": "12345"
+            }))
+            .await
+            .unwrap();
+        assert!(once.success, "{:?}", once.error);
+        assert!(once.output.contains("deliver to: telegram:12345"));
+
+        let jobs = cron::list_jobs(&config).unwrap();
+        assert_eq!(jobs.len(), 1);
+        assert_eq!(jobs[0].job_type, cron::JobType::Agent);
+        assert_eq!(
+            jobs[0].prompt.as_deref(),
+            Some("Remind me to water the plants")
+        );
+        assert_eq!(jobs[0].delivery.mode, "announce");
+        assert!(jobs[0].delete_after_run);
+
+        let list = tool.execute(json!({"action": "list"})).await.unwrap();
+        assert!(list
+            .output
+            .contains("prompt: Remind me to water the plants"));
+
+        let half_delivery = tool
+            .execute(json!({
+                "action": "add",
+                "expression": "0 9 * * *",
+                "prompt": "Daily standup summary",
+                "channel": "slack"
+            }))
+            .await
+            .unwrap();
+        assert!(!half_delivery.success);
+
+        let unknown_channel = tool
+            .execute(json!({
+                "action": "add",
+                "expression": "0 9 * * *",
+                "prompt": "Daily standup summary",
+                "channel": "carrier-pigeon",
+                "to": "coop"
+            }))
+            .await
+            .unwrap();
+        assert!(unknown_channel
+            .error
+            .unwrap()
+            .contains("Unsupported delivery channel"));
+
+        let both = tool
+            .execute(json!({
+                "action": "add",
+                "expression": "0 9 * * *",
+                "prompt": "x",
+                "command": "echo x"
+            }))
+            .await
+            .unwrap();
+        assert!(!both.success);
+    }
+
     #[tokio::test]
     async fn readonly_blocks_mutating_actions() {
         let tmp = TempDir::new().unwrap();
@@ -535,10 +535,199 @@ impl SchemaCleanr {
     }
 }
 
+/// Check tool-call arguments against the tool's parameter schema: the
+/// arguments must be an object, and at every level `required` properties
+/// must be present and values must match their `type`, `enum` and (for
+/// arrays) `items`.
+///
+/// Returns a model-facing description of the first problem found, naming
+/// nested arguments by path (e.g. `headers.Accept`, `steps[0].pin`).
+pub fn validate_arguments(schema: &Value, args: &Value) -> Result<(), String> {
+    if !args.is_object() {
+        return Err(format!(
+            "arguments must be a JSON object, got {}",
+            json_type_name(args)
+        ));
+    }
+    validate_value(schema, args, "")
+}
+
+fn validate_value(schema: &Value, value: &Value, path: &str) -> Result<(), String> {
+    if !path.is_empty() {
+        if let Some(expected) = schema.get("type") {
+            let allowed: Vec<&str> = match expected {
+                Value::String(t) => vec![t.as_str()],
+                Value::Array(ts) => ts.iter().filter_map(Value::as_str).collect(),
+                _ => Vec::new(),
+            };
+            if !allowed.is_empty() && !allowed.iter().any(|t| value_has_type(value, t)) {
+                return Err(format!(
+                    "argument `{path}`: expected {}, got {}",
+                    allowed.join(" or "),
+                    json_type_name(value)
+                ));
+            }
+        }
+        if let Some(options) = schema.get("enum").and_then(Value::as_array) {
+            if !value.is_null() && !options.contains(value) {
+                let options: Vec<String> = options.iter().map(Value::to_string).collect();
+                return Err(format!(
+                    "argument `{path}`: expected one of {}, got {value}",
+                    options.join(", ")
+                ));
+            }
+        }
+    }
+
+    match value {
+        Value::Object(map) => {
+            if let Some(required) = schema.get("required").and_then(Value::as_array) {
+                for name in required.iter().filter_map(Value::as_str) {
+                    if map.get(name).map_or(true, Value::is_null) {
+                        return Err(format!(
+                            "missing required argument `{}`",
+                            join_path(path, name)
+                        ));
+                    }
+                }
+            }
+            if let Some(properties) = schema.get("properties").and_then(Value::as_object) {
+                for (name, item) in map {
+                    if let Some(property) = properties.get(name) {
+                        validate_value(property, item, &join_path(path, name))?;
+                    }
+                }
+            }
+        }
+        Value::Array(items) => {
+            if let Some(item_schema) = schema.get("items").filter(|s| s.is_object()) {
+                for (index, item) in items.iter().enumerate() {
+                    validate_value(item_schema, item, &format!("{path}[{index}]"))?;
+                }
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+fn join_path(path: &str, name: &str) -> String {
+    if path.is_empty() {
+        name.to_string()
+    } else {
+        format!("{path}.{name}")
+    }
+}
+
+fn value_has_type(value: &Value, expected: &str) -> bool {
+    match expected {
+        "string" => value.is_string(),
+        "boolean" => value.is_boolean(),
+        "object" => value.is_object(),
+        "array" => value.is_array(),
+        "null" => value.is_null(),
+        "number" => value.is_number(),
+        // `5.0` is an integer as far as JSON Schema is concerned.
+        "integer" => {
+            value.is_i64() || value.is_u64() || value.as_f64().is_some_and(|f| f.fract() == 0.0)
+        }
+        // Unknown type keyword: don't second-guess the tool.
+        _ => true,
+    }
+}
+
+fn json_type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(n) if n.is_f64() => "number",
+        Value::Number(_) => "integer",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn validate_arguments_reports_missing_and_mistyped_args() {
+        let schema = json!({
+            "type": "object",
+            "properties": {
+                "pin": {"type": "integer"},
+                "mode": {"type": "string", "enum": ["in", "out"]},
+                "label": {"type": ["string", "null"]}
+            },
+            "required": ["pin"]
+        });
+
+        assert!(validate_arguments(&schema, &json!({"pin": 13, "extra": true})).is_ok());
+        assert!(validate_arguments(&schema, &json!({"pin": 13.0, "label": null})).is_ok());
+        assert_eq!(
+            validate_arguments(&schema, &json!({"mode": "in"})).unwrap_err(),
+            "missing required argument `pin`"
+        );
+        assert_eq!(
+            validate_arguments(&schema, &json!({"pin": "13"})).unwrap_err(),
+            "argument `pin`: expected integer, got string"
+        );
+        assert_eq!(
+            validate_arguments(&schema, &json!({"pin": 1, "mode": "up"})).unwrap_err(),
+            "argument `mode`: expected one of \"in\", \"out\", got \"up\""
+        );
+        assert_eq!(
+            validate_arguments(&schema, &json!({"pin": 1, "label": 5})).unwrap_err(),
+            "argument `label`: expected string or null, got integer"
+        );
+        assert_eq!(
+            validate_arguments(&schema, &json!(["pin"])).unwrap_err(),
+            "arguments must be a JSON object, got array"
+        );
+    }
+
+    #[test]
+    fn validate_arguments_checks_nested_objects_and_array_items() {
+        let schema = json!({
+            "type": "object",
+            "properties": {
+                "headers": {
+                    "type": "object",
+                    "properties": {"Accept": {"type": "string"}}
+                },
+                "steps": {
+                    "type": "array",
+                    "items": {
+                        "type": "object",
+                        "properties": {"pin": {"type": "integer"}},
+                        "required": ["pin"]
+                    }
+                }
+            }
+        });
+
+        assert!(validate_arguments(
+            &schema,
+            &json!({"headers": {"Accept": "text/plain"}, "steps": [{"pin": 1}]})
+        )
+        .is_ok());
+        assert_eq!(
+            validate_arguments(&schema, &json!({"headers": {"Accept": 1}})).unwrap_err(),
+            "argument `headers.Accept`: expected string, got integer"
+        );
+        assert_eq!(
+            validate_arguments(&schema, &json!({"steps": [{"pin": 1}, {"pin": true}]}))
+                .unwrap_err(),
+            "argument `steps[1].pin`: expected integer, got boolean"
+        );
+        assert_eq!(
+            validate_arguments(&schema, &json!({"steps": [{}]})).unwrap_err(),
+            "missing required argument `steps[0].pin`"
+        );
+    }
+
     #[test]
     fn test_remove_unsupported_keywords() {
         let schema = json!({
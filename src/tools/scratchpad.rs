@@ -0,0 +1,267 @@
+use super::traits::{Tool, ToolResult};
+use async_trait::async_trait;
+use parking_lot::Mutex;
+use serde_json::json;
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::sync::Arc;
+
+/// Max characters accepted for a single scratchpad value.
+const MAX_SCRATCH_VALUE_CHARS: usize = 16_000;
+
+/// Shared key-value scratchpad. The parent agent and every delegated
+/// sub-agent hold a clone of the same handle, so structured findings left
+/// by one agent are visible to the next (e.g. researcher → coder).
+pub type Scratchpad = Arc<Mutex<HashMap<String, String>>>;
+
+/// Create an empty scratchpad handle.
+pub fn new_scratchpad() -> Scratchpad {
+    Arc::new(Mutex::new(HashMap::new()))
+}
+
+/// Write a value into the shared scratchpad.
+pub struct ScratchSetTool {
+    scratchpad: Scratchpad,
+}
+
+impl ScratchSetTool {
+    pub fn new(scratchpad: Scratchpad) -> Self {
+        Self { scratchpad }
+    }
+}
+
+#[async_trait]
+impl Tool for ScratchSetTool {
+    fn name(&self) -> &str {
+        "scratch_set"
+    }
+
+    fn description(&self) -> &str {
+        "Write a value to the scratchpad shared with delegated agents. Use when: leaving structured findings for another agent (or the parent) to pick up."
+    }
+
+    fn category(&self) -> &str {
+        "memory"
+    }
+
+    fn parameters_schema(&self) -> serde_json::Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "key": {
+                    "type": "string",
+                    "description": "Scratchpad key (e.g. 'api_endpoints', 'open_questions')"
+                },
+                "value": {
+                    "type": "string",
+                    "description": "Value to store; overwrites any existing value for the key"
+                }
+            },
+            "required": ["key", "value"]
+        })
+    }
+
+    async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
+        let key = args
+            .get("key")
+            .and_then(|v| v.as_str())
+            .map(str::trim)
+            .ok_or_else(|| anyhow::anyhow!("Missing 'key' parameter"))?;
+
+        let value = args
+            .get("value")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| anyhow::anyhow!("Missing 'value' parameter"))?;
+
+        if key.is_empty() {
+            return Ok(ToolResult {
+                success: false,
+                output: String::new(),
+                error: Some("'key' parameter must not be empty".into()),
+                metadata: None,
+                content_type: None,
+            });
+        }
+
+        if value.chars().count() > MAX_SCRATCH_VALUE_CHARS {
+            return Ok(ToolResult {
+                success: false,
+                output: String::new(),
+                error: Some(format!(
+                    "Value too large ({} chars, max {MAX_SCRATCH_VALUE_CHARS})",
+                    value.chars().count()
+                )),
+                metadata: None,
+                content_type: None,
+            });
+        }
+
+        self.scratchpad
+            .lock()
+            .insert(key.to_string(), value.to_string());
+
+        Ok(ToolResult {
+            success: true,
+            output: format!("Scratchpad key '{key}' set"),
+            error: None,
+            metadata: None,
+            content_type: None,
+        })
+    }
+}
+
+/// Read one key, or every entry, from the shared scratchpad.
+pub struct ScratchGetTool {
+    scratchpad: Scratchpad,
+}
+
+impl ScratchGetTool {
+    pub fn new(scratchpad: Scratchpad) -> Self {
+        Self { scratchpad }
+    }
+}
+
+#[async_trait]
+impl Tool for ScratchGetTool {
+    fn name(&self) -> &str {
+        "scratch_get"
+    }
+
+    fn description(&self) -> &str {
+        "Read the scratchpad shared with delegated agents. Omit 'key' to list every entry."
+    }
+
+    fn category(&self) -> &str {
+        "memory"
+    }
+
+    fn parameters_schema(&self) -> serde_json::Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "key": {
+                    "type": "string",
+                    "description": "Key to read; omit to return all entries"
+                }
+            }
+        })
+    }
+
+    async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
+        let key = args
+            .get("key")
+            .and_then(|v| v.as_str())
+            .map(str::trim)
+            .filter(|k| !k.is_empty());
+
+        let guard = self.scratchpad.lock();
+
+        if let Some(key) = key {
+            return Ok(match guard.get(key) {
+                Some(value) => ToolResult {
+                    success: true,
+                    output: value.clone(),
+                    error: None,
+                    metadata: None,
+                    content_type: None,
+                },
+                None => ToolResult {
+                    success: false,
+                    output: String::new(),
+                    error: Some(format!("Scratchpad key '{key}' not found")),
+                    metadata: None,
+                    content_type: None,
+                },
+            });
+        }
+
+        if guard.is_empty() {
+            return Ok(ToolResult {
+                success: true,
+                output: "Scratchpad is empty.".into(),
+                error: None,
+                metadata: None,
+                content_type: None,
+            });
+        }
+
+        let mut keys: Vec<&String> = guard.keys().collect();
+        keys.sort();
+        let mut output = format!("Scratchpad ({} entries):\n", keys.len());
+        for key in keys {
+            let _ = writeln!(output, "- {key}: {}", guard[key]);
+        }
+
+        Ok(ToolResult {
+            success: true,
+            output,
+            error: None,
+            metadata: None,
+            content_type: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn set_then_get_roundtrip() {
+        let pad = new_scratchpad();
+        let set = ScratchSetTool::new(pad.clone());
+        let get = ScratchGetTool::new(pad.clone());
+
+        let result = set
+            .execute(json!({"key": "findings", "value": "use v2 endpoint"}))
+            .await
+            .unwrap();
+        assert!(result.success);
+
+        let result = get.execute(json!({"key": "findings"})).await.unwrap();
+        assert!(result.success);
+        assert_eq!(result.output, "use v2 endpoint");
+        assert_eq!(pad.lock().get("findings").unwrap(), "use v2 endpoint");
+    }
+
+    #[tokio::test]
+    async fn get_without_key_lists_sorted_entries() {
+        let pad = new_scratchpad();
+        pad.lock().insert("b".into(), "2".into());
+        pad.lock().insert("a".into(), "1".into());
+
+        let result = ScratchGetTool::new(pad).execute(json!({})).await.unwrap();
+        assert!(result.success);
+        assert!(result.output.starts_with("Scratchpad (2 entries)"));
+        assert!(result.output.find("- a: 1").unwrap() < result.output.find("- b: 2").unwrap());
+    }
+
+    #[tokio::test]
+    async fn get_missing_key_fails() {
+        let result = ScratchGetTool::new(new_scratchpad())
+            .execute(json!({"key": "nope"}))
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("not found"));
+    }
+
+    #[tokio::test]
+    async fn set_rejects_blank_key_and_oversized_value() {
+        let set = ScratchSetTool::new(new_scratchpad());
+
+        let result = set
+            .execute(json!({"key": "  ", "value": "x"}))
+            .await
+            .unwrap();
+        assert!(!result.success);
+
+        let big = "x".repeat(MAX_SCRATCH_VALUE_CHARS + 1);
+        let result = set
+            .execute(json!({"key": "k", "value": big}))
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("too large"));
+    }
+}
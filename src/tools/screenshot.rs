@@ -77,6 +77,8 @@ impl ScreenshotTool {
                 success: false,
                 output: String::new(),
                 error: Some("Filename contains characters unsafe for shell execution".into()),
+                metadata: None,
+                content_type: None,
             });
         }
 
@@ -88,6 +90,8 @@ impl ScreenshotTool {
                 success: false,
                 output: String::new(),
                 error: Some("Screenshot not supported on this platform".into()),
+                metadata: None,
+                content_type: None,
             });
         };
 
@@ -122,13 +126,15 @@ impl ScreenshotTool {
                             error: Some(
                                 "No screenshot tool found. Install gnome-screenshot, scrot, or ImageMagick."
                                     .into(),
-                            ),
+                            ), metadata: None, content_type: None,
                         });
                     }
                     return Ok(ToolResult {
                         success: false,
                         output: String::new(),
                         error: Some(format!("Screenshot command failed: {stderr}")),
+                        metadata: None,
+                        content_type: None,
                     });
                 }
 
@@ -138,6 +144,8 @@ impl ScreenshotTool {
                 success: false,
                 output: String::new(),
                 error: Some(format!("Failed to execute screenshot command: {e}")),
+                metadata: None,
+                content_type: None,
             }),
             Err(_) => Ok(ToolResult {
                 success: false,
@@ -145,6 +153,8 @@ impl ScreenshotTool {
                 error: Some(format!(
                     "Screenshot timed out after {SCREENSHOT_TIMEOUT_SECS}s"
                 )),
+                metadata: None,
+                content_type: None,
             }),
         }
     }
@@ -162,7 +172,7 @@ impl ScreenshotTool {
                         output_path.display(),
                         meta.len(),
                     ),
-                    error: None,
+                    error: None, metadata: None, content_type: None,
                 });
             }
         }
@@ -200,12 +210,16 @@ impl ScreenshotTool {
                     success: true,
                     output: output_msg,
                     error: None,
+                    metadata: None,
+                    content_type: None,
                 })
             }
             Err(e) => Ok(ToolResult {
                 success: false,
                 output: format!("Screenshot saved to: {}", output_path.display()),
                 error: Some(format!("Failed to read screenshot file: {e}")),
+                metadata: None,
+                content_type: None,
             }),
         }
     }
@@ -221,6 +235,10 @@ impl Tool for ScreenshotTool {
         "Capture a screenshot of the current screen. Returns the file path and base64-encoded PNG data."
     }
 
+    fn category(&self) -> &str {
+        "system"
+    }
+
     fn parameters_schema(&self) -> serde_json::Value {
         json!({
             "type": "object",
@@ -243,6 +261,8 @@ impl Tool for ScreenshotTool {
                 success: false,
                 output: String::new(),
                 error: Some("Action blocked: autonomy is read-only".into()),
+                metadata: None,
+                content_type: None,
             });
         }
         self.capture(args).await
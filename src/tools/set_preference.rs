@@ -0,0 +1,146 @@
+use super::traits::{Tool, ToolResult};
+use crate::memory::preferences;
+use crate::security::policy::ToolOperation;
+use crate::security::SecurityPolicy;
+use async_trait::async_trait;
+use serde_json::json;
+use std::sync::Arc;
+
+/// Let the agent record durable user preferences, which are injected into
+/// every system prompt instead of being recalled on demand.
+pub struct SetPreferenceTool {
+    security: Arc<SecurityPolicy>,
+}
+
+impl SetPreferenceTool {
+    pub fn new(security: Arc<SecurityPolicy>) -> Self {
+        Self { security }
+    }
+}
+
+#[async_trait]
+impl Tool for SetPreferenceTool {
+    fn name(&self) -> &str {
+        "set_preference"
+    }
+
+    fn description(&self) -> &str {
+        "Set or remove a durable user preference (e.g. language, units, tone, timezone). Preferences are always included in your instructions in future sessions, so only record standing preferences the user stated, not one-off requests."
+    }
+
+    fn category(&self) -> &str {
+        "memory"
+    }
+
+    fn parameters_schema(&self) -> serde_json::Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "key": {
+                    "type": "string",
+                    "description": "Preference name (e.g. 'units', 'language', 'tone')"
+                },
+                "value": {
+                    "type": "string",
+                    "description": "Preference value (e.g. 'metric'); replaces any existing value"
+                },
+                "remove": {
+                    "type": "boolean",
+                    "description": "Remove the preference instead of setting it"
+                }
+            },
+            "required": ["key"]
+        })
+    }
+
+    async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
+        let key = args
+            .get("key")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| anyhow::anyhow!("Missing 'key' parameter"))?;
+        let remove = args
+            .get("remove")
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false);
+
+        if let Err(error) = self
+            .security
+            .enforce_tool_operation(ToolOperation::Act, "set_preference")
+        {
+            return Ok(ToolResult::err(error));
+        }
+
+        let workspace_dir = &self.security.workspace_dir;
+        if remove {
+            return Ok(match preferences::remove(workspace_dir, key) {
+                Ok(true) => ToolResult::ok(format!("Removed preference: {key}")),
+                Ok(false) => ToolResult::err(format!("No preference named '{key}'")),
+                Err(e) => ToolResult::err(format!("Failed to remove preference: {e}")),
+            });
+        }
+
+        let Some(value) = args.get("value").and_then(|v| v.as_str()) else {
+            return Ok(ToolResult::err(
+                "'value' is required unless 'remove' is true",
+            ));
+        };
+        Ok(match preferences::set(workspace_dir, key, value) {
+            Ok(()) => ToolResult::ok(format!(
+                "Saved preference: {} = {}",
+                key.trim(),
+                value.trim()
+            )),
+            Err(e) => ToolResult::err(format!("Failed to save preference: {e}")),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::security::AutonomyLevel;
+
+    fn test_security(workspace: &std::path::Path, autonomy: AutonomyLevel) -> Arc<SecurityPolicy> {
+        Arc::new(SecurityPolicy {
+            autonomy,
+            workspace_dir: workspace.to_path_buf(),
+            ..SecurityPolicy::default()
+        })
+    }
+
+    #[tokio::test]
+    async fn set_and_remove_preference() {
+        let tmp = tempfile::tempdir().unwrap();
+        let tool = SetPreferenceTool::new(test_security(tmp.path(), AutonomyLevel::Supervised));
+
+        let result = tool
+            .execute(json!({"key": "units", "value": "metric"}))
+            .await
+            .unwrap();
+        assert!(result.success, "{:?}", result.error);
+        assert_eq!(
+            preferences::load(tmp.path())
+                .get("units")
+                .map(String::as_str),
+            Some("metric")
+        );
+
+        let result = tool.execute(json!({"key": "units"})).await.unwrap();
+        assert!(!result.success);
+
+        let result = tool
+            .execute(json!({"key": "units", "remove": true}))
+            .await
+            .unwrap();
+        assert!(result.success);
+        assert!(preferences::load(tmp.path()).is_empty());
+
+        let readonly = SetPreferenceTool::new(test_security(tmp.path(), AutonomyLevel::ReadOnly));
+        let result = readonly
+            .execute(json!({"key": "units", "value": "imperial"}))
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(preferences::load(tmp.path()).is_empty());
+    }
+}
@@ -7,6 +7,38 @@ pub struct ToolResult {
     pub success: bool,
     pub output: String,
     pub error: Option<String>,
+    /// Structured details (HTTP status, exit code, bytes written, ...),
+    /// recorded on the tool's audit entry. Never sent to the model; `output` is.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<serde_json::Value>,
+    /// MIME type of the content behind `output` (e.g. the `Content-Type` of
+    /// an HTTP response), recorded on the audit entry.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>,
+}
+
+impl ToolResult {
+    /// Successful result with `output` for the model.
+    pub fn ok(output: impl Into<String>) -> Self {
+        Self {
+            success: true,
+            output: output.into(),
+            error: None,
+            metadata: None,
+            content_type: None,
+        }
+    }
+
+    /// Failed result carrying `error`.
+    pub fn err(error: impl Into<String>) -> Self {
+        Self {
+            success: false,
+            output: String::new(),
+            error: Some(error.into()),
+            metadata: None,
+            content_type: None,
+        }
+    }
 }
 
 /// Description of a tool for the LLM
@@ -17,6 +49,9 @@ pub struct ToolSpec {
     pub parameters: serde_json::Value,
 }
 
+/// Receives a tool's incremental output, one line per call.
+pub type ToolOutputCallback<'a> = dyn Fn(&str) + Send + Sync + 'a;
+
 /// Core tool trait — implement for any capability
 #[async_trait]
 pub trait Tool: Send + Sync {
@@ -26,12 +61,37 @@ pub trait Tool: Send + Sync {
     /// Human-readable description
     fn description(&self) -> &str;
 
+    /// Group this tool is listed under in the system prompt (e.g.
+    /// "filesystem", "web", "memory", "hardware").
+    fn category(&self) -> &str {
+        "other"
+    }
+
     /// JSON schema for parameters
     fn parameters_schema(&self) -> serde_json::Value;
 
+    /// Check model-supplied arguments before `execute` runs. The default
+    /// checks them against `parameters_schema`; the error is shown to the
+    /// model so it can correct the call.
+    fn validate_args(&self, args: &serde_json::Value) -> Result<(), String> {
+        super::schema::validate_arguments(&self.parameters_schema(), args)
+    }
+
     /// Execute the tool with given arguments
     async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult>;
 
+    /// Execute while reporting output lines to `on_output` as they are
+    /// produced, so long-running work shows progress. The returned result is
+    /// the same complete result `execute` would give. Defaults to `execute`
+    /// for tools that only produce output at the end.
+    async fn execute_streaming(
+        &self,
+        args: serde_json::Value,
+        _on_output: &ToolOutputCallback<'_>,
+    ) -> anyhow::Result<ToolResult> {
+        self.execute(args).await
+    }
+
     /// Get the full spec for LLM registration
     fn spec(&self) -> ToolSpec {
         ToolSpec {
@@ -42,6 +102,29 @@ pub trait Tool: Send + Sync {
     }
 }
 
+/// Group tools by [`Tool::category`] for prompt listings. Groups keep
+/// first-seen order, except "other", which always comes last.
+pub fn group_by_category(tools: &[Box<dyn Tool>]) -> Vec<(&str, Vec<&dyn Tool>)> {
+    let mut groups: Vec<(&str, Vec<&dyn Tool>)> = Vec::new();
+    for tool in tools {
+        let category = tool.category();
+        match groups.iter_mut().find(|(name, _)| *name == category) {
+            Some((_, members)) => members.push(tool.as_ref()),
+            None => groups.push((category, vec![tool.as_ref()])),
+        }
+    }
+    groups.sort_by_key(|(name, _)| *name == "other");
+    groups
+}
+
+/// Prompt heading for a category: "filesystem" -> "Filesystem".
+pub fn category_heading(category: &str) -> String {
+    let mut chars = category.chars();
+    chars.next().map_or_else(String::new, |first| {
+        first.to_uppercase().chain(chars).collect()
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -76,10 +159,61 @@ mod tests {
                     .unwrap_or_default()
                     .to_string(),
                 error: None,
+                metadata: None,
+                content_type: None,
             })
         }
     }
 
+    struct Categorized(&'static str, &'static str);
+
+    #[async_trait]
+    impl Tool for Categorized {
+        fn name(&self) -> &str {
+            self.0
+        }
+
+        fn description(&self) -> &str {
+            "categorized"
+        }
+
+        fn category(&self) -> &str {
+            self.1
+        }
+
+        fn parameters_schema(&self) -> serde_json::Value {
+            serde_json::json!({"type": "object"})
+        }
+
+        async fn execute(&self, _args: serde_json::Value) -> anyhow::Result<ToolResult> {
+            unreachable!()
+        }
+    }
+
+    #[test]
+    fn tools_group_by_category_with_other_last() {
+        let tools: Vec<Box<dyn Tool>> = vec![
+            Box::new(DummyTool),
+            Box::new(Categorized("file_read", "filesystem")),
+            Box::new(Categorized("http_request", "web")),
+            Box::new(Categorized("file_write", "filesystem")),
+        ];
+        let groups: Vec<(&str, Vec<&str>)> = group_by_category(&tools)
+            .into_iter()
+            .map(|(category, members)| (category, members.iter().map(|t| t.name()).collect()))
+            .collect();
+        assert_eq!(
+            groups,
+            [
+                ("filesystem", vec!["file_read", "file_write"]),
+                ("web", vec!["http_request"]),
+                ("other", vec!["dummy_tool"]),
+            ]
+        );
+        assert_eq!(category_heading("filesystem"), "Filesystem");
+        assert_eq!(category_heading(""), "");
+    }
+
     #[test]
     fn spec_uses_tool_metadata_and_schema() {
         let tool = DummyTool;
@@ -104,13 +238,36 @@ mod tests {
         assert!(result.error.is_none());
     }
 
+    #[tokio::test]
+    async fn execute_streaming_defaults_to_batch_execute() {
+        let tool = DummyTool;
+        let lines = std::sync::Mutex::new(Vec::<String>::new());
+        let on_output = |line: &str| lines.lock().unwrap().push(line.to_string());
+        let result = tool
+            .execute_streaming(serde_json::json!({ "value": "batch" }), &on_output)
+            .await
+            .unwrap();
+
+        assert_eq!(result.output, "batch");
+        assert!(lines.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn ok_and_err_constructors() {
+        let ok = ToolResult::ok("done");
+        assert!(ok.success);
+        assert_eq!(ok.output, "done");
+        assert!(ok.error.is_none());
+
+        let err = ToolResult::err("boom");
+        assert!(!err.success);
+        assert!(err.output.is_empty());
+        assert_eq!(err.error.as_deref(), Some("boom"));
+    }
+
     #[test]
     fn tool_result_serialization_roundtrip() {
-        let result = ToolResult {
-            success: false,
-            output: String::new(),
-            error: Some("boom".into()),
-        };
+        let result = ToolResult::err("boom");
 
         let json = serde_json::to_string(&result).unwrap();
         let parsed: ToolResult = serde_json::from_str(&json).unwrap();
@@ -118,4 +275,21 @@ mod tests {
         assert!(!parsed.success);
         assert_eq!(parsed.error.as_deref(), Some("boom"));
     }
+
+    #[test]
+    fn tool_result_metadata_is_optional_on_the_wire() {
+        let legacy: ToolResult =
+            serde_json::from_str(r#"{"success":true,"output":"ok","error":null}"#).unwrap();
+        assert!(legacy.metadata.is_none());
+        assert!(legacy.content_type.is_none());
+
+        let result = ToolResult {
+            metadata: Some(serde_json::json!({ "status": 200 })),
+            content_type: Some("application/json".into()),
+            ..ToolResult::ok("{}")
+        };
+        let json = serde_json::to_value(&result).unwrap();
+        assert_eq!(json["metadata"]["status"], 200);
+        assert_eq!(json["content_type"], "application/json");
+    }
 }
@@ -0,0 +1,133 @@
+use super::traits::{Tool, ToolResult};
+use async_trait::async_trait;
+use serde_json::json;
+use std::time::Duration;
+
+/// Pause the agent for a bounded number of seconds.
+///
+/// Portable replacement for `shell` + `sleep` in timing-dependent sequences
+/// (e.g. "LED on, wait 2s, LED off") or polling loops. Requests above
+/// `max_secs` are rejected so the model cannot stall a turn indefinitely.
+pub struct WaitTool {
+    max_secs: u64,
+}
+
+impl WaitTool {
+    pub fn new(max_secs: u64) -> Self {
+        Self { max_secs }
+    }
+}
+
+#[async_trait]
+impl Tool for WaitTool {
+    fn name(&self) -> &str {
+        "wait"
+    }
+
+    fn description(&self) -> &str {
+        "Wait for a number of seconds before continuing. Use when: timing hardware actions or polling for a state change. Don't use when: no delay is needed."
+    }
+
+    fn category(&self) -> &str {
+        "system"
+    }
+
+    fn parameters_schema(&self) -> serde_json::Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "seconds": {
+                    "type": "number",
+                    "minimum": 0,
+                    "description": format!("Seconds to wait (fractions allowed, max {})", self.max_secs)
+                }
+            },
+            "required": ["seconds"]
+        })
+    }
+
+    async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
+        let seconds = args
+            .get("seconds")
+            .and_then(serde_json::Value::as_f64)
+            .ok_or_else(|| anyhow::anyhow!("Missing 'seconds' parameter"))?;
+
+        if !seconds.is_finite() || seconds < 0.0 {
+            return Ok(ToolResult {
+                success: false,
+                output: String::new(),
+                error: Some("'seconds' must be a non-negative number".into()),
+                metadata: None,
+                content_type: None,
+            });
+        }
+
+        if seconds > self.max_secs as f64 {
+            return Ok(ToolResult {
+                success: false,
+                output: String::new(),
+                error: Some(format!(
+                    "Requested wait of {seconds}s exceeds the maximum of {}s (agent.max_wait_secs)",
+                    self.max_secs
+                )),
+                metadata: None,
+                content_type: None,
+            });
+        }
+
+        tokio::time::sleep(Duration::from_secs_f64(seconds)).await;
+
+        Ok(ToolResult {
+            success: true,
+            output: format!("Waited {seconds}s"),
+            error: None,
+            metadata: None,
+            content_type: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wait_tool_name_and_schema() {
+        let tool = WaitTool::new(60);
+        assert_eq!(tool.name(), "wait");
+        let schema = tool.parameters_schema();
+        assert_eq!(schema["required"], json!(["seconds"]));
+        assert!(schema["properties"]["seconds"]["description"]
+            .as_str()
+            .unwrap()
+            .contains("max 60"));
+    }
+
+    #[tokio::test]
+    async fn wait_sleeps_for_requested_duration() {
+        let tool = WaitTool::new(60);
+        let started = std::time::Instant::now();
+        let result = tool.execute(json!({"seconds": 0.05})).await.unwrap();
+        assert!(result.success);
+        assert_eq!(result.output, "Waited 0.05s");
+        assert!(started.elapsed() >= Duration::from_millis(50));
+    }
+
+    #[tokio::test]
+    async fn wait_rejects_values_over_cap() {
+        let tool = WaitTool::new(5);
+        let result = tool.execute(json!({"seconds": 3600})).await.unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("exceeds the maximum of 5s"));
+    }
+
+    #[tokio::test]
+    async fn wait_rejects_negative_and_missing() {
+        let tool = WaitTool::new(5);
+        let result = tool.execute(json!({"seconds": -1})).await.unwrap();
+        assert!(!result.success);
+
+        assert!(tool.execute(json!({})).await.is_err());
+        assert!(tool.execute(json!({"seconds": "2"})).await.is_err());
+    }
+}
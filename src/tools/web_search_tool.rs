@@ -191,6 +191,10 @@ impl Tool for WebSearchTool {
         "Search the web for information. Returns relevant search results with titles, URLs, and descriptions. Use this to find current information, news, or research topics."
     }
 
+    fn category(&self) -> &str {
+        "web"
+    }
+
     fn parameters_schema(&self) -> serde_json::Value {
         json!({
             "type": "object",
@@ -226,6 +230,8 @@ impl Tool for WebSearchTool {
             success: true,
             output: result,
             error: None,
+            metadata: None,
+            content_type: None,
         })
     }
 }
@@ -99,6 +99,8 @@ impl Tool for EchoTool {
             success: true,
             output: msg,
             error: None,
+            metadata: None,
+            content_type: None,
         })
     }
 }
@@ -138,6 +140,8 @@ impl Tool for CountingTool {
             success: true,
             output: format!("call #{}", *c),
             error: None,
+            metadata: None,
+            content_type: None,
         })
     }
 }
@@ -263,7 +267,7 @@ fn build_agent_xml(provider: Box<dyn Provider>, tools: Vec<Box<dyn Tool>>) -> Ag
         .tools(tools)
         .memory(make_memory())
         .observer(make_observer())
-        .tool_dispatcher(Box::new(XmlToolDispatcher))
+        .tool_dispatcher(Box::new(XmlToolDispatcher::default()))
         .workspace_dir(std::env::temp_dir())
         .build()
         .unwrap()